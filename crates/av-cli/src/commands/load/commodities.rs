@@ -0,0 +1,278 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Commodity price loader for `av-cli load commodities`.
+//!
+//! Fetches the Alpha Vantage energy, metals, and agricultural commodity
+//! series and upserts them into the `commodityprices` hypertable, which sits
+//! alongside `summaryprices`. Each commodity gets a `symbols` row
+//! (`sec_type = 'Commodity'`) the first time it is loaded, so prices are
+//! keyed by a regular SID.
+//!
+//! ## What This Loads
+//!
+//! | Commodity         | API function      | Intervals                         |
+//! |-------------------|-------------------|-----------------------------------|
+//! | `wti`             | `WTI`             | daily, weekly, monthly (default)  |
+//! | `brent`           | `BRENT`           | daily, weekly, monthly (default)  |
+//! | `natural-gas`     | `NATURAL_GAS`     | daily, weekly, monthly (default)  |
+//! | `copper`          | `COPPER`          | monthly (default), quarterly, annual |
+//! | `aluminum`        | `ALUMINUM`        | monthly (default), quarterly, annual |
+//! | `wheat`           | `WHEAT`           | monthly (default), quarterly, annual |
+//! | `corn`            | `CORN`            | monthly (default), quarterly, annual |
+//! | `cotton`          | `COTTON`          | monthly (default), quarterly, annual |
+//! | `sugar`           | `SUGAR`           | monthly (default), quarterly, annual |
+//! | `coffee`          | `COFFEE`          | monthly (default), quarterly, annual |
+//! | `all-commodities` | `ALL_COMMODITIES` | monthly (default), quarterly, annual |
+//!
+//! ## Data Flow
+//!
+//! ```text
+//! AlphaVantage commodity endpoints
+//!   │  (one call per commodity, optionally cached for 24h)
+//!   ▼
+//! CommodityPriceLoader::load()
+//!   ├── symbols          (commodity registered on first load)
+//!   └── commodityprices  (upsert on tstamp/sid/interval)
+//! ```
+//!
+//! ## Interval Override
+//!
+//! `--interval` is applied only to commodities that publish that interval;
+//! the rest keep their monthly default. `--interval daily` therefore moves
+//! the energy series to daily prices and leaves metals and agriculture
+//! monthly.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Load every commodity at its default interval
+//! av-cli load commodities
+//!
+//! # Daily crude oil prices
+//! av-cli load commodities --commodities wti,brent --interval daily --verbose
+//!
+//! # Dry run (fetch only, no database writes)
+//! av-cli load commodities --dry-run --verbose
+//! ```
+
+use anyhow::{Result, anyhow};
+use clap::{Args, ValueEnum};
+use std::sync::Arc;
+
+use av_core::FuncType;
+use av_core::types::EconomicInterval;
+use av_database_postgres::repository::DatabaseContext;
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  commodity_price_loader::{CommodityPriceConfig, CommodityPriceLoader, CommodityPriceLoaderInput},
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load commodities`.
+#[derive(Args, Debug)]
+pub struct CommoditiesArgs {
+  /// Commodities to load (comma-separated). Defaults to all eleven.
+  #[arg(long, value_enum, value_delimiter = ',')]
+  commodities: Vec<CommodityArg>,
+
+  /// Sampling interval override, applied to commodities that support it.
+  #[arg(long, value_enum)]
+  interval: Option<CommodityIntervalArg>,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print the latest price of each commodity.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 24.
+  #[arg(long, default_value = "24")]
+  cache_ttl: i64,
+}
+
+/// CLI-level selector for a commodity function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CommodityArg {
+  Wti,
+  Brent,
+  NaturalGas,
+  Copper,
+  Aluminum,
+  Wheat,
+  Corn,
+  Cotton,
+  Sugar,
+  Coffee,
+  AllCommodities,
+}
+
+impl From<CommodityArg> for FuncType {
+  fn from(arg: CommodityArg) -> Self {
+    match arg {
+      CommodityArg::Wti => FuncType::Wti,
+      CommodityArg::Brent => FuncType::Brent,
+      CommodityArg::NaturalGas => FuncType::NaturalGas,
+      CommodityArg::Copper => FuncType::Copper,
+      CommodityArg::Aluminum => FuncType::Aluminum,
+      CommodityArg::Wheat => FuncType::Wheat,
+      CommodityArg::Corn => FuncType::Corn,
+      CommodityArg::Cotton => FuncType::Cotton,
+      CommodityArg::Sugar => FuncType::Sugar,
+      CommodityArg::Coffee => FuncType::Coffee,
+      CommodityArg::AllCommodities => FuncType::AllCommodities,
+    }
+  }
+}
+
+/// CLI-level wrapper for the [`EconomicInterval`]s commodities publish.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum CommodityIntervalArg {
+  Daily,
+  Weekly,
+  Monthly,
+  Quarterly,
+  Annual,
+}
+
+impl From<CommodityIntervalArg> for EconomicInterval {
+  fn from(arg: CommodityIntervalArg) -> Self {
+    match arg {
+      CommodityIntervalArg::Daily => EconomicInterval::Daily,
+      CommodityIntervalArg::Weekly => EconomicInterval::Weekly,
+      CommodityIntervalArg::Monthly => EconomicInterval::Monthly,
+      CommodityIntervalArg::Quarterly => EconomicInterval::Quarterly,
+      CommodityIntervalArg::Annual => EconomicInterval::Annual,
+    }
+  }
+}
+
+/// Main entry point for `av-cli load commodities`.
+///
+/// Runs [`CommodityPriceLoader`] over the selected commodities and prints a
+/// per-commodity summary.
+///
+/// # Errors
+///
+/// Returns errors from API client creation, database context creation, or
+/// loader execution. Failures of individual commodities are reported in the
+/// summary rather than aborting the run.
+pub async fn execute(args: CommoditiesArgs, config: Config) -> Result<()> {
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Commodities are fetched sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = CommodityPriceConfig {
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = CommodityPriceLoader::new(loader_config, database_url);
+
+  let commodities: Vec<FuncType> = if args.commodities.is_empty() {
+    av_client::COMMODITY_FUNCTIONS.to_vec()
+  } else {
+    args.commodities.iter().map(|&arg| arg.into()).collect()
+  };
+
+  let input = CommodityPriceLoaderInput { commodities, interval: args.interval.map(Into::into) };
+  let output = loader.load(&context, input).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       COMMODITY PRICES                 ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  for result in &output.series {
+    let source = if result.from_cache { "📦" } else { "🌐" };
+    println!(
+      "{} {:<16} {:<10} {:>6} obs",
+      source,
+      result.function.to_string(),
+      result.interval,
+      result.observations
+    );
+    if args.verbose {
+      if let Some(latest) = &result.latest {
+        println!("     {} | {} {}", latest.date, latest.value, result.unit);
+      }
+    }
+  }
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Failed commodities: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Records saved: {}", output.records_saved);
+  }
+
+  Ok(())
+}
@@ -0,0 +1,267 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Dividend and split loader for `av-cli load corporate-actions`.
+//!
+//! Fetches the full dividend and split history of each security from the
+//! AlphaVantage `DIVIDENDS` and `SPLITS` endpoints and upserts it into the
+//! `corporate_actions` table, keyed by SID, action type, and ex-date.
+//!
+//! ## Symbol Selection
+//!
+//! - `--symbol`: the listed tickers, resolved to their highest-priority
+//!   non-crypto, non-commodity listing.
+//! - Otherwise: every equity with a loaded company overview
+//!   (`overview = true`), in priority order, capped by `--limit`.
+//!
+//! Each symbol costs one API call per selected action type.
+//!
+//! ## Caching
+//!
+//! Responses are cached for **7 days** by default. Both endpoints return the
+//! full history, and new declarations are infrequent.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Dividends and splits for two symbols
+//! av-cli load corporate-actions --symbol IBM,AAPL
+//!
+//! # Splits only, for the first 100 equities with overviews
+//! av-cli load corporate-actions --actions splits --limit 100
+//!
+//! # Pick up newly declared dividends
+//! av-cli load corporate-actions --symbol MSFT --force-refresh
+//! ```
+
+use anyhow::{Result, anyhow};
+use clap::{Args, ValueEnum};
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use av_database_postgres::{repository::DatabaseContext, schema::symbols};
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  corporate_actions_loader::{
+    CorporateActionSymbol, CorporateActionsConfig, CorporateActionsLoader,
+    CorporateActionsLoaderInput,
+  },
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load corporate-actions`.
+#[derive(Args, Debug)]
+pub struct CorporateActionsArgs {
+  /// Symbol(s) to load (comma-separated). Defaults to all equities with a
+  /// loaded overview.
+  #[arg(short, long, value_delimiter = ',')]
+  symbol: Vec<String>,
+
+  /// Action types to fetch (comma-separated). Defaults to both.
+  #[arg(long, value_enum, value_delimiter = ',')]
+  actions: Vec<ActionArg>,
+
+  /// Maximum number of symbols when `--symbol` is not given.
+  #[arg(short, long)]
+  limit: Option<i64>,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print one line per symbol.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 168 (7 days).
+  #[arg(long, default_value = "168")]
+  cache_ttl: i64,
+}
+
+/// CLI-level selector for a corporate action endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ActionArg {
+  Dividends,
+  Splits,
+}
+
+/// Resolves `--symbol` tickers, or selects equities with overviews when no
+/// tickers were given.
+fn resolve_symbols(
+  args: &CorporateActionsArgs,
+  config: &Config,
+) -> Result<Vec<CorporateActionSymbol>> {
+  let mut conn = PgConnection::establish(&config.database_url)?;
+
+  if args.symbol.is_empty() {
+    let mut query = symbols::table
+      .filter(symbols::sec_type.eq("Equity"))
+      .filter(symbols::overview.eq(true))
+      .order_by(symbols::priority.asc())
+      .select((symbols::sid, symbols::symbol))
+      .into_boxed();
+    if let Some(limit) = args.limit {
+      query = query.limit(limit);
+    }
+
+    let rows: Vec<(i64, String)> = query.load(&mut conn)?;
+    return Ok(
+      rows.into_iter().map(|(sid, symbol)| CorporateActionSymbol { sid, symbol }).collect(),
+    );
+  }
+
+  let mut resolved = Vec::with_capacity(args.symbol.len());
+  for symbol in &args.symbol {
+    let symbol = symbol.trim().to_uppercase();
+    let sid: Option<i64> = symbols::table
+      .filter(symbols::symbol.eq(&symbol))
+      .filter(symbols::sec_type.ne_all(["Cryptocurrency", "Commodity"]))
+      .order_by(symbols::priority.asc())
+      .select(symbols::sid)
+      .first(&mut conn)
+      .optional()?;
+
+    match sid {
+      Some(sid) => resolved.push(CorporateActionSymbol { sid, symbol }),
+      None => {
+        return Err(anyhow!(
+          "Symbol {} not found in database. Please run 'av load securities' first",
+          symbol
+        ));
+      }
+    }
+  }
+
+  Ok(resolved)
+}
+
+/// Main entry point for `av-cli load corporate-actions`.
+///
+/// Resolves the target symbols, runs [`CorporateActionsLoader`], and prints
+/// a summary.
+///
+/// # Errors
+///
+/// Returns errors from symbol resolution, API client creation, database
+/// context creation, or loader execution. Per-symbol API failures are
+/// reported in the summary rather than aborting the run.
+pub async fn execute(args: CorporateActionsArgs, config: Config) -> Result<()> {
+  let targets = resolve_symbols(&args, &config)?;
+  if targets.is_empty() {
+    println!("No symbols to load. Run 'av load overviews' first or pass --symbol.");
+    return Ok(());
+  }
+
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Symbols are walked sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let all = args.actions.is_empty();
+  let loader_config = CorporateActionsConfig {
+    dividends: all || args.actions.contains(&ActionArg::Dividends),
+    splits: all || args.actions.contains(&ActionArg::Splits),
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = CorporateActionsLoader::new(loader_config, database_url);
+
+  let output = loader.load(&context, CorporateActionsLoaderInput { symbols: targets }).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       CORPORATE ACTIONS                ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  if args.verbose {
+    for result in &output.results {
+      let source = if result.from_cache { "📦" } else { "🌐" };
+      println!(
+        "{} {:<8} {:>4} dividends, {:>3} splits",
+        source, result.symbol, result.dividends, result.splits
+      );
+    }
+    println!();
+  }
+
+  let dividends: usize = output.results.iter().map(|r| r.dividends).sum();
+  let splits: usize = output.results.iter().map(|r| r.splits).sum();
+  println!("📊 Symbols processed: {}", output.results.len());
+  println!("   Dividends: {}", dividends);
+  println!("   Splits: {}", splits);
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Errors: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Records saved: {}", output.records_saved);
+  }
+
+  Ok(())
+}
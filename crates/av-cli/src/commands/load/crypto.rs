@@ -93,13 +93,12 @@
 //! av-cli load crypto --update-existing --continue-on-error --limit 500
 //! ```
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use clap::Args;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
-use av_client::AlphaVantageClient;
 use av_core::types::market::{SecurityIdentifier, SecurityType};
 use av_loaders::{
   DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
@@ -296,7 +295,7 @@ impl CryptoSidGenerator {
 /// 3. **Infrastructure setup** — Creates:
 ///    - [`DatabaseContext`](av_database_postgres::repository::DatabaseContext) for
 ///      repository access
-///    - [`AlphaVantageClient`] for HTTP operations
+///    - [`AlphaVantageClient`](av_client::AlphaVantageClient) for HTTP operations
 ///    - [`CryptoDbLoader`] with [`CryptoLoaderConfig`] and a cache repository
 ///    - [`LoaderContext`] with retry, concurrency, and optional process tracking
 /// 4. **Loader execution** — Calls [`CryptoDbLoader::load`] which fetches symbol
@@ -341,10 +340,7 @@ pub async fn execute(args: CryptoArgs, config: Config) -> Result<()> {
     Arc::new(db_context.cache_repository());
 
   // Create API client for HTTP operations
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create crypto loader configuration with multiple sources
   let crypto_config = CryptoLoaderConfig {
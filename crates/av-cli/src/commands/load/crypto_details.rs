@@ -60,7 +60,6 @@
 //! ```
 
 use anyhow::{Result, anyhow};
-use av_client::AlphaVantageClient;
 use av_database_postgres::models::crypto::{NewCryptoSocial, NewCryptoTechnical};
 use av_database_postgres::repository::{CryptoRepository, DatabaseContext};
 use av_loaders::crypto::{CoinGeckoDetailsInput, CoinGeckoDetailsLoader, CoinInfo};
@@ -155,10 +154,7 @@ pub async fn execute(args: CryptoDetailsArgs, config: Config) -> Result<()> {
     .map_err(|_| anyhow!("COINGECKO_API_KEY environment variable not set"))?;
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
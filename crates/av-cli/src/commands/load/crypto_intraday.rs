@@ -95,7 +95,7 @@
 //! av-cli load crypto-intraday --interval 5min --market EUR
 //! ```
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use diesel::prelude::*;
@@ -103,7 +103,6 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use av_client::AlphaVantageClient;
 use av_database_postgres::{
   establish_connection,
   models::price::NewIntradayPrice,
@@ -584,7 +583,7 @@ async fn cleanup_expired_cache(config: &Config) -> Result<()> {
 /// 4. **Time estimation** — For runs with more than 1 symbol, prints an
 ///    estimated minimum runtime based on `api_delay × symbol_count`. Warns
 ///    when processing more than 50 symbols.
-/// 5. **Loader setup** — Creates [`AlphaVantageClient`], [`LoaderContext`],
+/// 5. **Loader setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient), [`LoaderContext`],
 ///    [`CryptoIntradayConfig`], and [`CryptoIntradayLoader`]. Seeds the
 ///    starting event ID via [`get_max_eventid`].
 /// 6. **API loading** — Calls [`DataLoader::load`] which fetches data for all
@@ -667,10 +666,7 @@ pub async fn execute(args: CryptoIntradayArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config.clone())
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
@@ -92,14 +92,13 @@
 //! av-cli load crypto-markets --dry-run --verbose --limit 10
 //! ```
 
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result};
 use bigdecimal::ToPrimitive;
 use clap::Args;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
 use crate::config::Config;
-use av_client::AlphaVantageClient;
 use av_database_postgres::models::crypto_markets::NewCryptoMarket;
 use av_loaders::{
   LoaderConfig, LoaderContext,
@@ -356,18 +355,8 @@ pub async fn execute(args: CryptoMarketsArgs, config: &Config) -> Result<()> {
     batch_size: Some(args.batch_size),
   };
 
-  // Create loader context with proper parameters - FIX: Use av_core::Config
-  let av_config = av_core::Config {
-    api_key: config.api_config.api_key.clone(),
-    base_url: config.api_config.base_url.clone(),
-    rate_limit: config.api_config.rate_limit,
-    timeout_secs: config.api_config.timeout_secs,
-    max_retries: config.api_config.max_retries,
-  };
-  let client = Arc::new(
-    AlphaVantageClient::new(av_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  // Create loader context
+  let client = Arc::new(config.api_client()?);
   let loader_config_for_context = LoaderConfig::default();
   let loader_context = LoaderContext::new(client, loader_config_for_context);
   let markets_loader = CryptoMarketsLoader::new(loader_config);
@@ -89,14 +89,12 @@
 //! av-cli load crypto-metadata --cleanup-cache --verbose
 //! ```
 
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result};
 use clap::Args;
 use std::collections::HashSet;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use av_client::AlphaVantageClient;
-use av_core::Config as AvConfig;
 use av_loaders::{
   DataLoader, LoaderConfig, LoaderContext,
   crypto::{
@@ -342,18 +340,7 @@ pub async fn execute(args: CryptoMetadataArgs, config: &Config) -> Result<()> {
   };
 
   // Create loader context
-  let av_config = AvConfig {
-    api_key: config.api_config.api_key.clone(),
-    base_url: config.api_config.base_url.clone(),
-    rate_limit: config.api_config.rate_limit,
-    timeout_secs: config.api_config.timeout_secs,
-    max_retries: config.api_config.max_retries,
-  };
-
-  let client = Arc::new(
-    AlphaVantageClient::new(av_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   let loader_context = LoaderContext {
     client,
@@ -478,6 +465,7 @@ fn load_crypto_symbols_from_db(
     query = query.limit(limit_count as i64);
   }
 
+  #[allow(clippy::type_complexity)]
   let results: Vec<(
     (i64, String, String),                          // symbols: (sid, symbol, name)
     (String, String, Option<String>, Option<bool>), // crypto_api_map: (api_source, api_id, api_slug, is_active)
@@ -98,7 +98,6 @@ use clap::Parser;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use av_client::AlphaVantageClient;
 use av_loaders::{
   LoaderConfig, LoaderContext, NewsLoaderConfig, crypto::crypto_news_loader::load_crypto_news,
   news_loader::SymbolInfo,
@@ -310,7 +309,7 @@ fn get_specific_crypto_symbols(database_url: &str, symbols: &[String]) -> Result
 ///    limiting.
 /// 4. **Time estimation** — Logs an estimated runtime based on
 ///    `api_delay × symbol_count`.
-/// 5. **Infrastructure setup** — Creates [`AlphaVantageClient`],
+/// 5. **Infrastructure setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient),
 ///    [`DatabaseContext`], and a [`LoaderContext`] with both news and cache
 ///    repositories attached.
 /// 6. **API loading** — Calls [`load_crypto_news`] with a date range from
@@ -384,10 +383,7 @@ pub async fn execute(args: CryptoNewsArgs, config: Config) -> Result<()> {
   info!("Estimated processing time: {:.1} minutes", estimated_minutes);
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config.clone())
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create database context and repositories
   let db_context = DatabaseContext::new(&config.database_url)
@@ -478,6 +478,7 @@ async fn store_cached_github_data(
 ///
 /// Returns a 3-tuple `(overview, github_data, made_api_call)` where the bool
 /// indicates whether the call hit the API (used for cache-hit-rate statistics).
+#[allow(clippy::too_many_arguments)]
 async fn fetch_single_crypto(
   sid: i64,
   symbol: String,
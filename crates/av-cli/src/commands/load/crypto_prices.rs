@@ -614,6 +614,7 @@ async fn fetch_from_alphavantage(
 
 /// Fetch price from multiple sources with optional parallel execution
 /// Tries sources in priority order, using mappings when available
+#[allow(clippy::too_many_arguments)]
 async fn fetch_price_parallel(
   client: &reqwest::Client,
   symbol_with_mappings: &SymbolWithMappings,
@@ -35,7 +35,6 @@ use std::fs;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use av_client::AlphaVantageClient;
 use av_database_postgres::{
   models::price::NewSummaryPriceOwned,
   repository::{CacheRepository, DatabaseContext},
@@ -362,10 +361,7 @@ pub async fn execute(args: DailyArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config.clone())
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
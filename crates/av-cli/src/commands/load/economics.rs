@@ -0,0 +1,342 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! US economic indicator loader for `av-cli load economics`.
+//!
+//! Fetches the Alpha Vantage macro series and upserts them into the
+//! `economic_indicators` hypertable, replacing the separate pipeline the
+//! macro dashboards used to depend on.
+//!
+//! ## What This Loads
+//!
+//! | Indicator            | API function         | Intervals                    |
+//! |----------------------|----------------------|------------------------------|
+//! | `real-gdp`           | `REAL_GDP`           | annual (default), quarterly  |
+//! | `cpi`                | `CPI`                | monthly (default), semiannual |
+//! | `inflation`          | `INFLATION`          | annual only                  |
+//! | `treasury-yield`     | `TREASURY_YIELD`     | monthly (default), weekly, daily |
+//! | `federal-funds-rate` | `FEDERAL_FUNDS_RATE` | monthly (default), weekly, daily |
+//! | `unemployment`       | `UNEMPLOYMENT`       | monthly only                 |
+//! | `nonfarm-payroll`    | `NONFARM_PAYROLL`    | monthly only                 |
+//!
+//! Treasury yields are loaded once per requested maturity, so the default
+//! run makes 12 API calls (6 indicators + 6 maturities).
+//!
+//! ## Data Flow
+//!
+//! ```text
+//! AlphaVantage economic endpoints
+//!   │  (one call per series, optionally cached for 24h)
+//!   ▼
+//! EconomicIndicatorLoader::load()
+//!   │
+//!   ▼
+//! economic_indicators (upsert on indicator/interval/maturity/date)
+//! ```
+//!
+//! ## Interval Override
+//!
+//! `--interval` is applied only to indicators that publish that interval;
+//! the others keep their API default. For example `--interval daily` moves
+//! treasury yields and the federal funds rate to daily observations while
+//! GDP stays annual.
+//!
+//! ## Caching
+//!
+//! Responses are cached for **24 hours** by default. `--cache-ttl` overrides
+//! this; `--no-cache` disables caching entirely; `--force-refresh` bypasses
+//! cached reads while still writing the new response into the cache.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Load every indicator at its default interval
+//! av-cli load economics
+//!
+//! # Daily treasury curve and fed funds rate
+//! av-cli load economics --indicators treasury-yield,federal-funds-rate --interval daily
+//!
+//! # Only the 2y and 10y treasury yields, showing the latest values
+//! av-cli load economics --indicators treasury-yield --maturities 2year,10year --verbose
+//!
+//! # Dry run (fetch only, no database writes)
+//! av-cli load economics --dry-run --verbose
+//! ```
+
+use anyhow::{Result, anyhow};
+use clap::{Args, ValueEnum};
+use std::sync::Arc;
+
+use av_client::EconomicsEndpoints;
+use av_core::FuncType;
+use av_core::types::{EconomicInterval, TreasuryMaturity};
+use av_database_postgres::repository::DatabaseContext;
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  economic_indicator_loader::{
+    EconomicIndicatorConfig, EconomicIndicatorLoader, EconomicIndicatorLoaderInput,
+    EconomicSeriesSpec,
+  },
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load economics`.
+#[derive(Args, Debug)]
+pub struct EconomicsArgs {
+  /// Indicators to load (comma-separated). Defaults to all seven.
+  #[arg(long, value_enum, value_delimiter = ',')]
+  indicators: Vec<EconomicIndicatorArg>,
+
+  /// Sampling interval override, applied to indicators that support it.
+  #[arg(long, value_enum)]
+  interval: Option<EconomicIntervalArg>,
+
+  /// Treasury maturities to load (comma-separated). Defaults to all six.
+  ///
+  /// Ignored unless `treasury-yield` is among the selected indicators.
+  #[arg(long, value_enum, value_delimiter = ',')]
+  maturities: Vec<TreasuryMaturityArg>,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print the latest observation of each series.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 24.
+  #[arg(long, default_value = "24")]
+  cache_ttl: i64,
+}
+
+/// CLI-level selector for an economic indicator function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum EconomicIndicatorArg {
+  RealGdp,
+  Cpi,
+  Inflation,
+  TreasuryYield,
+  FederalFundsRate,
+  Unemployment,
+  NonfarmPayroll,
+}
+
+impl From<EconomicIndicatorArg> for FuncType {
+  fn from(arg: EconomicIndicatorArg) -> Self {
+    match arg {
+      EconomicIndicatorArg::RealGdp => FuncType::RealGdp,
+      EconomicIndicatorArg::Cpi => FuncType::Cpi,
+      EconomicIndicatorArg::Inflation => FuncType::Inflation,
+      EconomicIndicatorArg::TreasuryYield => FuncType::TreasuryYield,
+      EconomicIndicatorArg::FederalFundsRate => FuncType::FederalFundsRate,
+      EconomicIndicatorArg::Unemployment => FuncType::Unemployment,
+      EconomicIndicatorArg::NonfarmPayroll => FuncType::NonfarmPayroll,
+    }
+  }
+}
+
+/// CLI-level wrapper for [`EconomicInterval`].
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum EconomicIntervalArg {
+  Daily,
+  Weekly,
+  Monthly,
+  Quarterly,
+  Semiannual,
+  Annual,
+}
+
+impl From<EconomicIntervalArg> for EconomicInterval {
+  fn from(arg: EconomicIntervalArg) -> Self {
+    match arg {
+      EconomicIntervalArg::Daily => EconomicInterval::Daily,
+      EconomicIntervalArg::Weekly => EconomicInterval::Weekly,
+      EconomicIntervalArg::Monthly => EconomicInterval::Monthly,
+      EconomicIntervalArg::Quarterly => EconomicInterval::Quarterly,
+      EconomicIntervalArg::Semiannual => EconomicInterval::Semiannual,
+      EconomicIntervalArg::Annual => EconomicInterval::Annual,
+    }
+  }
+}
+
+/// CLI-level wrapper for [`TreasuryMaturity`], using the API spellings.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TreasuryMaturityArg {
+  #[value(name = "3month")]
+  ThreeMonth,
+  #[value(name = "2year")]
+  TwoYear,
+  #[value(name = "5year")]
+  FiveYear,
+  #[value(name = "7year")]
+  SevenYear,
+  #[value(name = "10year")]
+  TenYear,
+  #[value(name = "30year")]
+  ThirtyYear,
+}
+
+impl From<TreasuryMaturityArg> for TreasuryMaturity {
+  fn from(arg: TreasuryMaturityArg) -> Self {
+    match arg {
+      TreasuryMaturityArg::ThreeMonth => TreasuryMaturity::ThreeMonth,
+      TreasuryMaturityArg::TwoYear => TreasuryMaturity::TwoYear,
+      TreasuryMaturityArg::FiveYear => TreasuryMaturity::FiveYear,
+      TreasuryMaturityArg::SevenYear => TreasuryMaturity::SevenYear,
+      TreasuryMaturityArg::TenYear => TreasuryMaturity::TenYear,
+      TreasuryMaturityArg::ThirtyYear => TreasuryMaturity::ThirtyYear,
+    }
+  }
+}
+
+/// Expands the CLI selections into one [`EconomicSeriesSpec`] per API call.
+fn build_specs(args: &EconomicsArgs) -> Vec<EconomicSeriesSpec> {
+  let functions: Vec<FuncType> = if args.indicators.is_empty() {
+    av_loaders::ECONOMIC_FUNCTIONS.to_vec()
+  } else {
+    args.indicators.iter().map(|&arg| arg.into()).collect()
+  };
+
+  let maturities: Vec<TreasuryMaturity> = if args.maturities.is_empty() {
+    TreasuryMaturity::ALL.to_vec()
+  } else {
+    args.maturities.iter().map(|&arg| arg.into()).collect()
+  };
+
+  let interval: Option<EconomicInterval> = args.interval.map(Into::into);
+
+  let mut specs = Vec::new();
+  for function in functions {
+    let mut spec = EconomicSeriesSpec::new(function);
+    if let Some(interval) =
+      interval.filter(|i| EconomicsEndpoints::supported_intervals(function).contains(i))
+    {
+      spec = spec.with_interval(interval);
+    }
+
+    if function == FuncType::TreasuryYield {
+      specs.extend(maturities.iter().map(|&maturity| spec.with_maturity(maturity)));
+    } else {
+      specs.push(spec);
+    }
+  }
+
+  specs
+}
+
+/// Main entry point for `av-cli load economics`.
+///
+/// Builds one series spec per API call, runs [`EconomicIndicatorLoader`],
+/// and prints a per-series summary.
+///
+/// # Errors
+///
+/// Returns errors from API client creation, database context creation, or
+/// loader execution. Failures of individual series are reported in the
+/// summary rather than aborting the run.
+pub async fn execute(args: EconomicsArgs, config: Config) -> Result<()> {
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Series are fetched sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = EconomicIndicatorConfig {
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = EconomicIndicatorLoader::new(loader_config, database_url);
+
+  let input = EconomicIndicatorLoaderInput { series: build_specs(&args) };
+  let output = loader.load(&context, input).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       ECONOMIC INDICATORS              ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  for result in &output.series {
+    let source = if result.from_cache { "📦" } else { "🌐" };
+    println!("{} {:<32} {:>6} obs", source, result.spec.to_string(), result.observations);
+    if args.verbose {
+      if let Some(latest) = &result.latest {
+        println!("     {} | {} {}", latest.date, latest.value, result.unit);
+      }
+    }
+  }
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Failed series: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Records saved: {}", output.records_saved);
+  }
+
+  Ok(())
+}
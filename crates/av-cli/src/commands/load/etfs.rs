@@ -0,0 +1,252 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! ETF profile loader for `av-cli load etfs`.
+//!
+//! Loads key metrics, sector allocation, and constituent holdings from the
+//! AlphaVantage `ETF_PROFILE` endpoint into `etf_profiles`,
+//! `etf_sector_weights`, and `etf_holdings`, one snapshot per ETF per day.
+//!
+//! ## Symbol Selection
+//!
+//! - `--symbol`: the listed tickers, which must be ETFs in `symbols`.
+//! - Otherwise: every ETF in priority order, capped by `--limit`.
+//!
+//! ## Constituent Linking
+//!
+//! Holdings are linked to the constituent's `symbols` row so that "which
+//! ETFs hold X?" is a join. Tickers not yet in `symbols` are recorded in
+//! `missing_symbols` with source `etf_profile` (resolve them with
+//! `av load missing-symbols --source etf_profile`); each run also links
+//! previously unlinked holdings whose ticker has since been added.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Two ETFs, with every holding listed
+//! av-cli load etfs --symbol SPY,QQQ --verbose
+//!
+//! # The 50 highest-priority ETFs
+//! av-cli load etfs --limit 50
+//!
+//! # Dry run (no database writes, no missing-symbol tracking)
+//! av-cli load etfs --symbol QQQ --dry-run
+//! ```
+
+use anyhow::{Result, anyhow};
+use clap::Args;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use av_database_postgres::{repository::DatabaseContext, schema::symbols};
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  etf_profile_loader::{EtfProfileConfig, EtfProfileLoader, EtfProfileLoaderInput},
+};
+
+use crate::config::Config;
+
+/// `symbols.sec_type` value for exchange-traded funds.
+const ETF_SEC_TYPE: &str = "ETF";
+
+/// Command-line arguments for `av-cli load etfs`.
+#[derive(Args, Debug)]
+pub struct EtfsArgs {
+  /// ETF symbol(s) to load (comma-separated). Defaults to all ETFs.
+  #[arg(short, long, value_delimiter = ',')]
+  symbol: Vec<String>,
+
+  /// Maximum number of ETFs when `--symbol` is not given.
+  #[arg(short, long)]
+  limit: Option<i64>,
+
+  /// Fetch the data but skip database writes and missing-symbol tracking.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print one line per ETF and the unresolved holding tickers.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 168 (7 days).
+  #[arg(long, default_value = "168")]
+  cache_ttl: i64,
+}
+
+/// Resolves `--symbol` tickers to ETF SIDs, or selects all ETFs when no
+/// tickers were given.
+fn resolve_symbols(args: &EtfsArgs, config: &Config) -> Result<Vec<(i64, String)>> {
+  let mut conn = PgConnection::establish(&config.database_url)?;
+
+  let mut query = symbols::table
+    .filter(symbols::sec_type.eq(ETF_SEC_TYPE))
+    .order_by(symbols::priority.asc())
+    .select((symbols::sid, symbols::symbol))
+    .into_boxed();
+
+  if args.symbol.is_empty() {
+    if let Some(limit) = args.limit {
+      query = query.limit(limit);
+    }
+    return Ok(query.load(&mut conn)?);
+  }
+
+  let wanted: Vec<String> = args.symbol.iter().map(|s| s.trim().to_uppercase()).collect();
+  let rows: Vec<(i64, String)> = query.filter(symbols::symbol.eq_any(&wanted)).load(&mut conn)?;
+
+  let mut resolved = Vec::with_capacity(wanted.len());
+  for symbol in &wanted {
+    match rows.iter().find(|(_, s)| s == symbol) {
+      Some(row) => resolved.push(row.clone()),
+      None => {
+        return Err(anyhow!(
+          "ETF {} not found in database. Please run 'av load securities' first",
+          symbol
+        ));
+      }
+    }
+  }
+
+  Ok(resolved)
+}
+
+/// Main entry point for `av-cli load etfs`.
+///
+/// Resolves the target ETFs, runs [`EtfProfileLoader`], and prints a
+/// summary. Unless `--dry-run`, the news repository is attached so that
+/// unknown holding tickers are recorded in `missing_symbols`.
+///
+/// # Errors
+///
+/// Returns errors from symbol resolution, API client creation, database
+/// context creation, or loader execution. Per-ETF failures are reported in
+/// the summary rather than aborting the run.
+pub async fn execute(args: EtfsArgs, config: Config) -> Result<()> {
+  let targets = resolve_symbols(&args, &config)?;
+  if targets.is_empty() {
+    println!("No ETFs to load. Run 'av load securities' first or pass --symbol.");
+    return Ok(());
+  }
+
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // ETFs are walked sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    let news_repo: Arc<dyn av_database_postgres::repository::NewsRepository> =
+      Arc::new(db_context.news_repository());
+    context = context.with_news_repository(news_repo);
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = EtfProfileConfig {
+    track_missing_symbols: !args.dry_run,
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = EtfProfileLoader::new(loader_config, database_url);
+
+  let output = loader.load(&context, EtfProfileLoaderInput { symbols: targets }).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       ETF PROFILES                     ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  if args.verbose {
+    for result in &output.profiles {
+      let source = if result.from_cache { "📦" } else { "🌐" };
+      println!(
+        "{} {:<8} {:>5} holdings ({} linked) {:>3} sectors",
+        source, result.symbol, result.holdings, result.linked_holdings, result.sectors
+      );
+    }
+    println!();
+  }
+
+  println!("📊 ETFs loaded: {}", output.profiles.len());
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Errors: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Holdings saved: {}", output.holdings_saved);
+    if output.constituents_linked > 0 {
+      println!("   Previously unlinked holdings linked: {}", output.constituents_linked);
+    }
+    if !output.missing_symbols.is_empty() {
+      println!("   ⚠️  Missing symbols: {}", output.missing_symbols.len());
+      println!("   📝 Missing symbols recorded: {}", output.missing_recorded);
+      if args.verbose {
+        println!("   Missing symbols:");
+        for symbol in &output.missing_symbols {
+          println!("      - {}", symbol);
+        }
+      }
+    }
+  }
+
+  Ok(())
+}
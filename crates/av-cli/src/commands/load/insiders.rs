@@ -0,0 +1,290 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Insider transaction loader for `av-cli load insiders`.
+//!
+//! Fetches trades by officers, directors, and major holders from the
+//! AlphaVantage `INSIDER_TRANSACTIONS` endpoint and inserts them into
+//! `insider_transactions`, linked to the issuer's SID.
+//!
+//! ## Incremental Loading
+//!
+//! The API always returns the full history, but only trades dated on or
+//! after the latest stored date are written; duplicates are dropped by a
+//! unique index. `--full-history` sends every trade instead, which is only
+//! needed to backfill gaps.
+//!
+//! ## Symbol Selection
+//!
+//! - `--symbol`: the listed tickers.
+//! - Otherwise: every equity with a loaded company overview
+//!   (`overview = true`), in priority order, capped by `--limit`.
+//!
+//! ## Cluster Buying
+//!
+//! `--clusters` prints issuers where at least `--min-insiders` distinct
+//! insiders made open-market purchases in the last `--cluster-days` days,
+//! after the load finishes. Grants and awards are excluded.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Two symbols
+//! av-cli load insiders --symbol IBM,AAPL
+//!
+//! # Daily refresh of all equities, then report clusters of 3+ buyers
+//! av-cli load insiders --clusters --min-insiders 3 --cluster-days 30
+//! ```
+
+use anyhow::{Result, anyhow};
+use chrono::{Duration, Utc};
+use clap::Args;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use av_database_postgres::{
+  repository::{DatabaseContext, InsiderRepository},
+  schema::symbols,
+};
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  insider_transactions_loader::{
+    InsiderTransactionsConfig, InsiderTransactionsLoader, InsiderTransactionsLoaderInput,
+  },
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load insiders`.
+#[derive(Args, Debug)]
+pub struct InsidersArgs {
+  /// Symbol(s) to load (comma-separated). Defaults to all equities with a
+  /// loaded overview.
+  #[arg(short, long, value_delimiter = ',')]
+  symbol: Vec<String>,
+
+  /// Maximum number of symbols when `--symbol` is not given.
+  #[arg(short, long)]
+  limit: Option<i64>,
+
+  /// Write the full history instead of only trades since the latest
+  /// stored date.
+  #[arg(long)]
+  full_history: bool,
+
+  /// Print cluster-buying issuers after loading.
+  #[arg(long)]
+  clusters: bool,
+
+  /// Look-back window for `--clusters`, in days.
+  #[arg(long, default_value = "30")]
+  cluster_days: i64,
+
+  /// Minimum distinct buyers for `--clusters`.
+  #[arg(long, default_value = "3")]
+  min_insiders: i64,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print one line per symbol.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 24.
+  #[arg(long, default_value = "24")]
+  cache_ttl: i64,
+}
+
+/// Resolves `--symbol` tickers to equity SIDs, or selects equities with
+/// overviews when no tickers were given.
+fn resolve_symbols(args: &InsidersArgs, config: &Config) -> Result<Vec<(i64, String)>> {
+  let mut conn = PgConnection::establish(&config.database_url)?;
+
+  let mut query = symbols::table
+    .filter(symbols::sec_type.eq("Equity"))
+    .order_by(symbols::priority.asc())
+    .select((symbols::sid, symbols::symbol))
+    .into_boxed();
+
+  if args.symbol.is_empty() {
+    query = query.filter(symbols::overview.eq(true));
+    if let Some(limit) = args.limit {
+      query = query.limit(limit);
+    }
+    return Ok(query.load(&mut conn)?);
+  }
+
+  let wanted: Vec<String> = args.symbol.iter().map(|s| s.trim().to_uppercase()).collect();
+  let rows: Vec<(i64, String)> = query.filter(symbols::symbol.eq_any(&wanted)).load(&mut conn)?;
+
+  let mut resolved = Vec::with_capacity(wanted.len());
+  for symbol in &wanted {
+    match rows.iter().find(|(_, s)| s == symbol) {
+      Some(row) => resolved.push(row.clone()),
+      None => {
+        return Err(anyhow!(
+          "Symbol {} not found in database. Please run 'av load securities' first",
+          symbol
+        ));
+      }
+    }
+  }
+
+  Ok(resolved)
+}
+
+/// Main entry point for `av-cli load insiders`.
+///
+/// Resolves the target symbols, runs [`InsiderTransactionsLoader`], prints
+/// a summary, and optionally reports cluster buying.
+///
+/// # Errors
+///
+/// Returns errors from symbol resolution, API client creation, database
+/// context creation, loader execution, or the cluster query. Per-symbol API
+/// failures are reported in the summary rather than aborting the run.
+pub async fn execute(args: InsidersArgs, config: Config) -> Result<()> {
+  let targets = resolve_symbols(&args, &config)?;
+  if targets.is_empty() {
+    println!("No symbols to load. Run 'av load overviews' first or pass --symbol.");
+    return Ok(());
+  }
+
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Symbols are walked sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = InsiderTransactionsConfig {
+    full_history: args.full_history,
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = InsiderTransactionsLoader::new(loader_config, database_url);
+
+  let output = loader.load(&context, InsiderTransactionsLoaderInput { symbols: targets }).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       INSIDER TRANSACTIONS             ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  if args.verbose {
+    for result in &output.results {
+      let source = if result.from_cache { "📦" } else { "🌐" };
+      let latest = result.latest.map(|d| d.to_string()).unwrap_or_default();
+      println!(
+        "{} {:<8} {:>5} trades, {:>4} new, latest {}",
+        source, result.symbol, result.fetched, result.inserted, latest
+      );
+    }
+    println!();
+  }
+
+  let fetched: usize = output.results.iter().map(|r| r.fetched).sum();
+  println!("📊 Symbols processed: {}", output.results.len());
+  println!("   Trades fetched: {}", fetched);
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Errors: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   New trades saved: {}", output.records_saved);
+  }
+
+  if args.clusters {
+    let since = Utc::now().date_naive() - Duration::days(args.cluster_days);
+    let clusters = db_context
+      .insider_repository()
+      .cluster_buys(since, args.min_insiders)
+      .await
+      .map_err(|e| anyhow!("Failed to query cluster buying: {}", e))?;
+
+    println!(
+      "\n🔔 Cluster buying since {} ({}+ insiders): {}",
+      since,
+      args.min_insiders,
+      clusters.len()
+    );
+    for cluster in &clusters {
+      println!(
+        "   {:<8} {:>2} insiders, {:>3} buys, ${:>14.0}  {} → {}",
+        cluster.symbol,
+        cluster.insiders,
+        cluster.transactions,
+        cluster.total_value,
+        cluster.first_date,
+        cluster.last_date
+      );
+    }
+  }
+
+  Ok(())
+}
@@ -113,7 +113,6 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
-use av_client::AlphaVantageClient;
 use av_database_postgres::{
   establish_connection,
   models::price::NewIntradayPrice,
@@ -525,7 +524,7 @@ async fn save_intraday_prices_optimized(
 /// 4. **Latest-timestamp lookup** — Unless `--force-refresh`, `--dry-run`, or
 ///    `--check-each-record` is set, calls [`get_latest_timestamps`] to enable
 ///    incremental loading.
-/// 5. **Loader setup** — Creates [`AlphaVantageClient`], [`LoaderContext`]
+/// 5. **Loader setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient), [`LoaderContext`]
 ///    with optional process tracking, [`IntradayPriceConfig`] (with parsed
 ///    interval, extended hours, adjustments, month, cache settings), and
 ///    [`IntradayPriceLoader`] seeded with the next event ID via
@@ -604,10 +603,7 @@ pub async fn execute(args: IntradayArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config.clone())
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
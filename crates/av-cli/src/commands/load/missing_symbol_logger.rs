@@ -159,7 +159,7 @@ pub fn log_missing_symbols_batch(
   let mut logged_count = 0;
 
   for symbol in symbols {
-    if let Ok(_) = log_missing_symbol(conn, symbol, source) {
+    if log_missing_symbol(conn, symbol, source).is_ok() {
       logged_count += 1;
     }
   }
@@ -121,7 +121,7 @@
 //! av-cli load missing-symbols --no-continue-on-error
 //! ```
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use clap::Args;
 use diesel::prelude::*;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -265,8 +265,7 @@ pub async fn execute(args: MissingSymbolsArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = AlphaVantageClient::new(config.api_config.clone())
-    .map_err(|e| anyhow!("Failed to create API client: {}", e))?;
+  let client = config.api_client()?;
 
   // Initialize SID generator
   let mut sid_generator = tokio::task::spawn_blocking({
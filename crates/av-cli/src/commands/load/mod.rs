@@ -44,8 +44,15 @@
 //! ├── overviews          Fetch company overview data for equities
 //! ├── daily              Load daily price history for equities
 //! ├── intraday           Load intraday price data for equities
+//! ├── options            Load historical option chains with Greeks and IV
+//! ├── corporate-actions  Load dividend and stock split history
+//! ├── insiders           Load insider transactions incrementally
+//! ├── transcripts        Backfill earnings call transcripts with sentiment
+//! ├── etfs               Load ETF profiles, holdings, and sector weights
 //! ├── news               Fetch equity news articles with sentiment
 //! ├── top-movers         Fetch market top gainers/losers
+//! ├── economics          Load US economic indicators (GDP, CPI, yields, ...)
+//! ├── commodities        Load energy, metals, and agricultural commodity prices
 //! ├── missing-symbols    Resolve pending missing symbols from news feeds
 //! ├── crypto             Load crypto symbols from CoinGecko/CMC/SosoValue
 //! ├── crypto-overview    Fetch crypto overview data (prices, supply, market cap)
@@ -61,7 +68,7 @@
 //!
 //! ## Module Organization
 //!
-//! The 20 submodules are organized into three functional groups:
+//! The 27 submodules are organized into four functional groups:
 //!
 //! ### Equity Modules
 //!
@@ -73,6 +80,18 @@
 //!   years) into the `summaryprices` table.
 //! - [`intraday`] — Loads intraday price data for equities with multi-interval
 //!   support (1min, 5min, 15min, 30min, 60min).
+//! - [`options`] — Loads end-of-day option chains (Greeks and implied
+//!   volatility) over a date range into the `optionprices` hypertable.
+//! - [`corporate_actions`] — Loads dividend and split history into the
+//!   `corporate_actions` table, for named symbols or all equities with
+//!   overviews.
+//! - [`insiders`] — Incrementally loads insider purchases and sales into
+//!   `insider_transactions` and reports cluster buying.
+//! - [`transcripts`] — Backfills the last N quarters of earnings call
+//!   transcripts into `earnings_transcripts` / `transcript_segments`.
+//! - [`etfs`] — Loads ETF profile snapshots into `etf_profiles`,
+//!   `etf_holdings`, and `etf_sector_weights`, linking holdings to
+//!   constituent SIDs and tracking unknown tickers.
 //! - [`news`] — Fetches equity news articles from AlphaVantage with sentiment
 //!   scoring, topic filtering, and hash-based deduplication.
 //! - [`top_movers`] — Fetches market top gainers/losers for a given date and
@@ -80,6 +99,15 @@
 //! - [`missing_symbols`] — Resolves pending missing symbols (logged during news
 //!   or top-movers ingestion) via AlphaVantage symbol search.
 //!
+//! ### Macro Modules
+//!
+//! - [`economics`] — Loads US economic indicators (real GDP, CPI, inflation,
+//!   treasury yields, federal funds rate, unemployment, nonfarm payroll) into
+//!   the `economic_indicators` hypertable.
+//! - [`commodities`] — Loads energy, metals, and agricultural commodity prices
+//!   into the `commodityprices` hypertable, registering each commodity as a
+//!   `Commodity` symbol.
+//!
 //! ### Cryptocurrency Modules
 //!
 //! - [`crypto`] — Loads cryptocurrency symbols from CoinGecko, CoinMarketCap,
@@ -146,6 +174,10 @@ use anyhow::Result;
 
 use clap::{Args, Subcommand};
 
+/// Load commodity prices into the `commodityprices` table.
+pub mod commodities;
+/// Load dividends and splits into the `corporate_actions` table.
+pub mod corporate_actions;
 /// Load NASDAQ/NYSE securities from CSV files via AlphaVantage API.
 pub mod crypto;
 /// Load social and technical crypto data from CoinGecko.
@@ -166,6 +198,12 @@ pub mod crypto_overview;
 pub mod crypto_prices;
 /// Load daily price history for equities into the `summaryprices` table.
 pub mod daily;
+/// Load US economic indicator series into the `economic_indicators` table.
+pub mod economics;
+/// Load ETF profiles, holdings, and sector weights into the `etf_*` tables.
+pub mod etfs;
+/// Load insider transactions into the `insider_transactions` table.
+pub mod insiders;
 /// Load intraday price data for equities with multi-interval support.
 pub mod intraday;
 /// Utility: log unrecognized symbols encountered during news/top-movers ingestion.
@@ -178,6 +216,8 @@ pub mod news;
 pub mod news_utils;
 /// Utility: safe `f64` to `BigDecimal` conversion with precision clamping.
 pub mod numeric_helpers;
+/// Load historical option chains into the `optionprices` table.
+pub mod options;
 /// Fetch company overview data (financials, ratios) for equities from AlphaVantage.
 pub mod overviews;
 /// Load NASDAQ/NYSE securities from CSV files with SID generation.
@@ -186,6 +226,8 @@ pub mod securities;
 pub mod sid_generator;
 /// Fetch market top gainers/losers for a given date from AlphaVantage.
 pub mod top_movers;
+/// Backfill earnings call transcripts into the `earnings_transcripts` tables.
+pub mod transcripts;
 
 use tracing::info;
 
@@ -214,10 +256,20 @@ pub struct LoadCommand {
 /// - `Overviews` — Fetch company overview data for equities
 /// - `Daily` — Load daily price history
 /// - `Intraday` — Load intraday price data
+/// - `Options` — Load historical option chains
+/// - `CorporateActions` — Load dividends and stock splits
+/// - `Insiders` — Load insider transactions
+/// - `Transcripts` — Backfill earnings call transcripts
+/// - `Etfs` — Load ETF profiles and holdings
 /// - `News` — Fetch equity news with sentiment
 /// - `TopMovers` — Fetch market top gainers/losers
 /// - `MissingSymbols` — Resolve unrecognized symbols from news/top-movers
 ///
+/// ## Macro Subcommands
+///
+/// - `Economics` — Load US economic indicator series
+/// - `Commodities` — Load commodity price series
+///
 /// ## Cryptocurrency Subcommands
 ///
 /// - `Crypto` — Load crypto symbols from CoinGecko/CMC/SosoValue
@@ -277,6 +329,21 @@ enum LoadSubcommands {
   /// Load intraday price data for equities.
   #[clap(name = "intraday")]
   Intraday(intraday::IntradayArgs),
+  /// Load historical option chains with Greeks and implied volatility.
+  Options(options::OptionsArgs),
+  /// Load dividend and stock split history.
+  CorporateActions(corporate_actions::CorporateActionsArgs),
+  /// Load insider transactions incrementally.
+  Insiders(insiders::InsidersArgs),
+  /// Backfill earnings call transcripts for recent quarters.
+  Transcripts(transcripts::TranscriptsArgs),
+  /// Load ETF profiles, constituent holdings, and sector weights.
+  Etfs(etfs::EtfsArgs),
+
+  /// Load US economic indicators (GDP, CPI, treasury yields, ...).
+  Economics(economics::EconomicsArgs),
+  /// Load energy, metals, and agricultural commodity prices.
+  Commodities(commodities::CommoditiesArgs),
 }
 
 /// Dispatches `av-cli load` subcommands to their module-level `execute` functions.
@@ -315,8 +382,15 @@ pub async fn execute(cmd: LoadCommand, config: Config) -> Result<()> {
       crypto_overview::update_github_data(args, config).await
     }
     LoadSubcommands::Intraday(args) => intraday::execute(args, config).await,
+    LoadSubcommands::Options(args) => options::execute(args, config).await,
+    LoadSubcommands::CorporateActions(args) => corporate_actions::execute(args, config).await,
+    LoadSubcommands::Insiders(args) => insiders::execute(args, config).await,
+    LoadSubcommands::Transcripts(args) => transcripts::execute(args, config).await,
+    LoadSubcommands::Etfs(args) => etfs::execute(args, config).await,
     LoadSubcommands::Daily(args) => daily::execute(args, config).await,
     LoadSubcommands::News(args) => news::execute(args, config).await,
     LoadSubcommands::TopMovers(args) => top_movers::execute(args, config).await,
+    LoadSubcommands::Economics(args) => economics::execute(args, config).await,
+    LoadSubcommands::Commodities(args) => commodities::execute(args, config).await,
   }
 }
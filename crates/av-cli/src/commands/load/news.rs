@@ -101,7 +101,6 @@
 //! ```
 
 use anyhow::{Result, anyhow};
-use av_client::AlphaVantageClient;
 use av_database_postgres::repository::DatabaseContext;
 use av_loaders::{
   DataLoader,
@@ -225,11 +224,12 @@ pub struct NewsArgs {
 /// Orchestrates the equity news loading pipeline:
 ///
 /// 1. **Limit validation** — Returns an error if `--limit > 1000` or `--limit < 1`.
-/// 2. **Infrastructure setup** — Creates [`AlphaVantageClient`],
+/// 2. **Infrastructure setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient),
 ///    [`DatabaseContext`], and the news/cache repositories.
 /// 3. **Symbol selection** — Dispatches to one of two paths:
 ///    - `--all-equity` → [`NewsLoader::get_equity_symbols_with_overview`]
 ///    - `--symbols` → [`get_specific_symbols`]
+///
 ///    Returns an error if neither flag is set.
 /// 4. **Symbol limit** — Applies `--symbol-limit` (post-selection cap).
 /// 5. **Time estimation** — Logs an estimated runtime based on
@@ -266,10 +266,7 @@ pub async fn execute(args: NewsArgs, config: Config) -> Result<()> {
   let _continue_on_error = if args.stop_on_error { false } else { args.continue_on_error }; //todo:: fix this
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config.clone())
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create database context and repositories
   let db_context = DatabaseContext::new(&config.database_url)
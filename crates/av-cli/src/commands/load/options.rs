@@ -0,0 +1,265 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Option chain loader for `av-cli load options`.
+//!
+//! Pulls end-of-day option chains (with Greeks and implied volatility) from
+//! the AlphaVantage `HISTORICAL_OPTIONS` endpoint for one or more
+//! underlyings over a date range, and upserts them into the `optionprices`
+//! hypertable keyed by snapshot date and OCC contract symbol.
+//!
+//! ## Data Flow
+//!
+//! ```text
+//! --symbol IBM --from 2024-01-02 --to 2024-01-31
+//!   │  resolve SIDs from symbols table
+//!   ▼
+//! OptionsLoader::load()
+//!   │  one HISTORICAL_OPTIONS call per weekday, skipping stored dates
+//!   ▼
+//! optionprices (upsert on snapshot_date/contract_id)
+//! ```
+//!
+//! ## Date Range
+//!
+//! - No `--from`: a single call for the most recent trading session.
+//! - `--from` only: that one date.
+//! - `--from` and `--to`: every weekday in between, inclusive. Exchange
+//!   holidays return an empty chain and are reported, not treated as errors.
+//!
+//! Dates already present in `optionprices` for an underlying are skipped,
+//! so an interrupted backfill can be re-run as-is. `--reload` turns this off.
+//!
+//! ## Caching
+//!
+//! Chains are cached for **24 hours** by default. Option chains for liquid
+//! underlyings are several megabytes, so the TTL is kept short; `--no-cache`
+//! is recommended for long backfills.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Latest session for IBM
+//! av-cli load options --symbol IBM
+//!
+//! # January 2024 for two underlyings
+//! av-cli load options --symbol IBM,AAPL --from 2024-01-02 --to 2024-01-31
+//!
+//! # Re-load a single day, overwriting stored quotes
+//! av-cli load options --symbol SPY --from 2024-03-15 --reload
+//! ```
+
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+use clap::Args;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use av_database_postgres::{repository::DatabaseContext, schema::symbols};
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  options_loader::{OptionsConfig, OptionsLoader, OptionsLoaderInput},
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load options`.
+#[derive(Args, Debug)]
+pub struct OptionsArgs {
+  /// Underlying symbol(s) to load (comma-separated).
+  #[arg(short, long, required = true, value_delimiter = ',')]
+  symbol: Vec<String>,
+
+  /// First snapshot date (`YYYY-MM-DD`). Omit for the latest session.
+  #[arg(long)]
+  from: Option<NaiveDate>,
+
+  /// Last snapshot date (`YYYY-MM-DD`, inclusive). Defaults to `--from`.
+  #[arg(long, requires = "from")]
+  to: Option<NaiveDate>,
+
+  /// Re-fetch dates that are already stored instead of skipping them.
+  #[arg(long)]
+  reload: bool,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print one line per snapshot.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 24.
+  #[arg(long, default_value = "24")]
+  cache_ttl: i64,
+}
+
+/// Looks up the SID of each underlying.
+///
+/// Any non-crypto, non-commodity listing is accepted so that ETFs and
+/// indices with listed options resolve as well as equities. When a ticker
+/// has several listings the highest-priority one wins.
+fn resolve_underlyings(symbols: &[String], config: &Config) -> Result<Vec<(i64, String)>> {
+  let mut conn = PgConnection::establish(&config.database_url)?;
+  let mut resolved = Vec::with_capacity(symbols.len());
+
+  for symbol in symbols {
+    let symbol = symbol.trim().to_uppercase();
+    let sid: Option<i64> = symbols::table
+      .filter(symbols::symbol.eq(&symbol))
+      .filter(symbols::sec_type.ne_all(["Cryptocurrency", "Commodity"]))
+      .order_by(symbols::priority.asc())
+      .select(symbols::sid)
+      .first(&mut conn)
+      .optional()?;
+
+    match sid {
+      Some(sid) => resolved.push((sid, symbol)),
+      None => {
+        return Err(anyhow!(
+          "Symbol {} not found in database. Please run 'av load securities' first",
+          symbol
+        ));
+      }
+    }
+  }
+
+  Ok(resolved)
+}
+
+/// Main entry point for `av-cli load options`.
+///
+/// Resolves the underlyings, runs [`OptionsLoader`] over the requested date
+/// range, and prints a summary.
+///
+/// # Errors
+///
+/// Returns errors from symbol resolution, API client creation, database
+/// context creation, or loader execution. Failures for individual dates are
+/// reported in the summary rather than aborting the run.
+pub async fn execute(args: OptionsArgs, config: Config) -> Result<()> {
+  if let (Some(from), Some(to)) = (args.from, args.to) {
+    if to < from {
+      return Err(anyhow!("--to ({}) is before --from ({})", to, from));
+    }
+  }
+
+  let underlyings = resolve_underlyings(&args.symbol, &config)?;
+
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Dates are walked sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = OptionsConfig {
+    skip_existing: !args.reload,
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = OptionsLoader::new(loader_config, database_url);
+
+  let input = OptionsLoaderInput { symbols: underlyings, start_date: args.from, end_date: args.to };
+  let output = loader.load(&context, input).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       OPTION CHAINS                    ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  if args.verbose {
+    for snapshot in &output.snapshots {
+      let source = if snapshot.from_cache { "📦" } else { "🌐" };
+      let date = snapshot.snapshot_date.map(|d| d.to_string()).unwrap_or_default();
+      println!(
+        "{} {:<8} {} {:>6} contracts, {:>3} expirations",
+        source, snapshot.symbol, date, snapshot.contracts, snapshot.expirations
+      );
+    }
+    println!();
+  }
+
+  let contracts: usize = output.snapshots.iter().map(|s| s.contracts).sum();
+  println!("📊 Snapshots loaded: {}", output.snapshots.len());
+  println!("   Contracts: {}", contracts);
+  if output.dates_skipped > 0 {
+    println!("   Already stored (skipped): {}", output.dates_skipped);
+  }
+  if output.empty_dates > 0 {
+    println!("   Empty (non-trading days): {}", output.empty_dates);
+  }
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Failed snapshots: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Records saved: {}", output.records_saved);
+  }
+
+  Ok(())
+}
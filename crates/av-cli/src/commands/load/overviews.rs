@@ -122,7 +122,6 @@
 //! ```
 
 use anyhow::{Result, anyhow};
-use av_client::AlphaVantageClient;
 use av_database_postgres::models::security::{NewOverviewOwned, NewOverviewextOwned};
 use av_database_postgres::repository::{DatabaseContext, OverviewRepository, OverviewSymbolFilter};
 use av_loaders::{
@@ -145,14 +144,9 @@ use crate::config::Config;
 /// epoch) only as a defensive measure that's effectively unreachable.
 fn default_date() -> NaiveDate {
   // 2000-01-01 is always valid; from_ymd_opt returns Some for valid dates
-  match NaiveDate::from_ymd_opt(2000, 1, 1) {
-    Some(date) => date,
-    None => {
-      // This branch is unreachable for 2000-01-01, but we handle it
-      // by returning the Unix epoch start as an absolute fallback
-      NaiveDate::default()
-    }
-  }
+  // The fallback is unreachable for 2000-01-01, but we handle it
+  // by returning the Unix epoch start as an absolute fallback
+  NaiveDate::from_ymd_opt(2000, 1, 1).unwrap_or_default()
 }
 
 /// Command-line arguments for `av-cli load overviews`.
@@ -202,8 +196,9 @@ pub struct OverviewsArgs {
 ///    using the appropriate filter:
 ///    - Explicit `--symbols` or `--symbols-file` → no type/region filter
 ///    - Default → US equities only
+///
 ///    Both modes filter to `missing_overviews_only = true`.
-/// 3. **Loader setup** — Creates [`AlphaVantageClient`], [`LoaderContext`]
+/// 3. **Loader setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient), [`LoaderContext`]
 ///    with cache repository attached, and [`OverviewLoader`].
 /// 4. **API loading** — Calls [`DataLoader::load`] which fetches the
 ///    `OVERVIEW` endpoint for each symbol concurrently with caching.
@@ -246,10 +241,7 @@ pub async fn execute(args: OverviewsArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
@@ -287,6 +279,9 @@ pub async fn execute(args: OverviewsArgs, config: Config) -> Result<()> {
     "API loading complete: {} loaded, {} no data, {} errors, {} cache hits, {} API calls",
     output.loaded_count, output.no_data_count, output.errors, output.cache_hits, output.api_calls
   );
+  if output.skipped_count > 0 {
+    warn!("{} symbols skipped after the API key stopped accepting requests", output.skipped_count);
+  }
 
   // Save to database unless dry run
   let saved_count = if !args.dry_run && !output.data.is_empty() {
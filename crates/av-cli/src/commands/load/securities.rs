@@ -130,8 +130,7 @@
 //! ```
 
 use super::sid_generator::SidGenerator;
-use anyhow::{Result, anyhow};
-use av_client::AlphaVantageClient;
+use anyhow::Result;
 use av_core::types::market::{Exchange, SecurityType};
 use av_database_postgres::repository::DatabaseContext;
 use av_loaders::SecurityLoaderConfig;
@@ -293,7 +292,7 @@ pub fn normalize_alpha_region(region: &str) -> String {
 ///
 /// 1. **Dry-run check** — If `--dry-run`, delegates to [`execute_dry_run`]
 ///    and returns.
-/// 2. **Infrastructure setup** — Creates [`AlphaVantageClient`],
+/// 2. **Infrastructure setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient),
 ///    [`DatabaseContext`], cache repository, and [`LoaderContext`] with
 ///    process tracking enabled.
 /// 3. **Loader configuration** — Builds [`SecurityLoader`] with the selected
@@ -322,10 +321,7 @@ pub async fn execute(args: SecuritiesArgs, config: Config) -> Result<()> {
   }
 
   // Create API client
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
@@ -479,10 +475,7 @@ pub async fn execute(args: SecuritiesArgs, config: Config) -> Result<()> {
 /// Useful for verifying CSV file paths and AlphaVantage API connectivity
 /// before running a full bootstrap.
 async fn execute_dry_run(args: SecuritiesArgs, config: Config) -> Result<()> {
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   let loader_config = LoaderConfig {
     max_concurrent_requests: args.concurrent,
@@ -108,12 +108,11 @@
 //! av-cli load top-movers --dry-run --verbose
 //! ```
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use chrono::NaiveDate;
 use clap::Args;
 use std::sync::Arc;
 
-use av_client::AlphaVantageClient;
 use av_database_postgres::repository::DatabaseContext;
 use av_loaders::{
   DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
@@ -171,13 +170,14 @@ pub struct TopMoversArgs {
 ///
 /// Orchestrates the top-movers loading pipeline:
 ///
-/// 1. **API client setup** — Creates [`AlphaVantageClient`] from the
+/// 1. **API client setup** — Creates [`AlphaVantageClient`](av_client::AlphaVantageClient) from the
 ///    [`Config`].
 /// 2. **Loader context** — Creates [`LoaderContext`] with
 ///    `max_concurrent_requests = 1` (single API call) and `show_progress = false`.
 /// 3. **Repository attachment** — Unless `--dry-run`:
 ///    - News repository (used for missing-symbol tracking).
 ///    - Process tracker for monitoring.
+///
 ///    Cache repository is attached unless `--no-cache`, even in dry-run mode
 ///    (so cached responses can be read).
 /// 4. **Loader configuration** — Builds [`TopMoversConfig`] with
@@ -198,10 +198,7 @@ pub struct TopMoversArgs {
 /// loader execution.
 pub async fn execute(args: TopMoversArgs, config: Config) -> Result<()> {
   // Create API client with the correct Config type
-  let client = Arc::new(
-    AlphaVantageClient::new(config.api_config)
-      .map_err(|e| anyhow!("Failed to create API client: {}", e))?,
-  );
+  let client = Arc::new(config.api_client()?);
 
   // Create loader configuration
   let loader_config = LoaderConfig {
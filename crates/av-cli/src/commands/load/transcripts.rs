@@ -0,0 +1,255 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Earnings call transcript loader for `av-cli load transcripts`.
+//!
+//! Backfills the last `--quarters` fiscal quarters of earnings call
+//! transcripts from the AlphaVantage `EARNINGS_CALL_TRANSCRIPT` endpoint
+//! into `earnings_transcripts` and `transcript_segments`, where segment text
+//! is full-text indexed next to the news tables.
+//!
+//! ## Symbol Selection
+//!
+//! - `--symbol`: the listed tickers.
+//! - Otherwise: every equity with a loaded company overview
+//!   (`overview = true`), in priority order, capped by `--limit`.
+//!
+//! Each symbol costs one API call per quarter not already stored, so a
+//! first backfill of 8 quarters for 500 symbols is 4,000 calls.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Last four quarters for two symbols
+//! av-cli load transcripts --symbol IBM,MSFT
+//!
+//! # Two years for the top 100 equities
+//! av-cli load transcripts --quarters 8 --limit 100
+//!
+//! # Re-load quarters that are already stored
+//! av-cli load transcripts --symbol IBM --reload
+//! ```
+
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use clap::Args;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use av_database_postgres::{repository::DatabaseContext, schema::symbols};
+use av_loaders::{
+  DataLoader, LoaderConfig, LoaderContext, ProcessTracker,
+  earnings_transcript_loader::{
+    EarningsTranscriptConfig, EarningsTranscriptLoader, EarningsTranscriptLoaderInput,
+  },
+};
+
+use crate::config::Config;
+
+/// Command-line arguments for `av-cli load transcripts`.
+#[derive(Args, Debug)]
+pub struct TranscriptsArgs {
+  /// Symbol(s) to load (comma-separated). Defaults to all equities with a
+  /// loaded overview.
+  #[arg(short, long, value_delimiter = ',')]
+  symbol: Vec<String>,
+
+  /// Number of completed quarters to backfill, newest first.
+  #[arg(short, long, default_value = "4")]
+  quarters: usize,
+
+  /// Maximum number of symbols when `--symbol` is not given.
+  #[arg(short, long)]
+  limit: Option<i64>,
+
+  /// Re-fetch quarters that are already stored instead of skipping them.
+  #[arg(long)]
+  reload: bool,
+
+  /// Fetch the data but skip database writes.
+  #[arg(long)]
+  dry_run: bool,
+
+  /// Print one line per transcript.
+  #[arg(short = 'v', long)]
+  verbose: bool,
+
+  /// Disable response caching entirely.
+  #[arg(long)]
+  no_cache: bool,
+
+  /// Bypass the cache and fetch fresh data, but continue to write the new
+  /// response into the cache.
+  #[arg(long)]
+  force_refresh: bool,
+
+  /// Cache TTL in hours. Defaults to 720 (30 days).
+  #[arg(long, default_value = "720")]
+  cache_ttl: i64,
+}
+
+/// Resolves `--symbol` tickers to equity SIDs, or selects equities with
+/// overviews when no tickers were given.
+fn resolve_symbols(args: &TranscriptsArgs, config: &Config) -> Result<Vec<(i64, String)>> {
+  let mut conn = PgConnection::establish(&config.database_url)?;
+
+  let mut query = symbols::table
+    .filter(symbols::sec_type.eq("Equity"))
+    .order_by(symbols::priority.asc())
+    .select((symbols::sid, symbols::symbol))
+    .into_boxed();
+
+  if args.symbol.is_empty() {
+    query = query.filter(symbols::overview.eq(true));
+    if let Some(limit) = args.limit {
+      query = query.limit(limit);
+    }
+    return Ok(query.load(&mut conn)?);
+  }
+
+  let wanted: Vec<String> = args.symbol.iter().map(|s| s.trim().to_uppercase()).collect();
+  let rows: Vec<(i64, String)> = query.filter(symbols::symbol.eq_any(&wanted)).load(&mut conn)?;
+
+  let mut resolved = Vec::with_capacity(wanted.len());
+  for symbol in &wanted {
+    match rows.iter().find(|(_, s)| s == symbol) {
+      Some(row) => resolved.push(row.clone()),
+      None => {
+        return Err(anyhow!(
+          "Symbol {} not found in database. Please run 'av load securities' first",
+          symbol
+        ));
+      }
+    }
+  }
+
+  Ok(resolved)
+}
+
+/// Main entry point for `av-cli load transcripts`.
+///
+/// Resolves the target symbols, computes the quarter list, runs
+/// [`EarningsTranscriptLoader`], and prints a summary.
+///
+/// # Errors
+///
+/// Returns errors from symbol resolution, API client creation, database
+/// context creation, or loader execution. Per-quarter failures are reported
+/// in the summary rather than aborting the run.
+pub async fn execute(args: TranscriptsArgs, config: Config) -> Result<()> {
+  if args.quarters == 0 {
+    return Err(anyhow!("--quarters must be at least 1"));
+  }
+
+  let targets = resolve_symbols(&args, &config)?;
+  if targets.is_empty() {
+    println!("No symbols to load. Run 'av load overviews' first or pass --symbol.");
+    return Ok(());
+  }
+
+  let quarters = EarningsTranscriptLoader::recent_quarters(Utc::now().date_naive(), args.quarters);
+
+  let client = Arc::new(config.api_client()?);
+
+  let loader_config = LoaderConfig {
+    max_concurrent_requests: 1, // Quarters are walked sequentially
+    retry_attempts: 3,
+    retry_delay_ms: 1000,
+    show_progress: false,
+    track_process: !args.dry_run,
+    batch_size: 1000,
+  };
+
+  let mut context = LoaderContext::new(client, loader_config);
+
+  let db_context = DatabaseContext::new(&config.database_url)
+    .map_err(|e| anyhow!("Failed to create database context: {}", e))?;
+
+  if !args.dry_run {
+    context = context.with_process_tracker(ProcessTracker::new());
+  }
+
+  if !args.no_cache {
+    let cache_repo: Arc<dyn av_database_postgres::repository::CacheRepository> =
+      Arc::new(db_context.cache_repository());
+    context = context.with_cache_repository(cache_repo);
+  }
+
+  let loader_config = EarningsTranscriptConfig {
+    skip_existing: !args.reload,
+    enable_cache: !args.no_cache,
+    cache_ttl_hours: args.cache_ttl,
+    force_refresh: args.force_refresh,
+  };
+
+  let database_url = if args.dry_run { None } else { Some(config.database_url.clone()) };
+  let loader = EarningsTranscriptLoader::new(loader_config, database_url);
+
+  let input = EarningsTranscriptLoaderInput { symbols: targets, quarters };
+  let output = loader.load(&context, input).await?;
+
+  println!("\n╔════════════════════════════════════════╗");
+  println!("║       EARNINGS CALL TRANSCRIPTS        ║");
+  println!("╚════════════════════════════════════════╝\n");
+
+  if args.verbose {
+    for result in &output.transcripts {
+      let source = if result.from_cache { "📦" } else { "🌐" };
+      println!(
+        "{} {:<8} {} {:>4} segments",
+        source, result.symbol, result.quarter, result.segments
+      );
+    }
+    println!();
+  }
+
+  println!("📊 Transcripts loaded: {}", output.transcripts.len());
+  if output.quarters_skipped > 0 {
+    println!("   Already stored (skipped): {}", output.quarters_skipped);
+  }
+  if output.empty_quarters > 0 {
+    println!("   Not yet published: {}", output.empty_quarters);
+  }
+
+  if !output.errors.is_empty() {
+    println!("\n❌ Errors: {}", output.errors.len());
+    for error in &output.errors {
+      println!("   - {}", error);
+    }
+  }
+
+  if args.dry_run {
+    println!("\n⚠️  Dry run mode - no data saved to database");
+  } else {
+    println!("\n✅ Database Update:");
+    println!("   Segments saved: {}", output.segments_saved);
+  }
+
+  Ok(())
+}
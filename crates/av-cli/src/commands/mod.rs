@@ -30,7 +30,7 @@
 //! Command module registry for `av-cli`.
 //!
 //! This module serves as the top-level organizational hub for all CLI commands.
-//! It re-exports four public submodules, each corresponding to a primary command
+//! It re-exports five public submodules, each corresponding to a primary command
 //! category in the [`Commands`](crate::Commands) enum defined in `main.rs`.
 //!
 //! ## Module Structure
@@ -60,6 +60,8 @@
 //! │   ├── numeric_helpers.rs   Numeric conversion helpers
 //! │   ├── sid_generator.rs     Security ID generation
 //! │   └── missing_symbol_logger.rs  Logging for missing symbol detection
+//! ├── quota.rs             ← API call ledger report (single file)
+//! │                            QuotaCommand, execute()
 //! ├── query.rs             ← Data query commands (single file)
 //! │                            QueryCommand, QuerySubcommands, execute()
 //! │                            Subcommands: symbol, list-symbols
@@ -98,6 +100,7 @@
 //! and command dispatch:
 //! - [`load::LoadCommand`] — Clap args struct for `av-cli load`
 //! - [`query::QueryCommand`] — Clap args struct for `av-cli query`
+//! - [`quota::QuotaCommand`] — Clap args struct for `av-cli quota`
 //! - [`sync::SyncCommands`], [`sync::handle_sync`] — Enum + handler for `av-cli sync`
 //! - [`update::crypto::CryptoUpdateCommands`], [`update::crypto::handle_crypto_update`]
 //!   — Enum + handler for `av-cli update crypto`
@@ -110,6 +113,10 @@
 /// [`load::LoadCommand`] and [`load::execute`].
 pub mod load;
 
+/// API call ledger report — calls used and remaining today per API key and
+/// function. See [`quota::QuotaCommand`] and [`quota::execute`].
+pub mod quota;
+
 /// Data query commands — look up stored symbol data from the database.
 /// Currently **unimplemented** (both subcommands contain `todo!` placeholders).
 /// Exports [`query::QueryCommand`] and [`query::execute`].
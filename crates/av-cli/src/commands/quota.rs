@@ -0,0 +1,157 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Report AlphaVantage calls used and remaining per API key.
+//!
+//! `av quota` reads the call ledger selected by
+//! [`QuotaStore`](crate::quota::QuotaStore) and prints, for each configured
+//! key, the calls recorded on a UTC day, its daily limit and what is left,
+//! followed by a per-function breakdown:
+//!
+//! ```text
+//! API calls for 2026-10-17 (UTC)
+//!
+//! Key                         Used   Limit   Remaining
+//! ****ABCD                      18      25           7
+//!   TIME_SERIES_DAILY          15
+//!   OVERVIEW                    3
+//! ```
+//!
+//! Keys without a configured daily limit show `-` for limit and remaining;
+//! the primary key's limit is `AV_DAILY_LIMIT`. Keys found in the ledger but
+//! no longer configured are listed too, by their
+//! [`ledger_key`](ApiKeyConfig::ledger_key) since only its hash can tell
+//! apart keys ending in the same four characters.
+//!
+//! ```bash
+//! av quota
+//! av quota --date 2026-10-16
+//! ```
+
+use crate::config::Config;
+use anyhow::{Result, anyhow};
+use av_client::QuotaUsage;
+use av_core::ApiKeyConfig;
+use chrono::{NaiveDate, Utc};
+use clap::Args;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Arguments for `av quota`.
+#[derive(Args, Debug)]
+pub struct QuotaCommand {
+  /// UTC day to report, as YYYY-MM-DD (default: today)
+  #[arg(short, long)]
+  date: Option<NaiveDate>,
+}
+
+/// Prints the quota report for the requested day.
+///
+/// # Errors
+///
+/// Fails if the ledger is disabled (`AV_QUOTA_LEDGER=off`) or cannot be
+/// read.
+pub async fn execute(cmd: QuotaCommand, config: Config) -> Result<()> {
+  let date = cmd.date.unwrap_or_else(|| Utc::now().date_naive());
+  let ledger = config
+    .quota_store
+    .open(&config.database_url)?
+    .ok_or_else(|| anyhow!("The call ledger is disabled (AV_QUOTA_LEDGER=off)"))?;
+  let usage = ledger.usage(date).await.map_err(|e| anyhow!("Failed to read call ledger: {}", e))?;
+
+  print!("{}", report(date, &config.api_config.api_keys(), &usage));
+  Ok(())
+}
+
+/// Renders usage for `date` grouped by key, configured keys first.
+fn report(date: NaiveDate, keys: &[ApiKeyConfig], usage: &[QuotaUsage]) -> String {
+  let mut by_key: BTreeMap<&str, Vec<&QuotaUsage>> = BTreeMap::new();
+  for entry in usage {
+    by_key.entry(entry.key.as_str()).or_default().push(entry);
+  }
+
+  // (ledger key, label, daily limit)
+  let mut rows: Vec<(String, String, Option<u32>)> =
+    keys.iter().map(|k| (k.ledger_key(), k.masked_key(), k.daily_limit)).collect();
+  for key in by_key.keys() {
+    if !rows.iter().any(|(ledger_key, _, _)| ledger_key == key) {
+      rows.push((key.to_string(), key.to_string(), None));
+    }
+  }
+
+  let mut out = String::new();
+  let _ = writeln!(out, "API calls for {} (UTC)\n", date);
+  let _ = writeln!(out, "{:<25} {:>6} {:>7} {:>11}", "Key", "Used", "Limit", "Remaining");
+  for (ledger_key, key, limit) in rows {
+    let mut entries = by_key.remove(ledger_key.as_str()).unwrap_or_default();
+    entries.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.function.cmp(&b.function)));
+    let used: u32 = entries.iter().map(|e| e.calls).sum();
+    let (limit, remaining) = match limit {
+      Some(limit) => (limit.to_string(), limit.saturating_sub(used).to_string()),
+      None => ("-".to_string(), "-".to_string()),
+    };
+    let _ = writeln!(out, "{:<25} {:>6} {:>7} {:>11}", key, used, limit, remaining);
+    for entry in entries {
+      let _ = writeln!(out, "  {:<24} {:>6}", entry.function, entry.calls);
+    }
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_report_lists_configured_and_ledger_only_keys() {
+    let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+    let keys = vec![ApiKeyConfig::new("PRIMARYAAAA", 75).with_daily_limit(25)];
+    let (primary, retired) = (keys[0].ledger_key(), ApiKeyConfig::new("OLDAAAA", 5).ledger_key());
+    let usage = |key: &str, function: &str, calls| QuotaUsage {
+      date,
+      key: key.to_string(),
+      function: function.to_string(),
+      calls,
+    };
+    let usage = vec![
+      usage(&primary, "OVERVIEW", 3),
+      usage(&primary, "TIME_SERIES_DAILY", 15),
+      usage(&retired, "GLOBAL_QUOTE", 2),
+    ];
+
+    let text = report(date, &keys, &usage);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "API calls for 2026-10-17 (UTC)");
+    assert_eq!(lines[3].split_whitespace().collect::<Vec<_>>(), ["****AAAA", "18", "25", "7"]);
+    assert!(lines[4].trim_start().starts_with("TIME_SERIES_DAILY"));
+    // A retired key with the same last four characters is a separate row
+    assert_eq!(lines[6].split_whitespace().collect::<Vec<_>>(), [retired.as_str(), "2", "-", "-"]);
+    assert!(!text.contains("PRIMARY"));
+  }
+}
@@ -91,6 +91,7 @@ use diesel::prelude::*;
 /// Diesel ORM. All reports are read-only and produce formatted text output
 /// on stdout.
 #[derive(Subcommand, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum StatsCommands {
   /// Report on API symbol mapping coverage and staleness.
   ///
@@ -341,6 +342,7 @@ async fn execute_crypto_mapping_stats(
 
   // Detailed view
   if detailed {
+    #[allow(clippy::type_complexity)]
     let mappings: Vec<(String, String, String, Option<String>, Option<i32>)> =
       if let Some(ref source) = source_filter {
         crypto_api_map::table
@@ -450,11 +452,11 @@ async fn execute_crypto_market_stats(
   println!("Total markets: {}", total_markets);
 
   // Exchange breakdown - simplified query
-  let exchange_stats: Vec<(String, i64)> = if symbol_filter.is_some() {
+  let exchange_stats: Vec<(String, i64)> = if let Some(ref symbol) = symbol_filter {
     // For specific symbol, get exchange breakdown with join
     let mut query = symbols::table
       .inner_join(crypto_markets::table)
-      .filter(symbols::symbol.eq(symbol_filter.as_ref().unwrap()))
+      .filter(symbols::symbol.eq(symbol))
       .group_by(crypto_markets::exchange)
       .select((crypto_markets::exchange, diesel::dsl::count_star()))
       .order(diesel::dsl::count_star().desc())
@@ -63,6 +63,7 @@
 //! │   ├── top-movers        Load top market gainers/losers
 //! │   ├── daily             Load daily time series data
 //! │   └── intraday          Load intraday time series data
+//! ├── quota             Show API calls used and remaining today per key
 //! ├── query             Query stored data (currently unimplemented)
 //! │   ├── symbol            Look up a specific symbol
 //! │   └── list-symbols      List symbols with optional exchange filter
@@ -90,6 +91,18 @@
 //! | `DATABASE_URL`         | Yes      | PostgreSQL connection string                |
 //! | `NASDAQ_LISTED`        | No       | Path to NASDAQ CSV file (has default)      |
 //! | `OTHER_LISTED`         | No       | Path to NYSE/other CSV file (has default)  |
+//! | `AV_QUOTA_FILE`        | No       | Keep the call ledger in a JSON file        |
+//! | `AV_QUOTA_LEDGER`      | No       | `off` disables the call ledger             |
+//! | `AV_CACHE_DIR`         | No       | Cache API responses in this directory      |
+//! | `AV_AUDIT_LOG`         | No       | Append a JSONL line per API request here   |
+//! | `AV_PROXY_URL`         | No       | Send API requests through this proxy       |
+//! | `AV_ROOT_CERTS`        | No       | Extra CA certificate PEM files to trust    |
+//! | `AV_CONFIG_FILE`       | No       | Config file (`--config`)                   |
+//! | `AV_PROFILE`           | No       | Config file profile (`--profile`)          |
+//!
+//! Any of these can also be set in a TOML config file, by default
+//! `~/.config/av/config.toml`, with named profiles (e.g. `free`/`premium`).
+//! The environment overrides the file, and command-line flags override both.
 //!
 //! ## Example Usage
 //!
@@ -110,23 +123,27 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use dotenvy::dotenv;
+use std::path::PathBuf;
+use tracing::info;
 
 mod commands;
 use crate::commands::update::stats::{StatsCommands, handle_stats};
 use commands::{
   load::LoadCommand,
   query::QueryCommand,
+  quota::QuotaCommand,
   sync::{SyncCommands, handle_sync},
   update::crypto::{CryptoUpdateCommands, handle_crypto_update},
 };
 
 mod config;
+mod quota;
 
 /// Top-level CLI structure parsed by [`clap`].
 ///
-/// Provides a single global flag (`--verbose`) that switches the tracing log level
-/// from `info` to `debug`, and delegates to one of the four primary [`Commands`]
-/// subcommands.
+/// Provides global flags for the log level (`--verbose`, which switches from
+/// `info` to `debug`) and config file selection (`--profile`, `--config`),
+/// and delegates to one of the five primary [`Commands`] subcommands.
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 #[command(name = "av-cli")]
@@ -138,6 +155,14 @@ struct Cli {
   /// Verbose output — sets log level to `debug` (default is `info`)
   #[arg(short, long, global = true)]
   verbose: bool,
+
+  /// Config file profile to use (default: the file's `profile` setting)
+  #[arg(long, global = true, env = "AV_PROFILE")]
+  profile: Option<String>,
+
+  /// Config file to read (default: `~/.config/av/config.toml`)
+  #[arg(long = "config", global = true, env = "AV_CONFIG_FILE", value_name = "PATH")]
+  config_file: Option<PathBuf>,
 }
 
 /// Primary command categories for the CLI.
@@ -146,6 +171,8 @@ struct Cli {
 /// - [`Load`](Commands::Load) — Ingest data from AlphaVantage API or CSV files into
 ///   the PostgreSQL database. Supports 17 distinct subcommands covering equities,
 ///   crypto, news, and market movers.
+/// - [`Quota`](Commands::Quota) — Report API calls used and remaining per key for a
+///   UTC day, from the call ledger shared by all CLI runs.
 /// - [`Query`](Commands::Query) — Look up stored symbol data. Currently unimplemented
 ///   (`todo!` placeholders).
 /// - [`Sync`](Commands::Sync) — Synchronize market and crypto data. Currently
@@ -155,6 +182,7 @@ struct Cli {
 #[derive(Subcommand, Debug)]
 enum Commands {
   Load(LoadCommand),
+  Quota(QuotaCommand),
   Query(QueryCommand),
   Sync {
     #[command(subcommand)]
@@ -189,7 +217,7 @@ pub enum UpdateCommands {
 
 /// Application entry point.
 ///
-/// Performs four setup steps before dispatching the user's command:
+/// Performs five setup steps before dispatching the user's command:
 ///
 /// 1. **Environment loading** — Calls [`dotenv()`] to load variables from a `.env` file
 ///    in the working directory (or parent directories). Failures are silently ignored
@@ -199,37 +227,70 @@ pub enum UpdateCommands {
 /// 2. **Argument parsing** — Uses [`clap`] to parse CLI arguments into the [`Cli`] struct.
 ///    Invalid arguments produce an automatic error/help message and exit.
 ///
-/// 3. **Logging initialization** — Configures [`tracing_subscriber`] with a format
+/// 3. **Config file** — Applies the selected profile of the config file (see
+///    [`config::apply_config_file`]) to the environment, without overriding
+///    variables that are already set, and parses the arguments again so flags
+///    that fall back to environment variables see the file's values. Setting
+///    environment variables is only sound while the process has one thread, so
+///    `main` is synchronous and builds the tokio runtime after this step.
+///
+/// 4. **Logging initialization** — Configures [`tracing_subscriber`] with a format
 ///    subscriber. The log level is `debug` if `--verbose` is passed, otherwise `info`.
 ///    The `RUST_LOG` environment variable can further override this via the env filter.
 ///
-/// 4. **Configuration** — Loads [`config::Config`] from environment variables. This
+/// 5. **Configuration** — Loads [`config::Config`] from environment variables. This
 ///    includes the AlphaVantage API key, base URL, rate limits, timeouts, retry counts,
 ///    the PostgreSQL `DATABASE_URL`, and paths to NASDAQ/NYSE CSV listing files.
 ///
-/// After setup, the parsed command is matched and dispatched to the appropriate handler:
+/// After setup, [`run`] is driven on a multi-threaded tokio runtime and the parsed
+/// command is matched and dispatched to the appropriate handler:
 /// - `load` → [`commands::load::execute`]
+/// - `quota` → [`commands::quota::execute`]
 /// - `query` → [`commands::query::execute`]
 /// - `sync` → [`handle_sync`]
 /// - `update` → [`handle_update`] (local routing function)
-#[tokio::main]
-async fn main() -> Result<()> {
+fn main() -> Result<()> {
   // Load environment variables from .env file (if present)
   dotenv().ok();
 
   // Parse CLI arguments via clap
   let cli = Cli::parse();
 
+  // The config file sits below the environment; once applied, parse again so
+  // flags backed by environment variables pick up its values. This writes to
+  // the environment, so it must happen before the runtime starts any threads
+  let applied = config::apply_config_file(cli.config_file.as_deref(), cli.profile.as_deref())?;
+  let cli = if applied.is_some() { Cli::parse() } else { cli };
+
   // Initialize tracing subscriber with log level based on --verbose flag
   let log_level = if cli.verbose { "debug" } else { "info" };
   tracing_subscriber::fmt().with_env_filter(log_level).init();
 
+  if let Some(applied) = applied {
+    info!(
+      "Using config file {} (profile: {}); it supplied {}",
+      applied.path.display(),
+      applied.profile.as_deref().unwrap_or("none"),
+      if applied.variables.is_empty() {
+        "nothing".to_string()
+      } else {
+        applied.variable_names().join(", ")
+      }
+    );
+  }
+
+  tokio::runtime::Builder::new_multi_thread().enable_all().build()?.block_on(run(cli.command))
+}
+
+/// Loads the configuration from the environment and runs `command`.
+async fn run(command: Commands) -> Result<()> {
   // Load configuration from environment variables
   let config = config::Config::from_env()?;
 
   // Dispatch to the appropriate command handler
-  match cli.command {
+  match command {
     Commands::Load(cmd) => commands::load::execute(cmd, config).await?,
+    Commands::Quota(cmd) => commands::quota::execute(cmd, config).await?,
     Commands::Query(cmd) => commands::query::execute(cmd, config).await?,
     Commands::Sync { cmd } => handle_sync(cmd, config).await?,
     Commands::Update { cmd } => handle_update(cmd, config).await?,
@@ -270,8 +331,15 @@ async fn handle_update(cmd: UpdateCommands, config: config::Config) -> Result<()
         api_key: config.api_config.api_key,
         base_url: config.api_config.base_url,
         rate_limit: config.api_config.rate_limit,
+        daily_limit: config.api_config.daily_limit,
         timeout_secs: config.api_config.timeout_secs,
         max_retries: config.api_config.max_retries,
+        key_pool: config.api_config.key_pool,
+        key_selection: config.api_config.key_selection,
+        http: config.api_config.http,
+        cache_dir: config.api_config.cache_dir,
+        quota_file: config.api_config.quota_file,
+        audit_log: config.api_config.audit_log,
       };
       handle_crypto_update(cmd, core_config).await
     }
@@ -0,0 +1,143 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Where the CLI keeps its AlphaVantage call ledger.
+//!
+//! Every API client the CLI creates records its calls in a
+//! [`QuotaLedger`](av_client::QuotaLedger), so consecutive `av load` runs
+//! share one daily count per key instead of each starting from zero.
+//! `av quota` reads the same ledger.
+//!
+//! | Store                  | Selected by                                  |
+//! |------------------------|----------------------------------------------|
+//! | [`QuotaStore::File`]   | `AV_QUOTA_FILE=<path>`                       |
+//! | [`QuotaStore::Off`]    | `AV_QUOTA_LEDGER=off`                        |
+//! | [`QuotaStore::Postgres`] | Otherwise: the `api_quota_usage` table     |
+//!
+//! Daily limits come from the key configuration: `AV_DAILY_LIMIT=25` for
+//! the primary key, or e.g. `ALPHA_VANTAGE_API_KEYS=<key>:75:25` for 25
+//! calls per day with a pool key.
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use av_client::{FileQuotaLedger, QuotaLedger, QuotaUsage};
+use av_core::FuncType;
+use av_database_postgres::{DatabaseContext, QuotaRepository};
+use chrono::NaiveDate;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Storage selected for the call ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuotaStore {
+  /// The `api_quota_usage` table in `DATABASE_URL`
+  Postgres,
+  /// A JSON file shared by every process pointed at it
+  File(PathBuf),
+  /// Calls are not recorded
+  Off,
+}
+
+impl QuotaStore {
+  /// A file named by [`Config::quota_file`](av_core::Config::quota_file)
+  /// (`AV_QUOTA_FILE`) wins; otherwise `AV_QUOTA_LEDGER` picks the store.
+  pub fn from_config(api_config: &av_core::Config) -> Result<Self> {
+    if let Some(path) = &api_config.quota_file {
+      return Ok(Self::File(path.clone()));
+    }
+    match std::env::var("AV_QUOTA_LEDGER") {
+      Err(_) => Ok(Self::Postgres),
+      Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+        "" | "postgres" | "db" => Ok(Self::Postgres),
+        "off" | "none" => Ok(Self::Off),
+        other => {
+          Err(anyhow!("Unknown AV_QUOTA_LEDGER value '{}' (expected postgres or off)", other))
+        }
+      },
+    }
+  }
+
+  /// Opens the ledger, or `None` for [`QuotaStore::Off`].
+  pub fn open(&self, database_url: &str) -> Result<Option<Arc<dyn QuotaLedger>>> {
+    Ok(match self {
+      Self::Postgres => {
+        // A couple of connections is plenty for one counter per request
+        let db = DatabaseContext::with_pool_config(database_url, 4, 1)
+          .map_err(|e| anyhow!("Failed to open quota ledger database: {}", e))?;
+        Some(Arc::new(PgQuotaLedger { repo: db.quota_repository() }))
+      }
+      Self::File(path) => Some(Arc::new(FileQuotaLedger::new(path))),
+      Self::Off => None,
+    })
+  }
+}
+
+/// [`QuotaLedger`] backed by the `api_quota_usage` table.
+struct PgQuotaLedger<R> {
+  repo: R,
+}
+
+#[async_trait]
+impl<R: QuotaRepository> QuotaLedger for PgQuotaLedger<R> {
+  async fn record(&self, date: NaiveDate, key: &str, function: FuncType) -> av_core::Result<()> {
+    self
+      .repo
+      .record_call(date, key, &function.to_string())
+      .await
+      .map_err(|e| av_core::Error::Unexpected(format!("Quota ledger: {}", e)))
+  }
+
+  async fn usage(&self, date: NaiveDate) -> av_core::Result<Vec<QuotaUsage>> {
+    let rows = self
+      .repo
+      .usage_for(date)
+      .await
+      .map_err(|e| av_core::Error::Unexpected(format!("Quota ledger: {}", e)))?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| QuotaUsage {
+          date: row.usage_date,
+          key: row.api_key,
+          function: row.function,
+          calls: row.calls.max(0) as u32,
+        })
+        .collect(),
+    )
+  }
+
+  async fn calls(&self, date: NaiveDate, key: &str) -> av_core::Result<u32> {
+    let calls = self
+      .repo
+      .calls_for_key(date, key)
+      .await
+      .map_err(|e| av_core::Error::Unexpected(format!("Quota ledger: {}", e)))?;
+    Ok(u32::try_from(calls).unwrap_or(u32::MAX))
+  }
+}
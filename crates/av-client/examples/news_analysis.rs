@@ -37,6 +37,8 @@
 //! - Sentiment trend analysis
 //! - Real-time news monitoring
 
+#![allow(dead_code)]
+
 use av_client::AlphaVantageClient;
 use av_core::{Config, Error};
 use av_models::news::NewsSentiment;
@@ -101,9 +103,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
   tracing_subscriber::fmt::init();
 
   // Load configuration
-  let config = Config::from_env().map_err(|e| {
+  let config = Config::from_env().inspect_err(|_| {
     error!("Failed to load configuration. Make sure ALPHA_VANTAGE_API_KEY is set.");
-    e
   })?;
 
   // Create client
@@ -36,6 +36,8 @@
 //! - Calculate portfolio metrics
 //! - Handle errors and rate limiting
 
+#![allow(dead_code)]
+
 use av_client::AlphaVantageClient;
 use av_core::{Config, Error};
 use colored::*;
@@ -221,15 +223,8 @@ async fn get_quote_with_retry(
   symbol: &str,
 ) -> Result<GlobalQuote, Error> {
   for attempt in 1..=3 {
-    match client.time_series().daily(symbol, "compact").await {
-      Ok(daily_data) => {
-        // Extract latest price from daily data
-        if let Some((_, latest)) = daily_data.latest() {
-          // Create a mock GlobalQuote from daily data
-          // In a real implementation, you'd use the actual GLOBAL_QUOTE endpoint
-          return Ok(create_mock_quote(symbol, &latest.close));
-        }
-      }
+    match client.time_series().quote(symbol).await {
+      Ok(quote) => return Ok(quote),
       Err(Error::RateLimit(_)) if attempt < 3 => {
         println!("    Rate limited, waiting...");
         client.wait_for_rate_limit().await?;
@@ -855,27 +850,6 @@ async fn get_top_movers(client: &AlphaVantageClient) -> Result<(), Error> {
   Ok(())
 }
 
-/// Create a mock GlobalQuote for demo purposes
-/// In a real implementation, you'd use the actual GLOBAL_QUOTE endpoint
-fn create_mock_quote(symbol: &str, price: &str) -> GlobalQuote {
-  use av_models::time_series::QuoteData;
-
-  GlobalQuote {
-    global_quote: QuoteData {
-      symbol: symbol.to_string(),
-      open: price.to_string(),
-      high: price.to_string(),
-      low: price.to_string(),
-      price: price.to_string(),
-      volume: "1000000".to_string(),
-      latest_trading_day: "2024-01-15".to_string(),
-      previous_close: price.to_string(),
-      change: "0.00".to_string(),
-      change_percent: "0.00%".to_string(),
-    },
-  }
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
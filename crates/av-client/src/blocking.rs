@@ -0,0 +1,497 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Synchronous client for callers without an async runtime.
+//!
+//! Enabled with the `blocking` feature. [`AlphaVantageClient`] wraps the
+//! async [`crate::AlphaVantageClient`] together with a private
+//! current-thread Tokio runtime, and each endpoint group mirrors its async
+//! counterpart method for method, returning the same models and
+//! [`Error`] type.
+//!
+//! These methods block the calling thread and panic if called from inside
+//! an async runtime; async code should use the async client directly.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use av_client::blocking::AlphaVantageClient;
+//! use av_core::Config;
+//!
+//! let client = AlphaVantageClient::new(Config::from_env()?)?;
+//! let overview = client.fundamentals().company_overview("IBM")?;
+//! println!("{} market cap: {}", overview.name, overview.market_capitalization);
+//! # Ok::<(), av_core::Error>(())
+//! ```
+
+use crate::endpoints::{
+  analytics, commodities, crypto, crypto_social, economics, forex, fundamentals, indicators, news,
+  options, time_series,
+};
+use crate::transport::{
+  CachePolicy, HttpTransport, KeyStats, QuotaLedger, RequestHook, ResponseCache,
+};
+use analytics::AnalyticsRequest;
+use av_core::types::common::{
+  EconomicInterval, IndicatorInterval, Interval, SeriesType, TreasuryMaturity,
+};
+use av_core::{Config, Error, FuncType, Result};
+use av_models::{
+  analytics::ReturnsAnalytics,
+  commodities::CommoditySeries,
+  crypto::*,
+  crypto_social::{CoinGeckoSocialResponse, GitHubRepoInfo},
+  economics::EconomicSeries,
+  forex::*,
+  fundamentals::*,
+  indicators::*,
+  news::NewsSentiment,
+  options::OptionChain,
+  time_series::*,
+};
+use chrono::NaiveDate;
+use crypto::CryptoRequest;
+use forex::FxRequest;
+use fundamentals::{EarningsCalendarRequest, ListingStatusRequest};
+use futures::StreamExt;
+use indicators::{BbandsParams, MacdParams, StochParams};
+use news::NewsRequest;
+use std::collections::HashMap;
+use std::sync::Arc;
+use time_series::{IntradayHistory, IntradayMonth, TimeSeriesRequest};
+use tokio::runtime::{Builder, Runtime};
+
+/// Synchronous AlphaVantage API client
+///
+/// Cheap to share by reference; endpoint groups borrow the client's
+/// runtime and drive each request to completion before returning.
+pub struct AlphaVantageClient {
+  inner: crate::AlphaVantageClient,
+  runtime: Runtime,
+}
+
+impl AlphaVantageClient {
+  /// Create a client from `config`, as [`crate::AlphaVantageClient::new`]
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the HTTP client or the runtime cannot be created.
+  pub fn new(config: Config) -> Result<Self> {
+    Self::from_async(crate::AlphaVantageClient::new(config)?)
+  }
+
+  /// Create a client that sends requests through `backend`, as
+  /// [`crate::AlphaVantageClient::with_transport`]
+  pub fn with_transport(config: Config, backend: impl HttpTransport + 'static) -> Result<Self> {
+    Self::from_async(crate::AlphaVantageClient::with_transport(config, backend)?)
+  }
+
+  /// Create a client that counts calls in `ledger`, as
+  /// [`crate::AlphaVantageClient::with_quota_ledger`]
+  pub fn with_quota_ledger(config: Config, ledger: Arc<dyn QuotaLedger>) -> Result<Self> {
+    Self::from_async(crate::AlphaVantageClient::with_quota_ledger(config, ledger)?)
+  }
+
+  /// Create a client that serves repeated requests from `cache`, as
+  /// [`crate::AlphaVantageClient::with_cache`]
+  pub fn with_cache(
+    config: Config,
+    cache: Arc<dyn ResponseCache>,
+    policy: CachePolicy,
+  ) -> Result<Self> {
+    Self::from_async(crate::AlphaVantageClient::with_cache(config, cache, policy)?)
+  }
+
+  /// Create a client that reports every request attempt to `hooks`, as
+  /// [`crate::AlphaVantageClient::with_hooks`]
+  pub fn with_hooks(config: Config, hooks: Vec<Arc<dyn RequestHook>>) -> Result<Self> {
+    Self::from_async(crate::AlphaVantageClient::with_hooks(config, hooks)?)
+  }
+
+  /// Wrap an already configured async client
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Unexpected`] if the runtime cannot be started.
+  pub fn from_async(inner: crate::AlphaVantageClient) -> Result<Self> {
+    let runtime = Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .map_err(|e| Error::Unexpected(format!("Failed to start blocking runtime: {}", e)))?;
+
+    Ok(Self { inner, runtime })
+  }
+
+  /// The wrapped async client
+  pub fn as_async(&self) -> &crate::AlphaVantageClient {
+    &self.inner
+  }
+
+  /// Usage of each API key in the pool
+  pub fn key_stats(&self) -> Vec<KeyStats> {
+    self.inner.key_stats()
+  }
+
+  pub fn time_series(&self) -> TimeSeriesEndpoints<'_> {
+    TimeSeriesEndpoints { inner: self.inner.time_series(), runtime: &self.runtime }
+  }
+
+  pub fn fundamentals(&self) -> FundamentalsEndpoints<'_> {
+    FundamentalsEndpoints { inner: self.inner.fundamentals(), runtime: &self.runtime }
+  }
+
+  pub fn indicators(&self) -> IndicatorEndpoints<'_> {
+    IndicatorEndpoints { inner: self.inner.indicators(), runtime: &self.runtime }
+  }
+
+  pub fn economics(&self) -> EconomicsEndpoints<'_> {
+    EconomicsEndpoints { inner: self.inner.economics(), runtime: &self.runtime }
+  }
+
+  pub fn commodities(&self) -> CommodityEndpoints<'_> {
+    CommodityEndpoints { inner: self.inner.commodities(), runtime: &self.runtime }
+  }
+
+  pub fn options(&self) -> OptionsEndpoints<'_> {
+    OptionsEndpoints { inner: self.inner.options(), runtime: &self.runtime }
+  }
+
+  pub fn analytics(&self) -> AnalyticsEndpoints<'_> {
+    AnalyticsEndpoints { inner: self.inner.analytics(), runtime: &self.runtime }
+  }
+
+  pub fn news(&self) -> NewsEndpoints<'_> {
+    NewsEndpoints { inner: self.inner.news(), runtime: &self.runtime }
+  }
+
+  pub fn forex(&self) -> ForexEndpoints<'_> {
+    ForexEndpoints { inner: self.inner.forex(), runtime: &self.runtime }
+  }
+
+  pub fn crypto(&self) -> CryptoEndpoints<'_> {
+    CryptoEndpoints { inner: self.inner.crypto(), runtime: &self.runtime }
+  }
+
+  pub fn crypto_social(&self) -> CryptoSocialEndpoints<'_> {
+    CryptoSocialEndpoints { inner: self.inner.crypto_social(), runtime: &self.runtime }
+  }
+}
+
+/// Declares a blocking endpoint group whose methods run the async method of
+/// the same name on the client's runtime.
+macro_rules! blocking_endpoints {
+  (
+    $(#[$meta:meta])*
+    $name:ident => $module:ident::$inner:ident {
+      $( fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty; )*
+    }
+  ) => {
+    $(#[$meta])*
+    pub struct $name<'a> {
+      inner: $module::$inner,
+      runtime: &'a Runtime,
+    }
+
+    impl $name<'_> {
+      $(
+        #[doc = concat!(
+          "Blocking [`", stringify!($inner), "::", stringify!($method), "`](crate::endpoints::",
+          stringify!($module), "::", stringify!($inner), "::", stringify!($method), ")"
+        )]
+        pub fn $method(&self $(, $arg: $ty)*) -> $ret {
+          self.runtime.block_on(self.inner.$method($($arg),*))
+        }
+      )*
+    }
+  };
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`time_series::TimeSeriesEndpoints`]
+  TimeSeriesEndpoints => time_series::TimeSeriesEndpoints {
+    fn intraday(&self, symbol: &str, interval: &str) -> Result<IntradayTimeSeries>;
+    fn intraday_extended(
+      &self,
+      symbol: &str,
+      interval: &str,
+      adjusted: bool,
+      extended_hours: bool,
+    ) -> Result<IntradayTimeSeries>;
+    fn intraday_with(&self, request: &TimeSeriesRequest) -> Result<IntradayTimeSeries>;
+    fn daily(&self, symbol: &str, outputsize: &str) -> Result<DailyTimeSeries>;
+    fn daily_with(&self, request: &TimeSeriesRequest) -> Result<DailyTimeSeries>;
+    fn daily_adjusted(&self, symbol: &str, outputsize: &str) -> Result<DailyAdjustedTimeSeries>;
+    fn daily_adjusted_with(&self, request: &TimeSeriesRequest) -> Result<DailyAdjustedTimeSeries>;
+    fn weekly(&self, symbol: &str) -> Result<WeeklyTimeSeries>;
+    fn weekly_adjusted(&self, symbol: &str) -> Result<WeeklyAdjustedTimeSeries>;
+    fn monthly(&self, symbol: &str) -> Result<MonthlyTimeSeries>;
+    fn monthly_adjusted(&self, symbol: &str) -> Result<MonthlyAdjustedTimeSeries>;
+    fn quote(&self, symbol: &str) -> Result<GlobalQuote>;
+    fn market_status(&self) -> Result<MarketStatus>;
+    fn symbol_search(&self, keywords: &str) -> Result<SymbolSearch>;
+  }
+}
+
+impl TimeSeriesEndpoints<'_> {
+  /// Blocking [`TimeSeriesEndpoints::bulk_quotes`](time_series::TimeSeriesEndpoints::bulk_quotes)
+  pub fn bulk_quotes<S: AsRef<str>>(&self, symbols: &[S]) -> Result<HashMap<String, GlobalQuote>> {
+    self.runtime.block_on(self.inner.bulk_quotes(symbols))
+  }
+
+  /// Blocking [`TimeSeriesEndpoints::bulk_quotes_page`](time_series::TimeSeriesEndpoints::bulk_quotes_page)
+  pub fn bulk_quotes_page<S: AsRef<str>>(&self, symbols: &[S]) -> Result<BulkQuotes> {
+    self.runtime.block_on(self.inner.bulk_quotes_page(symbols))
+  }
+
+  /// Iterator over [`TimeSeriesEndpoints::intraday_history`](time_series::TimeSeriesEndpoints::intraday_history);
+  /// each `next` fetches one month.
+  pub fn intraday_history(
+    &self,
+    history: IntradayHistory,
+  ) -> impl Iterator<Item = Result<IntradayMonth>> + '_ {
+    let mut months = Box::pin(self.inner.intraday_history(history));
+    std::iter::from_fn(move || self.runtime.block_on(months.next()))
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`fundamentals::FundamentalsEndpoints`]
+  FundamentalsEndpoints => fundamentals::FundamentalsEndpoints {
+    fn company_overview(&self, symbol: &str) -> Result<CompanyOverview>;
+    fn income_statement(&self, symbol: &str) -> Result<IncomeStatement>;
+    fn balance_sheet(&self, symbol: &str) -> Result<BalanceSheet>;
+    fn cash_flow(&self, symbol: &str) -> Result<CashFlow>;
+    fn earnings(&self, symbol: &str) -> Result<Earnings>;
+    fn dividends(&self, symbol: &str) -> Result<Dividends>;
+    fn splits(&self, symbol: &str) -> Result<Splits>;
+    fn insider_transactions(&self, symbol: &str) -> Result<InsiderTransactions>;
+    fn earnings_call_transcript(&self, symbol: &str, quarter: &str) -> Result<EarningsCallTranscript>;
+    fn etf_profile(&self, symbol: &str) -> Result<EtfProfile>;
+    fn top_gainers_losers(&self) -> Result<TopGainersLosers>;
+    fn listing_status(&self, date: Option<&str>, state: Option<&str>) -> Result<ListingStatus>;
+    fn listing_status_with(&self, request: &ListingStatusRequest) -> Result<ListingStatus>;
+    fn earnings_calendar(&self, symbol: Option<&str>, horizon: Option<&str>) -> Result<EarningsCalendar>;
+    fn earnings_calendar_with(&self, request: &EarningsCalendarRequest) -> Result<EarningsCalendar>;
+    fn ipo_calendar(&self) -> Result<IpoCalendar>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`indicators::IndicatorEndpoints`]
+  IndicatorEndpoints => indicators::IndicatorEndpoints {
+    fn sma(
+      &self,
+      symbol: &str,
+      interval: IndicatorInterval,
+      time_period: u32,
+      series_type: SeriesType,
+    ) -> Result<SmaSeries>;
+    fn ema(
+      &self,
+      symbol: &str,
+      interval: IndicatorInterval,
+      time_period: u32,
+      series_type: SeriesType,
+    ) -> Result<EmaSeries>;
+    fn rsi(
+      &self,
+      symbol: &str,
+      interval: IndicatorInterval,
+      time_period: u32,
+      series_type: SeriesType,
+    ) -> Result<RsiSeries>;
+    fn macd(
+      &self,
+      symbol: &str,
+      interval: IndicatorInterval,
+      series_type: SeriesType,
+      macd_params: MacdParams,
+    ) -> Result<MacdSeries>;
+    fn bbands(
+      &self,
+      symbol: &str,
+      interval: IndicatorInterval,
+      time_period: u32,
+      series_type: SeriesType,
+      bbands_params: BbandsParams,
+    ) -> Result<BbandsSeries>;
+    fn adx(&self, symbol: &str, interval: IndicatorInterval, time_period: u32) -> Result<AdxSeries>;
+    fn stoch(&self, symbol: &str, interval: IndicatorInterval, stoch_params: StochParams) -> Result<StochSeries>;
+    fn atr(&self, symbol: &str, interval: IndicatorInterval, time_period: u32) -> Result<AtrSeries>;
+    fn obv(&self, symbol: &str, interval: IndicatorInterval) -> Result<ObvSeries>;
+    fn vwap(&self, symbol: &str, interval: Interval) -> Result<VwapSeries>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`economics::EconomicsEndpoints`]
+  EconomicsEndpoints => economics::EconomicsEndpoints {
+    fn real_gdp(&self, interval: Option<EconomicInterval>) -> Result<EconomicSeries>;
+    fn cpi(&self, interval: Option<EconomicInterval>) -> Result<EconomicSeries>;
+    fn inflation(&self) -> Result<EconomicSeries>;
+    fn treasury_yield(
+      &self,
+      interval: Option<EconomicInterval>,
+      maturity: Option<TreasuryMaturity>,
+    ) -> Result<EconomicSeries>;
+    fn federal_funds_rate(&self, interval: Option<EconomicInterval>) -> Result<EconomicSeries>;
+    fn unemployment(&self) -> Result<EconomicSeries>;
+    fn nonfarm_payroll(&self) -> Result<EconomicSeries>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`commodities::CommodityEndpoints`]
+  CommodityEndpoints => commodities::CommodityEndpoints {
+    fn series(&self, function: FuncType, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn wti(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn brent(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn natural_gas(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn copper(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn aluminum(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn wheat(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn corn(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn cotton(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn sugar(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn coffee(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+    fn all_commodities(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`options::OptionsEndpoints`]
+  OptionsEndpoints => options::OptionsEndpoints {
+    fn realtime(&self, symbol: &str, require_greeks: bool, contract: Option<&str>) -> Result<OptionChain>;
+    fn historical(&self, symbol: &str, date: Option<NaiveDate>) -> Result<OptionChain>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`analytics::AnalyticsEndpoints`]
+  AnalyticsEndpoints => analytics::AnalyticsEndpoints {
+    fn fixed_window(&self, request: &AnalyticsRequest) -> Result<ReturnsAnalytics>;
+    fn sliding_window(&self, request: &AnalyticsRequest, window_size: u32) -> Result<ReturnsAnalytics>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`news::NewsEndpoints`]
+  NewsEndpoints => news::NewsEndpoints {
+    fn news_sentiment(
+      &self,
+      tickers: Option<&str>,
+      topics: Option<&str>,
+      time_from: Option<&str>,
+      time_to: Option<&str>,
+      sort: Option<&str>,
+      limit: Option<u32>,
+    ) -> Result<NewsSentiment>;
+    fn news_sentiment_with(&self, request: &NewsRequest) -> Result<NewsSentiment>;
+    fn news_sentiment_all(&self, request: &NewsRequest) -> Result<NewsSentiment>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`forex::ForexEndpoints`]
+  ForexEndpoints => forex::ForexEndpoints {
+    fn exchange_rate(&self, from_currency: &str, to_currency: &str) -> Result<ExchangeRate>;
+    fn intraday(
+      &self,
+      from_symbol: &str,
+      to_symbol: &str,
+      interval: &str,
+      outputsize: &str,
+    ) -> Result<FxIntraday>;
+    fn intraday_with(&self, request: &FxRequest) -> Result<FxIntraday>;
+    fn daily(&self, from_symbol: &str, to_symbol: &str, outputsize: &str) -> Result<FxDaily>;
+    fn daily_with(&self, request: &FxRequest) -> Result<FxDaily>;
+    fn weekly(&self, from_symbol: &str, to_symbol: &str) -> Result<FxWeekly>;
+    fn monthly(&self, from_symbol: &str, to_symbol: &str) -> Result<FxMonthly>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`crypto::CryptoEndpoints`]
+  CryptoEndpoints => crypto::CryptoEndpoints {
+    fn exchange_rate(&self, from_currency: &str, to_currency: &str) -> Result<CryptoExchangeRate>;
+    fn intraday(&self, symbol: &str, market: &str, interval: &str) -> Result<CryptoIntraday>;
+    fn intraday_with(&self, request: &CryptoRequest) -> Result<CryptoIntraday>;
+    fn daily(&self, symbol: &str, market: &str) -> Result<CryptoDaily>;
+    fn weekly(&self, symbol: &str, market: &str) -> Result<CryptoWeekly>;
+    fn monthly(&self, symbol: &str, market: &str) -> Result<CryptoMonthly>;
+    fn health_score(&self, symbol: &str, market: &str) -> Result<f64>;
+  }
+}
+
+blocking_endpoints! {
+  /// Blocking mirror of [`crypto_social::CryptoSocialEndpoints`]
+  CryptoSocialEndpoints => crypto_social::CryptoSocialEndpoints {
+    fn fetch_coingecko_social_data(
+      &self,
+      coingecko_id: &str,
+      api_key: Option<&str>,
+    ) -> Result<CoinGeckoSocialResponse>;
+    fn fetch_github_repo_info(&self, repo_url: &str, github_token: Option<&str>) -> Result<GitHubRepoInfo>;
+    fn batch_fetch_social_data(
+      &self,
+      coingecko_ids: Vec<&str>,
+      api_key: Option<&str>,
+      delay_ms: u64,
+    ) -> Vec<Result<CoinGeckoSocialResponse>>;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::FixtureTransport;
+  use av_core::types::common::YearMonth;
+
+  #[test]
+  fn test_blocking_client_uses_async_transport() {
+    let fixture = Arc::new(FixtureTransport::new().with_response(
+      FuncType::MarketStatus,
+      r#"{"endpoint": "Global Market Open & Close Status", "markets": []}"#,
+    ));
+    let config = Config { max_retries: 0, ..Config::default_with_key("demo".into()) };
+    let client = AlphaVantageClient::with_transport(config, fixture.clone()).unwrap();
+
+    let status = client.time_series().market_status().unwrap();
+    assert!(status.markets.is_empty());
+    assert!(matches!(client.fundamentals().company_overview("IBM"), Err(Error::Http(_))));
+
+    let too_early = IntradayHistory::new("IBM", Interval::Min5, YearMonth::new(1999, 1).unwrap());
+    let months: Vec<_> = client.time_series().intraday_history(too_early).collect();
+    assert!(matches!(months.as_slice(), [Err(Error::Config(_))]));
+
+    let requests = fixture.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].params["symbol"], "IBM");
+  }
+}
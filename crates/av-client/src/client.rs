@@ -29,11 +29,15 @@
 
 use crate::endpoints::crypto_social::CryptoSocialEndpoints;
 use crate::endpoints::{
-  crypto::CryptoEndpoints, forex::ForexEndpoints, fundamentals::FundamentalsEndpoints,
-  news::NewsEndpoints, time_series::TimeSeriesEndpoints,
+  analytics::AnalyticsEndpoints, commodities::CommodityEndpoints, crypto::CryptoEndpoints,
+  economics::EconomicsEndpoints, forex::ForexEndpoints, fundamentals::FundamentalsEndpoints,
+  indicators::IndicatorEndpoints, news::NewsEndpoints, options::OptionsEndpoints,
+  time_series::TimeSeriesEndpoints,
 };
 
-use crate::transport::Transport;
+use crate::transport::{
+  CachePolicy, HttpTransport, KeyStats, QuotaLedger, RequestHook, ResponseCache, Transport,
+};
 use av_core::{Config, Result};
 use governor::{
   Quota, RateLimiter,
@@ -84,6 +88,9 @@ impl AlphaVantageClient {
   ///
   /// * `config` - Configuration containing API key and other settings
   ///
+  /// Requests are spread over [`Config::api_keys`], each limited separately;
+  /// the client-wide limit is the sum of the keys' per-minute limits.
+  ///
   /// # Errors
   ///
   /// Returns an error if the HTTP client cannot be created.
@@ -98,18 +105,72 @@ impl AlphaVantageClient {
   /// let client = AlphaVantageClient::new(config).expect("Failed to create client");
   /// ```
   pub fn new(config: Config) -> Result<Self> {
-    let rate_limit = config.rate_limit;
+    Ok(Self::from_transport(Transport::new(config)?))
+  }
+
+  /// Create a client around a fully configured [`Transport`]
+  ///
+  /// Use this to combine what the other constructors attach one at a time:
+  /// a cache, a quota ledger, hooks and a retry policy can all be set on the
+  /// transport first. Unless the transport already has a rate limiter, one
+  /// is created from the key pool's combined per-minute limit. The limiter
+  /// is only waited on for requests that miss the cache.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// use av_client::{AlphaVantageClient, CachePolicy, MemoryCache, MetricsHook, Transport};
+  ///
+  /// let transport = Transport::new(config)?
+  ///   .with_quota_ledger(ledger)
+  ///   .with_cache(Arc::new(MemoryCache::new(1024)))
+  ///   .with_cache_policy(CachePolicy::default())
+  ///   .with_hook(Arc::new(MetricsHook::new()));
+  /// let client = AlphaVantageClient::from_transport(transport);
+  /// ```
+  pub fn from_transport(transport: Transport) -> Self {
+    let rate_limiter = match transport.rate_limiter() {
+      Some(rate_limiter) => rate_limiter.clone(),
+      None => Self::default_rate_limiter(transport.key_pool().total_rate_limit()),
+    };
+    let transport = Arc::new(transport.with_rate_limiter(rate_limiter.clone()));
+
+    Self { transport, rate_limiter }
+  }
+
+  /// Create a client that sends requests through a custom [`HttpTransport`]
+  ///
+  /// Rate limiting, retries, and response decoding behave as with
+  /// [`new`](Self::new); only the sending of requests is replaced. Pass an
+  /// `Arc` to keep a handle on the backend, e.g. to inspect the requests a
+  /// [`FixtureTransport`](crate::transport::FixtureTransport) received.
+  ///
+  /// # Errors
+  ///
+  /// Currently infallible; returns `Result` for symmetry with [`new`](Self::new).
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// use av_client::{AlphaVantageClient, FixtureTransport};
+  /// use av_core::{Config, FuncType};
+  ///
+  /// let fixture = FixtureTransport::new().with_response(FuncType::TopGainersLosers, body);
+  /// let client = AlphaVantageClient::with_transport(Config::default_with_key("demo".into()), fixture)?;
+  /// ```
+  pub fn with_transport(config: Config, backend: impl HttpTransport + 'static) -> Result<Self> {
+    Ok(Self::from_transport(Transport::with_backend(config, backend)))
+  }
 
-    // Ensure rate_limit is non-zero, fallback to default if invalid
+  /// A per-minute limiter for `rate_limit`, falling back to the default
+  /// when it is zero.
+  fn default_rate_limiter(
+    rate_limit: u32,
+  ) -> Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>> {
     let rate_limit_value = NonZeroU32::new(rate_limit).unwrap_or_else(|| {
       NonZeroU32::new(av_core::DEFAULT_RATE_LIMIT).expect("DEFAULT_RATE_LIMIT must be non-zero")
     });
-    let quota = Quota::per_minute(rate_limit_value);
-    let rate_limiter = Arc::new(RateLimiter::direct(quota));
-
-    let transport = Arc::new(Transport::new(config)?);
-
-    Ok(Self { transport, rate_limiter })
+    Arc::new(RateLimiter::direct(Quota::per_minute(rate_limit_value)))
   }
 
   /// Create a new client with custom rate limiting
@@ -126,7 +187,63 @@ impl AlphaVantageClient {
     config: Config,
     rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
   ) -> Result<Self> {
-    Ok(Self { transport: Arc::new(Transport::new(config)?), rate_limiter })
+    Ok(Self::from_transport(Transport::new(config)?.with_rate_limiter(rate_limiter)))
+  }
+
+  /// Create a client that counts its calls in `ledger`
+  ///
+  /// Same as [`new`](Self::new), but every request is recorded in the
+  /// ledger and a key is no longer used once the calls recorded for it
+  /// today reach its daily limit, even if they were made by an earlier
+  /// process.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the HTTP client cannot be created.
+  pub fn with_quota_ledger(config: Config, ledger: Arc<dyn QuotaLedger>) -> Result<Self> {
+    Ok(Self::from_transport(Transport::new(config)?.with_quota_ledger(ledger)))
+  }
+
+  /// Create a client that serves repeated requests from `cache`
+  ///
+  /// Same as [`new`](Self::new), but successful responses are stored in the
+  /// cache for as long as `policy` allows and later requests for the same
+  /// function and parameters are answered from it without spending an API
+  /// call. Pass [`CachePolicy::default`] for the built-in per-function
+  /// lifetimes.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the HTTP client cannot be created.
+  pub fn with_cache(
+    config: Config,
+    cache: Arc<dyn ResponseCache>,
+    policy: CachePolicy,
+  ) -> Result<Self> {
+    let transport = Transport::new(config)?.with_cache(cache).with_cache_policy(policy);
+    Ok(Self::from_transport(transport))
+  }
+
+  /// Create a client that reports every request attempt to `hooks`
+  ///
+  /// Hooks run in order, after the audit log [`Transport::new`] attaches
+  /// for [`Config::audit_log`]. See [`MetricsHook`](crate::MetricsHook)
+  /// and [`JsonlAuditHook`](crate::JsonlAuditHook) for the built-in ones.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the HTTP client cannot be created.
+  pub fn with_hooks(config: Config, hooks: Vec<Arc<dyn RequestHook>>) -> Result<Self> {
+    let transport = hooks.into_iter().fold(Transport::new(config)?, Transport::with_hook);
+    Ok(Self::from_transport(transport))
+  }
+
+  /// Usage of each API key in the pool
+  ///
+  /// Keys are masked to their last four characters. See
+  /// [`Config::key_pool`] for configuring more than one key.
+  pub fn key_stats(&self) -> Vec<KeyStats> {
+    self.transport.key_pool().stats()
   }
 
   /// Get access to time series endpoints
@@ -145,7 +262,7 @@ impl AlphaVantageClient {
   /// # Ok::<(), av_core::Error>(())
   /// ```
   pub fn time_series(&self) -> TimeSeriesEndpoints {
-    TimeSeriesEndpoints::new(self.transport.clone(), self.rate_limiter.clone())
+    TimeSeriesEndpoints::new(self.transport.clone())
   }
 
   /// Get access to fundamentals endpoints
@@ -164,7 +281,110 @@ impl AlphaVantageClient {
   /// # Ok::<(), av_core::Error>(())
   /// ```
   pub fn fundamentals(&self) -> FundamentalsEndpoints {
-    FundamentalsEndpoints::new(self.transport.clone(), self.rate_limiter.clone())
+    FundamentalsEndpoints::new(self.transport.clone())
+  }
+
+  /// Get access to technical indicator endpoints
+  ///
+  /// Returns an `IndicatorEndpoints` instance for server-side computed
+  /// indicators such as SMA, RSI, MACD, and Bollinger bands.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::Client;
+  /// # use av_core::Config;
+  /// # use av_core::types::{IndicatorInterval, SeriesType};
+  /// # let client = Client::new(Config::from_env().unwrap());
+  /// let rsi = client.indicators().rsi("AAPL", IndicatorInterval::Daily, 14, SeriesType::Close).await?;
+  /// let obv = client.indicators().obv("MSFT", IndicatorInterval::Weekly).await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  pub fn indicators(&self) -> IndicatorEndpoints {
+    IndicatorEndpoints::new(self.transport.clone())
+  }
+
+  /// Get access to economic indicator endpoints
+  ///
+  /// Returns an `EconomicsEndpoints` instance for US macro series such as
+  /// real GDP, CPI, treasury yields, and the federal funds rate.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::Client;
+  /// # use av_core::Config;
+  /// # use av_core::types::{EconomicInterval, TreasuryMaturity};
+  /// # let client = Client::new(Config::from_env().unwrap());
+  /// let cpi = client.economics().cpi(None).await?;
+  /// let ten_year = client.economics().treasury_yield(Some(EconomicInterval::Daily), Some(TreasuryMaturity::TenYear)).await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  pub fn economics(&self) -> EconomicsEndpoints {
+    EconomicsEndpoints::new(self.transport.clone())
+  }
+
+  /// Get access to commodity endpoints
+  ///
+  /// Returns a `CommodityEndpoints` instance for energy, metals, and
+  /// agricultural commodity prices.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::Client;
+  /// # use av_core::Config;
+  /// # use av_core::types::EconomicInterval;
+  /// # let client = Client::new(Config::from_env().unwrap());
+  /// let wti = client.commodities().wti(Some(EconomicInterval::Daily)).await?;
+  /// let copper = client.commodities().copper(None).await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  pub fn commodities(&self) -> CommodityEndpoints {
+    CommodityEndpoints::new(self.transport.clone())
+  }
+
+  /// Get access to option chain endpoints
+  ///
+  /// Returns an `OptionsEndpoints` instance for realtime and historical
+  /// option chains with Greeks and implied volatility.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::Client;
+  /// # use av_core::Config;
+  /// # use chrono::NaiveDate;
+  /// # let client = Client::new(Config::from_env().unwrap());
+  /// let live = client.options().realtime("IBM", true, None).await?;
+  /// let eod = client.options().historical("IBM", NaiveDate::from_ymd_opt(2024, 1, 16)).await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  pub fn options(&self) -> OptionsEndpoints {
+    OptionsEndpoints::new(self.transport.clone())
+  }
+
+  /// Get access to returns analytics endpoints
+  ///
+  /// Returns an `AnalyticsEndpoints` instance for server-side return
+  /// statistics (mean, volatility, drawdown, correlation, ...) across
+  /// several symbols, over a fixed or sliding window.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::{AnalyticsRange, AnalyticsRequest, Calculation, Client};
+  /// # use av_core::Config;
+  /// # let client = Client::new(Config::from_env().unwrap());
+  /// let request = AnalyticsRequest::new(["AAPL", "MSFT"])
+  ///     .range(AnalyticsRange::Months(6))
+  ///     .calculation(Calculation::StdDev { annualized: true });
+  /// let fixed = client.analytics().fixed_window(&request).await?;
+  /// let rolling = client.analytics().sliding_window(&request, 20).await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  pub fn analytics(&self) -> AnalyticsEndpoints {
+    AnalyticsEndpoints::new(self.transport.clone())
   }
 
   /// Get access to news endpoints
@@ -181,7 +401,7 @@ impl AlphaVantageClient {
   /// # Ok::<(), av_core::Error>(())
   /// ```
   pub fn news(&self) -> NewsEndpoints {
-    NewsEndpoints::new(self.transport.clone(), self.rate_limiter.clone())
+    NewsEndpoints::new(self.transport.clone())
   }
 
   /// Get access to forex endpoints
@@ -199,7 +419,7 @@ impl AlphaVantageClient {
   /// # Ok::<(), av_core::Error>(())
   /// ```
   pub fn forex(&self) -> ForexEndpoints {
-    ForexEndpoints::new(self.transport.clone(), self.rate_limiter.clone())
+    ForexEndpoints::new(self.transport.clone())
   }
 
   /// Get access to cryptocurrency endpoints
@@ -217,7 +437,7 @@ impl AlphaVantageClient {
   /// # Ok::<(), av_core::Error>(())
   /// ```
   pub fn crypto(&self) -> CryptoEndpoints {
-    CryptoEndpoints::new(self.transport.clone(), self.rate_limiter.clone())
+    CryptoEndpoints::new(self.transport.clone())
   }
 
   /// Get access to crypto social endpoints
@@ -327,9 +547,16 @@ mod tests {
     let config = Config {
       api_key: "test_key".to_string(),
       rate_limit: 75,
+      daily_limit: None,
       timeout_secs: 30,
       max_retries: 3,
       base_url: av_core::ALPHA_VANTAGE_BASE_URL.to_string(),
+      key_pool: Vec::new(),
+      key_selection: Default::default(),
+      http: Default::default(),
+      cache_dir: None,
+      quota_file: None,
+      audit_log: None,
     };
 
     let client = AlphaVantageClient::new(config).expect("Failed to create client");
@@ -342,9 +569,16 @@ mod tests {
     let config = Config {
       api_key: "test_key".to_string(),
       rate_limit: 600,
+      daily_limit: None,
       timeout_secs: 30,
       max_retries: 3,
       base_url: av_core::ALPHA_VANTAGE_BASE_URL.to_string(),
+      key_pool: Vec::new(),
+      key_selection: Default::default(),
+      http: Default::default(),
+      cache_dir: None,
+      quota_file: None,
+      audit_log: None,
     };
 
     let _client = AlphaVantageClient::new(config).expect("Failed to create client");
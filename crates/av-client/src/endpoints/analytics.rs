@@ -0,0 +1,406 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::EndpointBase;
+use crate::impl_endpoint_base;
+use crate::transport::Transport;
+use av_core::types::common::{IndicatorInterval, SeriesType};
+use av_core::{Error, FuncType, Result};
+use av_models::analytics::ReturnsAnalytics;
+use chrono::NaiveDate;
+use std::fmt;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Date range for an [`AnalyticsRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalyticsRange {
+  /// All available history
+  Full,
+  /// The trailing N days
+  Days(u32),
+  /// The trailing N weeks
+  Weeks(u32),
+  /// The trailing N months
+  Months(u32),
+  /// The trailing N years
+  Years(u32),
+  /// From a date through the latest data
+  Since(NaiveDate),
+  /// Between two dates, inclusive
+  Between(NaiveDate, NaiveDate),
+}
+
+impl AnalyticsRange {
+  /// The `RANGE` values to send; [`Between`](Self::Between) needs two.
+  fn values(&self) -> Vec<String> {
+    let date = |d: &NaiveDate| d.format("%Y-%m-%d").to_string();
+    match self {
+      Self::Full => vec!["full".to_string()],
+      Self::Days(n) => vec![format!("{}day", n)],
+      Self::Weeks(n) => vec![format!("{}week", n)],
+      Self::Months(n) => vec![format!("{}month", n)],
+      Self::Years(n) => vec![format!("{}year", n)],
+      Self::Since(start) => vec![date(start)],
+      Self::Between(start, end) => vec![date(start), date(end)],
+    }
+  }
+}
+
+/// Method for [`Calculation::Correlation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationMethod {
+  Pearson,
+  Kendall,
+  Spearman,
+}
+
+impl fmt::Display for CorrelationMethod {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CorrelationMethod::Pearson => write!(f, "PEARSON"),
+      CorrelationMethod::Kendall => write!(f, "KENDALL"),
+      CorrelationMethod::Spearman => write!(f, "SPEARMAN"),
+    }
+  }
+}
+
+/// A statistic computed on the symbols' returns.
+///
+/// Options left at `false` / `None` use the API's defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Calculation {
+  Min,
+  Max,
+  Mean,
+  Median,
+  CumulativeReturn,
+  Variance {
+    annualized: bool,
+  },
+  StdDev {
+    annualized: bool,
+  },
+  MaxDrawdown,
+  /// Return distribution with `bins` buckets (API default 10)
+  Histogram {
+    bins: Option<u32>,
+  },
+  /// Autocorrelation at `lag` (API default 1)
+  Autocorrelation {
+    lag: Option<u32>,
+  },
+  Covariance {
+    annualized: bool,
+  },
+  Correlation {
+    method: Option<CorrelationMethod>,
+  },
+}
+
+impl Calculation {
+  /// Whether `ANALYTICS_SLIDING_WINDOW` supports this calculation.
+  pub fn supports_sliding_window(&self) -> bool {
+    matches!(
+      self,
+      Calculation::Mean
+        | Calculation::Median
+        | Calculation::CumulativeReturn
+        | Calculation::Variance { .. }
+        | Calculation::StdDev { .. }
+        | Calculation::Covariance { .. }
+        | Calculation::Correlation { .. }
+    )
+  }
+}
+
+/// Formats as the `CALCULATIONS` entry (e.g., `"STDDEV(annualized=True)"`).
+impl fmt::Display for Calculation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let annualized = |f: &mut fmt::Formatter<'_>, name: &str, on: bool| {
+      if on { write!(f, "{}(annualized=True)", name) } else { write!(f, "{}", name) }
+    };
+    match self {
+      Calculation::Min => write!(f, "MIN"),
+      Calculation::Max => write!(f, "MAX"),
+      Calculation::Mean => write!(f, "MEAN"),
+      Calculation::Median => write!(f, "MEDIAN"),
+      Calculation::CumulativeReturn => write!(f, "CUMULATIVE_RETURN"),
+      Calculation::Variance { annualized: on } => annualized(f, "VARIANCE", *on),
+      Calculation::StdDev { annualized: on } => annualized(f, "STDDEV", *on),
+      Calculation::MaxDrawdown => write!(f, "MAX_DRAWDOWN"),
+      Calculation::Histogram { bins: Some(bins) } => write!(f, "HISTOGRAM(bins={})", bins),
+      Calculation::Histogram { bins: None } => write!(f, "HISTOGRAM"),
+      Calculation::Autocorrelation { lag: Some(lag) } => write!(f, "AUTOCORRELATION(lag={})", lag),
+      Calculation::Autocorrelation { lag: None } => write!(f, "AUTOCORRELATION"),
+      Calculation::Covariance { annualized: on } => annualized(f, "COVARIANCE", *on),
+      Calculation::Correlation { method: Some(method) } => {
+        write!(f, "CORRELATION(method={})", method)
+      }
+      Calculation::Correlation { method: None } => write!(f, "CORRELATION"),
+    }
+  }
+}
+
+/// Parameters shared by [`AnalyticsEndpoints::fixed_window`] and
+/// [`AnalyticsEndpoints::sliding_window`].
+///
+/// Defaults to the full history of daily closing prices.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = AnalyticsRequest::new(["AAPL", "MSFT", "IBM"])
+///     .range(AnalyticsRange::Months(6))
+///     .calculation(Calculation::StdDev { annualized: true })
+///     .calculation(Calculation::Correlation { method: None });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyticsRequest {
+  pub symbols: Vec<String>,
+  pub range: AnalyticsRange,
+  pub interval: IndicatorInterval,
+  pub ohlc: SeriesType,
+  pub calculations: Vec<Calculation>,
+}
+
+impl AnalyticsRequest {
+  /// Most symbols the API accepts in one request.
+  pub const MAX_SYMBOLS: usize = 50;
+
+  pub fn new<I, S>(symbols: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    Self {
+      symbols: symbols.into_iter().map(Into::into).collect(),
+      range: AnalyticsRange::Full,
+      interval: IndicatorInterval::Daily,
+      ohlc: SeriesType::Close,
+      calculations: Vec::new(),
+    }
+  }
+
+  pub fn range(mut self, range: AnalyticsRange) -> Self {
+    self.range = range;
+    self
+  }
+
+  pub fn interval(mut self, interval: IndicatorInterval) -> Self {
+    self.interval = interval;
+    self
+  }
+
+  pub fn ohlc(mut self, ohlc: SeriesType) -> Self {
+    self.ohlc = ohlc;
+    self
+  }
+
+  pub fn calculation(mut self, calculation: Calculation) -> Self {
+    self.calculations.push(calculation);
+    self
+  }
+
+  pub fn calculations(mut self, calculations: impl IntoIterator<Item = Calculation>) -> Self {
+    self.calculations.extend(calculations);
+    self
+  }
+
+  fn validate(&self) -> Result<()> {
+    if self.symbols.is_empty() {
+      return Err(Error::Config("Analytics request needs at least one symbol".to_string()));
+    }
+    if self.symbols.len() > Self::MAX_SYMBOLS {
+      return Err(Error::Config(format!(
+        "Analytics request has {} symbols; the maximum is {}",
+        self.symbols.len(),
+        Self::MAX_SYMBOLS
+      )));
+    }
+    if self.calculations.is_empty() {
+      return Err(Error::Config("Analytics request needs at least one calculation".to_string()));
+    }
+    if let AnalyticsRange::Between(start, end) = self.range {
+      if start > end {
+        return Err(Error::Config(format!("Analytics range start {} is after end {}", start, end)));
+      }
+    }
+    Ok(())
+  }
+
+  /// Query pairs, with one `RANGE` pair per range value.
+  fn to_params(&self) -> Vec<(String, String)> {
+    let interval = match self.interval {
+      IndicatorInterval::Daily | IndicatorInterval::Weekly | IndicatorInterval::Monthly => {
+        self.interval.to_string().to_uppercase()
+      }
+      intraday => intraday.to_string(),
+    };
+    let symbols: Vec<String> = self.symbols.iter().map(|s| s.trim().to_uppercase()).collect();
+    let calculations: Vec<String> = self.calculations.iter().map(ToString::to_string).collect();
+
+    let mut params = vec![("SYMBOLS".to_string(), symbols.join(","))];
+    params.extend(self.range.values().into_iter().map(|v| ("RANGE".to_string(), v)));
+    params.push(("INTERVAL".to_string(), interval));
+    params.push(("OHLC".to_string(), self.ohlc.to_string()));
+    params.push(("CALCULATIONS".to_string(), calculations.join(",")));
+    params
+  }
+}
+
+/// Server-side returns analytics endpoints
+pub struct AnalyticsEndpoints {
+  transport: Arc<Transport>,
+}
+
+impl AnalyticsEndpoints {
+  /// Smallest `WINDOW_SIZE` the sliding-window endpoint accepts.
+  pub const MIN_WINDOW_SIZE: u32 = 10;
+
+  /// Create a new analytics endpoints instance
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
+  }
+
+  /// Compute return statistics over the whole requested range
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::{AnalyticsEndpoints, AnalyticsRequest, AnalyticsRange, Calculation};
+  /// # let endpoints = AnalyticsEndpoints::new(Arc::new(transport));
+  /// let request = AnalyticsRequest::new(["AAPL", "MSFT"])
+  ///     .range(AnalyticsRange::Years(1))
+  ///     .calculation(Calculation::Correlation { method: None });
+  /// let result = endpoints.fixed_window(&request).await?;
+  /// let rho = result.matrix("CORRELATION").and_then(|m| m.get("AAPL", "MSFT"));
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(symbols = request.symbols.len()))]
+  pub async fn fixed_window(&self, request: &AnalyticsRequest) -> Result<ReturnsAnalytics> {
+    request.validate()?;
+
+    self.transport.get_pairs(FuncType::AnalyticsFixedWindow, request.to_params()).await
+  }
+
+  /// Compute return statistics over a window of `window_size` bars that
+  /// slides across the requested range
+  ///
+  /// Only calculations for which [`Calculation::supports_sliding_window`]
+  /// holds are accepted.
+  #[instrument(skip(self), fields(symbols = request.symbols.len(), window_size))]
+  pub async fn sliding_window(
+    &self,
+    request: &AnalyticsRequest,
+    window_size: u32,
+  ) -> Result<ReturnsAnalytics> {
+    request.validate()?;
+    if window_size < Self::MIN_WINDOW_SIZE {
+      return Err(Error::Config(format!(
+        "Sliding window size must be at least {}",
+        Self::MIN_WINDOW_SIZE
+      )));
+    }
+    if let Some(unsupported) = request.calculations.iter().find(|c| !c.supports_sliding_window()) {
+      return Err(Error::Config(format!(
+        "{} is not available for sliding-window analytics",
+        unsupported
+      )));
+    }
+
+    let mut params = request.to_params();
+    params.push(("WINDOW_SIZE".to_string(), window_size.to_string()));
+
+    self.transport.get_pairs(FuncType::AnalyticsSlidingWindow, params).await
+  }
+}
+
+impl_endpoint_base!(AnalyticsEndpoints);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_request_params() {
+    let start = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 8, 31).unwrap();
+    let request = AnalyticsRequest::new(["aapl", "MSFT"])
+      .range(AnalyticsRange::Between(start, end))
+      .calculations([
+        Calculation::Mean,
+        Calculation::StdDev { annualized: true },
+        Calculation::Correlation { method: Some(CorrelationMethod::Kendall) },
+      ]);
+
+    assert!(request.validate().is_ok());
+    assert_eq!(
+      request.to_params(),
+      vec![
+        ("SYMBOLS".to_string(), "AAPL,MSFT".to_string()),
+        ("RANGE".to_string(), "2023-07-01".to_string()),
+        ("RANGE".to_string(), "2023-08-31".to_string()),
+        ("INTERVAL".to_string(), "DAILY".to_string()),
+        ("OHLC".to_string(), "close".to_string()),
+        (
+          "CALCULATIONS".to_string(),
+          "MEAN,STDDEV(annualized=True),CORRELATION(method=KENDALL)".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_request_validation() {
+    assert!(AnalyticsRequest::new(["AAPL"]).validate().is_err());
+    assert!(
+      AnalyticsRequest::new(Vec::<String>::new())
+        .calculation(Calculation::Mean)
+        .validate()
+        .is_err()
+    );
+
+    let start = NaiveDate::from_ymd_opt(2023, 8, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+    let reversed = AnalyticsRequest::new(["AAPL"])
+      .range(AnalyticsRange::Between(start, end))
+      .calculation(Calculation::Mean);
+    assert!(reversed.validate().is_err());
+  }
+
+  #[test]
+  fn test_sliding_window_support() {
+    assert!(Calculation::Mean.supports_sliding_window());
+    assert!(Calculation::Correlation { method: None }.supports_sliding_window());
+    assert!(!Calculation::MaxDrawdown.supports_sliding_window());
+    assert!(!Calculation::Histogram { bins: Some(20) }.supports_sliding_window());
+    assert_eq!(Calculation::Histogram { bins: Some(20) }.to_string(), "HISTOGRAM(bins=20)");
+  }
+}
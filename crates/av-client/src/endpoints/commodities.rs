@@ -0,0 +1,242 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::EndpointBase;
+use crate::impl_endpoint_base;
+use crate::transport::Transport;
+use av_core::types::common::EconomicInterval;
+use av_core::{Error, FuncType, Result};
+use av_models::commodities::CommoditySeries;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Every commodity function, energy first.
+pub const COMMODITY_FUNCTIONS: [FuncType; 11] = [
+  FuncType::Wti,
+  FuncType::Brent,
+  FuncType::NaturalGas,
+  FuncType::Copper,
+  FuncType::Aluminum,
+  FuncType::Wheat,
+  FuncType::Corn,
+  FuncType::Cotton,
+  FuncType::Sugar,
+  FuncType::Coffee,
+  FuncType::AllCommodities,
+];
+
+/// Commodity price endpoints
+pub struct CommodityEndpoints {
+  transport: Arc<Transport>,
+}
+
+impl CommodityEndpoints {
+  /// Create a new commodity endpoints instance
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
+  }
+
+  /// Intervals accepted by a commodity function.
+  ///
+  /// Energy series publish daily through monthly; metals, agriculture, and
+  /// the all-commodities index publish monthly through annual. The API
+  /// default is `Monthly` for every commodity. Returns an empty slice for
+  /// non-commodity functions.
+  pub fn supported_intervals(function: FuncType) -> &'static [EconomicInterval] {
+    match function {
+      FuncType::Wti | FuncType::Brent | FuncType::NaturalGas => {
+        &[EconomicInterval::Daily, EconomicInterval::Weekly, EconomicInterval::Monthly]
+      }
+      FuncType::Copper
+      | FuncType::Aluminum
+      | FuncType::Wheat
+      | FuncType::Corn
+      | FuncType::Cotton
+      | FuncType::Sugar
+      | FuncType::Coffee
+      | FuncType::AllCommodities => {
+        &[EconomicInterval::Monthly, EconomicInterval::Quarterly, EconomicInterval::Annual]
+      }
+      _ => &[],
+    }
+  }
+
+  /// Get a commodity price series by function
+  ///
+  /// The named methods ([`wti`](Self::wti), [`copper`](Self::copper), ...)
+  /// delegate here; call this directly when the commodity is chosen at
+  /// runtime.
+  ///
+  /// # Arguments
+  ///
+  /// * `function` - One of [`COMMODITY_FUNCTIONS`]
+  /// * `interval` - Sampling interval; `None` uses the API default (monthly)
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::CommodityEndpoints;
+  /// # use av_core::{FuncType, types::EconomicInterval};
+  /// # let endpoints = CommodityEndpoints::new(Arc::new(transport));
+  /// let brent = endpoints.series(FuncType::Brent, Some(EconomicInterval::Daily)).await?;
+  /// if let Some(point) = brent.latest() {
+  ///     println!("{}: {} {}", point.date, point.value, brent.unit);
+  /// }
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(function = %function, interval))]
+  pub async fn series(
+    &self,
+    function: FuncType,
+    interval: Option<EconomicInterval>,
+  ) -> Result<CommoditySeries> {
+    let supported = Self::supported_intervals(function);
+    if supported.is_empty() {
+      return Err(Error::Config(format!("{} is not a commodity function", function)));
+    }
+
+    let mut params = HashMap::new();
+    if let Some(interval) = interval {
+      if !supported.contains(&interval) {
+        return Err(Error::Config(format!(
+          "{} does not support interval '{}'",
+          function, interval
+        )));
+      }
+      params.insert("interval".to_string(), interval.to_string());
+    }
+
+    self.transport.get(function, params).await
+  }
+
+  /// Get West Texas Intermediate crude oil prices
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Daily`, `Weekly`, or `Monthly` (default)
+  pub async fn wti(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Wti, interval).await
+  }
+
+  /// Get Brent crude oil prices
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Daily`, `Weekly`, or `Monthly` (default)
+  pub async fn brent(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Brent, interval).await
+  }
+
+  /// Get Henry Hub natural gas spot prices
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Daily`, `Weekly`, or `Monthly` (default)
+  pub async fn natural_gas(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::NaturalGas, interval).await
+  }
+
+  /// Get the global price of copper
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn copper(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Copper, interval).await
+  }
+
+  /// Get the global price of aluminum
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn aluminum(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Aluminum, interval).await
+  }
+
+  /// Get the global price of wheat
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn wheat(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Wheat, interval).await
+  }
+
+  /// Get the global price of corn
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn corn(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Corn, interval).await
+  }
+
+  /// Get the global price of cotton
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn cotton(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Cotton, interval).await
+  }
+
+  /// Get the global price of sugar
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn sugar(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Sugar, interval).await
+  }
+
+  /// Get the global price of coffee
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn coffee(&self, interval: Option<EconomicInterval>) -> Result<CommoditySeries> {
+    self.series(FuncType::Coffee, interval).await
+  }
+
+  /// Get the global price index of all commodities
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Quarterly`, or `Annual`
+  pub async fn all_commodities(
+    &self,
+    interval: Option<EconomicInterval>,
+  ) -> Result<CommoditySeries> {
+    self.series(FuncType::AllCommodities, interval).await
+  }
+}
+
+impl_endpoint_base!(CommodityEndpoints);
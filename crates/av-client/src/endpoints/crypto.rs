@@ -27,34 +27,80 @@
  * SOFTWARE.
  */
 
-use super::EndpointBase;
+use super::{EndpointBase, parse_arg};
 use crate::impl_endpoint_base;
 use crate::transport::Transport;
-use av_core::{FuncType, Result};
+use av_core::types::common::{Interval, OutputSize};
+use av_core::{Error, FuncType, Result};
 use av_models::crypto::*;
-use governor::{
-  RateLimiter,
-  clock::DefaultClock,
-  middleware::NoOpMiddleware,
-  state::{InMemoryState, NotKeyed},
-};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::instrument;
 
+/// Parameters for [`CryptoEndpoints::intraday_with`]
+///
+/// The interval is required; `output_size` falls back to the API's compact
+/// default when unset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CryptoRequest {
+  pub symbol: String,
+  pub market: String,
+  pub interval: Option<Interval>,
+  pub output_size: Option<OutputSize>,
+}
+
+impl CryptoRequest {
+  pub fn new(symbol: impl Into<String>, market: impl Into<String>) -> Self {
+    Self { symbol: symbol.into(), market: market.into(), interval: None, output_size: None }
+  }
+
+  pub fn interval(mut self, interval: Interval) -> Self {
+    self.interval = Some(interval);
+    self
+  }
+
+  pub fn output_size(mut self, output_size: OutputSize) -> Self {
+    self.output_size = Some(output_size);
+    self
+  }
+
+  fn validate(&self) -> Result<()> {
+    if self.symbol.trim().is_empty() || self.market.trim().is_empty() {
+      return Err(Error::Config(format!(
+        "{} request needs a symbol and a market",
+        FuncType::CryptoIntraday
+      )));
+    }
+    if self.interval.is_none() {
+      return Err(Error::Config(format!("{} request needs an interval", FuncType::CryptoIntraday)));
+    }
+    Ok(())
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = vec![
+      ("symbol".to_string(), self.symbol.trim().to_string()),
+      ("market".to_string(), self.market.trim().to_string()),
+    ];
+    if let Some(interval) = self.interval {
+      params.push(("interval".to_string(), interval.to_string()));
+    }
+    if let Some(output_size) = self.output_size {
+      params.push(("outputsize".to_string(), output_size.to_string()));
+    }
+    params
+  }
+}
+
 /// Cryptocurrency endpoints
 pub struct CryptoEndpoints {
   transport: Arc<Transport>,
-  rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
 impl CryptoEndpoints {
   /// Create a new crypto endpoints instance
-  pub fn new(
-    transport: Arc<Transport>,
-    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
-  ) -> Self {
-    Self { transport, rate_limiter }
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
   }
 
   /// Get current exchange rate for a cryptocurrency
@@ -69,7 +115,7 @@ impl CryptoEndpoints {
   /// ```ignore
   /// # use av_client::CryptoEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport));
   /// // Get Bitcoin price in USD
   /// let btc_usd = endpoints.exchange_rate("BTC", "USD").await?;
   /// println!("Bitcoin price: ${}", btc_usd.exchange_rate);
@@ -85,8 +131,6 @@ impl CryptoEndpoints {
     from_currency: &str,
     to_currency: &str,
   ) -> Result<CryptoExchangeRate> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("from_currency".to_string(), from_currency.to_string());
     params.insert("to_currency".to_string(), to_currency.to_string());
@@ -107,7 +151,7 @@ impl CryptoEndpoints {
   /// ```ignore
   /// # use av_client::CryptoEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport));
   /// let data = endpoints.intraday("BTC", "USD", "5min").await?;
   /// for (timestamp, price) in &data.time_series {
   ///     println!("{}: ${}", timestamp, price.close_usd);
@@ -121,14 +165,21 @@ impl CryptoEndpoints {
     market: &str,
     interval: &str,
   ) -> Result<CryptoIntraday> {
-    self.wait_for_rate_limit().await?;
+    let request = CryptoRequest::new(symbol, market).interval(parse_arg(interval)?);
+    self.intraday_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("symbol".to_string(), symbol.to_string());
-    params.insert("market".to_string(), market.to_string());
-    params.insert("interval".to_string(), interval.to_string());
+  /// Get intraday cryptocurrency time series data for a [`CryptoRequest`]
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if the symbol,
+  /// market or interval is missing.
+  #[instrument(skip(self), fields(symbol = %request.symbol, market = %request.market))]
+  pub async fn intraday_with(&self, request: &CryptoRequest) -> Result<CryptoIntraday> {
+    request.validate()?;
 
-    self.transport.get(FuncType::CryptoIntraday, params).await
+    self.transport.get_pairs(FuncType::CryptoIntraday, request.to_params()).await
   }
 
   /// Get daily cryptocurrency time series data
@@ -143,7 +194,7 @@ impl CryptoEndpoints {
   /// ```ignore
   /// # use av_client::CryptoEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport));
   /// let data = endpoints.daily("BTC", "USD").await?;
   /// for (date, price) in &data.time_series {
   ///     println!("{}: ${}", date, price.close_usd);
@@ -152,8 +203,6 @@ impl CryptoEndpoints {
   /// ```
   #[instrument(skip(self), fields(symbol, market))]
   pub async fn daily(&self, symbol: &str, market: &str) -> Result<CryptoDaily> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
     params.insert("market".to_string(), market.to_string());
@@ -169,8 +218,6 @@ impl CryptoEndpoints {
   /// * `market` - The market currency (e.g., "USD", "EUR")
   #[instrument(skip(self), fields(symbol, market))]
   pub async fn weekly(&self, symbol: &str, market: &str) -> Result<CryptoWeekly> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
     params.insert("market".to_string(), market.to_string());
@@ -186,8 +233,6 @@ impl CryptoEndpoints {
   /// * `market` - The market currency (e.g., "USD", "EUR")
   #[instrument(skip(self), fields(symbol, market))]
   pub async fn monthly(&self, symbol: &str, market: &str) -> Result<CryptoMonthly> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
     params.insert("market".to_string(), market.to_string());
@@ -211,7 +256,7 @@ impl CryptoEndpoints {
   /// ```ignore
   /// # use av_client::CryptoEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = CryptoEndpoints::new(Arc::new(transport));
   /// let health_score = endpoints.health_score("BTC", "USD").await?;
   /// println!("Bitcoin health score: {:.2}", health_score);
   /// # Ok::<(), av_core::Error>(())
@@ -0,0 +1,189 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::EndpointBase;
+use crate::impl_endpoint_base;
+use crate::transport::Transport;
+use av_core::types::common::{EconomicInterval, TreasuryMaturity};
+use av_core::{Error, FuncType, Result};
+use av_models::economics::EconomicSeries;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// US economic indicator endpoints
+pub struct EconomicsEndpoints {
+  transport: Arc<Transport>,
+}
+
+impl EconomicsEndpoints {
+  /// Create a new economics endpoints instance
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
+  }
+
+  /// Intervals accepted by an economic indicator function.
+  ///
+  /// The first entry is the interval the API uses when none is given. An
+  /// empty slice means the series has a fixed interval and takes no
+  /// `interval` parameter.
+  pub fn supported_intervals(function: FuncType) -> &'static [EconomicInterval] {
+    match function {
+      FuncType::RealGdp => &[EconomicInterval::Annual, EconomicInterval::Quarterly],
+      FuncType::Cpi => &[EconomicInterval::Monthly, EconomicInterval::Semiannual],
+      FuncType::TreasuryYield | FuncType::FederalFundsRate => {
+        &[EconomicInterval::Monthly, EconomicInterval::Weekly, EconomicInterval::Daily]
+      }
+      _ => &[],
+    }
+  }
+
+  /// Get US real gross domestic product
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Annual` (default) or `Quarterly`
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::EconomicsEndpoints;
+  /// # use av_core::types::EconomicInterval;
+  /// # let endpoints = EconomicsEndpoints::new(Arc::new(transport));
+  /// let gdp = endpoints.real_gdp(Some(EconomicInterval::Quarterly)).await?;
+  /// if let Some(point) = gdp.latest() {
+  ///     println!("{}: {} {}", point.date, point.value, gdp.unit);
+  /// }
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(interval))]
+  pub async fn real_gdp(&self, interval: Option<EconomicInterval>) -> Result<EconomicSeries> {
+    let params = Self::interval_params(FuncType::RealGdp, interval)?;
+
+    self.transport.get(FuncType::RealGdp, params).await
+  }
+
+  /// Get the consumer price index
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default) or `Semiannual`
+  #[instrument(skip(self), fields(interval))]
+  pub async fn cpi(&self, interval: Option<EconomicInterval>) -> Result<EconomicSeries> {
+    let params = Self::interval_params(FuncType::Cpi, interval)?;
+
+    self.transport.get(FuncType::Cpi, params).await
+  }
+
+  /// Get the annual US inflation rate
+  #[instrument(skip(self))]
+  pub async fn inflation(&self) -> Result<EconomicSeries> {
+    self.transport.get(FuncType::Inflation, HashMap::new()).await
+  }
+
+  /// Get US treasury yields for one maturity
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Weekly`, or `Daily`
+  /// * `maturity` - Constant maturity; the API defaults to `TenYear`
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::EconomicsEndpoints;
+  /// # use av_core::types::{EconomicInterval, TreasuryMaturity};
+  /// # let endpoints = EconomicsEndpoints::new(Arc::new(transport));
+  /// let two_year = endpoints
+  ///     .treasury_yield(Some(EconomicInterval::Daily), Some(TreasuryMaturity::TwoYear))
+  ///     .await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(interval, maturity))]
+  pub async fn treasury_yield(
+    &self,
+    interval: Option<EconomicInterval>,
+    maturity: Option<TreasuryMaturity>,
+  ) -> Result<EconomicSeries> {
+    let mut params = Self::interval_params(FuncType::TreasuryYield, interval)?;
+    if let Some(maturity) = maturity {
+      params.insert("maturity".to_string(), maturity.to_string());
+    }
+
+    self.transport.get(FuncType::TreasuryYield, params).await
+  }
+
+  /// Get the effective federal funds rate
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - `Monthly` (default), `Weekly`, or `Daily`
+  #[instrument(skip(self), fields(interval))]
+  pub async fn federal_funds_rate(
+    &self,
+    interval: Option<EconomicInterval>,
+  ) -> Result<EconomicSeries> {
+    let params = Self::interval_params(FuncType::FederalFundsRate, interval)?;
+
+    self.transport.get(FuncType::FederalFundsRate, params).await
+  }
+
+  /// Get the monthly US unemployment rate
+  #[instrument(skip(self))]
+  pub async fn unemployment(&self) -> Result<EconomicSeries> {
+    self.transport.get(FuncType::Unemployment, HashMap::new()).await
+  }
+
+  /// Get monthly US total nonfarm payroll
+  #[instrument(skip(self))]
+  pub async fn nonfarm_payroll(&self) -> Result<EconomicSeries> {
+    self.transport.get(FuncType::NonfarmPayroll, HashMap::new()).await
+  }
+
+  /// Builds the `interval` parameter, rejecting intervals the function does
+  /// not publish before any request is made.
+  fn interval_params(
+    function: FuncType,
+    interval: Option<EconomicInterval>,
+  ) -> Result<HashMap<String, String>> {
+    let mut params = HashMap::new();
+    if let Some(interval) = interval {
+      if !Self::supported_intervals(function).contains(&interval) {
+        return Err(Error::Config(format!(
+          "{} does not support interval '{}'",
+          function, interval
+        )));
+      }
+      params.insert("interval".to_string(), interval.to_string());
+    }
+    Ok(params)
+  }
+}
+
+impl_endpoint_base!(EconomicsEndpoints);
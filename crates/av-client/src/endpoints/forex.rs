@@ -27,35 +27,94 @@
  * SOFTWARE.
  */
 
-use super::EndpointBase;
+use super::{EndpointBase, parse_arg};
 use crate::impl_endpoint_base;
 
 use crate::transport::Transport;
-use av_core::{FuncType, Result};
+use av_core::types::common::{Interval, OutputSize};
+use av_core::{Error, FuncType, Result};
 use av_models::forex::*;
-use governor::{
-  RateLimiter,
-  clock::DefaultClock,
-  middleware::NoOpMiddleware,
-  state::{InMemoryState, NotKeyed},
-};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::instrument;
 
+/// Parameters for the forex time series endpoints
+/// [`ForexEndpoints::intraday_with`] and [`ForexEndpoints::daily_with`].
+///
+/// Both currencies must be three-letter codes; they are upper-cased when
+/// sent. `interval` is required for intraday and rejected for daily.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FxRequest {
+  pub from_symbol: String,
+  pub to_symbol: String,
+  pub interval: Option<Interval>,
+  pub output_size: Option<OutputSize>,
+}
+
+impl FxRequest {
+  pub fn new(from_symbol: impl Into<String>, to_symbol: impl Into<String>) -> Self {
+    Self {
+      from_symbol: from_symbol.into(),
+      to_symbol: to_symbol.into(),
+      interval: None,
+      output_size: None,
+    }
+  }
+
+  pub fn interval(mut self, interval: Interval) -> Self {
+    self.interval = Some(interval);
+    self
+  }
+
+  pub fn output_size(mut self, output_size: OutputSize) -> Self {
+    self.output_size = Some(output_size);
+    self
+  }
+
+  fn validate(&self, function: FuncType) -> Result<()> {
+    for code in [&self.from_symbol, &self.to_symbol] {
+      let code = code.trim();
+      if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(Error::Config(format!("'{}' is not a three-letter currency code", code)));
+      }
+    }
+    match (function, self.interval) {
+      (FuncType::FxIntraday, None) => {
+        Err(Error::Config(format!("{} request needs an interval", function)))
+      }
+      (FuncType::FxIntraday, Some(_)) | (_, None) => Ok(()),
+      (_, Some(_)) => Err(Error::Config(format!(
+        "`interval` only applies to {}, not {}",
+        FuncType::FxIntraday,
+        function
+      ))),
+    }
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = vec![
+      ("from_symbol".to_string(), self.from_symbol.trim().to_ascii_uppercase()),
+      ("to_symbol".to_string(), self.to_symbol.trim().to_ascii_uppercase()),
+    ];
+    if let Some(interval) = self.interval {
+      params.push(("interval".to_string(), interval.to_string()));
+    }
+    if let Some(output_size) = self.output_size {
+      params.push(("outputsize".to_string(), output_size.to_string()));
+    }
+    params
+  }
+}
+
 /// Foreign exchange (forex) endpoints
 pub struct ForexEndpoints {
   transport: Arc<Transport>,
-  rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
 impl ForexEndpoints {
   /// Create a new forex endpoints instance
-  pub fn new(
-    transport: Arc<Transport>,
-    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
-  ) -> Self {
-    Self { transport, rate_limiter }
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
   }
 
   /// Get real-time exchange rate between two currencies
@@ -70,7 +129,7 @@ impl ForexEndpoints {
   /// ```ignore
   /// # use av_client::ForexEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = ForexEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = ForexEndpoints::new(Arc::new(transport));
   /// // Get USD to EUR exchange rate
   /// let rate = endpoints.exchange_rate("USD", "EUR").await?;
   /// println!("1 {} = {} {}",
@@ -92,8 +151,6 @@ impl ForexEndpoints {
     from_currency: &str,
     to_currency: &str,
   ) -> Result<ExchangeRate> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("from_currency".to_string(), from_currency.to_string());
     params.insert("to_currency".to_string(), to_currency.to_string());
@@ -115,7 +172,7 @@ impl ForexEndpoints {
   /// ```ignore
   /// # use av_client::ForexEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = ForexEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = ForexEndpoints::new(Arc::new(transport));
   /// let data = endpoints.intraday("EUR", "USD", "5min", "compact").await?;
   /// for (timestamp, fx_data) in &data.time_series {
   ///     println!("{}: {}", timestamp, fx_data.close);
@@ -130,15 +187,24 @@ impl ForexEndpoints {
     interval: &str,
     outputsize: &str,
   ) -> Result<FxIntraday> {
-    self.wait_for_rate_limit().await?;
+    let request = FxRequest::new(from_symbol, to_symbol)
+      .interval(parse_arg(interval)?)
+      .output_size(parse_arg(outputsize)?);
+    self.intraday_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("from_symbol".to_string(), from_symbol.to_string());
-    params.insert("to_symbol".to_string(), to_symbol.to_string());
-    params.insert("interval".to_string(), interval.to_string());
-    params.insert("outputsize".to_string(), outputsize.to_string());
+  /// Get intraday forex time series data for an [`FxRequest`] with an
+  /// interval set
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if a currency code is
+  /// malformed or the interval is missing.
+  #[instrument(skip(self), fields(from_symbol = %request.from_symbol, to_symbol = %request.to_symbol))]
+  pub async fn intraday_with(&self, request: &FxRequest) -> Result<FxIntraday> {
+    request.validate(FuncType::FxIntraday)?;
 
-    self.transport.get(FuncType::FxIntraday, params).await
+    self.transport.get_pairs(FuncType::FxIntraday, request.to_params()).await
   }
 
   /// Get daily forex time series data
@@ -154,7 +220,7 @@ impl ForexEndpoints {
   /// ```ignore
   /// # use av_client::ForexEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = ForexEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = ForexEndpoints::new(Arc::new(transport));
   /// let data = endpoints.daily("EUR", "USD", "compact").await?;
   /// for (date, fx_data) in &data.time_series {
   ///     println!("{}: {}", date, fx_data.close);
@@ -168,14 +234,21 @@ impl ForexEndpoints {
     to_symbol: &str,
     outputsize: &str,
   ) -> Result<FxDaily> {
-    self.wait_for_rate_limit().await?;
+    let request = FxRequest::new(from_symbol, to_symbol).output_size(parse_arg(outputsize)?);
+    self.daily_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("from_symbol".to_string(), from_symbol.to_string());
-    params.insert("to_symbol".to_string(), to_symbol.to_string());
-    params.insert("outputsize".to_string(), outputsize.to_string());
+  /// Get daily forex time series data for an [`FxRequest`]
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if a currency code is
+  /// malformed or an interval is set.
+  #[instrument(skip(self), fields(from_symbol = %request.from_symbol, to_symbol = %request.to_symbol))]
+  pub async fn daily_with(&self, request: &FxRequest) -> Result<FxDaily> {
+    request.validate(FuncType::FxDaily)?;
 
-    self.transport.get(FuncType::FxDaily, params).await
+    self.transport.get_pairs(FuncType::FxDaily, request.to_params()).await
   }
 
   /// Get weekly forex time series data
@@ -186,8 +259,6 @@ impl ForexEndpoints {
   /// * `to_symbol` - The quote currency (e.g., "USD", "JPY")
   #[instrument(skip(self), fields(from_symbol, to_symbol))]
   pub async fn weekly(&self, from_symbol: &str, to_symbol: &str) -> Result<FxWeekly> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("from_symbol".to_string(), from_symbol.to_string());
     params.insert("to_symbol".to_string(), to_symbol.to_string());
@@ -203,8 +274,6 @@ impl ForexEndpoints {
   /// * `to_symbol` - The quote currency (e.g., "USD", "JPY")
   #[instrument(skip(self), fields(from_symbol, to_symbol))]
   pub async fn monthly(&self, from_symbol: &str, to_symbol: &str) -> Result<FxMonthly> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("from_symbol".to_string(), from_symbol.to_string());
     params.insert("to_symbol".to_string(), to_symbol.to_string());
@@ -214,3 +283,27 @@ impl ForexEndpoints {
 }
 
 impl_endpoint_base!(ForexEndpoints);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fx_request() {
+    let request = FxRequest::new("eur", "USD").interval(Interval::Min15);
+    assert!(request.validate(FuncType::FxIntraday).is_ok());
+    assert!(request.validate(FuncType::FxDaily).is_err());
+    assert_eq!(
+      request.to_params(),
+      vec![
+        ("from_symbol".to_string(), "EUR".to_string()),
+        ("to_symbol".to_string(), "USD".to_string()),
+        ("interval".to_string(), "15min".to_string()),
+      ]
+    );
+
+    assert!(FxRequest::new("EUR", "USD").validate(FuncType::FxIntraday).is_err());
+    assert!(FxRequest::new("EURO", "USD").validate(FuncType::FxDaily).is_err());
+    assert!(FxRequest::new("EUR", "US1").validate(FuncType::FxDaily).is_err());
+  }
+}
@@ -27,34 +27,124 @@
  * SOFTWARE.
  */
 
-use super::EndpointBase;
+use super::{EndpointBase, parse_arg};
 use crate::impl_endpoint_base;
 use crate::transport::Transport;
-use av_core::{FuncType, Result};
+use av_core::types::common::{ListingState, TimeHorizon};
+use av_core::{Error, FuncType, Result};
 use av_models::fundamentals::*;
-use governor::{
-  RateLimiter,
-  clock::DefaultClock,
-  middleware::NoOpMiddleware,
-  state::{InMemoryState, NotKeyed},
-};
+use chrono::NaiveDate;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::instrument;
 
+/// Parameters for [`FundamentalsEndpoints::listing_status_with`]
+///
+/// Without a date the API returns today's listings; historical snapshots
+/// go back to 2010-01-01.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListingStatusRequest {
+  pub date: Option<NaiveDate>,
+  pub state: Option<ListingState>,
+}
+
+impl ListingStatusRequest {
+  /// Earliest date the API keeps listing snapshots for
+  pub const FIRST_DATE: NaiveDate = match NaiveDate::from_ymd_opt(2010, 1, 1) {
+    Some(date) => date,
+    None => panic!("invalid listing status start date"),
+  };
+
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn date(mut self, date: NaiveDate) -> Self {
+    self.date = Some(date);
+    self
+  }
+
+  pub fn state(mut self, state: ListingState) -> Self {
+    self.state = Some(state);
+    self
+  }
+
+  fn validate(&self) -> Result<()> {
+    match self.date {
+      Some(date) if date < Self::FIRST_DATE => {
+        Err(Error::Config(format!("Listing status date {} is before {}", date, Self::FIRST_DATE)))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    if let Some(date) = self.date {
+      params.push(("date".to_string(), date.format("%Y-%m-%d").to_string()));
+    }
+    if let Some(state) = self.state {
+      params.push(("state".to_string(), state.to_string()));
+    }
+    params
+  }
+}
+
+/// Parameters for [`FundamentalsEndpoints::earnings_calendar_with`]
+///
+/// Leaving `symbol` unset returns the calendar for every company; the API
+/// defaults `horizon` to three months.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EarningsCalendarRequest {
+  pub symbol: Option<String>,
+  pub horizon: Option<TimeHorizon>,
+}
+
+impl EarningsCalendarRequest {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+    self.symbol = Some(symbol.into());
+    self
+  }
+
+  pub fn horizon(mut self, horizon: TimeHorizon) -> Self {
+    self.horizon = Some(horizon);
+    self
+  }
+
+  fn validate(&self) -> Result<()> {
+    match &self.symbol {
+      Some(symbol) if symbol.trim().is_empty() => {
+        Err(Error::Config("Earnings calendar symbol must not be empty".to_string()))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    if let Some(symbol) = &self.symbol {
+      params.push(("symbol".to_string(), symbol.trim().to_string()));
+    }
+    if let Some(horizon) = self.horizon {
+      params.push(("horizon".to_string(), horizon.to_string()));
+    }
+    params
+  }
+}
+
 /// Fundamental data endpoints for company financial information
 pub struct FundamentalsEndpoints {
   transport: Arc<Transport>,
-  rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
 impl FundamentalsEndpoints {
   /// Create a new fundamentals endpoints instance
-  pub fn new(
-    transport: Arc<Transport>,
-    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
-  ) -> Self {
-    Self { transport, rate_limiter }
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
   }
 
   /// Get company overview with key financial metrics
@@ -71,7 +161,7 @@ impl FundamentalsEndpoints {
   /// ```ignore
   /// # use av_client::FundamentalsEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport));
   /// let overview = endpoints.company_overview("AAPL").await?;
   /// println!("Market Cap: {}", overview.market_capitalization);
   /// println!("P/E Ratio: {}", overview.pe_ratio);
@@ -79,8 +169,6 @@ impl FundamentalsEndpoints {
   /// ```
   #[instrument(skip(self), fields(symbol))]
   pub async fn company_overview(&self, symbol: &str) -> Result<CompanyOverview> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -98,7 +186,7 @@ impl FundamentalsEndpoints {
   /// ```ignore
   /// # use av_client::FundamentalsEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport));
   /// let income_statement = endpoints.income_statement("AAPL").await?;
   /// for report in &income_statement.annual_reports {
   ///     println!("Year: {}, Revenue: {}", report.fiscal_date_ending, report.total_revenue);
@@ -107,8 +195,6 @@ impl FundamentalsEndpoints {
   /// ```
   #[instrument(skip(self), fields(symbol))]
   pub async fn income_statement(&self, symbol: &str) -> Result<IncomeStatement> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -122,8 +208,6 @@ impl FundamentalsEndpoints {
   /// * `symbol` - The stock symbol
   #[instrument(skip(self), fields(symbol))]
   pub async fn balance_sheet(&self, symbol: &str) -> Result<BalanceSheet> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -137,8 +221,6 @@ impl FundamentalsEndpoints {
   /// * `symbol` - The stock symbol
   #[instrument(skip(self), fields(symbol))]
   pub async fn cash_flow(&self, symbol: &str) -> Result<CashFlow> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -152,14 +234,93 @@ impl FundamentalsEndpoints {
   /// * `symbol` - The stock symbol
   #[instrument(skip(self), fields(symbol))]
   pub async fn earnings(&self, symbol: &str) -> Result<Earnings> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
     self.transport.get(FuncType::Earnings, params).await
   }
 
+  /// Get historical and declared dividend distributions
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol
+  #[instrument(skip(self), fields(symbol))]
+  pub async fn dividends(&self, symbol: &str) -> Result<Dividends> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    self.transport.get(FuncType::Dividends, params).await
+  }
+
+  /// Get historical stock splits
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol
+  #[instrument(skip(self), fields(symbol))]
+  pub async fn splits(&self, symbol: &str) -> Result<Splits> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    self.transport.get(FuncType::Splits, params).await
+  }
+
+  /// Get trades reported by company insiders, newest first
+  ///
+  /// The full filing history is returned on every call.
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol
+  #[instrument(skip(self), fields(symbol))]
+  pub async fn insider_transactions(&self, symbol: &str) -> Result<InsiderTransactions> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    self.transport.get(FuncType::InsiderTransactions, params).await
+  }
+
+  /// Get the earnings call transcript for one fiscal quarter
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol
+  /// * `quarter` - Fiscal quarter in `YYYYQn` form, e.g. `"2024Q1"`
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if `quarter` is not in `YYYYQn` form.
+  #[instrument(skip(self), fields(symbol, quarter))]
+  pub async fn earnings_call_transcript(
+    &self,
+    symbol: &str,
+    quarter: &str,
+  ) -> Result<EarningsCallTranscript> {
+    let Some((year, q)) = parse_fiscal_quarter(quarter) else {
+      return Err(Error::Config(format!("Invalid quarter '{}', expected YYYYQn", quarter)));
+    };
+
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+    params.insert("quarter".to_string(), format!("{}Q{}", year, q));
+
+    self.transport.get(FuncType::EarningsCallTranscript, params).await
+  }
+
+  /// Get ETF key metrics, holdings, and sector allocation
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The ETF symbol
+  #[instrument(skip(self), fields(symbol))]
+  pub async fn etf_profile(&self, symbol: &str) -> Result<EtfProfile> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    self.transport.get(FuncType::EtfProfile, params).await
+  }
+
   /// Get top gainers, losers, and most actively traded stocks
   ///
   /// # Examples
@@ -167,7 +328,7 @@ impl FundamentalsEndpoints {
   /// ```ignore
   /// # use av_client::FundamentalsEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = FundamentalsEndpoints::new(Arc::new(transport));
   /// let top_stats = endpoints.top_gainers_losers().await?;
   /// for gainer in &top_stats.top_gainers {
   ///     println!("Top Gainer: {} (+{}%)", gainer.ticker, gainer.change_percentage);
@@ -176,8 +337,6 @@ impl FundamentalsEndpoints {
   /// ```
   #[instrument(skip(self))]
   pub async fn top_gainers_losers(&self) -> Result<TopGainersLosers> {
-    self.wait_for_rate_limit().await?;
-
     let params = HashMap::new();
 
     self.transport.get(FuncType::TopGainersLosers, params).await
@@ -185,6 +344,8 @@ impl FundamentalsEndpoints {
 
   /// Get listing status (active or delisted) for securities
   ///
+  /// The API responds with CSV, which the transport decodes row by row.
+  ///
   /// # Arguments
   ///
   /// * `date` - Optional date in YYYY-MM-DD format
@@ -195,17 +356,27 @@ impl FundamentalsEndpoints {
     date: Option<&str>,
     state: Option<&str>,
   ) -> Result<ListingStatus> {
-    self.wait_for_rate_limit().await?;
+    let date = date
+      .map(|d| {
+        NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d")
+          .map_err(|e| Error::Config(format!("Invalid listing status date '{}': {}", d, e)))
+      })
+      .transpose()?;
+    let request = ListingStatusRequest { date, state: state.map(parse_arg).transpose()? };
+    self.listing_status_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    if let Some(date) = date {
-      params.insert("date".to_string(), date.to_string());
-    }
-    if let Some(state) = state {
-      params.insert("state".to_string(), state.to_string());
-    }
+  /// Get listing status for a [`ListingStatusRequest`]
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if the date is
+  /// before [`ListingStatusRequest::FIRST_DATE`].
+  #[instrument(skip(self), fields(date = ?request.date, state = ?request.state))]
+  pub async fn listing_status_with(&self, request: &ListingStatusRequest) -> Result<ListingStatus> {
+    request.validate()?;
 
-    self.transport.get(FuncType::ListingStatus, params).await
+    self.transport.get_pairs(FuncType::ListingStatus, request.to_params()).await
   }
 
   /// Get earnings calendar data
@@ -220,24 +391,27 @@ impl FundamentalsEndpoints {
     symbol: Option<&str>,
     horizon: Option<&str>,
   ) -> Result<EarningsCalendar> {
-    self.wait_for_rate_limit().await?;
+    let request = EarningsCalendarRequest {
+      symbol: symbol.map(String::from),
+      horizon: horizon.map(parse_arg).transpose()?,
+    };
+    self.earnings_calendar_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    if let Some(symbol) = symbol {
-      params.insert("symbol".to_string(), symbol.to_string());
-    }
-    if let Some(horizon) = horizon {
-      params.insert("horizon".to_string(), horizon.to_string());
-    }
+  /// Get earnings calendar data for an [`EarningsCalendarRequest`]
+  #[instrument(skip(self), fields(symbol = ?request.symbol, horizon = ?request.horizon))]
+  pub async fn earnings_calendar_with(
+    &self,
+    request: &EarningsCalendarRequest,
+  ) -> Result<EarningsCalendar> {
+    request.validate()?;
 
-    self.transport.get(FuncType::EarningsCalendar, params).await
+    self.transport.get_pairs(FuncType::EarningsCalendar, request.to_params()).await
   }
 
   /// Get IPO calendar data
   #[instrument(skip(self))]
   pub async fn ipo_calendar(&self) -> Result<IpoCalendar> {
-    self.wait_for_rate_limit().await?;
-
     let params = HashMap::new();
 
     self.transport.get(FuncType::IpoCalendar, params).await
@@ -0,0 +1,445 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::EndpointBase;
+use crate::impl_endpoint_base;
+use crate::transport::Transport;
+use av_core::types::common::{IndicatorInterval, Interval, SeriesType};
+use av_core::{FuncType, Result};
+use av_models::indicators::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Period settings for [`IndicatorEndpoints::macd`].
+///
+/// Defaults to the conventional 12/26/9 configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacdParams {
+  pub fast_period: u32,
+  pub slow_period: u32,
+  pub signal_period: u32,
+}
+
+impl Default for MacdParams {
+  fn default() -> Self {
+    Self { fast_period: 12, slow_period: 26, signal_period: 9 }
+  }
+}
+
+impl MacdParams {
+  /// Add `fastperiod`, `slowperiod` and `signalperiod` to `params`
+  fn apply(&self, params: &mut HashMap<String, String>) {
+    params.insert("fastperiod".to_string(), self.fast_period.to_string());
+    params.insert("slowperiod".to_string(), self.slow_period.to_string());
+    params.insert("signalperiod".to_string(), self.signal_period.to_string());
+  }
+}
+
+/// Band settings for [`IndicatorEndpoints::bbands`].
+///
+/// Defaults to two standard deviations above and below the middle band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BbandsParams {
+  pub nbdev_up: u32,
+  pub nbdev_dn: u32,
+}
+
+impl Default for BbandsParams {
+  fn default() -> Self {
+    Self { nbdev_up: 2, nbdev_dn: 2 }
+  }
+}
+
+impl BbandsParams {
+  /// Add `nbdevup` and `nbdevdn` to `params`
+  fn apply(&self, params: &mut HashMap<String, String>) {
+    params.insert("nbdevup".to_string(), self.nbdev_up.to_string());
+    params.insert("nbdevdn".to_string(), self.nbdev_dn.to_string());
+  }
+}
+
+/// Period settings for [`IndicatorEndpoints::stoch`].
+///
+/// Defaults to the API's 5/3/3 configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StochParams {
+  pub fastk_period: u32,
+  pub slowk_period: u32,
+  pub slowd_period: u32,
+}
+
+impl Default for StochParams {
+  fn default() -> Self {
+    Self { fastk_period: 5, slowk_period: 3, slowd_period: 3 }
+  }
+}
+
+impl StochParams {
+  /// Add `fastkperiod`, `slowkperiod` and `slowdperiod` to `params`
+  fn apply(&self, params: &mut HashMap<String, String>) {
+    params.insert("fastkperiod".to_string(), self.fastk_period.to_string());
+    params.insert("slowkperiod".to_string(), self.slowk_period.to_string());
+    params.insert("slowdperiod".to_string(), self.slowd_period.to_string());
+  }
+}
+
+/// Technical indicator endpoints
+pub struct IndicatorEndpoints {
+  transport: Arc<Transport>,
+}
+
+impl IndicatorEndpoints {
+  /// Create a new indicator endpoints instance
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
+  }
+
+  /// Get the simple moving average (SMA)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Number of bars per average (e.g., 20, 50, 200)
+  /// * `series_type` - Price field to average
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::IndicatorEndpoints;
+  /// # use av_core::types::{IndicatorInterval, SeriesType};
+  /// # let endpoints = IndicatorEndpoints::new(Arc::new(transport));
+  /// let sma = endpoints.sma("IBM", IndicatorInterval::Daily, 50, SeriesType::Close).await?;
+  /// if let Some((date, point)) = sma.latest() {
+  ///     println!("{}: {}", date, point.sma);
+  /// }
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn sma(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+    series_type: SeriesType,
+  ) -> Result<SmaSeries> {
+    let params = Self::period_params(symbol, interval, time_period, series_type);
+    self.transport.get(FuncType::Sma, params).await
+  }
+
+  /// Get the exponential moving average (EMA)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Number of bars per average
+  /// * `series_type` - Price field to average
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn ema(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+    series_type: SeriesType,
+  ) -> Result<EmaSeries> {
+    let params = Self::period_params(symbol, interval, time_period, series_type);
+    self.transport.get(FuncType::Ema, params).await
+  }
+
+  /// Get the relative strength index (RSI)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Lookback in bars (14 is the usual choice)
+  /// * `series_type` - Price field to evaluate
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn rsi(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+    series_type: SeriesType,
+  ) -> Result<RsiSeries> {
+    let params = Self::period_params(symbol, interval, time_period, series_type);
+    self.transport.get(FuncType::Rsi, params).await
+  }
+
+  /// Get moving average convergence/divergence (MACD)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `series_type` - Price field to evaluate
+  /// * `macd_params` - Fast, slow, and signal periods (see [`MacdParams::default`])
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::{IndicatorEndpoints, MacdParams};
+  /// # use av_core::types::{IndicatorInterval, SeriesType};
+  /// # let endpoints = IndicatorEndpoints::new(Arc::new(transport));
+  /// let macd = endpoints
+  ///     .macd("IBM", IndicatorInterval::Daily, SeriesType::Close, MacdParams::default())
+  ///     .await?;
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(symbol, interval = %interval))]
+  pub async fn macd(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    series_type: SeriesType,
+    macd_params: MacdParams,
+  ) -> Result<MacdSeries> {
+    let mut params = Self::base_params(symbol, interval);
+    params.insert("series_type".to_string(), series_type.to_string());
+    macd_params.apply(&mut params);
+
+    self.transport.get(FuncType::Macd, params).await
+  }
+
+  /// Get Bollinger bands (BBANDS)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Bars in the middle-band moving average
+  /// * `series_type` - Price field to evaluate
+  /// * `bbands_params` - Standard-deviation multipliers (see [`BbandsParams::default`])
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn bbands(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+    series_type: SeriesType,
+    bbands_params: BbandsParams,
+  ) -> Result<BbandsSeries> {
+    let mut params = Self::period_params(symbol, interval, time_period, series_type);
+    bbands_params.apply(&mut params);
+
+    self.transport.get(FuncType::Bbands, params).await
+  }
+
+  /// Get the average directional movement index (ADX)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Lookback in bars
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn adx(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+  ) -> Result<AdxSeries> {
+    let mut params = Self::base_params(symbol, interval);
+    params.insert("time_period".to_string(), time_period.to_string());
+
+    self.transport.get(FuncType::Adx, params).await
+  }
+
+  /// Get the slow stochastic oscillator (STOCH)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `stoch_params` - %K and %D periods (see [`StochParams::default`])
+  #[instrument(skip(self), fields(symbol, interval = %interval))]
+  pub async fn stoch(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    stoch_params: StochParams,
+  ) -> Result<StochSeries> {
+    let mut params = Self::base_params(symbol, interval);
+    stoch_params.apply(&mut params);
+
+    self.transport.get(FuncType::Stoch, params).await
+  }
+
+  /// Get the average true range (ATR)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  /// * `time_period` - Lookback in bars
+  #[instrument(skip(self), fields(symbol, interval = %interval, time_period))]
+  pub async fn atr(
+    &self,
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+  ) -> Result<AtrSeries> {
+    let mut params = Self::base_params(symbol, interval);
+    params.insert("time_period".to_string(), time_period.to_string());
+
+    self.transport.get(FuncType::Atr, params).await
+  }
+
+  /// Get on-balance volume (OBV)
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Bar width, intraday through monthly
+  #[instrument(skip(self), fields(symbol, interval = %interval))]
+  pub async fn obv(&self, symbol: &str, interval: IndicatorInterval) -> Result<ObvSeries> {
+    let params = Self::base_params(symbol, interval);
+    self.transport.get(FuncType::Obv, params).await
+  }
+
+  /// Get the volume-weighted average price (VWAP)
+  ///
+  /// The API only computes VWAP on intraday bars, so this takes an
+  /// intraday [`Interval`] rather than an [`IndicatorInterval`].
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol (e.g., "IBM")
+  /// * `interval` - Intraday bar width
+  #[instrument(skip(self), fields(symbol, interval = %interval))]
+  pub async fn vwap(&self, symbol: &str, interval: Interval) -> Result<VwapSeries> {
+    let params = Self::base_params(symbol, interval.into());
+    self.transport.get(FuncType::Vwap, params).await
+  }
+
+  /// `symbol` and `interval`, common to every indicator
+  fn base_params(symbol: &str, interval: IndicatorInterval) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+    params.insert("interval".to_string(), interval.to_string());
+    params
+  }
+
+  /// Base parameters plus `time_period` and `series_type`
+  fn period_params(
+    symbol: &str,
+    interval: IndicatorInterval,
+    time_period: u32,
+    series_type: SeriesType,
+  ) -> HashMap<String, String> {
+    let mut params = Self::base_params(symbol, interval);
+    params.insert("time_period".to_string(), time_period.to_string());
+    params.insert("series_type".to_string(), series_type.to_string());
+    params
+  }
+}
+
+impl_endpoint_base!(IndicatorEndpoints);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn query(params: HashMap<String, String>) -> Vec<(String, String)> {
+    let mut pairs: Vec<_> = params.into_iter().collect();
+    pairs.sort();
+    pairs
+  }
+
+  fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+    let mut pairs: Vec<_> = expected.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    pairs.sort();
+    pairs
+  }
+
+  #[test]
+  fn test_param_defaults() {
+    assert_eq!(
+      MacdParams::default(),
+      MacdParams { fast_period: 12, slow_period: 26, signal_period: 9 }
+    );
+    assert_eq!(BbandsParams::default(), BbandsParams { nbdev_up: 2, nbdev_dn: 2 });
+    assert_eq!(
+      StochParams::default(),
+      StochParams { fastk_period: 5, slowk_period: 3, slowd_period: 3 }
+    );
+  }
+
+  #[test]
+  fn test_macd_query_params() {
+    let mut params = IndicatorEndpoints::base_params("IBM", IndicatorInterval::Daily);
+    MacdParams::default().apply(&mut params);
+    assert_eq!(
+      query(params),
+      pairs(&[
+        ("symbol", "IBM"),
+        ("interval", "daily"),
+        ("fastperiod", "12"),
+        ("slowperiod", "26"),
+        ("signalperiod", "9"),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_bbands_query_params() {
+    let mut params =
+      IndicatorEndpoints::period_params("IBM", IndicatorInterval::Weekly, 20, SeriesType::Close);
+    BbandsParams { nbdev_up: 3, ..BbandsParams::default() }.apply(&mut params);
+    assert_eq!(
+      query(params),
+      pairs(&[
+        ("symbol", "IBM"),
+        ("interval", "weekly"),
+        ("time_period", "20"),
+        ("series_type", "close"),
+        ("nbdevup", "3"),
+        ("nbdevdn", "2"),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_stoch_query_params() {
+    let mut params = IndicatorEndpoints::base_params("MSFT", IndicatorInterval::Min15);
+    StochParams::default().apply(&mut params);
+    assert_eq!(
+      query(params),
+      pairs(&[
+        ("symbol", "MSFT"),
+        ("interval", "15min"),
+        ("fastkperiod", "5"),
+        ("slowkperiod", "3"),
+        ("slowdperiod", "3"),
+      ])
+    );
+  }
+}
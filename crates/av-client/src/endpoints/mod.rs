@@ -29,22 +29,25 @@
 
 #![allow(async_fn_in_trait)]
 
+pub mod analytics;
+pub mod commodities;
 pub mod crypto;
 pub mod crypto_social;
+pub mod economics;
 pub mod forex;
 pub mod fundamentals;
+pub mod indicators;
 pub mod news;
+pub mod options;
 pub mod time_series;
 
 use crate::transport::Transport;
-use av_core::Result;
+use av_core::{Error, Result};
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// Base trait for all endpoint implementations
 pub trait EndpointBase: Send + Sync {
-  /// Wait for rate limit before making a request
-  async fn wait_for_rate_limit(&self) -> Result<()>; // Keep it simple with async fn
-
   /// Get the transport instance
   fn transport(&self) -> &Arc<Transport>;
 }
@@ -54,14 +57,21 @@ pub trait EndpointBase: Send + Sync {
 macro_rules! impl_endpoint_base {
   ($endpoint:ty) => {
     impl EndpointBase for $endpoint {
-      async fn wait_for_rate_limit(&self) -> Result<()> {
-        self.rate_limiter.until_ready().await;
-        Ok(())
-      }
-
       fn transport(&self) -> &Arc<Transport> {
         &self.transport
       }
     }
   };
 }
+
+/// Parse a string argument of one of the untyped endpoint methods.
+///
+/// Those methods are thin wrappers over the typed request builders; an
+/// unrecognized value fails with [`Error::Config`] before any request is
+/// sent.
+pub(crate) fn parse_arg<T>(value: &str) -> Result<T>
+where
+  T: FromStr<Err = String>,
+{
+  value.trim().parse().map_err(Error::Config)
+}
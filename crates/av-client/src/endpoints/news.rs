@@ -27,34 +27,197 @@
  * SOFTWARE.
  */
 
-use super::EndpointBase;
+use super::{EndpointBase, parse_arg};
 use crate::impl_endpoint_base;
 use crate::transport::Transport;
-use av_core::{FuncType, Result};
+use av_core::types::common::{NewsTopic, SortOrder};
+use av_core::{Error, FuncType, Result};
 use av_models::news::*;
-use governor::{
-  RateLimiter,
-  clock::DefaultClock,
-  middleware::NoOpMiddleware,
-  state::{InMemoryState, NotKeyed},
-};
-use std::collections::HashMap;
+use chrono::{DateTime, Duration, DurationRound, NaiveDateTime, Utc};
+use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::instrument;
+use tracing::{debug, instrument, warn};
+
+/// Timestamp format of the `time_from` / `time_to` parameters
+const NEWS_TIME_FORMAT: &str = "%Y%m%dT%H%M";
+
+/// Ticker prefixes the news feed accepts besides plain equity symbols
+const TICKER_PREFIXES: [&str; 2] = ["CRYPTO", "FOREX"];
+
+/// Parameters for [`NewsEndpoints::news_sentiment_with`] and
+/// [`NewsEndpoints::news_sentiment_all`]
+///
+/// Every filter is optional; an empty request returns the latest articles
+/// across the whole feed. Tickers are equity symbols or `CRYPTO:`/`FOREX:`
+/// prefixed currencies (see [`crypto`](Self::crypto) and
+/// [`forex`](Self::forex)); the API times are minute-precision UTC.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewsRequest {
+  pub tickers: Vec<String>,
+  pub topics: Vec<NewsTopic>,
+  pub time_from: Option<DateTime<Utc>>,
+  pub time_to: Option<DateTime<Utc>>,
+  pub sort: Option<SortOrder>,
+  pub limit: Option<u32>,
+}
+
+impl NewsRequest {
+  /// Maximum `limit` the API accepts
+  pub const MAX_LIMIT: u32 = 1000;
+
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn ticker(mut self, ticker: impl Into<String>) -> Self {
+    self.tickers.push(ticker.into());
+    self
+  }
+
+  /// Add a cryptocurrency ticker, e.g. `crypto("BTC")` for `CRYPTO:BTC`
+  pub fn crypto(self, symbol: &str) -> Self {
+    self.ticker(format!("CRYPTO:{}", symbol.trim().to_uppercase()))
+  }
+
+  /// Add a currency ticker, e.g. `forex("USD")` for `FOREX:USD`
+  pub fn forex(self, currency: &str) -> Self {
+    self.ticker(format!("FOREX:{}", currency.trim().to_uppercase()))
+  }
+
+  pub fn topic(mut self, topic: NewsTopic) -> Self {
+    self.topics.push(topic);
+    self
+  }
+
+  pub fn time_from(mut self, time_from: DateTime<Utc>) -> Self {
+    self.time_from = Some(time_from);
+    self
+  }
+
+  pub fn time_to(mut self, time_to: DateTime<Utc>) -> Self {
+    self.time_to = Some(time_to);
+    self
+  }
+
+  pub fn sort(mut self, sort: SortOrder) -> Self {
+    self.sort = Some(sort);
+    self
+  }
+
+  pub fn limit(mut self, limit: u32) -> Self {
+    self.limit = Some(limit);
+    self
+  }
+
+  fn validate(&self) -> Result<()> {
+    for ticker in &self.tickers {
+      let symbol = match ticker.split_once(':') {
+        Some((prefix, symbol)) if TICKER_PREFIXES.contains(&prefix) => symbol,
+        Some(_) => {
+          return Err(Error::Config(format!(
+            "News ticker '{}' has an unknown prefix (expected CRYPTO: or FOREX:)",
+            ticker
+          )));
+        }
+        None => ticker,
+      };
+      if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphanumeric() || c == '.') {
+        return Err(Error::Config(format!("Invalid news ticker '{}'", ticker)));
+      }
+    }
+    if let Some(limit) = self.limit {
+      if limit == 0 || limit > Self::MAX_LIMIT {
+        return Err(Error::Config(format!(
+          "News limit must be between 1 and {}, got {}",
+          Self::MAX_LIMIT,
+          limit
+        )));
+      }
+    }
+    if let (Some(from), Some(to)) = (self.time_from, self.time_to) {
+      if from > to {
+        return Err(Error::Config(format!("News time_from {} is after time_to {}", from, to)));
+      }
+    }
+    Ok(())
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    if !self.tickers.is_empty() {
+      params.push(("tickers".to_string(), self.tickers.join(",")));
+    }
+    if !self.topics.is_empty() {
+      let topics: Vec<_> = self.topics.iter().map(NewsTopic::to_string).collect();
+      params.push(("topics".to_string(), topics.join(",")));
+    }
+    if let Some(time_from) = self.time_from {
+      params.push(("time_from".to_string(), time_from.format(NEWS_TIME_FORMAT).to_string()));
+    }
+    if let Some(time_to) = self.time_to {
+      params.push(("time_to".to_string(), time_to.format(NEWS_TIME_FORMAT).to_string()));
+    }
+    if let Some(sort) = self.sort {
+      params.push(("sort".to_string(), sort.to_string()));
+    }
+    if let Some(limit) = self.limit {
+      params.push(("limit".to_string(), limit.to_string()));
+    }
+    params
+  }
+
+  /// This request restricted to `[from, to]`, asking for as many articles
+  /// as the API returns at once
+  fn window(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+    Self { time_from: Some(from), time_to: Some(to), limit: Some(Self::MAX_LIMIT), ..self.clone() }
+  }
+}
+
+/// Where to split the window `[from, to]` into two, or `None` once it is a
+/// single minute (the API's time resolution) wide
+fn split_window(from: DateTime<Utc>, to: DateTime<Utc>) -> Option<DateTime<Utc>> {
+  let minute = Duration::minutes(1);
+  let mid = (from + (to - from) / 2).duration_trunc(minute).ok()?;
+  (to - from > minute && mid > from && mid < to).then_some(mid)
+}
+
+/// Order merged articles as `sort` asks; relevance order is kept as fetched
+fn sort_feed(feed: &mut [NewsArticle], sort: Option<SortOrder>) {
+  // `time_published` is `YYYYMMDDTHHMMSS`, so it sorts chronologically as text
+  match sort.unwrap_or(SortOrder::Latest) {
+    SortOrder::Latest => feed.sort_by(|a, b| b.time_published.cmp(&a.time_published)),
+    SortOrder::Earliest => feed.sort_by(|a, b| a.time_published.cmp(&b.time_published)),
+    // Rejected by `news_sentiment_all`
+    SortOrder::Relevance => {}
+  }
+}
+
+/// Split a comma-separated filter into its non-empty items
+fn split_list(value: Option<&str>) -> Vec<String> {
+  value
+    .map(|v| v.split(',').map(str::trim).filter(|s| !s.is_empty()).map(String::from).collect())
+    .unwrap_or_default()
+}
+
+fn parse_news_time(value: Option<&str>) -> Result<Option<DateTime<Utc>>> {
+  value
+    .map(|v| {
+      NaiveDateTime::parse_from_str(v.trim(), NEWS_TIME_FORMAT).map(|t| t.and_utc()).map_err(|e| {
+        Error::Config(format!("Invalid news time '{}' (expected YYYYMMDDTHHMM): {}", v, e))
+      })
+    })
+    .transpose()
+}
 
 /// News sentiment endpoints
 pub struct NewsEndpoints {
   transport: Arc<Transport>,
-  rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
 impl NewsEndpoints {
   /// Create a new news endpoints instance
-  pub fn new(
-    transport: Arc<Transport>,
-    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
-  ) -> Self {
-    Self { transport, rate_limiter }
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
   }
 
   /// Get news sentiment data
@@ -62,7 +225,7 @@ impl NewsEndpoints {
   /// # Arguments
   ///
   /// * `tickers` - Optional comma-separated list of stock tickers
-  /// * `topics` - Optional topics to filter by
+  /// * `topics` - Optional comma-separated topics to filter by (see [`NewsTopic`])
   /// * `time_from` - Optional start time in YYYYMMDDTHHMM format
   /// * `time_to` - Optional end time in YYYYMMDDTHHMM format
   /// * `sort` - Optional sort order ("LATEST", "EARLIEST", "RELEVANCE")
@@ -73,7 +236,7 @@ impl NewsEndpoints {
   /// ```ignore
   /// # use av_client::NewsEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = NewsEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = NewsEndpoints::new(Arc::new(transport));
   /// // Get news for Apple and Microsoft
   /// let news = endpoints.news_sentiment(
   ///     Some("AAPL,MSFT"),
@@ -100,31 +263,244 @@ impl NewsEndpoints {
     sort: Option<&str>,
     limit: Option<u32>,
   ) -> Result<NewsSentiment> {
-    self.wait_for_rate_limit().await?;
+    let request = NewsRequest {
+      tickers: split_list(tickers),
+      topics: split_list(topics).iter().map(|t| parse_arg(t)).collect::<Result<_>>()?,
+      time_from: parse_news_time(time_from)?,
+      time_to: parse_news_time(time_to)?,
+      sort: sort.map(parse_arg).transpose()?,
+      limit,
+    };
+    self.news_sentiment_with(&request).await
+  }
 
-    let mut params = HashMap::new();
+  /// Get news sentiment data for a [`NewsRequest`]
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if a ticker is
+  /// malformed, `limit` is out of range or `time_from` is after `time_to`.
+  #[instrument(skip(self), fields(tickers = request.tickers.len(), topics = request.topics.len()))]
+  pub async fn news_sentiment_with(&self, request: &NewsRequest) -> Result<NewsSentiment> {
+    request.validate()?;
 
-    if let Some(tickers) = tickers {
-      params.insert("tickers".to_string(), tickers.to_string());
-    }
-    if let Some(topics) = topics {
-      params.insert("topics".to_string(), topics.to_string());
+    self.transport.get_pairs(FuncType::NewsSentiment, request.to_params()).await
+  }
+
+  /// Get every article matching `request` between `time_from` and
+  /// `time_to` (default: now), however many there are
+  ///
+  /// A single call returns at most [`NewsRequest::MAX_LIMIT`] articles.
+  /// Whenever a window comes back full it is split in two and both halves
+  /// are fetched again, down to one-minute windows; a full one-minute window
+  /// is kept as is with a warning. Articles are deduplicated by URL and
+  /// ordered by `sort` (latest first by default), and `limit`, if set,
+  /// caps the merged feed rather than each call.
+  ///
+  /// Relevance scores are only comparable within one call, so
+  /// [`SortOrder::Relevance`] cannot order a merged feed and is rejected.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if `time_from` is not set or `sort` is
+  /// [`SortOrder::Relevance`], besides anything
+  /// [`news_sentiment_with`](Self::news_sentiment_with) returns. Any failed
+  /// call fails the whole fetch.
+  #[instrument(skip(self), fields(tickers = request.tickers.len(), topics = request.topics.len()))]
+  pub async fn news_sentiment_all(&self, request: &NewsRequest) -> Result<NewsSentiment> {
+    let from = request.time_from.ok_or_else(|| {
+      Error::Config("Paging through news requires time_from to bound the search".to_string())
+    })?;
+    if request.sort == Some(SortOrder::Relevance) {
+      return Err(Error::Config(
+        "Paging through news cannot sort by relevance; use latest or earliest".to_string(),
+      ));
     }
-    if let Some(time_from) = time_from {
-      params.insert("time_from".to_string(), time_from.to_string());
+    let to = request.time_to.unwrap_or_else(Utc::now);
+    request.window(from, to).validate()?;
+
+    let mut seen = HashSet::new();
+    let mut merged: Option<NewsSentiment> = None;
+    let mut pending = vec![(from, to)];
+    while let Some((from, to)) = pending.pop() {
+      let page = self.news_sentiment_with(&request.window(from, to)).await?;
+      let full = page.feed.len() >= NewsRequest::MAX_LIMIT as usize;
+      debug!("News window {} to {}: {} articles", from, to, page.feed.len());
+
+      if full {
+        match split_window(from, to) {
+          // Refetch both halves; this page's articles all reappear there
+          Some(mid) => {
+            pending.push((mid, to));
+            pending.push((from, mid));
+            continue;
+          }
+          None => warn!("News window {} to {} is full; some articles may be missing", from, to),
+        }
+      }
+
+      let merged = merged.get_or_insert_with(|| NewsSentiment { feed: Vec::new(), ..page.clone() });
+      merged.feed.extend(page.feed.into_iter().filter(|a| seen.insert(a.url.clone())));
     }
-    if let Some(time_to) = time_to {
-      params.insert("time_to".to_string(), time_to.to_string());
+
+    let mut merged =
+      merged.ok_or_else(|| Error::Unexpected("No news window was fetched".to_string()))?;
+    sort_feed(&mut merged.feed, request.sort);
+    if let Some(limit) = request.limit {
+      merged.feed.truncate(limit as usize);
     }
-    if let Some(sort) = sort {
-      params.insert("sort".to_string(), sort.to_string());
+    merged.items = merged.feed.len().to_string();
+    Ok(merged)
+  }
+}
+
+impl_endpoint_base!(NewsEndpoints);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+
+  fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(year, month, day)
+      .unwrap()
+      .and_hms_opt(hour, minute, 0)
+      .unwrap()
+      .and_utc()
+  }
+
+  #[test]
+  fn test_news_request() {
+    let from = at(2024, 3, 1, 9, 30);
+    let request = NewsRequest::new()
+      .ticker("AAPL")
+      .crypto("btc")
+      .topic(NewsTopic::Earnings)
+      .topic(NewsTopic::EconomyMonetary)
+      .time_from(from)
+      .sort(SortOrder::Earliest)
+      .limit(200);
+
+    assert!(request.validate().is_ok());
+    assert_eq!(
+      request.to_params(),
+      vec![
+        ("tickers".to_string(), "AAPL,CRYPTO:BTC".to_string()),
+        ("topics".to_string(), "earnings,economy_monetary".to_string()),
+        ("time_from".to_string(), "20240301T0930".to_string()),
+        ("sort".to_string(), "EARLIEST".to_string()),
+        ("limit".to_string(), "200".to_string()),
+      ]
+    );
+
+    assert!(NewsRequest::new().limit(0).validate().is_err());
+    assert!(NewsRequest::new().limit(1001).validate().is_err());
+    let backwards = NewsRequest::new().time_from(from).time_to(from - chrono::Duration::hours(1));
+    assert!(backwards.validate().is_err());
+    assert!(NewsRequest::new().forex("eur").validate().is_ok());
+    for ticker in ["STOCK:AAPL", "CRYPTO:", "BRK B", ""] {
+      assert!(NewsRequest::new().ticker(ticker).validate().is_err(), "{}", ticker);
     }
-    if let Some(limit) = limit {
-      params.insert("limit".to_string(), limit.to_string());
+  }
+
+  #[test]
+  fn test_split_window() {
+    let from = at(2024, 3, 1, 0, 0);
+    assert_eq!(split_window(from, at(2024, 3, 1, 1, 1)), Some(at(2024, 3, 1, 0, 30)));
+    assert_eq!(split_window(from, at(2024, 3, 1, 0, 2)), Some(at(2024, 3, 1, 0, 1)));
+    assert_eq!(split_window(from, at(2024, 3, 1, 0, 1)), None);
+  }
+
+  fn article(url: &str, time: &str) -> serde_json::Value {
+    serde_json::json!({
+      "title": url, "url": url, "time_published": time, "authors": [], "summary": "",
+      "banner_image": null, "source": "Wire", "category_within_source": "n/a",
+      "source_domain": "example.com", "topics": [], "overall_sentiment_score": 0.0,
+      "overall_sentiment_label": "Neutral", "ticker_sentiment": []
+    })
+  }
+
+  fn feed(articles: Vec<serde_json::Value>) -> String {
+    serde_json::json!({
+      "items": articles.len().to_string(),
+      "sentiment_score_definition": "x <= -0.35: Bearish",
+      "relevance_score_definition": "0 < x <= 1",
+      "feed": articles,
+    })
+    .to_string()
+  }
+
+  #[tokio::test]
+  async fn test_news_sentiment_all_splits_full_windows() {
+    use crate::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+
+    fn window<'a>(from: &'a str, to: &'a str) -> [(&'static str, &'a str); 2] {
+      [("time_from", from), ("time_to", to)]
     }
+    let articles = |prefix: &str, n: usize, time: &str| {
+      (0..n).map(|i| article(&format!("https://example.com/{}{}", prefix, i), time)).collect()
+    };
+    // The full day is capped; its halves overlap by the article at noon
+    let mut afternoon: Vec<_> = articles("pm", 400, "20240301T150000");
+    afternoon.push(article("https://example.com/am0", "20240301T120000"));
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response_for(
+          FuncType::NewsSentiment,
+          &window("20240301T0000", "20240302T0000"),
+          feed(articles("am", 1000, "20240301T090000")),
+        )
+        .with_response_for(
+          FuncType::NewsSentiment,
+          &window("20240301T0000", "20240301T1200"),
+          feed(articles("am", 700, "20240301T090000")),
+        )
+        .with_response_for(
+          FuncType::NewsSentiment,
+          &window("20240301T1200", "20240302T0000"),
+          feed(afternoon),
+        ),
+    );
+    let config = Config { max_retries: 0, ..Config::default_with_key("demo".into()) };
+    let client = AlphaVantageClient::with_transport(config, fixture.clone()).unwrap();
 
-    self.transport.get(FuncType::NewsSentiment, params).await
+    let request = NewsRequest::new()
+      .crypto("BTC")
+      .time_from(at(2024, 3, 1, 0, 0))
+      .time_to(at(2024, 3, 2, 0, 0));
+    let news = client.news().news_sentiment_all(&request).await.unwrap();
+
+    assert_eq!(fixture.requests().len(), 3);
+    assert_eq!(news.feed.len(), 1100);
+    assert_eq!(news.items, "1100");
+    assert_eq!(news.feed[0].time_published, "20240301T150000");
+    assert!(fixture.requests().iter().all(|r| r.params["limit"] == "1000"));
+
+    assert!(client.news().news_sentiment_all(&NewsRequest::new()).await.is_err());
   }
-}
 
-impl_endpoint_base!(NewsEndpoints);
+  #[tokio::test]
+  async fn test_news_sentiment_all_rejects_relevance() {
+    use crate::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+
+    let fixture = Arc::new(FixtureTransport::new());
+    let config = Config { max_retries: 0, ..Config::default_with_key("demo".into()) };
+    let client = AlphaVantageClient::with_transport(config, fixture.clone()).unwrap();
+
+    let request = NewsRequest::new().time_from(at(2024, 3, 1, 0, 0)).sort(SortOrder::Relevance);
+    let result = client.news().news_sentiment_all(&request).await;
+
+    assert!(matches!(result, Err(Error::Config(_))));
+    assert!(fixture.requests().is_empty());
+  }
+
+  #[test]
+  fn test_string_arguments() {
+    assert_eq!(split_list(Some("AAPL, MSFT,,")), vec!["AAPL", "MSFT"]);
+    assert!(split_list(None).is_empty());
+    assert!(parse_news_time(Some("20240301T0930")).unwrap().is_some());
+    assert!(parse_news_time(Some("2024-03-01")).is_err());
+  }
+}
@@ -0,0 +1,123 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::EndpointBase;
+use crate::impl_endpoint_base;
+use crate::transport::Transport;
+use av_core::types::market::OccSymbol;
+use av_core::{Error, FuncType, Result};
+use av_models::options::OptionChain;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Option chain endpoints
+pub struct OptionsEndpoints {
+  transport: Arc<Transport>,
+}
+
+impl OptionsEndpoints {
+  /// Earliest date `HISTORICAL_OPTIONS` has data for.
+  pub const HISTORY_START: NaiveDate = NaiveDate::from_ymd_opt(2008, 1, 1).unwrap();
+
+  /// Create a new options endpoints instance
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
+  }
+
+  /// Get the realtime option chain for an underlying
+  ///
+  /// Requires a premium API key.
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The underlying symbol
+  /// * `require_greeks` - Include Greeks and implied volatility
+  /// * `contract` - Restrict the response to one OCC contract symbol
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::OptionsEndpoints;
+  /// # let endpoints = OptionsEndpoints::new(Arc::new(transport));
+  /// let chain = endpoints.realtime("IBM", true, None).await?;
+  /// for call in chain.calls() {
+  ///     println!("{} delta={:?}", call.contract_id, call.greeks().delta);
+  /// }
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self), fields(symbol, require_greeks, contract))]
+  pub async fn realtime(
+    &self,
+    symbol: &str,
+    require_greeks: bool,
+    contract: Option<&str>,
+  ) -> Result<OptionChain> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+    params.insert("require_greeks".to_string(), require_greeks.to_string());
+
+    if let Some(contract) = contract {
+      let occ: OccSymbol = contract.parse().map_err(Error::Config)?;
+      params.insert("contract".to_string(), occ.to_string());
+    }
+
+    self.transport.get(FuncType::RealtimeOptions, params).await
+  }
+
+  /// Get the end-of-day option chain for an underlying, with Greeks and IV
+  ///
+  /// Non-trading days return an empty chain.
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The underlying symbol
+  /// * `date` - Trading date, no earlier than [`HISTORY_START`](Self::HISTORY_START);
+  ///   `None` returns the previous trading session
+  #[instrument(skip(self), fields(symbol, date))]
+  pub async fn historical(&self, symbol: &str, date: Option<NaiveDate>) -> Result<OptionChain> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    if let Some(date) = date {
+      if date < Self::HISTORY_START {
+        return Err(Error::Config(format!(
+          "Historical options are only available from {}",
+          Self::HISTORY_START
+        )));
+      }
+      params.insert("date".to_string(), date.format("%Y-%m-%d").to_string());
+    }
+
+    self.transport.get(FuncType::HistoricalOptions, params).await
+  }
+}
+
+impl_endpoint_base!(OptionsEndpoints);
@@ -27,34 +27,306 @@
  * SOFTWARE.
  */
 
-use super::EndpointBase;
+use super::{EndpointBase, parse_arg};
 use crate::impl_endpoint_base;
 use crate::transport::Transport;
-use av_core::{FuncType, Result};
+use av_core::types::common::{Interval, OutputSize, YearMonth};
+use av_core::{Error, FuncType, Result};
 use av_models::time_series::*;
-use governor::{
-  RateLimiter,
-  clock::DefaultClock,
-  middleware::NoOpMiddleware,
-  state::{InMemoryState, NotKeyed},
-};
-use std::collections::HashMap;
+use chrono::Utc;
+use futures::stream::{self, Stream};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
-use tracing::instrument;
+use tracing::{debug, instrument};
+
+/// Parameters for the equity time series endpoints that take more than a
+/// symbol: [`TimeSeriesEndpoints::intraday_with`],
+/// [`daily_with`](TimeSeriesEndpoints::daily_with) and
+/// [`daily_adjusted_with`](TimeSeriesEndpoints::daily_adjusted_with).
+///
+/// Options left unset use the API's defaults (latest 100 points; intraday
+/// bars adjusted and including extended hours).
+///
+/// | Option           | Intraday   | Daily / daily adjusted |
+/// |------------------|------------|------------------------|
+/// | `interval`       | required   | rejected               |
+/// | `month`          | optional   | rejected               |
+/// | `adjusted`       | optional   | rejected               |
+/// | `extended_hours` | optional   | rejected               |
+/// | `output_size`    | optional   | optional               |
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = TimeSeriesRequest::new("IBM")
+///     .interval(Interval::Min5)
+///     .month(YearMonth::new(2009, 1).unwrap())
+///     .output_size(OutputSize::Full)
+///     .extended_hours(false);
+/// let bars = endpoints.intraday_with(&request).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeSeriesRequest {
+  pub symbol: String,
+  pub interval: Option<Interval>,
+  pub month: Option<YearMonth>,
+  pub output_size: Option<OutputSize>,
+  pub adjusted: Option<bool>,
+  pub extended_hours: Option<bool>,
+}
+
+impl TimeSeriesRequest {
+  pub fn new(symbol: impl Into<String>) -> Self {
+    Self {
+      symbol: symbol.into(),
+      interval: None,
+      month: None,
+      output_size: None,
+      adjusted: None,
+      extended_hours: None,
+    }
+  }
+
+  pub fn interval(mut self, interval: Interval) -> Self {
+    self.interval = Some(interval);
+    self
+  }
+
+  /// Request one calendar month of intraday history instead of the most
+  /// recent bars
+  pub fn month(mut self, month: YearMonth) -> Self {
+    self.month = Some(month);
+    self
+  }
+
+  pub fn output_size(mut self, output_size: OutputSize) -> Self {
+    self.output_size = Some(output_size);
+    self
+  }
+
+  /// Whether intraday bars are adjusted for splits and dividends
+  pub fn adjusted(mut self, adjusted: bool) -> Self {
+    self.adjusted = Some(adjusted);
+    self
+  }
+
+  /// Whether intraday bars include pre- and post-market trading
+  pub fn extended_hours(mut self, extended_hours: bool) -> Self {
+    self.extended_hours = Some(extended_hours);
+    self
+  }
+
+  fn validate(&self, function: FuncType) -> Result<()> {
+    if self.symbol.trim().is_empty() {
+      return Err(Error::Config(format!("{} request needs a symbol", function)));
+    }
+
+    if function == FuncType::TimeSeriesIntraday {
+      if self.interval.is_none() {
+        return Err(Error::Config(format!("{} request needs an interval", function)));
+      }
+      if let Some(month) = self.month {
+        let current = YearMonth::of(Utc::now().date_naive());
+        if month < YearMonth::FIRST_INTRADAY || month > current {
+          return Err(Error::Config(format!(
+            "Intraday month {} is outside {}..={}",
+            month,
+            YearMonth::FIRST_INTRADAY,
+            current
+          )));
+        }
+      }
+      return Ok(());
+    }
+
+    let intraday_only = [
+      ("interval", self.interval.is_some()),
+      ("month", self.month.is_some()),
+      ("adjusted", self.adjusted.is_some()),
+      ("extended_hours", self.extended_hours.is_some()),
+    ];
+    if let Some((name, _)) = intraday_only.iter().find(|(_, set)| *set) {
+      return Err(Error::Config(format!(
+        "`{}` only applies to {}, not {}",
+        name,
+        FuncType::TimeSeriesIntraday,
+        function
+      )));
+    }
+    Ok(())
+  }
+
+  fn to_params(&self) -> Vec<(String, String)> {
+    let mut params = vec![("symbol".to_string(), self.symbol.trim().to_string())];
+    let mut push = |key: &str, value: Option<String>| {
+      if let Some(value) = value {
+        params.push((key.to_string(), value));
+      }
+    };
+    push("interval", self.interval.map(|i| i.to_string()));
+    push("month", self.month.map(|m| m.to_string()));
+    push("outputsize", self.output_size.map(|o| o.to_string()));
+    push("adjusted", self.adjusted.map(|a| a.to_string()));
+    push("extended_hours", self.extended_hours.map(|e| e.to_string()));
+    params
+  }
+}
+
+/// A month-by-month walk over a symbol's intraday history, consumed with
+/// [`TimeSeriesEndpoints::intraday_history`].
+///
+/// Each month is one full-size `TIME_SERIES_INTRADAY` call with the
+/// `month` parameter, from `start` through `end` (the current month when
+/// unset). Months in `skip` — typically ones already cached or stored —
+/// are not requested. To resume an interrupted walk, persist
+/// [`IntradayMonth::checkpoint`] and pass it to
+/// [`resume_from`](Self::resume_from).
+///
+/// # Examples
+///
+/// ```ignore
+/// let history = IntradayHistory::new("IBM", Interval::Min1, YearMonth::new(2006, 1).unwrap())
+///     .skip_months(already_loaded)
+///     .resume_from(saved_checkpoint);
+/// let mut months = std::pin::pin!(client.time_series().intraday_history(history));
+/// while let Some(month) = months.next().await {
+///     let month = month?;
+///     store(&month.series).await?;
+///     save_checkpoint(month.checkpoint()).await?;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntradayHistory {
+  pub symbol: String,
+  pub interval: Interval,
+  pub start: YearMonth,
+  pub end: Option<YearMonth>,
+  pub adjusted: Option<bool>,
+  pub extended_hours: Option<bool>,
+  pub skip: BTreeSet<YearMonth>,
+}
+
+impl IntradayHistory {
+  pub fn new(symbol: impl Into<String>, interval: Interval, start: YearMonth) -> Self {
+    Self {
+      symbol: symbol.into(),
+      interval,
+      start,
+      end: None,
+      adjusted: None,
+      extended_hours: None,
+      skip: BTreeSet::new(),
+    }
+  }
+
+  /// Stop after `end` instead of the current month
+  pub fn until(mut self, end: YearMonth) -> Self {
+    self.end = Some(end);
+    self
+  }
+
+  pub fn adjusted(mut self, adjusted: bool) -> Self {
+    self.adjusted = Some(adjusted);
+    self
+  }
+
+  pub fn extended_hours(mut self, extended_hours: bool) -> Self {
+    self.extended_hours = Some(extended_hours);
+    self
+  }
+
+  /// Continue from a checkpoint returned by [`IntradayMonth::checkpoint`];
+  /// months before it are not requested again. After the current month's
+  /// checkpoint nothing is pending and the walk yields no months.
+  pub fn resume_from(mut self, checkpoint: YearMonth) -> Self {
+    self.start = self.start.max(checkpoint);
+    self
+  }
+
+  /// Leave out months that are already available locally
+  pub fn skip_months(mut self, months: impl IntoIterator<Item = YearMonth>) -> Self {
+    self.skip.extend(months);
+    self
+  }
+
+  /// Months that will be requested, oldest first
+  pub fn pending_months(&self) -> Vec<YearMonth> {
+    let end = self.end.unwrap_or_else(current_month);
+    let mut months = Vec::new();
+    let mut month = self.start;
+    while month <= end {
+      if !self.skip.contains(&month) {
+        months.push(month);
+      }
+      month = month.next();
+    }
+    months
+  }
+
+  fn validate(&self) -> Result<()> {
+    let current = current_month();
+    if self.start < YearMonth::FIRST_INTRADAY {
+      return Err(Error::Config(format!(
+        "Intraday history starts at {}, not {}",
+        YearMonth::FIRST_INTRADAY,
+        self.start
+      )));
+    }
+    if let Some(end) = self.end {
+      if end > current {
+        return Err(Error::Config(format!("Intraday history end {} is in the future", end)));
+      }
+    }
+    // A checkpoint from the current month resumes past it, leaving nothing
+    // to request rather than a future month to reject
+    match self.pending_months().first() {
+      Some(&month) => self.month_request(month).validate(FuncType::TimeSeriesIntraday),
+      None => Ok(()),
+    }
+  }
+
+  fn month_request(&self, month: YearMonth) -> TimeSeriesRequest {
+    TimeSeriesRequest {
+      symbol: self.symbol.clone(),
+      interval: Some(self.interval),
+      month: Some(month),
+      output_size: Some(OutputSize::Full),
+      adjusted: self.adjusted,
+      extended_hours: self.extended_hours,
+    }
+  }
+}
+
+/// One month of bars yielded by [`TimeSeriesEndpoints::intraday_history`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntradayMonth {
+  pub month: YearMonth,
+  pub series: IntradayTimeSeries,
+}
+
+impl IntradayMonth {
+  /// Where a walk interrupted after this month should resume
+  pub fn checkpoint(&self) -> YearMonth {
+    self.month.next()
+  }
+}
+
+fn current_month() -> YearMonth {
+  YearMonth::of(Utc::now().date_naive())
+}
 
 /// Time series endpoints for historical and intraday price data
 pub struct TimeSeriesEndpoints {
   transport: Arc<Transport>,
-  rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
 impl TimeSeriesEndpoints {
+  /// Maximum number of symbols `REALTIME_BULK_QUOTES` accepts per call.
+  pub const MAX_BULK_QUOTE_SYMBOLS: usize = 100;
+
   /// Create a new time series endpoints instance
-  pub fn new(
-    transport: Arc<Transport>,
-    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
-  ) -> Self {
-    Self { transport, rate_limiter }
+  pub fn new(transport: Arc<Transport>) -> Self {
+    Self { transport }
   }
 
   /// Get intraday time series data
@@ -63,16 +335,17 @@ impl TimeSeriesEndpoints {
   ///
   /// * `symbol` - The stock symbol (e.g., "AAPL", "MSFT")
   /// * `interval` - Time interval: "1min", "5min", "15min", "30min", "60min"
-  /// * `month` - Optional month filter in YYYY-MM format for historical data
-  /// * `adjusted` - Whether to include split/dividend adjustments
-  /// * `extended_hours` - Whether to include extended trading hours
+  ///
+  /// Bars are unadjusted and exclude extended hours. Use
+  /// [`intraday_with`](Self::intraday_with) for the other options,
+  /// including a historical `month`.
   ///
   /// # Examples
   ///
   /// ```ignore
   /// # use av_client::TimeSeriesEndpoints;
   /// # use std::sync::Arc;
-  /// # let endpoints = TimeSeriesEndpoints::new(Arc::new(transport), Arc::new(rate_limiter));
+  /// # let endpoints = TimeSeriesEndpoints::new(Arc::new(transport));
   /// // Get 5-minute intraday data for Apple
   /// let data = endpoints.intraday("AAPL", "5min").await?;
   ///
@@ -94,15 +367,63 @@ impl TimeSeriesEndpoints {
     adjusted: bool,
     extended_hours: bool,
   ) -> Result<IntradayTimeSeries> {
-    self.wait_for_rate_limit().await?;
+    let request = TimeSeriesRequest::new(symbol)
+      .interval(parse_arg(interval)?)
+      .adjusted(adjusted)
+      .extended_hours(extended_hours);
+    self.intraday_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("symbol".to_string(), symbol.to_string());
-    params.insert("interval".to_string(), interval.to_string());
-    params.insert("adjusted".to_string(), adjusted.to_string());
-    params.insert("extended_hours".to_string(), extended_hours.to_string());
+  /// Get intraday time series data for a [`TimeSeriesRequest`] with an
+  /// interval set
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if the request has
+  /// no symbol or interval, or a month outside the available history.
+  #[instrument(skip(self), fields(symbol = %request.symbol))]
+  pub async fn intraday_with(&self, request: &TimeSeriesRequest) -> Result<IntradayTimeSeries> {
+    request.validate(FuncType::TimeSeriesIntraday)?;
 
-    self.transport.get(FuncType::TimeSeriesIntraday, params).await
+    self.transport.get_pairs(FuncType::TimeSeriesIntraday, request.to_params()).await
+  }
+
+  /// Walk a symbol's intraday history one month at a time
+  ///
+  /// The stream yields each month of [`IntradayHistory::pending_months`] in
+  /// order, waiting on the rate limiter before every request that is not
+  /// answered from the response cache, so a twenty-year backfill is a single
+  /// call. It ends after the first error;
+  /// resume from the last successful month's
+  /// [`checkpoint`](IntradayMonth::checkpoint).
+  ///
+  /// # Errors
+  ///
+  /// Yields [`Error::Config`] without calling the API if the history starts
+  /// before [`YearMonth::FIRST_INTRADAY`], ends in the future or has no
+  /// symbol.
+  pub fn intraday_history(
+    &self,
+    history: IntradayHistory,
+  ) -> impl Stream<Item = Result<IntradayMonth>> + '_ {
+    let pending = history.validate().map(|()| history.pending_months().into_iter());
+
+    stream::unfold(Some(pending), move |state| {
+      let history = history.clone();
+      async move {
+        let mut months = match state? {
+          Ok(months) => months,
+          Err(e) => return Some((Err(e), None)),
+        };
+        let month = months.next()?;
+
+        debug!("Fetching {} intraday history for {}", history.symbol, month);
+        match self.intraday_with(&history.month_request(month)).await {
+          Ok(series) => Some((Ok(IntradayMonth { month, series }), Some(Ok(months)))),
+          Err(e) => Some((Err(e), None)),
+        }
+      }
+    })
   }
 
   /// Get daily time series data
@@ -113,13 +434,21 @@ impl TimeSeriesEndpoints {
   /// * `outputsize` - "compact" for latest 100 data points, "full" for 20+ years
   #[instrument(skip(self), fields(symbol, outputsize))]
   pub async fn daily(&self, symbol: &str, outputsize: &str) -> Result<DailyTimeSeries> {
-    self.wait_for_rate_limit().await?;
+    let request = TimeSeriesRequest::new(symbol).output_size(parse_arg(outputsize)?);
+    self.daily_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("symbol".to_string(), symbol.to_string());
-    params.insert("outputsize".to_string(), outputsize.to_string());
+  /// Get daily time series data for a [`TimeSeriesRequest`]
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] without calling the API if the request has
+  /// no symbol or sets an intraday-only option.
+  #[instrument(skip(self), fields(symbol = %request.symbol))]
+  pub async fn daily_with(&self, request: &TimeSeriesRequest) -> Result<DailyTimeSeries> {
+    request.validate(FuncType::TimeSeriesDaily)?;
 
-    self.transport.get(FuncType::TimeSeriesDaily, params).await
+    self.transport.get_pairs(FuncType::TimeSeriesDaily, request.to_params()).await
   }
 
   /// Get daily adjusted time series data (includes splits and dividends)
@@ -129,20 +458,26 @@ impl TimeSeriesEndpoints {
     symbol: &str,
     outputsize: &str,
   ) -> Result<DailyAdjustedTimeSeries> {
-    self.wait_for_rate_limit().await?;
+    let request = TimeSeriesRequest::new(symbol).output_size(parse_arg(outputsize)?);
+    self.daily_adjusted_with(&request).await
+  }
 
-    let mut params = HashMap::new();
-    params.insert("symbol".to_string(), symbol.to_string());
-    params.insert("outputsize".to_string(), outputsize.to_string());
+  /// Get daily adjusted time series data for a [`TimeSeriesRequest`]
+  ///
+  /// Validated like [`daily_with`](Self::daily_with).
+  #[instrument(skip(self), fields(symbol = %request.symbol))]
+  pub async fn daily_adjusted_with(
+    &self,
+    request: &TimeSeriesRequest,
+  ) -> Result<DailyAdjustedTimeSeries> {
+    request.validate(FuncType::TimeSeriesDailyAdjusted)?;
 
-    self.transport.get(FuncType::TimeSeriesDailyAdjusted, params).await
+    self.transport.get_pairs(FuncType::TimeSeriesDailyAdjusted, request.to_params()).await
   }
 
   /// Get weekly time series data
   #[instrument(skip(self), fields(symbol))]
   pub async fn weekly(&self, symbol: &str) -> Result<WeeklyTimeSeries> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -152,8 +487,6 @@ impl TimeSeriesEndpoints {
   /// Get weekly adjusted time series data
   #[instrument(skip(self), fields(symbol))]
   pub async fn weekly_adjusted(&self, symbol: &str) -> Result<WeeklyAdjustedTimeSeries> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -163,8 +496,6 @@ impl TimeSeriesEndpoints {
   /// Get monthly time series data
   #[instrument(skip(self), fields(symbol))]
   pub async fn monthly(&self, symbol: &str) -> Result<MonthlyTimeSeries> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
@@ -174,19 +505,92 @@ impl TimeSeriesEndpoints {
   /// Get monthly adjusted time series data
   #[instrument(skip(self), fields(symbol))]
   pub async fn monthly_adjusted(&self, symbol: &str) -> Result<MonthlyAdjustedTimeSeries> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("symbol".to_string(), symbol.to_string());
 
     self.transport.get(FuncType::TimeSeriesMonthlyAdjusted, params).await
   }
 
+  /// Get the latest price, volume, and change for a symbol
+  ///
+  /// # Arguments
+  ///
+  /// * `symbol` - The stock symbol
+  #[instrument(skip(self), fields(symbol))]
+  pub async fn quote(&self, symbol: &str) -> Result<GlobalQuote> {
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), symbol.to_string());
+
+    self.transport.get(FuncType::GlobalQuote, params).await
+  }
+
+  /// Get quotes for any number of symbols, keyed by symbol
+  ///
+  /// Symbols are upper-cased and de-duplicated, then fetched
+  /// [`MAX_BULK_QUOTE_SYMBOLS`](Self::MAX_BULK_QUOTE_SYMBOLS) at a time via
+  /// `REALTIME_BULK_QUOTES` (a premium endpoint), so 250 symbols cost three
+  /// API calls. Symbols the API does not recognize are absent from the map.
+  ///
+  /// # Examples
+  ///
+  /// ```ignore
+  /// # use av_client::TimeSeriesEndpoints;
+  /// # let endpoints = TimeSeriesEndpoints::new(Arc::new(transport));
+  /// let quotes = endpoints.bulk_quotes(&["AAPL", "MSFT", "NVDA"]).await?;
+  /// if let Some(quote) = quotes.get("MSFT") {
+  ///     println!("MSFT: {}", quote.global_quote.price);
+  /// }
+  /// # Ok::<(), av_core::Error>(())
+  /// ```
+  #[instrument(skip(self, symbols), fields(count = symbols.len()))]
+  pub async fn bulk_quotes<S: AsRef<str>>(
+    &self,
+    symbols: &[S],
+  ) -> Result<HashMap<String, GlobalQuote>> {
+    let mut unique: Vec<String> = Vec::with_capacity(symbols.len());
+    for symbol in symbols {
+      let symbol = symbol.as_ref().trim().to_uppercase();
+      if !symbol.is_empty() && !unique.contains(&symbol) {
+        unique.push(symbol);
+      }
+    }
+
+    let mut quotes = HashMap::with_capacity(unique.len());
+    for chunk in unique.chunks(Self::MAX_BULK_QUOTE_SYMBOLS) {
+      let page = self.bulk_quotes_page(chunk).await?;
+      for entry in page.data {
+        quotes.insert(entry.symbol.to_uppercase(), GlobalQuote::from(entry));
+      }
+    }
+
+    Ok(quotes)
+  }
+
+  /// Get the raw `REALTIME_BULK_QUOTES` response for a single call
+  ///
+  /// # Arguments
+  ///
+  /// * `symbols` - Between 1 and [`MAX_BULK_QUOTE_SYMBOLS`](Self::MAX_BULK_QUOTE_SYMBOLS) symbols
+  #[instrument(skip(self, symbols), fields(count = symbols.len()))]
+  pub async fn bulk_quotes_page<S: AsRef<str>>(&self, symbols: &[S]) -> Result<BulkQuotes> {
+    if symbols.is_empty() || symbols.len() > Self::MAX_BULK_QUOTE_SYMBOLS {
+      return Err(Error::Config(format!(
+        "REALTIME_BULK_QUOTES accepts 1 to {} symbols, got {}",
+        Self::MAX_BULK_QUOTE_SYMBOLS,
+        symbols.len()
+      )));
+    }
+
+    let joined = symbols.iter().map(|s| s.as_ref()).collect::<Vec<_>>().join(",");
+    let mut params = HashMap::new();
+    params.insert("symbol".to_string(), joined);
+
+    self.transport.get(FuncType::RealtimeBulkQuotes, params).await
+  }
+
   /// Get market status (open/closed) for major trading venues
   #[instrument(skip(self))]
   pub async fn market_status(&self) -> Result<MarketStatus> {
-    self.wait_for_rate_limit().await?;
-
     let params = HashMap::new();
 
     self.transport.get(FuncType::MarketStatus, params).await
@@ -199,8 +603,6 @@ impl TimeSeriesEndpoints {
   /// * `keywords` - Search keywords for company name or ticker symbol
   #[instrument(skip(self), fields(keywords))]
   pub async fn symbol_search(&self, keywords: &str) -> Result<SymbolSearch> {
-    self.wait_for_rate_limit().await?;
-
     let mut params = HashMap::new();
     params.insert("keywords".to_string(), keywords.to_string());
 
@@ -209,3 +611,159 @@ impl TimeSeriesEndpoints {
 }
 
 impl_endpoint_base!(TimeSeriesEndpoints);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_intraday_request_params() {
+    let request = TimeSeriesRequest::new("IBM")
+      .interval(Interval::Min5)
+      .month(YearMonth::new(2009, 1).unwrap())
+      .output_size(OutputSize::Full)
+      .extended_hours(false);
+
+    assert!(request.validate(FuncType::TimeSeriesIntraday).is_ok());
+    assert_eq!(
+      request.to_params(),
+      vec![
+        ("symbol".to_string(), "IBM".to_string()),
+        ("interval".to_string(), "5min".to_string()),
+        ("month".to_string(), "2009-01".to_string()),
+        ("outputsize".to_string(), "full".to_string()),
+        ("extended_hours".to_string(), "false".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_request_combinations() {
+    let intraday = TimeSeriesRequest::new("IBM").interval(Interval::Min1);
+    assert!(intraday.validate(FuncType::TimeSeriesIntraday).is_ok());
+    assert!(intraday.validate(FuncType::TimeSeriesDaily).is_err());
+    assert!(TimeSeriesRequest::new("IBM").validate(FuncType::TimeSeriesIntraday).is_err());
+
+    let monthly_daily = TimeSeriesRequest::new("IBM").month(YearMonth::new(2020, 5).unwrap());
+    assert!(monthly_daily.validate(FuncType::TimeSeriesDailyAdjusted).is_err());
+    let flags_daily = TimeSeriesRequest::new("IBM").adjusted(true);
+    assert!(flags_daily.validate(FuncType::TimeSeriesDaily).is_err());
+    let full_daily = TimeSeriesRequest::new("IBM").output_size(OutputSize::Full);
+    assert!(full_daily.validate(FuncType::TimeSeriesDaily).is_ok());
+
+    let too_early = intraday.clone().month(YearMonth::new(1999, 12).unwrap());
+    assert!(too_early.validate(FuncType::TimeSeriesIntraday).is_err());
+    assert!(TimeSeriesRequest::new(" ").validate(FuncType::TimeSeriesDaily).is_err());
+  }
+
+  fn month_body(month: &str) -> String {
+    format!(
+      r#"{{"Meta Data": {{"1. Information": "Intraday (5min)", "2. Symbol": "IBM",
+        "3. Last Refreshed": "{month}-28 19:55:00", "4. Interval": "5min",
+        "5. Output Size": "Full size", "6. Time Zone": "US/Eastern"}},
+        "Time Series (5min)": {{"{month}-28 19:55:00": {{"1. open": "1.0", "2. high": "1.0",
+        "3. low": "1.0", "4. close": "1.0", "5. volume": "10"}}}}}}"#
+    )
+  }
+
+  #[tokio::test]
+  async fn test_intraday_history_walks_months() {
+    use crate::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+    use futures::StreamExt;
+
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response_for(
+          FuncType::TimeSeriesIntraday,
+          &[("month", "2024-01")],
+          month_body("2024-01"),
+        )
+        .with_response_for(
+          FuncType::TimeSeriesIntraday,
+          &[("month", "2024-03")],
+          month_body("2024-03"),
+        ),
+    );
+    let config = Config { max_retries: 0, ..Config::default_with_key("demo".into()) };
+    let client = AlphaVantageClient::with_transport(config, fixture.clone()).unwrap();
+
+    let month = |m| YearMonth::new(2024, m).unwrap();
+    let history =
+      IntradayHistory::new("IBM", Interval::Min5, month(1)).until(month(4)).skip_months([month(2)]);
+    assert_eq!(history.pending_months(), vec![month(1), month(3), month(4)]);
+
+    let results: Vec<_> = client.time_series().intraday_history(history.clone()).collect().await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().checkpoint(), month(2));
+    assert_eq!(results[1].as_ref().unwrap().series.time_series.len(), 1);
+    // No fixture for April, so the walk stops there
+    assert!(results[2].is_err());
+
+    let requests = fixture.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].params["month"], "2024-03");
+    assert_eq!(requests[1].params["outputsize"], "full");
+
+    let resumed = history.resume_from(month(4));
+    assert_eq!(resumed.pending_months(), vec![month(4)]);
+
+    // Resuming after the current month's checkpoint leaves nothing to fetch
+    let up_to_date =
+      IntradayHistory::new("IBM", Interval::Min5, month(1)).resume_from(current_month().next());
+    assert!(up_to_date.pending_months().is_empty());
+    let results: Vec<_> = client.time_series().intraday_history(up_to_date).collect().await;
+    assert!(results.is_empty());
+
+    let too_early = IntradayHistory::new("IBM", Interval::Min5, YearMonth::new(1999, 6).unwrap());
+    let results: Vec<_> = client.time_series().intraday_history(too_early).collect().await;
+    assert!(matches!(results.as_slice(), [Err(Error::Config(_))]));
+    assert_eq!(fixture.requests().len(), 3);
+  }
+
+  #[tokio::test]
+  async fn test_cached_rewalk_skips_rate_limiter() {
+    use crate::transport::{MemoryCache, MetricsHook, Transport};
+    use crate::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+    use futures::StreamExt;
+    use governor::{Quota, RateLimiter};
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response_for(
+          FuncType::TimeSeriesIntraday,
+          &[("month", "2024-01")],
+          month_body("2024-01"),
+        )
+        .with_response_for(
+          FuncType::TimeSeriesIntraday,
+          &[("month", "2024-02")],
+          month_body("2024-02"),
+        ),
+    );
+    let config = Config { max_retries: 0, ..Config::default_with_key("demo".into()) };
+    let metrics = Arc::new(MetricsHook::new());
+    // Enough tokens for one walk; a second walk that spent any would stall
+    let limiter = Arc::new(RateLimiter::direct(Quota::per_minute(NonZeroU32::new(2).unwrap())));
+    let transport = Transport::with_backend(config, fixture.clone())
+      .with_cache(Arc::new(MemoryCache::new(16)))
+      .with_hook(metrics.clone())
+      .with_rate_limiter(limiter);
+    let client = AlphaVantageClient::from_transport(transport);
+
+    let month = |m| YearMonth::new(2024, m).unwrap();
+    let history = IntradayHistory::new("IBM", Interval::Min5, month(1)).until(month(2));
+    let time_series = client.time_series();
+    for _ in 0..3 {
+      let walk = time_series.intraday_history(history.clone()).collect::<Vec<_>>();
+      let results = tokio::time::timeout(Duration::from_secs(5), walk).await.unwrap();
+      assert!(results.iter().all(Result::is_ok));
+    }
+
+    assert_eq!(fixture.requests().len(), 2);
+    assert_eq!(metrics.function(FuncType::TimeSeriesIntraday).requests, 2);
+  }
+}
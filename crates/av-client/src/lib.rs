@@ -32,14 +32,33 @@
 //! Async HTTP client for the AlphaVantage financial data API.
 //!
 //! This crate provides a rate-limited, async client for accessing AlphaVantage endpoints
-//! including time series, fundamentals, forex, cryptocurrency, and news sentiment data.
+//! including time series, fundamentals, technical indicators, US economic indicators,
+//! commodities, options, returns analytics, forex, cryptocurrency, and news sentiment data.
 //!
 //! ## Features
 //!
 //! - **Async/Await**: Built on tokio and reqwest
 //! - **Rate Limiting**: Automatic rate limiting (75/min free, 600/min premium)
-//! - **Type Safety**: Strongly-typed responses via `av-models`
+//! - **Type Safety**: Strongly-typed responses via `av-models`, and typed
+//!   request builders such as [`TimeSeriesRequest`] that reject invalid
+//!   parameter combinations before a call is spent
 //! - **Organized Endpoints**: Modular access to API domains
+//! - **Pluggable Transport**: Swap the HTTP layer via [`HttpTransport`]; the
+//!   `test-utils` feature adds an in-memory `FixtureTransport`
+//! - **Record/Replay**: [`CassetteTransport`] records responses to disk and
+//!   replays them offline (`AV_CASSETTE_MODE=record|replay|auto`)
+//! - **History Walks**: [`TimeSeriesEndpoints::intraday_history`] streams
+//!   intraday bars month by month and resumes from a checkpoint, and
+//!   [`NewsEndpoints::news_sentiment_all`] pages past the 1000-article cap
+//! - **Blocking API**: the `blocking` feature adds a synchronous
+//!   [`blocking::AlphaVantageClient`](crate::blocking) for non-async callers
+//! - **Response Cache**: [`ResponseCache`] with in-memory LRU and on-disk
+//!   backends and per-function TTLs, off unless enabled (`AV_CACHE_DIR=<dir>`)
+//! - **Request Hooks**: [`RequestHook`] observes every attempt; built-in
+//!   [`MetricsHook`] histograms and a [`JsonlAuditHook`] log
+//!   (`AV_AUDIT_LOG=<path>`)
+//! - **Quota Ledger**: [`QuotaLedger`] counts calls per key across runs so
+//!   daily limits hold between processes (`AV_QUOTA_FILE=<path>`)
 //!
 //! ## Example
 //!
@@ -55,6 +74,8 @@
 
 #![warn(clippy::all)]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
 pub mod endpoints;
 pub mod transport;
@@ -64,10 +85,31 @@ pub use av_core::{Config, Error, Result};
 pub use av_models::*;
 pub use client::AlphaVantageClient;
 
+// Re-export the transport extension point
+pub use transport::{
+  CachePolicy, CacheTtl, CallInfo, CallOutcome, CassetteMode, CassetteTransport, DiskCache,
+  FileQuotaLedger, FunctionMetrics, HttpTransport, JsonlAuditHook, KeyPool, KeyStats, MemoryCache,
+  MetricsHook, QuotaLedger, QuotaUsage, RawResponse, RequestHook, ReqwestTransport, ResponseCache,
+  RetryPolicy, Transport, response_error,
+};
+#[cfg(any(test, feature = "test-utils"))]
+pub use transport::{FixtureTransport, RecordedRequest};
+
 // Re-export endpoint modules for direct access if needed
 pub use endpoints::{
-  crypto::CryptoEndpoints, crypto_social::CryptoSocialEndpoints, forex::ForexEndpoints,
-  fundamentals::FundamentalsEndpoints, news::NewsEndpoints, time_series::TimeSeriesEndpoints,
+  analytics::{
+    AnalyticsEndpoints, AnalyticsRange, AnalyticsRequest, Calculation, CorrelationMethod,
+  },
+  commodities::{COMMODITY_FUNCTIONS, CommodityEndpoints},
+  crypto::{CryptoEndpoints, CryptoRequest},
+  crypto_social::CryptoSocialEndpoints,
+  economics::EconomicsEndpoints,
+  forex::{ForexEndpoints, FxRequest},
+  fundamentals::{EarningsCalendarRequest, FundamentalsEndpoints, ListingStatusRequest},
+  indicators::{BbandsParams, IndicatorEndpoints, MacdParams, StochParams},
+  news::{NewsEndpoints, NewsRequest},
+  options::OptionsEndpoints,
+  time_series::{IntradayHistory, IntradayMonth, TimeSeriesEndpoints, TimeSeriesRequest},
 };
 
 #[cfg(test)]
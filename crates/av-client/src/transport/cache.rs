@@ -0,0 +1,456 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Response caching in front of the API.
+//!
+//! With a [`ResponseCache`] attached, [`Transport`] looks each request up
+//! before leasing a key and stores successful response bodies afterwards.
+//! Entries are keyed by function and normalized query without the API key,
+//! so every key in the pool shares them. How long an entry lives is decided
+//! per request by a [`CachePolicy`]; see [`default_ttl`] for the defaults.
+//!
+//! | Cache           | Storage                                            |
+//! |-----------------|----------------------------------------------------|
+//! | [`MemoryCache`] | In-process LRU with a fixed number of entries      |
+//! | [`DiskCache`]   | One JSON file per entry, enabled by [`Config::cache_dir`] |
+//! | Custom          | Anything implementing [`ResponseCache`], e.g. a database table |
+//!
+//! Hits skip the key pool, quota ledger and retries, but endpoint methods
+//! still wait on the client's rate limiter before calling the transport.
+//!
+//! [`Transport`]: super::Transport
+//! [`Config::cache_dir`]: av_core::Config::cache_dir
+
+use super::cassette::{cassette_name, normalize_params, normalized_query};
+use async_trait::async_trait;
+use av_core::types::common::YearMonth;
+use av_core::{Error, FuncType, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const MINUTE: Duration = Duration::from_secs(60);
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long a response may be served from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheTtl {
+  /// Always ask the API
+  NoCache,
+  /// Serve from the cache until the entry is this old
+  For(Duration),
+  /// The data can no longer change, e.g. a finished month of intraday bars
+  Immutable,
+}
+
+impl CacheTtl {
+  /// The lifetime to store an entry with; `None` means it never expires.
+  pub fn expiry(&self) -> Option<Duration> {
+    match self {
+      CacheTtl::For(ttl) => Some(*ttl),
+      CacheTtl::NoCache | CacheTtl::Immutable => None,
+    }
+  }
+}
+
+/// The built-in lifetime of a `function` response requested with `params`.
+///
+/// | Data                                             | TTL        |
+/// |--------------------------------------------------|------------|
+/// | Quotes, exchange rates, market status, live options | 1 minute |
+/// | Current intraday bars                            | 5 minutes  |
+/// | News, top movers                                 | 15 minutes |
+/// | Daily/weekly/monthly series, indicators, analytics | 1 hour   |
+/// | Economic and commodity series, calendars, listings, insiders | 1 day |
+/// | Statements, earnings, dividends, splits, ETF profiles, symbol search | 7 days |
+/// | `OVERVIEW`, earnings call transcripts            | 30 days    |
+/// | Intraday bars for a finished `month`, options for a past `date` | immutable |
+pub fn default_ttl(function: FuncType, params: &[(String, String)]) -> CacheTtl {
+  let param = |name: &str| params.iter().find(|(k, _)| k == name).map(|(_, v)| v.trim());
+
+  match function {
+    FuncType::TimeSeriesIntraday | FuncType::TsIntra => {
+      let finished = param("month")
+        .and_then(|m| m.parse::<YearMonth>().ok())
+        .is_some_and(|m| m < YearMonth::of(Utc::now().date_naive()));
+      if finished { CacheTtl::Immutable } else { CacheTtl::For(5 * MINUTE) }
+    }
+    FuncType::HistoricalOptions => {
+      let past = param("date")
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .is_some_and(|d| d < Utc::now().date_naive());
+      if past { CacheTtl::Immutable } else { CacheTtl::For(MINUTE) }
+    }
+    FuncType::GlobalQuote
+    | FuncType::RealtimeBulkQuotes
+    | FuncType::CurrencyExchangeRate
+    | FuncType::CryptoExchangeRate
+    | FuncType::MarketStatus
+    | FuncType::RealtimeOptions => CacheTtl::For(MINUTE),
+    FuncType::FxIntraday | FuncType::CryptoIntraday | FuncType::CryptoIntraDay => {
+      CacheTtl::For(5 * MINUTE)
+    }
+    FuncType::NewsSentiment
+    | FuncType::NewsQuery
+    | FuncType::TopGainersLosers
+    | FuncType::TopQuery => CacheTtl::For(15 * MINUTE),
+    FuncType::RealGdp
+    | FuncType::Cpi
+    | FuncType::Inflation
+    | FuncType::TreasuryYield
+    | FuncType::FederalFundsRate
+    | FuncType::Unemployment
+    | FuncType::NonfarmPayroll
+    | FuncType::Wti
+    | FuncType::Brent
+    | FuncType::NaturalGas
+    | FuncType::Copper
+    | FuncType::Aluminum
+    | FuncType::Wheat
+    | FuncType::Corn
+    | FuncType::Cotton
+    | FuncType::Sugar
+    | FuncType::Coffee
+    | FuncType::AllCommodities
+    | FuncType::ListingStatus
+    | FuncType::EarningsCalendar
+    | FuncType::IpoCalendar
+    | FuncType::InsiderTransactions => CacheTtl::For(DAY),
+    FuncType::IncomeStatement
+    | FuncType::BalanceSheet
+    | FuncType::CashFlow
+    | FuncType::Earnings
+    | FuncType::Dividends
+    | FuncType::Splits
+    | FuncType::EtfProfile
+    | FuncType::SymbolSearch
+    | FuncType::SymSearch => CacheTtl::For(7 * DAY),
+    FuncType::Overview | FuncType::EarningsCallTranscript => CacheTtl::For(30 * DAY),
+    _ => CacheTtl::For(HOUR),
+  }
+}
+
+/// Per-function cache lifetimes: [`default_ttl`] unless overridden.
+#[derive(Debug, Clone, Default)]
+pub struct CachePolicy {
+  overrides: HashMap<FuncType, CacheTtl>,
+}
+
+impl CachePolicy {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Use `ttl` for every `function` request instead of the default
+  pub fn with_ttl(mut self, function: FuncType, ttl: CacheTtl) -> Self {
+    self.overrides.insert(function, ttl);
+    self
+  }
+
+  /// The lifetime of a `function` response requested with `params`
+  pub fn ttl(&self, function: FuncType, params: &[(String, String)]) -> CacheTtl {
+    self.overrides.get(&function).copied().unwrap_or_else(|| default_ttl(function, params))
+  }
+}
+
+/// The cache key of a request: function and normalized query, without the
+/// API key.
+pub fn cache_key(function: FuncType, params: &[(String, String)]) -> String {
+  format!("{}?{}", function, normalized_query(&normalize_params(params)))
+}
+
+/// Storage for raw response bodies.
+///
+/// Errors are logged by [`Transport`](super::Transport) and treated as a
+/// miss; they never fail the request.
+#[async_trait]
+pub trait ResponseCache: Send + Sync {
+  /// The body stored under `key`, unless missing or expired.
+  async fn get(&self, key: &str) -> Result<Option<String>>;
+
+  /// Store `body` under `key`, expiring after `ttl` (never if `None`).
+  async fn put(&self, key: &str, body: &str, ttl: Option<Duration>) -> Result<()>;
+}
+
+struct MemoryEntry {
+  body: String,
+  expires: Option<Instant>,
+  used: u64,
+}
+
+#[derive(Default)]
+struct Lru {
+  entries: HashMap<String, MemoryEntry>,
+  /// Last-use tick → key, oldest first
+  order: BTreeMap<u64, String>,
+  tick: u64,
+}
+
+impl Lru {
+  fn touch(&mut self, key: &str) {
+    self.tick += 1;
+    if let Some(entry) = self.entries.get_mut(key) {
+      self.order.remove(&entry.used);
+      entry.used = self.tick;
+      self.order.insert(self.tick, key.to_string());
+    }
+  }
+
+  fn remove(&mut self, key: &str) {
+    if let Some(entry) = self.entries.remove(key) {
+      self.order.remove(&entry.used);
+    }
+  }
+}
+
+/// [`ResponseCache`] holding up to `capacity` entries in memory, evicting
+/// the least recently used.
+pub struct MemoryCache {
+  capacity: usize,
+  lru: Mutex<Lru>,
+}
+
+impl MemoryCache {
+  pub fn new(capacity: usize) -> Self {
+    Self { capacity: capacity.max(1), lru: Mutex::new(Lru::default()) }
+  }
+
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Number of entries currently held, including expired ones not yet
+  /// looked up
+  pub fn len(&self) -> usize {
+    self.lock().entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+    self.lru.lock().unwrap_or_else(|e| e.into_inner())
+  }
+}
+
+impl std::fmt::Debug for MemoryCache {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("MemoryCache")
+      .field("capacity", &self.capacity)
+      .field("len", &self.len())
+      .finish()
+  }
+}
+
+#[async_trait]
+impl ResponseCache for MemoryCache {
+  async fn get(&self, key: &str) -> Result<Option<String>> {
+    let mut lru = self.lock();
+    let expired = match lru.entries.get(key) {
+      None => return Ok(None),
+      Some(entry) => entry.expires.is_some_and(|at| at <= Instant::now()),
+    };
+    if expired {
+      lru.remove(key);
+      return Ok(None);
+    }
+    lru.touch(key);
+    Ok(lru.entries.get(key).map(|e| e.body.clone()))
+  }
+
+  async fn put(&self, key: &str, body: &str, ttl: Option<Duration>) -> Result<()> {
+    let mut lru = self.lock();
+    lru.remove(key);
+    let expires = ttl.map(|ttl| Instant::now() + ttl);
+    lru.entries.insert(key.to_string(), MemoryEntry { body: body.to_string(), expires, used: 0 });
+    lru.touch(key);
+
+    while lru.entries.len() > self.capacity {
+      let Some((_, oldest)) = lru.order.pop_first() else { break };
+      lru.entries.remove(&oldest);
+    }
+    Ok(())
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+  key: String,
+  expires_at: Option<DateTime<Utc>>,
+  body: String,
+}
+
+/// [`ResponseCache`] storing one JSON file per entry under a directory.
+///
+/// File names are derived from the key the same way cassette names are.
+/// Expired files are deleted when next looked up. File access runs on the
+/// blocking pool.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+  dir: PathBuf,
+}
+
+impl DiskCache {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into() }
+  }
+
+  pub fn dir(&self) -> &Path {
+    &self.dir
+  }
+
+  /// Path of the file holding `key`
+  pub fn entry_path(&self, key: &str) -> PathBuf {
+    self.dir.join(format!("{}.json", cassette_name(key)))
+  }
+}
+
+#[async_trait]
+impl ResponseCache for DiskCache {
+  async fn get(&self, key: &str) -> Result<Option<String>> {
+    let (path, key) = (self.entry_path(key), key.to_string());
+    super::blocking_io(move || read_entry(&path, &key)).await
+  }
+
+  async fn put(&self, key: &str, body: &str, ttl: Option<Duration>) -> Result<()> {
+    let (dir, path) = (self.dir.clone(), self.entry_path(key));
+    let expires_at =
+      ttl.and_then(|ttl| chrono::Duration::from_std(ttl).ok()).map(|ttl| Utc::now() + ttl);
+    let entry = DiskEntry { key: key.to_string(), expires_at, body: body.to_string() };
+    super::blocking_io(move || write_entry(&dir, &path, &entry)).await
+  }
+}
+
+/// The body stored in the cache file at `path`, if it holds `key` and has
+/// not expired
+fn read_entry(path: &Path, key: &str) -> Result<Option<String>> {
+  let text = match std::fs::read_to_string(path) {
+    Ok(text) => text,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(e) => {
+      return Err(Error::Config(format!("Failed to read cache entry {}: {}", path.display(), e)));
+    }
+  };
+  let entry: DiskEntry = serde_json::from_str(&text)
+    .map_err(|e| Error::Config(format!("Invalid cache entry {}: {}", path.display(), e)))?;
+
+  if entry.key != key {
+    return Ok(None);
+  }
+  if entry.expires_at.is_some_and(|at| at <= Utc::now()) {
+    let _ = std::fs::remove_file(path);
+    return Ok(None);
+  }
+  Ok(Some(entry.body))
+}
+
+fn write_entry(dir: &Path, path: &Path, entry: &DiskEntry) -> Result<()> {
+  let write = || -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let json = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)
+  };
+  write()
+    .map_err(|e| Error::Config(format!("Failed to write cache entry {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+  }
+
+  #[test]
+  fn test_default_ttls() {
+    let none = params(&[]);
+    assert_eq!(default_ttl(FuncType::GlobalQuote, &none), CacheTtl::For(MINUTE));
+    assert_eq!(default_ttl(FuncType::Overview, &none), CacheTtl::For(30 * DAY));
+    assert_eq!(default_ttl(FuncType::TimeSeriesIntraday, &none), CacheTtl::For(5 * MINUTE));
+
+    let finished = params(&[("symbol", "IBM"), ("month", "2009-01")]);
+    assert_eq!(default_ttl(FuncType::TimeSeriesIntraday, &finished), CacheTtl::Immutable);
+    let current = YearMonth::of(Utc::now().date_naive()).to_string();
+    let current = params(&[("month", current.as_str())]);
+    assert_eq!(default_ttl(FuncType::TimeSeriesIntraday, &current), CacheTtl::For(5 * MINUTE));
+
+    let policy = CachePolicy::new().with_ttl(FuncType::GlobalQuote, CacheTtl::NoCache);
+    assert_eq!(policy.ttl(FuncType::GlobalQuote, &none), CacheTtl::NoCache);
+    assert_eq!(policy.ttl(FuncType::Overview, &none), CacheTtl::For(30 * DAY));
+  }
+
+  #[test]
+  fn test_cache_key_ignores_api_key_and_order() {
+    let a = params(&[("symbol", "IBM"), ("interval", "5min"), ("apikey", "one")]);
+    let b = params(&[("apikey", "two"), ("interval", "5min"), ("symbol", "IBM")]);
+    assert_eq!(
+      cache_key(FuncType::TimeSeriesIntraday, &a),
+      cache_key(FuncType::TimeSeriesIntraday, &b)
+    );
+    assert_eq!(cache_key(FuncType::Overview, &a), "OVERVIEW?interval=5min&symbol=IBM");
+  }
+
+  #[tokio::test]
+  async fn test_memory_cache_evicts_least_recently_used() {
+    let cache = MemoryCache::new(2);
+    cache.put("a", "1", None).await.unwrap();
+    cache.put("b", "2", None).await.unwrap();
+    assert_eq!(cache.get("a").await.unwrap().as_deref(), Some("1"));
+
+    cache.put("c", "3", None).await.unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("b").await.unwrap().is_none());
+    assert!(cache.get("a").await.unwrap().is_some());
+
+    cache.put("d", "4", Some(Duration::ZERO)).await.unwrap();
+    assert!(cache.get("d").await.unwrap().is_none());
+  }
+
+  #[tokio::test]
+  async fn test_disk_cache_round_trip_and_expiry() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = DiskCache::new(dir.path());
+    cache.put("OVERVIEW?symbol=IBM", "{}", None).await.unwrap();
+    cache.put("GLOBAL_QUOTE?symbol=IBM", "{}", Some(Duration::ZERO)).await.unwrap();
+
+    let reopened = DiskCache::new(dir.path());
+    assert_eq!(reopened.get("OVERVIEW?symbol=IBM").await.unwrap().as_deref(), Some("{}"));
+    assert!(reopened.get("OVERVIEW?symbol=MSFT").await.unwrap().is_none());
+    assert!(reopened.get("GLOBAL_QUOTE?symbol=IBM").await.unwrap().is_none());
+    assert!(!reopened.entry_path("GLOBAL_QUOTE?symbol=IBM").exists());
+  }
+}
@@ -0,0 +1,387 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Record/replay [`HttpTransport`] backed by a directory of cassettes.
+//!
+//! A cassette is one recorded response, stored as a JSON file under
+//! `<dir>/<FUNCTION>/<params>-<hash>.json`. The file name is derived from
+//! the request's normalized parameters: `function` and `apikey` are
+//! dropped, the rest are sorted by key, so the same query always maps to
+//! the same cassette and no API key ever reaches the disk.
+//!
+//! | Mode                               | Cassette present | Cassette missing              |
+//! |------------------------------------|------------------|-------------------------------|
+//! | [`CassetteMode::Record`]           | re-record        | record                        |
+//! | [`CassetteMode::ReplayOrRecord`]   | replay           | record                        |
+//! | [`CassetteMode::Replay`]           | replay           | `Error::Config` (strict)      |
+//!
+//! Only successful responses are recorded; error statuses and rate-limit
+//! notices are passed through without being written.
+//!
+//! [`Transport::new`](super::Transport::new) wraps its reqwest backend in a
+//! cassette transport when [`CASSETTE_MODE_ENV`] is set:
+//!
+//! ```bash
+//! # Record once, with network access and a real key
+//! AV_CASSETTE_MODE=record AV_CASSETTE_DIR=tests/cassettes cargo test
+//!
+//! # Replay offline; unrecorded requests fail
+//! AV_CASSETTE_MODE=replay AV_CASSETTE_DIR=tests/cassettes cargo test
+//! ```
+
+use super::{HttpTransport, RawResponse};
+use async_trait::async_trait;
+use av_core::hash::fnv1a;
+use av_core::{Error, FuncType, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Environment variable selecting the cassette mode (`record`, `replay`,
+/// `auto`, or `off`).
+pub const CASSETTE_MODE_ENV: &str = "AV_CASSETTE_MODE";
+
+/// Environment variable holding the cassette directory.
+pub const CASSETTE_DIR_ENV: &str = "AV_CASSETTE_DIR";
+
+/// Directory used when [`CASSETTE_DIR_ENV`] is not set.
+pub const DEFAULT_CASSETTE_DIR: &str = "tests/cassettes";
+
+/// Longest readable prefix kept in a cassette file name.
+const MAX_SLUG_LEN: usize = 80;
+
+/// How a [`CassetteTransport`] treats each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+  /// Always send the request and overwrite the cassette
+  Record,
+  /// Serve from cassettes only; an unrecorded request is an error
+  Replay,
+  /// Serve from a cassette when one exists, otherwise send and record
+  ReplayOrRecord,
+}
+
+impl CassetteMode {
+  /// Whether this mode may send requests to the inner transport.
+  pub fn uses_network(&self) -> bool {
+    !matches!(self, CassetteMode::Replay)
+  }
+}
+
+impl FromStr for CassetteMode {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s.trim().to_ascii_lowercase().as_str() {
+      "record" => Ok(CassetteMode::Record),
+      "replay" | "strict" => Ok(CassetteMode::Replay),
+      "auto" | "replay_or_record" | "replay-or-record" => Ok(CassetteMode::ReplayOrRecord),
+      other => Err(Error::Config(format!(
+        "Invalid {}: {} (expected record, replay, auto or off)",
+        CASSETTE_MODE_ENV, other
+      ))),
+    }
+  }
+}
+
+impl fmt::Display for CassetteMode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CassetteMode::Record => write!(f, "record"),
+      CassetteMode::Replay => write!(f, "replay"),
+      CassetteMode::ReplayOrRecord => write!(f, "auto"),
+    }
+  }
+}
+
+/// On-disk form of one recorded response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+  pub function: String,
+  /// Normalized request parameters, without `function` and `apikey`
+  pub params: Vec<(String, String)>,
+  pub status: u16,
+  pub body: String,
+}
+
+/// Records responses from an inner transport and replays them from disk.
+pub struct CassetteTransport {
+  dir: PathBuf,
+  mode: CassetteMode,
+  inner: Option<Arc<dyn HttpTransport>>,
+}
+
+impl CassetteTransport {
+  /// Cassette transport in `mode` that sends unreplayed requests to `inner`.
+  pub fn new(
+    dir: impl Into<PathBuf>,
+    mode: CassetteMode,
+    inner: impl HttpTransport + 'static,
+  ) -> Self {
+    Self { dir: dir.into(), mode, inner: Some(Arc::new(inner)) }
+  }
+
+  /// Strict replay from `dir`, with no network access at all.
+  pub fn replay(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into(), mode: CassetteMode::Replay, inner: None }
+  }
+
+  /// Build from [`CASSETTE_MODE_ENV`] and [`CASSETTE_DIR_ENV`].
+  ///
+  /// Returns `Ok(None)` when the mode variable is unset, empty or `off`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if the mode variable has an unknown value.
+  pub fn from_env(inner: impl HttpTransport + 'static) -> Result<Option<Self>> {
+    let mode = match std::env::var(CASSETTE_MODE_ENV) {
+      Ok(value) if value.trim().is_empty() || value.trim().eq_ignore_ascii_case("off") => {
+        return Ok(None);
+      }
+      Ok(value) => value.parse::<CassetteMode>()?,
+      Err(_) => return Ok(None),
+    };
+    let dir = std::env::var(CASSETTE_DIR_ENV).unwrap_or_else(|_| DEFAULT_CASSETTE_DIR.to_string());
+
+    info!("Using {} cassettes in {}", mode, dir);
+    Ok(Some(Self::new(dir, mode, inner)))
+  }
+
+  pub fn mode(&self) -> CassetteMode {
+    self.mode
+  }
+
+  pub fn dir(&self) -> &Path {
+    &self.dir
+  }
+
+  /// Path of the cassette for a request.
+  ///
+  /// `params` may include `function` and `apikey`; both are ignored.
+  pub fn cassette_path(&self, function: FuncType, params: &[(String, String)]) -> PathBuf {
+    let query = normalized_query(&normalize_params(params));
+    self.dir.join(function.to_string()).join(format!("{}.json", cassette_name(&query)))
+  }
+
+  async fn record(
+    &self,
+    function: FuncType,
+    params: &[(String, String)],
+    path: &Path,
+  ) -> Result<RawResponse> {
+    let inner = self.inner.as_ref().ok_or_else(|| {
+      Error::Config(format!("No transport to record {} with ({})", function, path.display()))
+    })?;
+
+    let response = inner.send(function, params).await?;
+    if is_recordable(&response) {
+      let cassette = Cassette {
+        function: function.to_string(),
+        params: normalize_params(params),
+        status: response.status,
+        body: response.body.clone(),
+      };
+      let file = path.to_path_buf();
+      super::blocking_io(move || save(&file, &cassette)).await?;
+      debug!("Recorded cassette {}", path.display());
+    } else {
+      debug!("Not recording {} response for {}", response.status, function);
+    }
+    Ok(response)
+  }
+}
+
+impl fmt::Debug for CassetteTransport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CassetteTransport")
+      .field("dir", &self.dir)
+      .field("mode", &self.mode)
+      .field("has_inner", &self.inner.is_some())
+      .finish()
+  }
+}
+
+#[async_trait]
+impl HttpTransport for CassetteTransport {
+  async fn send(&self, function: FuncType, params: &[(String, String)]) -> Result<RawResponse> {
+    let path = self.cassette_path(function, params);
+
+    if self.mode != CassetteMode::Record {
+      let file = path.clone();
+      if let Some(cassette) = super::blocking_io(move || load(&file)).await? {
+        debug!("Replaying cassette {}", path.display());
+        return Ok(RawResponse::new(cassette.status, cassette.body));
+      }
+      if self.mode == CassetteMode::Replay {
+        return Err(Error::Config(format!(
+          "No cassette recorded for {} {} (expected {})",
+          function,
+          normalized_query(&normalize_params(params)),
+          path.display()
+        )));
+      }
+    }
+
+    self.record(function, params, &path).await
+  }
+}
+
+fn load(path: &Path) -> Result<Option<Cassette>> {
+  let text = match std::fs::read_to_string(path) {
+    Ok(text) => text,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(e) => {
+      return Err(Error::Config(format!("Failed to read cassette {}: {}", path.display(), e)));
+    }
+  };
+  serde_json::from_str(&text)
+    .map(Some)
+    .map_err(|e| Error::Config(format!("Invalid cassette {}: {}", path.display(), e)))
+}
+
+fn save(path: &Path, cassette: &Cassette) -> Result<()> {
+  let write = || -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(cassette).map_err(std::io::Error::other)?;
+    // Write then rename so a concurrent replay never sees half a file
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)
+  };
+  write().map_err(|e| Error::Config(format!("Failed to write cassette {}: {}", path.display(), e)))
+}
+
+/// Drop `function` and `apikey` and sort by key. Repeated keys keep their
+/// relative order, since it is significant (e.g. `RANGE`).
+pub(super) fn normalize_params(params: &[(String, String)]) -> Vec<(String, String)> {
+  let mut normalized: Vec<(String, String)> = params
+    .iter()
+    .filter(|(k, _)| k != "function" && k != "apikey")
+    .map(|(k, v)| (k.clone(), v.trim().to_string()))
+    .collect();
+  normalized.sort_by(|a, b| a.0.cmp(&b.0));
+  normalized
+}
+
+pub(super) fn normalized_query(params: &[(String, String)]) -> String {
+  params.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join("&")
+}
+
+/// Readable, file-system safe name for a normalized query, suffixed with a
+/// hash of the full query so that truncated or sanitized names stay unique.
+pub(super) fn cassette_name(query: &str) -> String {
+  let mut slug: String = query
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '=') { c } else { '_' })
+    .take(MAX_SLUG_LEN)
+    .collect();
+  if slug.is_empty() {
+    slug.push_str("default");
+  }
+  format!("{}-{:016x}", slug, fnv1a(query.as_bytes()))
+}
+
+/// Error statuses and API notices are not worth replaying: a cassette of a
+/// rate-limit message would make every later replay fail the same way.
+fn is_recordable(response: &RawResponse) -> bool {
+  if !response.is_success() {
+    return false;
+  }
+  match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&response.body) {
+    Ok(map) => !(map.contains_key("Note") || map.contains_key("Information")),
+    Err(_) => true,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::transport::FixtureTransport;
+
+  fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+    items.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+  }
+
+  #[test]
+  fn test_cassette_path_ignores_apikey_and_order() {
+    let cassettes = CassetteTransport::replay("cassettes");
+    let a = cassettes.cassette_path(
+      FuncType::GlobalQuote,
+      &pairs(&[("symbol", "IBM"), ("function", "GLOBAL_QUOTE"), ("apikey", "secret")]),
+    );
+    let b = cassettes.cassette_path(
+      FuncType::GlobalQuote,
+      &pairs(&[("apikey", "other"), ("symbol", "IBM"), ("function", "GLOBAL_QUOTE")]),
+    );
+    assert_eq!(a, b);
+    assert!(a.starts_with("cassettes/GLOBAL_QUOTE"));
+    assert!(!a.to_string_lossy().contains("secret"));
+
+    let c = cassettes.cassette_path(FuncType::GlobalQuote, &pairs(&[("symbol", "MSFT")]));
+    assert_ne!(a, c);
+  }
+
+  #[tokio::test]
+  async fn test_record_then_strict_replay() {
+    let dir = tempfile::tempdir().unwrap();
+    let fixture = FixtureTransport::new()
+      .with_response(FuncType::GlobalQuote, r#"{"Global Quote": {"01. symbol": "IBM"}}"#)
+      .with_response(FuncType::Overview, r#"{"Information": "premium endpoint"}"#);
+    let params = pairs(&[("symbol", "IBM"), ("apikey", "secret")]);
+
+    let recorder = CassetteTransport::new(dir.path(), CassetteMode::Record, fixture);
+    recorder.send(FuncType::GlobalQuote, &params).await.unwrap();
+    recorder.send(FuncType::Overview, &params).await.unwrap();
+
+    let path = recorder.cassette_path(FuncType::GlobalQuote, &params);
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert!(!saved.contains("secret"));
+
+    let replayer = CassetteTransport::replay(dir.path());
+    let replayed = replayer.send(FuncType::GlobalQuote, &params).await.unwrap();
+    assert!(replayed.body.contains("IBM"));
+
+    // Notices are not recorded, so strict replay rejects them
+    let err = replayer.send(FuncType::Overview, &params).await.unwrap_err();
+    assert!(matches!(err, Error::Config(_)));
+  }
+
+  #[test]
+  fn test_mode_from_str() {
+    assert_eq!("RECORD".parse::<CassetteMode>().unwrap(), CassetteMode::Record);
+    assert_eq!("replay".parse::<CassetteMode>().unwrap(), CassetteMode::Replay);
+    assert_eq!("auto".parse::<CassetteMode>().unwrap(), CassetteMode::ReplayOrRecord);
+    assert!("sometimes".parse::<CassetteMode>().is_err());
+  }
+}
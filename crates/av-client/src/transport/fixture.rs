@@ -0,0 +1,137 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! In-memory [`HttpTransport`] for tests.
+
+use super::{HttpTransport, RawResponse};
+use async_trait::async_trait;
+use av_core::{Error, FuncType, Result};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// A request received by a [`FixtureTransport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+  pub function: FuncType,
+  /// Query parameters without `function` and `apikey`
+  pub params: BTreeMap<String, String>,
+}
+
+#[derive(Debug)]
+struct Fixture {
+  function: FuncType,
+  params: Vec<(String, String)>,
+  response: RawResponse,
+}
+
+/// Serves canned responses by [`FuncType`] without opening a socket.
+///
+/// A fixture registered with parameters only matches requests that carry
+/// all of them; when several fixtures match, the one with the most
+/// parameters wins, and ties go to the one registered last. Requests with no matching fixture fail with
+/// [`Error::Http`]. Every request is recorded for later assertions.
+///
+/// # Examples
+///
+/// ```ignore
+/// let fixture = Arc::new(
+///     FixtureTransport::new()
+///         .with_response(FuncType::TopGainersLosers, include_str!("top_movers.json"))
+///         .with_response_for(FuncType::Overview, &[("symbol", "IBM")], ibm_overview),
+/// );
+/// let client = AlphaVantageClient::with_transport(config, fixture.clone())?;
+/// client.fundamentals().company_overview("IBM").await?;
+/// assert_eq!(fixture.requests().len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct FixtureTransport {
+  fixtures: Vec<Fixture>,
+  requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl FixtureTransport {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Respond to every `function` request with `body`.
+  pub fn with_response(self, function: FuncType, body: impl Into<String>) -> Self {
+    self.with_raw_response(function, &[], RawResponse::ok(body))
+  }
+
+  /// Respond to `function` requests carrying all of `params` with `body`.
+  pub fn with_response_for(
+    self,
+    function: FuncType,
+    params: &[(&str, &str)],
+    body: impl Into<String>,
+  ) -> Self {
+    self.with_raw_response(function, params, RawResponse::ok(body))
+  }
+
+  /// Respond to matching requests with an arbitrary status and body.
+  pub fn with_raw_response(
+    mut self,
+    function: FuncType,
+    params: &[(&str, &str)],
+    response: RawResponse,
+  ) -> Self {
+    let params = params.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    self.fixtures.push(Fixture { function, params, response });
+    self
+  }
+
+  /// Requests received so far, oldest first.
+  pub fn requests(&self) -> Vec<RecordedRequest> {
+    self.requests.lock().map(|r| r.clone()).unwrap_or_default()
+  }
+}
+
+#[async_trait]
+impl HttpTransport for FixtureTransport {
+  async fn send(&self, function: FuncType, params: &[(String, String)]) -> Result<RawResponse> {
+    let recorded: BTreeMap<String, String> =
+      params.iter().filter(|(k, _)| k != "function" && k != "apikey").cloned().collect();
+
+    if let Ok(mut requests) = self.requests.lock() {
+      requests.push(RecordedRequest { function, params: recorded.clone() });
+    }
+
+    self
+      .fixtures
+      .iter()
+      .filter(|f| f.function == function)
+      .filter(|f| f.params.iter().all(|(k, v)| recorded.get(k) == Some(v)))
+      .max_by_key(|f| f.params.len())
+      .map(|f| f.response.clone())
+      .ok_or_else(|| {
+        Error::Http(format!("No fixture registered for {} with params {:?}", function, recorded))
+      })
+  }
+}
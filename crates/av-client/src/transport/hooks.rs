@@ -0,0 +1,350 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Hooks for observing the requests [`Transport`] sends.
+//!
+//! A [`RequestHook`] is called around every attempt that goes to the
+//! backend, retries included; cache hits are not calls and are not seen.
+//! For each attempt a hook gets [`before_request`](RequestHook::before_request)
+//! and then exactly one of [`after_response`](RequestHook::after_response),
+//! when the response decoded, or [`on_error`](RequestHook::on_error).
+//!
+//! | Hook              | Records                                                   |
+//! |-------------------|-----------------------------------------------------------|
+//! | [`MetricsHook`]   | Per-function request/error counts and latency histograms |
+//! | [`JsonlAuditHook`]| One JSON line per attempt, enabled by [`Config::audit_log`] |
+//!
+//! Hooks run inline on the request path, so they should be quick and must
+//! not block on I/O for long.
+//!
+//! [`Transport`]: super::Transport
+//! [`Config::audit_log`]: av_core::Config::audit_log
+
+use av_core::{Error, FuncType, Result};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use tracing::warn;
+
+/// Upper bounds of the [`MetricsHook`] latency buckets, in milliseconds.
+/// Slower calls fall into a final overflow bucket.
+pub const LATENCY_BUCKETS_MS: [u64; 9] = [25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+/// One attempt at a request, as seen by a [`RequestHook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallInfo {
+  pub function: FuncType,
+  /// Query parameters including `function`, with `apikey` masked
+  pub params: Vec<(String, String)>,
+  /// 0 for the first attempt, then 1, 2, ... for retries
+  pub retry: u32,
+}
+
+impl CallInfo {
+  pub(super) fn new(
+    function: FuncType,
+    params: &[(String, String)],
+    masked_key: &str,
+    retry: u32,
+  ) -> Self {
+    let params = params
+      .iter()
+      .map(|(k, v)| {
+        let v = if k == "apikey" { masked_key.to_string() } else { v.clone() };
+        (k.clone(), v)
+      })
+      .collect();
+    Self { function, params, retry }
+  }
+}
+
+/// How an attempt went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallOutcome {
+  /// Time from sending the request to decoding (or failing on) the response
+  pub latency: Duration,
+  /// HTTP status, if a response was received
+  pub status: Option<u16>,
+  /// Response body length in bytes; 0 without a response
+  pub body_size: usize,
+}
+
+/// Observer of the requests a [`Transport`](super::Transport) sends.
+///
+/// All methods default to doing nothing.
+pub trait RequestHook: Send + Sync {
+  fn before_request(&self, _call: &CallInfo) {}
+
+  fn after_response(&self, _call: &CallInfo, _outcome: &CallOutcome) {}
+
+  fn on_error(&self, _call: &CallInfo, _outcome: &CallOutcome, _error: &Error) {}
+}
+
+/// Counts and latencies for one function, collected by [`MetricsHook`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunctionMetrics {
+  pub requests: u64,
+  pub errors: u64,
+  /// Attempts per latency bucket: one per [`LATENCY_BUCKETS_MS`] bound,
+  /// then the overflow bucket
+  pub latency_buckets: [u64; LATENCY_BUCKETS_MS.len() + 1],
+  pub total_latency: Duration,
+}
+
+impl FunctionMetrics {
+  /// Attempts that finished, successfully or not
+  pub fn completed(&self) -> u64 {
+    self.latency_buckets.iter().sum()
+  }
+
+  pub fn mean_latency(&self) -> Option<Duration> {
+    let completed = u32::try_from(self.completed()).ok().filter(|n| *n > 0)?;
+    Some(self.total_latency / completed)
+  }
+
+  /// Upper bound of the bucket holding quantile `q` (0.0–1.0) of latencies;
+  /// `None` if nothing completed or it falls in the overflow bucket
+  pub fn latency_quantile(&self, q: f64) -> Option<Duration> {
+    let completed = self.completed();
+    if completed == 0 {
+      return None;
+    }
+    let rank = ((completed as f64) * q.clamp(0.0, 1.0)).ceil().max(1.0) as u64;
+    let mut seen = 0;
+    for (bound, count) in LATENCY_BUCKETS_MS.iter().zip(self.latency_buckets) {
+      seen += count;
+      if seen >= rank {
+        return Some(Duration::from_millis(*bound));
+      }
+    }
+    None
+  }
+
+  fn observe(&mut self, latency: Duration) {
+    let millis = latency.as_millis();
+    let bucket = LATENCY_BUCKETS_MS
+      .iter()
+      .position(|bound| millis <= u128::from(*bound))
+      .unwrap_or(LATENCY_BUCKETS_MS.len());
+    self.latency_buckets[bucket] += 1;
+    self.total_latency += latency;
+  }
+}
+
+/// [`RequestHook`] keeping in-memory request counts and latency histograms
+/// per function.
+#[derive(Debug, Default)]
+pub struct MetricsHook {
+  metrics: Mutex<HashMap<FuncType, FunctionMetrics>>,
+}
+
+impl MetricsHook {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Metrics collected so far, by function
+  pub fn snapshot(&self) -> HashMap<FuncType, FunctionMetrics> {
+    self.lock().clone()
+  }
+
+  /// Metrics for one function, zero if it was never called
+  pub fn function(&self, function: FuncType) -> FunctionMetrics {
+    self.lock().get(&function).cloned().unwrap_or_default()
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HashMap<FuncType, FunctionMetrics>> {
+    self.metrics.lock().unwrap_or_else(|e| e.into_inner())
+  }
+}
+
+impl RequestHook for MetricsHook {
+  fn before_request(&self, call: &CallInfo) {
+    self.lock().entry(call.function).or_default().requests += 1;
+  }
+
+  fn after_response(&self, call: &CallInfo, outcome: &CallOutcome) {
+    self.lock().entry(call.function).or_default().observe(outcome.latency);
+  }
+
+  fn on_error(&self, call: &CallInfo, outcome: &CallOutcome, _error: &Error) {
+    let mut metrics = self.lock();
+    let entry = metrics.entry(call.function).or_default();
+    entry.errors += 1;
+    entry.observe(outcome.latency);
+  }
+}
+
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+  timestamp: String,
+  function: String,
+  params: BTreeMap<&'a str, &'a str>,
+  retry: u32,
+  latency_ms: u128,
+  status: Option<u16>,
+  body_size: usize,
+  error: Option<String>,
+}
+
+/// [`RequestHook`] appending one JSON object per finished attempt to a
+/// file (JSON Lines).
+///
+/// API keys only appear masked. Write failures are logged and otherwise
+/// ignored.
+#[derive(Debug)]
+pub struct JsonlAuditHook {
+  path: PathBuf,
+  file: Mutex<File>,
+}
+
+impl JsonlAuditHook {
+  /// Append to the file at `path`, creating it and its directory if needed.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if the file cannot be opened.
+  pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+    let path = path.into();
+    let open = || -> std::io::Result<File> {
+      if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+      }
+      OpenOptions::new().create(true).append(true).open(&path)
+    };
+    let file = open()
+      .map_err(|e| Error::Config(format!("Failed to open audit log {}: {}", path.display(), e)))?;
+    Ok(Self { path, file: Mutex::new(file) })
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  fn write(&self, call: &CallInfo, outcome: &CallOutcome, error: Option<&Error>) {
+    let record = AuditRecord {
+      timestamp: Utc::now().to_rfc3339(),
+      function: call.function.to_string(),
+      params: call
+        .params
+        .iter()
+        .filter(|(k, _)| k != "function")
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect(),
+      retry: call.retry,
+      latency_ms: outcome.latency.as_millis(),
+      status: outcome.status,
+      body_size: outcome.body_size,
+      error: error.map(|e| e.to_string()),
+    };
+
+    let result = serde_json::to_string(&record).map_err(std::io::Error::other).and_then(|line| {
+      let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+      writeln!(file, "{}", line)
+    });
+    if let Err(e) = result {
+      warn!("Could not write audit log {}: {}", self.path.display(), e);
+    }
+  }
+}
+
+impl RequestHook for JsonlAuditHook {
+  fn after_response(&self, call: &CallInfo, outcome: &CallOutcome) {
+    self.write(call, outcome, None);
+  }
+
+  fn on_error(&self, call: &CallInfo, outcome: &CallOutcome, error: &Error) {
+    self.write(call, outcome, Some(error));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn call(function: FuncType) -> CallInfo {
+    let params = vec![
+      ("symbol".to_string(), "IBM".to_string()),
+      ("function".to_string(), function.to_string()),
+      ("apikey".to_string(), "SECRETKEY1234".to_string()),
+    ];
+    CallInfo::new(function, &params, "****1234", 0)
+  }
+
+  fn outcome(millis: u64) -> CallOutcome {
+    CallOutcome { latency: Duration::from_millis(millis), status: Some(200), body_size: 42 }
+  }
+
+  #[test]
+  fn test_metrics_histogram() {
+    let hook = MetricsHook::new();
+    let overview = call(FuncType::Overview);
+    for millis in [10, 40, 90, 20_000] {
+      hook.before_request(&overview);
+      hook.after_response(&overview, &outcome(millis));
+    }
+    hook.before_request(&overview);
+    hook.on_error(&overview, &outcome(30), &Error::Api("Invalid API call".into()));
+
+    let metrics = hook.function(FuncType::Overview);
+    assert_eq!((metrics.requests, metrics.errors, metrics.completed()), (5, 1, 5));
+    assert_eq!(metrics.latency_buckets[..3], [1, 2, 1]);
+    assert_eq!(metrics.latency_buckets[LATENCY_BUCKETS_MS.len()], 1);
+    assert_eq!(metrics.latency_quantile(0.5), Some(Duration::from_millis(50)));
+    assert_eq!(metrics.latency_quantile(1.0), None);
+    assert_eq!(hook.function(FuncType::GlobalQuote), FunctionMetrics::default());
+  }
+
+  #[test]
+  fn test_audit_log_masks_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit").join("calls.jsonl");
+    let hook = JsonlAuditHook::new(&path).unwrap();
+    let overview = call(FuncType::Overview);
+    hook.after_response(&overview, &outcome(12));
+    let no_response = CallOutcome { latency: Duration::from_secs(30), status: None, body_size: 0 };
+    hook.on_error(&overview, &no_response, &Error::Http("timeout".into()));
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(!text.contains("SECRETKEY1234"));
+    let lines: Vec<serde_json::Value> =
+      text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["function"], "OVERVIEW");
+    assert_eq!(lines[0]["params"]["apikey"], "****1234");
+    assert_eq!(lines[0]["status"], 200);
+    assert_eq!(lines[1]["error"], "HTTP error: timeout");
+    assert!(lines[1]["status"].is_null());
+  }
+}
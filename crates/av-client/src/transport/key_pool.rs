@@ -0,0 +1,361 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Pool of API keys shared by all requests of a [`Transport`](super::Transport).
+//!
+//! Every key from [`Config::api_keys`] gets its own per-minute limiter and,
+//! when configured, a daily allowance. Each attempt leases one key, chosen
+//! by [`KeySelection`]:
+//!
+//! | Strategy        | Picks                                                          |
+//! |-----------------|----------------------------------------------------------------|
+//! | `RoundRobin`    | The next usable key after the one used last                    |
+//! | `LeastLoaded`   | The usable key with the fewest requests in flight, then today  |
+//!
+//! A key stops being usable when it reaches its daily limit or when the API
+//! reports [`Error::QuotaExhausted`] for it (both until the next UTC day), or
+//! permanently when the API rejects it with [`Error::InvalidApiKey`]. When no
+//! key is usable, [`KeyPool::acquire`] fails with `Error::QuotaExhausted`.
+
+use av_core::{ApiKeyConfig, Config, Error, KeySelection, Result};
+use chrono::{NaiveDate, Utc};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use tracing::warn;
+
+/// Usage of one pooled key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyStats {
+  /// The key with all but its last four characters masked
+  pub key: String,
+  pub rate_limit: u32,
+  pub daily_limit: Option<u32>,
+  /// Requests sent with this key since the pool was created
+  pub requests: u64,
+  /// Requests that failed, for any reason
+  pub errors: u64,
+  /// Requests sent with this key today (UTC)
+  pub used_today: u32,
+  pub in_flight: usize,
+  /// Out of quota until the next UTC day
+  pub exhausted: bool,
+  /// Why the key was permanently removed, if it was
+  pub removed: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct KeyState {
+  requests: u64,
+  errors: u64,
+  in_flight: usize,
+  day: Option<NaiveDate>,
+  used_today: u32,
+  exhausted: bool,
+  removed: Option<String>,
+}
+
+impl KeyState {
+  /// Reset the daily counters when the UTC day changes.
+  fn roll_over(&mut self, today: NaiveDate) {
+    if self.day != Some(today) {
+      self.day = Some(today);
+      self.used_today = 0;
+      self.exhausted = false;
+    }
+  }
+
+  fn is_usable(&self, config: &ApiKeyConfig) -> bool {
+    self.removed.is_none()
+      && !self.exhausted
+      && config.daily_limit.is_none_or(|limit| self.used_today < limit)
+  }
+}
+
+/// API keys with per-key rate limits, daily allowances and usage stats.
+pub struct KeyPool {
+  keys: Vec<ApiKeyConfig>,
+  limiters: Vec<DefaultDirectRateLimiter>,
+  states: Mutex<Vec<KeyState>>,
+  selection: KeySelection,
+  next: AtomicUsize,
+}
+
+impl KeyPool {
+  /// Pool of `keys`; a key with a zero rate limit gets the default limit.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `keys` is empty.
+  pub fn new(keys: Vec<ApiKeyConfig>, selection: KeySelection) -> Self {
+    assert!(!keys.is_empty(), "a key pool needs at least one key");
+    let limiters = keys
+      .iter()
+      .map(|k| {
+        let per_minute = NonZeroU32::new(k.rate_limit).unwrap_or_else(|| {
+          NonZeroU32::new(av_core::DEFAULT_RATE_LIMIT).expect("DEFAULT_RATE_LIMIT must be non-zero")
+        });
+        RateLimiter::direct(Quota::per_minute(per_minute))
+      })
+      .collect();
+    let states = Mutex::new(keys.iter().map(|_| KeyState::default()).collect());
+    Self { keys, limiters, states, selection, next: AtomicUsize::new(0) }
+  }
+
+  /// Pool of [`Config::api_keys`] using [`Config::key_selection`].
+  pub fn from_config(config: &Config) -> Self {
+    Self::new(config.api_keys(), config.key_selection)
+  }
+
+  /// Number of keys, including exhausted and removed ones.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Always `false`; a pool has at least one key.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Combined per-minute limit of all keys.
+  pub fn total_rate_limit(&self) -> u32 {
+    self.keys.iter().map(|k| k.rate_limit).sum()
+  }
+
+  /// Whether any key can currently be used.
+  pub fn has_usable_key(&self) -> bool {
+    let today = Utc::now().date_naive();
+    let mut states = self.lock();
+    states.iter_mut().zip(&self.keys).any(|(state, config)| {
+      state.roll_over(today);
+      state.is_usable(config)
+    })
+  }
+
+  /// Usage of every key, in configuration order.
+  pub fn stats(&self) -> Vec<KeyStats> {
+    let today = Utc::now().date_naive();
+    let mut states = self.lock();
+    states
+      .iter_mut()
+      .zip(&self.keys)
+      .map(|(state, config)| {
+        state.roll_over(today);
+        KeyStats {
+          key: config.masked_key(),
+          rate_limit: config.rate_limit,
+          daily_limit: config.daily_limit,
+          requests: state.requests,
+          errors: state.errors,
+          used_today: state.used_today,
+          in_flight: state.in_flight,
+          exhausted: state.exhausted,
+          removed: state.removed.clone(),
+        }
+      })
+      .collect()
+  }
+
+  /// Pick a key, count the request against it and wait for its limiter.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::QuotaExhausted`] if every key is exhausted or removed.
+  pub async fn acquire(&self) -> Result<KeyLease<'_>> {
+    let index = self.select()?;
+    self.limiters[index].until_ready().await;
+    Ok(KeyLease { pool: self, index })
+  }
+
+  fn select(&self) -> Result<usize> {
+    let today = Utc::now().date_naive();
+    let mut states = self.lock();
+    for state in states.iter_mut() {
+      state.roll_over(today);
+    }
+
+    let usable: Vec<usize> =
+      (0..self.keys.len()).filter(|&i| states[i].is_usable(&self.keys[i])).collect();
+
+    let index = match self.selection {
+      KeySelection::RoundRobin => {
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % self.keys.len();
+        usable.iter().copied().find(|&i| i >= start).or_else(|| usable.first().copied())
+      }
+      KeySelection::LeastLoaded => {
+        usable.iter().copied().min_by_key(|&i| (states[i].in_flight, states[i].used_today))
+      }
+    }
+    .ok_or_else(|| {
+      Error::QuotaExhausted(format!(
+        "all {} API keys are exhausted or were rejected",
+        self.keys.len()
+      ))
+    })?;
+
+    let state = &mut states[index];
+    state.requests += 1;
+    state.used_today += 1;
+    state.in_flight += 1;
+    Ok(index)
+  }
+
+  fn finish(&self, index: usize, error: Option<&Error>) {
+    let mut states = self.lock();
+    let state = &mut states[index];
+    if let Some(error) = error {
+      state.errors += 1;
+      match error {
+        Error::QuotaExhausted(_) => {
+          warn!("API key {} is out of quota until tomorrow", self.keys[index].masked_key());
+          state.exhausted = true;
+        }
+        Error::InvalidApiKey(_) => {
+          warn!("Removing API key {} from the pool: {}", self.keys[index].masked_key(), error);
+          state.removed = Some(error.to_string());
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn lock(&self) -> MutexGuard<'_, Vec<KeyState>> {
+    self.states.lock().unwrap_or_else(|e| e.into_inner())
+  }
+}
+
+impl std::fmt::Debug for KeyPool {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("KeyPool").field("keys", &self.keys).field("selection", &self.selection).finish()
+  }
+}
+
+/// One key checked out of a [`KeyPool`] for a single attempt.
+///
+/// Report the attempt's outcome with [`finish`](Self::finish); a lease that
+/// is dropped without it counts as a success.
+#[derive(Debug)]
+pub struct KeyLease<'a> {
+  pool: &'a KeyPool,
+  index: usize,
+}
+
+impl KeyLease<'_> {
+  pub fn key(&self) -> &str {
+    &self.pool.keys[self.index].key
+  }
+
+  pub fn masked_key(&self) -> String {
+    self.pool.keys[self.index].masked_key()
+  }
+
+  /// See [`ApiKeyConfig::ledger_key`]
+  pub fn ledger_key(&self) -> String {
+    self.pool.keys[self.index].ledger_key()
+  }
+
+  pub fn daily_limit(&self) -> Option<u32> {
+    self.pool.keys[self.index].daily_limit
+  }
+
+  /// Record the attempt's error, if any, against this key.
+  pub fn finish(self, error: Option<&Error>) {
+    self.pool.finish(self.index, error);
+  }
+}
+
+impl Drop for KeyLease<'_> {
+  fn drop(&mut self) {
+    let mut states = self.pool.lock();
+    states[self.index].in_flight -= 1;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pool(selection: KeySelection) -> KeyPool {
+    KeyPool::new(
+      vec![
+        ApiKeyConfig::new("FIRSTKEY", 600),
+        ApiKeyConfig::new("SECONDKEY", 600).with_daily_limit(2),
+      ],
+      selection,
+    )
+  }
+
+  #[tokio::test]
+  async fn test_round_robin_and_daily_limit() {
+    let pool = pool(KeySelection::RoundRobin);
+
+    let mut used = Vec::new();
+    for _ in 0..6 {
+      used.push(pool.acquire().await.unwrap().key().to_string());
+    }
+    assert_eq!(used, ["FIRSTKEY", "SECONDKEY", "FIRSTKEY", "SECONDKEY", "FIRSTKEY", "FIRSTKEY"]);
+
+    let stats = pool.stats();
+    assert_eq!(stats[0].requests, 4);
+    assert_eq!(stats[1].used_today, 2);
+    assert_eq!(stats[0].key, "****TKEY");
+  }
+
+  #[tokio::test]
+  async fn test_least_loaded_prefers_idle_key() {
+    let pool = pool(KeySelection::LeastLoaded);
+
+    let busy = pool.acquire().await.unwrap();
+    let idle = pool.acquire().await.unwrap();
+    assert_ne!(busy.key(), idle.key());
+    drop(idle);
+
+    assert_ne!(pool.acquire().await.unwrap().key(), busy.key());
+  }
+
+  #[tokio::test]
+  async fn test_failing_keys_are_dropped() {
+    let pool = pool(KeySelection::RoundRobin);
+
+    let lease = pool.acquire().await.unwrap();
+    lease.finish(Some(&Error::InvalidApiKey("apikey is invalid".to_string())));
+    let lease = pool.acquire().await.unwrap();
+    assert_eq!(lease.key(), "SECONDKEY");
+    lease.finish(Some(&Error::QuotaExhausted("25 requests per day".to_string())));
+
+    assert!(!pool.has_usable_key());
+    assert!(matches!(pool.acquire().await.unwrap_err(), Error::QuotaExhausted(_)));
+
+    let stats = pool.stats();
+    assert!(stats[0].removed.is_some());
+    assert!(stats[1].exhausted);
+    assert_eq!(stats[1].errors, 1);
+  }
+}
@@ -0,0 +1,1169 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Request execution for all AlphaVantage endpoint groups.
+//!
+//! [`Transport`] owns everything that is the same for every endpoint:
+//! adding `function` and an `apikey` from the [`KeyPool`], retries (see
+//! [`RetryPolicy`]), API error detection, and JSON/CSV decoding. Sending the request is delegated to an
+//! [`HttpTransport`] backend:
+//!
+//! | Backend               | Use                                                 |
+//! |-----------------------|-----------------------------------------------------|
+//! | [`ReqwestTransport`]  | Real HTTP requests (the default)                    |
+//! | `FixtureTransport`    | Canned in-memory responses for tests (`test-utils`) |
+//! | [`CassetteTransport`] | Responses recorded to and replayed from disk        |
+//!
+//! [`Transport::new`] switches to cassettes when `AV_CASSETTE_MODE` is set;
+//! see [`cassette`] for the modes and file layout. Calls are counted in a
+//! [`QuotaLedger`] when one is attached (see [`quota`]), and responses are
+//! served from a [`ResponseCache`] when one is attached (see [`cache`]).
+//! Every attempt is reported to the [`RequestHook`]s attached with
+//! [`Transport::with_hook`] (see [`hooks`]).
+//!
+//! Any other implementation can be supplied through
+//! [`Transport::with_backend`] or
+//! [`AlphaVantageClient::with_transport`](crate::AlphaVantageClient::with_transport).
+
+pub mod cache;
+pub mod cassette;
+#[cfg(any(test, feature = "test-utils"))]
+mod fixture;
+pub mod hooks;
+mod http;
+mod key_pool;
+pub mod quota;
+mod retry;
+
+pub use cache::{CachePolicy, CacheTtl, DiskCache, MemoryCache, ResponseCache};
+pub use cassette::{CassetteMode, CassetteTransport};
+#[cfg(any(test, feature = "test-utils"))]
+pub use fixture::{FixtureTransport, RecordedRequest};
+pub use hooks::{CallInfo, CallOutcome, FunctionMetrics, JsonlAuditHook, MetricsHook, RequestHook};
+pub use http::ReqwestTransport;
+pub use key_pool::{KeyLease, KeyPool, KeyStats};
+pub use quota::{FileQuotaLedger, QuotaLedger, QuotaUsage};
+pub use retry::RetryPolicy;
+
+use async_trait::async_trait;
+use av_core::{Config, Error, FuncType, Result};
+use chrono::Utc;
+use governor::{
+  RateLimiter,
+  clock::DefaultClock,
+  middleware::NoOpMiddleware,
+  state::{InMemoryState, NotKeyed},
+};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{Span, debug, error, info, instrument, warn};
+
+/// Run blocking file IO on tokio's blocking pool, off the async workers.
+async fn blocking_io<R, F>(f: F) -> Result<R>
+where
+  R: Send + 'static,
+  F: FnOnce() -> Result<R> + Send + 'static,
+{
+  tokio::task::spawn_blocking(f)
+    .await
+    .map_err(|e| Error::Unexpected(format!("File IO task failed: {}", e)))?
+}
+
+/// Status and body of one API response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawResponse {
+  pub status: u16,
+  pub body: String,
+  /// Parsed `Retry-After` header, if the server sent one
+  pub retry_after: Option<Duration>,
+}
+
+impl RawResponse {
+  pub fn new(status: u16, body: impl Into<String>) -> Self {
+    Self { status, body: body.into(), retry_after: None }
+  }
+
+  /// A `200 OK` response with the given body.
+  pub fn ok(body: impl Into<String>) -> Self {
+    Self::new(200, body)
+  }
+
+  pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+    self.retry_after = Some(retry_after);
+    self
+  }
+
+  pub fn is_success(&self) -> bool {
+    (200..300).contains(&self.status)
+  }
+}
+
+/// Sends a single AlphaVantage query and returns the raw response.
+///
+/// `params` already contains `function` and `apikey`. Implementations
+/// return `Err` only when no response was received; HTTP error statuses are
+/// returned as a [`RawResponse`] and handled by [`Transport`].
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+  async fn send(&self, function: FuncType, params: &[(String, String)]) -> Result<RawResponse>;
+}
+
+#[async_trait]
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+  async fn send(&self, function: FuncType, params: &[(String, String)]) -> Result<RawResponse> {
+    (**self).send(function, params).await
+  }
+}
+
+/// HTTP transport for API requests
+///
+/// Handles request construction, response parsing, error handling, and
+/// retries on top of an [`HttpTransport`] backend.
+///
+/// Responses are decoded as JSON, except for endpoints that only serve CSV
+/// ([`FuncType::returns_csv`]) and requests with `datatype=csv`. A CSV body
+/// is decoded into `{"data": [row, ...]}`, one object per row keyed by the
+/// `snake_case` column name, and then deserialized into the target type.
+pub struct Transport {
+  backend: Arc<dyn HttpTransport>,
+  client: Client,
+  keys: KeyPool,
+  base_url: String,
+  retry: RetryPolicy,
+  ledger: Option<Arc<dyn QuotaLedger>>,
+  cache: Option<Arc<dyn ResponseCache>>,
+  cache_policy: CachePolicy,
+  hooks: Vec<Arc<dyn RequestHook>>,
+  rate_limiter: Option<Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>>,
+}
+
+/// Where and for how long a successful response is cached
+struct CacheSlot {
+  key: String,
+  ttl: CacheTtl,
+}
+
+/// Why one attempt failed, and whether another is worthwhile.
+struct Failure {
+  error: Error,
+  retryable: bool,
+  retry_after: Option<Duration>,
+}
+
+impl Failure {
+  fn fatal(error: Error) -> Self {
+    Self { error, retryable: false, retry_after: None }
+  }
+
+  fn retryable(error: Error, retry_after: Option<Duration>) -> Self {
+    Self { error, retryable: true, retry_after }
+  }
+}
+
+impl Transport {
+  /// Create a new transport instance
+  ///
+  /// Requests go out over reqwest, unless the `AV_CASSETTE_MODE`
+  /// environment variable selects a [`CassetteTransport`] in front of it
+  /// (a test-harness switch). Everything else comes from `config`:
+  /// [`Config::quota_file`] counts calls in a [`FileQuotaLedger`],
+  /// [`Config::audit_log`] attaches a [`JsonlAuditHook`] and
+  /// [`Config::cache_dir`] caches responses in a [`DiskCache`]; each is off
+  /// when unset. The proxy, headers, certificates and connection settings
+  /// come from [`Config::http`].
+  ///
+  /// # Arguments
+  ///
+  /// * `config` - Configuration containing API key and other settings
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the HTTP client cannot be built from `config` (see
+  /// [`ReqwestTransport::build_client`]), if `AV_CASSETTE_MODE` has an
+  /// unknown value, or if the audit log cannot be opened.
+  pub fn new(config: Config) -> Result<Self> {
+    let client = ReqwestTransport::build_client(&config)?;
+
+    let http = ReqwestTransport::new(client.clone(), config.base_url.clone());
+    let backend: Arc<dyn HttpTransport> = match CassetteTransport::from_env(http.clone())? {
+      Some(cassettes) => Arc::new(cassettes),
+      None => Arc::new(http),
+    };
+
+    let retry = RetryPolicy::from_config(&config);
+    let keys = KeyPool::from_config(&config);
+    let ledger = config.quota_file.as_ref().map(|path| {
+      info!("Recording API calls in {}", path.display());
+      Arc::new(FileQuotaLedger::new(path)) as Arc<dyn QuotaLedger>
+    });
+    let cache = config.cache_dir.as_ref().map(|dir| {
+      info!("Caching API responses in {}", dir.display());
+      Arc::new(DiskCache::new(dir)) as Arc<dyn ResponseCache>
+    });
+    let mut hooks: Vec<Arc<dyn RequestHook>> = Vec::new();
+    if let Some(path) = &config.audit_log {
+      info!("Writing API audit log to {}", path.display());
+      hooks.push(Arc::new(JsonlAuditHook::new(path)?));
+    }
+    Ok(Self {
+      backend,
+      client,
+      keys,
+      base_url: config.base_url,
+      retry,
+      ledger,
+      cache,
+      cache_policy: CachePolicy::default(),
+      hooks,
+      rate_limiter: None,
+    })
+  }
+
+  /// Create a transport that sends requests through `backend`
+  ///
+  /// The API keys and base URL are taken from `config`; the base URL is only
+  /// reported by [`base_url`](Self::base_url), since the backend decides
+  /// where requests go.
+  pub fn with_backend(config: Config, backend: impl HttpTransport + 'static) -> Self {
+    Self {
+      backend: Arc::new(backend),
+      client: Client::new(),
+      retry: RetryPolicy::from_config(&config),
+      keys: KeyPool::from_config(&config),
+      base_url: config.base_url,
+      ledger: None,
+      cache: None,
+      cache_policy: CachePolicy::default(),
+      hooks: Vec::new(),
+      rate_limiter: None,
+    }
+  }
+
+  /// Replace the retry policy derived from [`Config::max_retries`]
+  pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+    self.retry = retry;
+    self
+  }
+
+  pub fn retry_policy(&self) -> &RetryPolicy {
+    &self.retry
+  }
+
+  /// Count calls in `ledger` and stop using a key once the calls recorded
+  /// there today reach its daily limit
+  pub fn with_quota_ledger(mut self, ledger: Arc<dyn QuotaLedger>) -> Self {
+    self.ledger = Some(ledger);
+    self
+  }
+
+  pub fn quota_ledger(&self) -> Option<&Arc<dyn QuotaLedger>> {
+    self.ledger.as_ref()
+  }
+
+  /// Serve repeated requests from `cache` for as long as the
+  /// [`CachePolicy`] allows
+  pub fn with_cache(mut self, cache: Arc<dyn ResponseCache>) -> Self {
+    self.cache = Some(cache);
+    self
+  }
+
+  /// Replace the default per-function cache lifetimes
+  pub fn with_cache_policy(mut self, policy: CachePolicy) -> Self {
+    self.cache_policy = policy;
+    self
+  }
+
+  pub fn cache(&self) -> Option<&Arc<dyn ResponseCache>> {
+    self.cache.as_ref()
+  }
+
+  /// Report every attempt to `hook`, after any hooks already attached
+  pub fn with_hook(mut self, hook: Arc<dyn RequestHook>) -> Self {
+    self.hooks.push(hook);
+    self
+  }
+
+  pub fn hooks(&self) -> &[Arc<dyn RequestHook>] {
+    &self.hooks
+  }
+
+  /// Wait on `rate_limiter` before every request that is not answered from
+  /// the cache
+  pub fn with_rate_limiter(
+    mut self,
+    rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
+  ) -> Self {
+    self.rate_limiter = Some(rate_limiter);
+    self
+  }
+
+  pub fn rate_limiter(
+    &self,
+  ) -> Option<&Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>> {
+    self.rate_limiter.as_ref()
+  }
+
+  /// The keys requests are spread across, with their usage stats
+  pub fn key_pool(&self) -> &KeyPool {
+    &self.keys
+  }
+
+  /// Get access to the internal reqwest client for direct (non-AlphaVantage)
+  /// calls, which do not go through the backend
+  pub fn client(&self) -> &Client {
+    &self.client
+  }
+
+  /// Execute a GET request to the AlphaVantage API
+  ///
+  /// # Arguments
+  ///
+  /// * `function` - The API function to call
+  /// * `params` - Additional parameters for the request
+  ///
+  /// # Returns
+  ///
+  /// Returns the deserialized response data or an error
+  pub async fn get<T>(&self, function: FuncType, params: HashMap<String, String>) -> Result<T>
+  where
+    T: DeserializeOwned,
+  {
+    self.get_pairs(function, params.into_iter().collect()).await
+  }
+
+  /// Execute a GET request whose query repeats a parameter
+  ///
+  /// Same as [`get`](Self::get), but takes ordered key/value pairs so that
+  /// a key may appear more than once (e.g. the analytics endpoints'
+  /// `RANGE=<start>&RANGE=<end>`).
+  ///
+  /// Each attempt leases a key from the [`KeyPool`]. Failed attempts are
+  /// retried according to the [`RetryPolicy`]; the number of retries is
+  /// recorded in the span's `retries` field. When a key turns out to be out
+  /// of quota or invalid, the request moves on to the next usable key
+  /// without counting a retry. With a [`QuotaLedger`] attached, a key whose
+  /// recorded calls today have reached its daily limit is treated the same
+  /// way before anything is sent. With a [`ResponseCache`] attached, a
+  /// cached body is returned without leasing a key or waiting on the rate
+  /// limiter; only requests that reach the API wait for a token.
+  #[instrument(skip(self), fields(function = %function, retries = 0))]
+  pub async fn get_pairs<T>(
+    &self,
+    function: FuncType,
+    mut params: Vec<(String, String)>,
+  ) -> Result<T>
+  where
+    T: DeserializeOwned,
+  {
+    let csv = function.returns_csv()
+      || params.iter().any(|(k, v)| k == "datatype" && v.eq_ignore_ascii_case("csv"));
+
+    let cache_slot = self.cache_slot(function, &params);
+    if let Some(slot) = &cache_slot {
+      if let Some(data) = self.cached::<T>(slot, function, csv).await {
+        return Ok(data);
+      }
+    }
+
+    if let Some(rate_limiter) = &self.rate_limiter {
+      rate_limiter.until_ready().await;
+    }
+
+    // Add function to parameters; the API key is added per attempt
+    params.push(("function".to_string(), function.to_string()));
+    params.push(("apikey".to_string(), String::new()));
+    let key_slot = params.len() - 1;
+
+    let mut retries = 0;
+    loop {
+      let lease = self.keys.acquire().await?;
+      params[key_slot].1 = lease.key().to_string();
+      debug!("Using API key {}", lease.masked_key());
+
+      if let Some(e) = self.ledger_exhausted(&lease).await {
+        lease.finish(Some(&e));
+        if self.keys.has_usable_key() {
+          continue;
+        }
+        return Err(e);
+      }
+
+      let outcome =
+        self.attempt::<T>(function, &params, csv, &lease, cache_slot.as_ref(), retries).await;
+      lease.finish(outcome.as_ref().err().map(|f| &f.error));
+      let failure = match outcome {
+        Ok(data) => {
+          info!("Successfully parsed response for function: {:?}", function);
+          return Ok(data);
+        }
+        Err(failure) => failure,
+      };
+
+      // The pool has retired that key; another one may still work
+      if failure.error.is_terminal() && self.keys.has_usable_key() {
+        continue;
+      }
+      if !failure.retryable {
+        return Err(failure.error);
+      }
+      if retries >= self.retry.max_retries {
+        warn!("Giving up on function {:?} after {} retries: {}", function, retries, failure.error);
+        return Err(failure.error);
+      }
+
+      retries += 1;
+      Span::current().record("retries", retries);
+      let delay = self.retry.delay(retries, failure.retry_after);
+      warn!(
+        "Request for function {:?} failed ({}); retry {}/{} in {:?}",
+        function, failure.error, retries, self.retry.max_retries, delay
+      );
+      tokio::time::sleep(delay).await;
+    }
+  }
+
+  /// The quota error for a leased key whose ledger count has reached its
+  /// daily limit. Ledger failures are logged and do not block the request.
+  async fn ledger_exhausted(&self, lease: &KeyLease<'_>) -> Option<Error> {
+    let (ledger, limit) = (self.ledger.as_ref()?, lease.daily_limit()?);
+    match ledger.calls(Utc::now().date_naive(), &lease.ledger_key()).await {
+      Ok(calls) if calls >= limit => Some(Error::QuotaExhausted(format!(
+        "{} calls recorded today for API key {}, daily limit is {}",
+        calls,
+        lease.masked_key(),
+        limit
+      ))),
+      Ok(_) => None,
+      Err(e) => {
+        warn!("Could not read quota ledger for API key {}: {}", lease.masked_key(), e);
+        None
+      }
+    }
+  }
+
+  /// Where a response to this request would be cached, if anywhere
+  fn cache_slot(&self, function: FuncType, params: &[(String, String)]) -> Option<CacheSlot> {
+    self.cache.as_ref()?;
+    let ttl = self.cache_policy.ttl(function, params);
+    (ttl != CacheTtl::NoCache).then(|| CacheSlot { key: cache::cache_key(function, params), ttl })
+  }
+
+  /// A cached response for `slot` that still decodes. Cache failures are
+  /// logged and treated as a miss.
+  async fn cached<T>(&self, slot: &CacheSlot, function: FuncType, csv: bool) -> Option<T>
+  where
+    T: DeserializeOwned,
+  {
+    let body = match self.cache.as_ref()?.get(&slot.key).await {
+      Ok(body) => body?,
+      Err(e) => {
+        warn!("Could not read response cache for {}: {}", slot.key, e);
+        return None;
+      }
+    };
+    match Self::parse_response::<T>(&body, function, csv) {
+      Ok(data) => {
+        debug!("Cache hit for {}", slot.key);
+        Some(data)
+      }
+      Err(e) => {
+        warn!("Ignoring cached response for {}: {}", slot.key, e);
+        None
+      }
+    }
+  }
+
+  /// Send the request once and decode the response, classifying any failure.
+  /// Attached hooks see the attempt before it is sent and once it is decoded
+  /// or has failed.
+  async fn attempt<T>(
+    &self,
+    function: FuncType,
+    params: &[(String, String)],
+    csv: bool,
+    lease: &KeyLease<'_>,
+    cache_slot: Option<&CacheSlot>,
+    retry: u32,
+  ) -> std::result::Result<T, Failure>
+  where
+    T: DeserializeOwned,
+  {
+    let ledger_key = lease.ledger_key();
+    if self.hooks.is_empty() {
+      let sent = self.backend.send(function, params).await;
+      return self.receive(function, csv, &ledger_key, cache_slot, sent).await;
+    }
+
+    let call = CallInfo::new(function, params, &lease.masked_key(), retry);
+    for hook in &self.hooks {
+      hook.before_request(&call);
+    }
+    let started = Instant::now();
+    let sent = self.backend.send(function, params).await;
+    let (status, body_size) = match &sent {
+      Ok(response) => (Some(response.status), response.body.len()),
+      Err(_) => (None, 0),
+    };
+    let result = self.receive(function, csv, &ledger_key, cache_slot, sent).await;
+    let outcome = CallOutcome { latency: started.elapsed(), status, body_size };
+    for hook in &self.hooks {
+      match &result {
+        Ok(_) => hook.after_response(&call, &outcome),
+        Err(failure) => hook.on_error(&call, &outcome, &failure.error),
+      }
+    }
+    result
+  }
+
+  /// Classify the backend's answer to one attempt and decode the response
+  async fn receive<T>(
+    &self,
+    function: FuncType,
+    csv: bool,
+    ledger_key: &str,
+    cache_slot: Option<&CacheSlot>,
+    sent: Result<RawResponse>,
+  ) -> std::result::Result<T, Failure>
+  where
+    T: DeserializeOwned,
+  {
+    let response = match sent {
+      Ok(response) => {
+        // Anything that got a response counts against the quota
+        if let Some(ledger) = &self.ledger {
+          if let Err(e) = ledger.record(Utc::now().date_naive(), ledger_key, function).await {
+            warn!("Could not record call in quota ledger: {}", e);
+          }
+        }
+        response
+      }
+      // Misconfiguration (e.g. a missing cassette) won't fix itself
+      Err(e @ Error::Config(_)) => return Err(Failure::fatal(e)),
+      // No response at all: connection failure or timeout
+      Err(e) => return Err(Failure::retryable(e, None)),
+    };
+
+    if !response.is_success() {
+      error!("HTTP error: {}", response.status);
+      let e = Error::Http(format!("HTTP error: {}", response.status));
+      return Err(if RetryPolicy::is_retryable_status(response.status) {
+        Failure::retryable(e, response.retry_after)
+      } else {
+        Failure::fatal(e)
+      });
+    }
+
+    match Self::parse_response::<T>(&response.body, function, csv) {
+      Ok(data) => {
+        if let (Some(slot), Some(cache)) = (cache_slot, &self.cache) {
+          if let Err(e) = cache.put(&slot.key, &response.body, slot.ttl.expiry()).await {
+            warn!("Could not store response for {} in cache: {}", slot.key, e);
+          }
+        }
+        Ok(data)
+      }
+      Err(e @ Error::RateLimit(_)) => Err(Failure::retryable(e, response.retry_after)),
+      Err(
+        e @ (Error::Api(_)
+        | Error::PremiumRequired(_)
+        | Error::QuotaExhausted(_)
+        | Error::InvalidApiKey(_)),
+      ) => Err(Failure::fatal(e)),
+      Err(e) => {
+        error!("Failed to parse response for function {:?}: {}", function, e);
+        Err(Failure::fatal(Error::Parse(format!(
+          "Failed to parse response for function {:?}: {}",
+          function, e
+        ))))
+      }
+    }
+  }
+
+  /// Parse the response body and handle API errors
+  fn parse_response<T>(text: &str, function: FuncType, csv: bool) -> Result<T>
+  where
+    T: DeserializeOwned,
+  {
+    debug!("Raw response: {}", text);
+
+    // CSV endpoints still report errors and rate limits as JSON
+    if csv && !text.trim_start().starts_with('{') {
+      return parse_csv(text, function);
+    }
+
+    if let Some(e) = response_error(text) {
+      return Err(e);
+    }
+
+    // Parse the successful response
+    serde_json::from_str(text).map_err(|e| {
+      Error::Parse(format!(
+        "Failed to deserialize response for function {:?}: {}. Raw response: {}",
+        function, e, text
+      ))
+    })
+  }
+
+  /// Get the base URL being used
+  pub fn base_url(&self) -> &str {
+    &self.base_url
+  }
+
+  /// Create a mock transport for testing
+  #[cfg(test)]
+  pub fn new_mock() -> Result<Self> {
+    let config = Config {
+      api_key: "mock_key".to_string(),
+      base_url: "https://mock.alphavantage.co".to_string(),
+      rate_limit: 75,
+      daily_limit: None,
+      timeout_secs: 10,
+      max_retries: 3,
+      key_pool: Vec::new(),
+      key_selection: Default::default(),
+      http: Default::default(),
+      cache_dir: None,
+      quota_file: None,
+      audit_log: None,
+    };
+    Self::new(config)
+  }
+}
+
+/// The error an AlphaVantage response body reports instead of data, if any.
+///
+/// Recognizes `"Error Message"` ([`Error::Api`], or [`Error::InvalidApiKey`]
+/// for a rejected key), and `"Note"`/`"Information"` notices for rate limits,
+/// the daily quota and premium-only endpoints. An unrecognized
+/// `"Information"` that is the whole response is reported as
+/// [`Error::Api`]. CSV and other non-JSON bodies never match.
+///
+/// [`Transport`] applies this to every response; it is public for callers
+/// that fetch AlphaVantage data without going through the client.
+pub fn response_error(text: &str) -> Option<Error> {
+  let response = serde_json::from_str::<HashMap<String, serde_json::Value>>(text).ok()?;
+
+  if let Some(message) = response.get("Error Message").and_then(|v| v.as_str()) {
+    return Some(classify_notice(message).unwrap_or_else(|| Error::Api(message.to_string())));
+  }
+
+  for key in ["Note", "Information"] {
+    if let Some(notice) = response.get(key).and_then(|v| v.as_str()) {
+      if let Some(e) = classify_notice(notice) {
+        return Some(e);
+      }
+      // A bare notice is the whole response; one next to data is informational
+      if key == "Information" && response.len() == 1 {
+        return Some(Error::Api(notice.to_string()));
+      }
+    }
+  }
+  None
+}
+
+/// Map the text of an AlphaVantage notice to the error it stands for.
+///
+/// The API reports quota, plan and key problems as prose under `"Note"`,
+/// `"Information"` or `"Error Message"`, so the wording is all there is to
+/// go on. The classic throttle note quotes both the per-minute and per-day
+/// allowance, so per-minute wording is checked first and only the daily cap
+/// message ("rate limit is N requests per day") is treated as terminal. That
+/// message also mentions premium plans, which is why it is checked before the
+/// premium-endpoint message.
+fn classify_notice(message: &str) -> Option<Error> {
+  let text = message.to_ascii_lowercase();
+  let error = if text.contains("apikey is invalid") || text.contains("invalid api key") {
+    Error::InvalidApiKey(message.to_string())
+  } else if text.contains("call frequency")
+    || text.contains("per second")
+    || text.contains("per minute")
+  {
+    Error::RateLimit(message.to_string())
+  } else if text.contains("requests per day") || text.contains("daily rate limit") {
+    Error::QuotaExhausted(message.to_string())
+  } else if text.contains("rate limit") {
+    Error::RateLimit(message.to_string())
+  } else if text.contains("premium endpoint") {
+    Error::PremiumRequired(message.to_string())
+  } else {
+    return None;
+  };
+  Some(error)
+}
+
+/// Converts a CSV header such as `assetType` to `asset_type`.
+fn snake_case(header: &str) -> String {
+  let mut out = String::with_capacity(header.len() + 4);
+  for (i, c) in header.trim().chars().enumerate() {
+    if c.is_ascii_uppercase() {
+      if i > 0 {
+        out.push('_');
+      }
+      out.push(c.to_ascii_lowercase());
+    } else {
+      out.push(c);
+    }
+  }
+  out
+}
+
+/// Decode a CSV body into `T` via a `{"data": [row, ...]}` envelope.
+///
+/// Malformed records and rows that do not fit `T` are reported with their
+/// line number.
+fn parse_csv<T>(text: &str, function: FuncType) -> Result<T>
+where
+  T: DeserializeOwned,
+{
+  let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(text.as_bytes());
+
+  let headers: Vec<String> = reader
+    .headers()
+    .map_err(|e| Error::Parse(format!("Invalid CSV header for function {:?}: {}", function, e)))?
+    .iter()
+    .map(snake_case)
+    .collect();
+
+  let mut rows = Vec::new();
+  let mut lines = Vec::new();
+  for record in reader.records() {
+    let record = record
+      .map_err(|e| Error::Parse(format!("Invalid CSV row for function {:?}: {}", function, e)))?;
+    let row: serde_json::Map<String, serde_json::Value> = headers
+      .iter()
+      .cloned()
+      .zip(record.iter().map(|v| serde_json::Value::String(v.to_string())))
+      .collect();
+    lines.push(record.position().map_or(0, |p| p.line()));
+    rows.push(serde_json::Value::Object(row));
+  }
+
+  let envelope = serde_json::json!({ "data": rows });
+  serde_json::from_value(envelope).map_err(|e| {
+    // Re-try rows one at a time to name the one that does not fit
+    let failing = rows.iter().zip(&lines).find_map(|(row, line)| {
+      serde_json::from_value::<T>(serde_json::json!({ "data": [row] })).err().map(|e| (line, e))
+    });
+    match failing {
+      Some((line, row_err)) => Error::Parse(format!(
+        "Failed to deserialize CSV line {} for function {:?}: {}",
+        line, function, row_err
+      )),
+      None => Error::Parse(format!(
+        "Failed to deserialize CSV response for function {:?}: {}",
+        function, e
+      )),
+    }
+  })
+}
+
+impl std::fmt::Debug for Transport {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Transport")
+      .field("base_url", &self.base_url)
+      .field("keys", &self.keys)
+      .field("retry", &self.retry)
+      .field("quota_ledger", &self.ledger.is_some())
+      .field("cache", &self.cache.is_some())
+      .field("cache_policy", &self.cache_policy)
+      .field("hooks", &self.hooks.len())
+      .field("rate_limiter", &self.rate_limiter.is_some())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_transport_creation() {
+    let config = Config {
+      api_key: "test_key".to_string(),
+      base_url: "https://www.alphavantage.co/query".to_string(),
+      rate_limit: 75,
+      daily_limit: None,
+      timeout_secs: 30,
+      max_retries: 3,
+      key_pool: Vec::new(),
+      key_selection: Default::default(),
+      http: Default::default(),
+      cache_dir: None,
+      quota_file: None,
+      audit_log: None,
+    };
+
+    let transport = Transport::new(config).expect("Failed to create transport");
+    assert_eq!(transport.base_url(), "https://www.alphavantage.co/query");
+  }
+
+  #[test]
+  fn test_transport_custom_base_url() {
+    let custom_url = "https://custom.alphavantage.co/query";
+    let config = Config {
+      api_key: "test_key".to_string(),
+      base_url: custom_url.to_string(),
+      rate_limit: 75,
+      daily_limit: None,
+      timeout_secs: 10,
+      max_retries: 3,
+      key_pool: Vec::new(),
+      key_selection: Default::default(),
+      http: Default::default(),
+      cache_dir: None,
+      quota_file: None,
+      audit_log: None,
+    };
+
+    let transport = Transport::new(config).expect("Failed to create transport");
+    assert_eq!(transport.base_url(), custom_url);
+  }
+
+  #[test]
+  fn test_disk_cache_is_opt_in() {
+    let transport = Transport::new(Config::default_with_key("test_key".to_string())).unwrap();
+    assert!(transport.cache().is_none());
+
+    let dir = tempfile::tempdir().unwrap();
+    let config =
+      Config { cache_dir: Some(dir.path().to_path_buf()), ..Config::default_with_key("k".into()) };
+    let transport = Transport::new(config).unwrap();
+    assert!(transport.cache().is_some());
+  }
+
+  #[test]
+  fn test_quota_file_and_audit_log_come_from_config() {
+    let transport = Transport::new(Config::default_with_key("test_key".to_string())).unwrap();
+    assert!(transport.quota_ledger().is_none());
+    assert!(transport.hooks().is_empty());
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+      quota_file: Some(dir.path().join("quota.json")),
+      audit_log: Some(dir.path().join("audit.jsonl")),
+      ..Config::default_with_key("k".into())
+    };
+    let transport = Transport::new(config).unwrap();
+    assert!(transport.quota_ledger().is_some());
+    assert_eq!(transport.hooks().len(), 1);
+  }
+
+  #[test]
+  fn test_parse_csv_listing_status() {
+    let body = "symbol,name,exchange,assetType,ipoDate,delistingDate,status\r\n\
+                IBM,International Business Machines Corp,NYSE,Stock,1962-01-02,null,Active\r\n\
+                AAA,\"Alternative Access, Inc\",NYSE ARCA,ETF,2020-09-09,null,Active\r\n";
+
+    let listing: av_models::fundamentals::ListingStatus =
+      parse_csv(body, FuncType::ListingStatus).unwrap();
+    assert_eq!(listing.data.len(), 2);
+    assert_eq!(listing.data[0].asset_type, "Stock");
+    assert_eq!(listing.data[0].delisting_date, None);
+    assert_eq!(listing.data[1].name, "Alternative Access, Inc");
+  }
+
+  #[test]
+  fn test_parse_csv_reports_line() {
+    let ragged = "symbol,name,ipoDate,priceRangeLow,priceRangeHigh,currency,exchange\n\
+                  ABC,Abc Corp,2025-01-10,10,12,USD,NASDAQ\n\
+                  DEF,Def Corp\n";
+    let err =
+      parse_csv::<av_models::fundamentals::IpoCalendar>(ragged, FuncType::IpoCalendar).unwrap_err();
+    assert!(err.to_string().contains("line: 3"), "{}", err);
+
+    let missing_column = "symbol,name,reportDate,fiscalDateEnding,estimate\n\
+                          IBM,International Business Machines Corp,2025-01-29,2024-12-31,3.78\n";
+    let err = parse_csv::<av_models::fundamentals::EarningsCalendar>(
+      missing_column,
+      FuncType::EarningsCalendar,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("CSV line 2"), "{}", err);
+    assert!(err.to_string().contains("currency"), "{}", err);
+  }
+
+  #[test]
+  fn test_parse_information_notices() {
+    let parse = |body: &str| {
+      Transport::parse_response::<HashMap<String, String>>(body, FuncType::Overview, false)
+        .unwrap_err()
+    };
+
+    let daily = r#"{"Information": "Thank you for using Alpha Vantage! Our standard API rate limit is 25 requests per day. Please subscribe to any of the premium plans at https://www.alphavantage.co/premium/ to instantly remove all daily rate limits."}"#;
+    assert!(matches!(parse(daily), Error::QuotaExhausted(_)));
+
+    let throttle = r#"{"Note": "Thank you for using Alpha Vantage! Our standard API call frequency is 5 calls per minute and 500 calls per day. Please visit https://www.alphavantage.co/premium/ if you would like to target a higher API call frequency."}"#;
+    assert!(matches!(parse(throttle), Error::RateLimit(_)));
+
+    let premium = r#"{"Information": "Thank you for using Alpha Vantage! This is a premium endpoint. You may subscribe to any of the premium plans at https://www.alphavantage.co/premium/ to instantly unlock all premium endpoints"}"#;
+    assert!(matches!(parse(premium), Error::PremiumRequired(_)));
+
+    let burst = r#"{"Information": "Please consider spreading out your free API requests more sparingly (1 request per second)."}"#;
+    assert!(matches!(parse(burst), Error::RateLimit(_)));
+
+    let key = r#"{"Error Message": "the parameter apikey is invalid or missing. Please claim your free API key on (https://www.alphavantage.co/support/#api-key)."}"#;
+    assert!(matches!(parse(key), Error::InvalidApiKey(_)));
+
+    assert!(matches!(parse(r#"{"Information": "Something new"}"#), Error::Api(_)));
+  }
+
+  #[tokio::test]
+  async fn test_key_pool_skips_rejected_key() {
+    struct ByKey;
+
+    #[async_trait]
+    impl HttpTransport for ByKey {
+      async fn send(&self, _: FuncType, params: &[(String, String)]) -> Result<RawResponse> {
+        let key = params.iter().find(|(k, _)| k == "apikey").map(|(_, v)| v.as_str());
+        Ok(RawResponse::ok(match key {
+          Some("GOODKEY") => r#"{"Symbol": "IBM"}"#,
+          _ => r#"{"Error Message": "the parameter apikey is invalid or missing."}"#,
+        }))
+      }
+    }
+
+    let mut config = Config::default_with_key("BADKEY".to_string());
+    config.key_pool = vec![av_core::ApiKeyConfig::new("GOODKEY", 600)];
+    let transport = Transport::with_backend(config, ByKey);
+
+    for _ in 0..2 {
+      let params = HashMap::from([("symbol".to_string(), "IBM".to_string())]);
+      let body: HashMap<String, String> = transport.get(FuncType::Overview, params).await.unwrap();
+      assert_eq!(body["Symbol"], "IBM");
+    }
+
+    let stats = transport.key_pool().stats();
+    assert!(stats[0].removed.is_some());
+    assert_eq!(stats[0].requests, 1);
+    assert_eq!(stats[1].requests, 2);
+    assert!(!format!("{:?}", transport).contains("GOODKEY"));
+  }
+
+  #[tokio::test]
+  async fn test_quota_ledger_carries_daily_limit_across_transports() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quota.json");
+    let config = Config { daily_limit: Some(2), ..Config::default_with_key("LIMITEDKEY".into()) };
+    let body = r#"{"Symbol": "IBM"}"#;
+
+    // Two processes' worth of transports sharing one ledger file
+    for expected in [Ok(()), Ok(()), Err(())] {
+      let fixture = Arc::new(FixtureTransport::new().with_response(FuncType::Overview, body));
+      let transport = Transport::with_backend(config.clone(), fixture.clone())
+        .with_quota_ledger(Arc::new(FileQuotaLedger::new(&path)));
+      let params = HashMap::from([("symbol".to_string(), "IBM".to_string())]);
+      let result = transport.get::<HashMap<String, String>>(FuncType::Overview, params).await;
+
+      match expected {
+        Ok(()) => assert_eq!(fixture.requests().len(), 1),
+        Err(()) => {
+          assert!(matches!(result, Err(Error::QuotaExhausted(_))));
+          assert!(fixture.requests().is_empty());
+        }
+      }
+    }
+
+    let ledger = FileQuotaLedger::new(&path);
+    let usage = ledger.usage(Utc::now().date_naive()).await.unwrap();
+    assert_eq!(usage.len(), 1);
+    let key = av_core::ApiKeyConfig::new("LIMITEDKEY", 75).ledger_key();
+    assert_eq!((usage[0].key.as_str(), usage[0].calls), (key.as_str(), 2));
+    assert!(key.starts_with("****DKEY-"));
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("LIMITEDKEY"));
+  }
+
+  #[tokio::test]
+  async fn test_cached_responses_skip_the_backend() {
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response_for(FuncType::Overview, &[("symbol", "IBM")], r#"{"Symbol": "IBM"}"#)
+        .with_response(FuncType::Overview, r#"{"Error Message": "Invalid API call."}"#),
+    );
+    let cache = Arc::new(MemoryCache::new(16));
+    let config = Config { max_retries: 0, ..Config::default_with_key("k".to_string()) };
+    let transport = Transport::with_backend(config, fixture.clone())
+      .with_cache(cache.clone())
+      .with_cache_policy(CachePolicy::new().with_ttl(FuncType::GlobalQuote, CacheTtl::NoCache));
+    let get = |function, symbol: &str| {
+      let params = vec![("symbol".to_string(), symbol.to_string())];
+      transport.get_pairs::<HashMap<String, String>>(function, params)
+    };
+
+    for _ in 0..2 {
+      assert_eq!(get(FuncType::Overview, "IBM").await.unwrap()["Symbol"], "IBM");
+    }
+    assert_eq!(fixture.requests().len(), 1);
+
+    // Errors are not cached, and neither is anything the policy excludes
+    for _ in 0..2 {
+      assert!(get(FuncType::Overview, "XXXX").await.is_err());
+      assert!(get(FuncType::GlobalQuote, "IBM").await.is_err());
+    }
+    assert_eq!(fixture.requests().len(), 5);
+    assert_eq!(cache.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_audit_log_masks_key_in_connection_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("calls.jsonl");
+    // Nothing listens on port 1, so the connection is refused
+    let config = Config {
+      base_url: "http://127.0.0.1:1/query".to_string(),
+      timeout_secs: 5,
+      max_retries: 0,
+      ..Config::default_with_key("SECRETKEY1234".to_string())
+    };
+    let transport =
+      Transport::new(config).unwrap().with_hook(Arc::new(JsonlAuditHook::new(&path).unwrap()));
+
+    let params = HashMap::from([("symbol".to_string(), "IBM".to_string())]);
+    let result = transport.get::<HashMap<String, String>>(FuncType::Overview, params).await;
+    let error = result.unwrap_err();
+    assert!(matches!(error, Error::Http(_)));
+    assert!(!error.to_string().contains("SECRETKEY1234"));
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(text.contains("Request failed"), "{}", text);
+    assert!(!text.contains("SECRETKEY1234"), "{}", text);
+  }
+
+  #[tokio::test]
+  async fn test_hooks_see_every_attempt() {
+    #[derive(Default)]
+    struct Recorder(std::sync::Mutex<Vec<String>>);
+    impl RequestHook for Recorder {
+      fn before_request(&self, call: &CallInfo) {
+        let key = call.params.iter().find(|(k, _)| k == "apikey").unwrap();
+        self.0.lock().unwrap().push(format!("before {}", key.1));
+      }
+      fn after_response(&self, _: &CallInfo, outcome: &CallOutcome) {
+        self.0.lock().unwrap().push(format!("ok {:?} {}", outcome.status, outcome.body_size));
+      }
+      fn on_error(&self, _: &CallInfo, outcome: &CallOutcome, _: &Error) {
+        self.0.lock().unwrap().push(format!("error {:?}", outcome.status));
+      }
+    }
+
+    let body = r#"{"Symbol": "IBM"}"#;
+    let fixture = FixtureTransport::new()
+      .with_response_for(FuncType::Overview, &[("symbol", "IBM")], body)
+      .with_raw_response(FuncType::Overview, &[("symbol", "DOWN")], RawResponse::new(503, ""));
+    let recorder = Arc::new(Recorder::default());
+    let metrics = Arc::new(MetricsHook::new());
+    let config = Config { max_retries: 0, ..Config::default_with_key("secret-key".to_string()) };
+    let transport = Transport::with_backend(config, fixture)
+      .with_hook(recorder.clone())
+      .with_hook(metrics.clone());
+
+    for symbol in ["IBM", "DOWN"] {
+      let params = vec![("symbol".to_string(), symbol.to_string())];
+      let _ = transport.get_pairs::<HashMap<String, String>>(FuncType::Overview, params).await;
+    }
+
+    let events = recorder.0.lock().unwrap().clone();
+    assert_eq!(events.len(), 4);
+    assert!(!events[0].contains("secret-key"));
+    assert_eq!(events[1], format!("ok Some(200) {}", body.len()));
+    assert_eq!(events[3], "error Some(503)");
+
+    let overview = metrics.function(FuncType::Overview);
+    assert_eq!((overview.requests, overview.errors, overview.completed()), (2, 1, 2));
+  }
+
+  #[tokio::test]
+  async fn test_retry_classification() {
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_raw_response(FuncType::Overview, &[("symbol", "BAD")], RawResponse::new(400, ""))
+        .with_raw_response(FuncType::Overview, &[("symbol", "DOWN")], RawResponse::new(503, ""))
+        .with_response_for(
+          FuncType::Overview,
+          &[("symbol", "BUSY")],
+          r#"{"Note": "Please consider optimizing your API call frequency."}"#,
+        )
+        .with_response_for(
+          FuncType::Overview,
+          &[("symbol", "NONE")],
+          r#"{"Error Message": "Invalid API call."}"#,
+        ),
+    );
+    let transport =
+      Transport::with_backend(Config::default_with_key("k".to_string()), fixture.clone())
+        .with_retry_policy(
+          RetryPolicy::default().with_max_retries(2).with_base_delay(Duration::ZERO),
+        );
+
+    let mut attempts = Vec::new();
+    for symbol in ["BAD", "DOWN", "BUSY", "NONE"] {
+      let before = fixture.requests().len();
+      let params = HashMap::from([("symbol".to_string(), symbol.to_string())]);
+      let err = transport.get::<HashMap<String, String>>(FuncType::Overview, params).await;
+      attempts.push((err.unwrap_err(), fixture.requests().len() - before));
+    }
+
+    assert!(matches!(attempts[0], (Error::Http(_), 1)));
+    assert!(matches!(attempts[1], (Error::Http(_), 3)));
+    assert!(matches!(attempts[2], (Error::RateLimit(_), 3)));
+    assert!(matches!(attempts[3], (Error::Api(_), 1)));
+  }
+
+  #[tokio::test]
+  async fn test_fixture_backend() {
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response(FuncType::Overview, r#"{"Error Message": "Invalid API call."}"#)
+        .with_response_for(FuncType::Overview, &[("symbol", "IBM")], r#"{"Symbol": "IBM"}"#),
+    );
+    let transport =
+      Transport::with_backend(Config::default_with_key("k".to_string()), fixture.clone());
+
+    let params = HashMap::from([("symbol".to_string(), "IBM".to_string())]);
+    let body: HashMap<String, String> = transport.get(FuncType::Overview, params).await.unwrap();
+    assert_eq!(body["Symbol"], "IBM");
+
+    let params = HashMap::from([("symbol".to_string(), "XXXX".to_string())]);
+    let err = transport.get::<HashMap<String, String>>(FuncType::Overview, params).await;
+    assert!(err.unwrap_err().to_string().contains("Invalid API call"));
+
+    let requests = fixture.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].function, FuncType::Overview);
+    assert!(!requests[0].params.contains_key("apikey"));
+  }
+
+  #[tokio::test]
+  async fn test_mock_transport() {
+    let transport = Transport::new_mock().expect("Failed to create mock transport");
+    assert_eq!(transport.base_url(), "https://mock.alphavantage.co");
+  }
+}
@@ -0,0 +1,244 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Persistent record of the calls made with each API key.
+//!
+//! The key pool's daily counters live in memory and start from zero in
+//! every process, so back-to-back runs can overrun a key's daily quota. A
+//! [`QuotaLedger`] keeps that count outside the process: [`Transport`]
+//! records every request that reached the API and, before sending, refuses
+//! to use a key whose recorded calls for the UTC day have reached its
+//! [`daily_limit`](av_core::ApiKeyConfig::daily_limit).
+//!
+//! | Ledger              | Storage                                          |
+//! |---------------------|--------------------------------------------------|
+//! | [`FileQuotaLedger`] | A JSON file, enabled by [`Config::quota_file`]   |
+//! | Custom              | Anything implementing [`QuotaLedger`], e.g. a database table |
+//!
+//! Keys are recorded by [`ApiKeyConfig::ledger_key`](av_core::ApiKeyConfig::ledger_key),
+//! the masked key plus a hash of the full key, never in full.
+//!
+//! [`Transport`]: super::Transport
+//! [`Config::quota_file`]: av_core::Config::quota_file
+
+use async_trait::async_trait;
+use av_core::{Error, FuncType, Result};
+use chrono::{Days, NaiveDate};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Days of history a [`FileQuotaLedger`] keeps, including today.
+const RETAINED_DAYS: u64 = 7;
+
+/// Calls made with one key to one function on one UTC day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotaUsage {
+  pub date: NaiveDate,
+  /// The API key's [`ledger_key`](av_core::ApiKeyConfig::ledger_key)
+  pub key: String,
+  /// The API function name, e.g. `TIME_SERIES_DAILY`
+  pub function: String,
+  pub calls: u32,
+}
+
+/// Storage for per-key, per-function daily call counts.
+#[async_trait]
+pub trait QuotaLedger: Send + Sync {
+  /// Count one call made with `key` (a ledger key) to `function` on `date`.
+  async fn record(&self, date: NaiveDate, key: &str, function: FuncType) -> Result<()>;
+
+  /// Every count recorded for `date`.
+  async fn usage(&self, date: NaiveDate) -> Result<Vec<QuotaUsage>>;
+
+  /// Total calls made with `key` on `date`.
+  async fn calls(&self, date: NaiveDate, key: &str) -> Result<u32> {
+    Ok(self.usage(date).await?.iter().filter(|u| u.key == key).map(|u| u.calls).sum())
+  }
+}
+
+/// Day → ledger key → function → calls
+type Counts = BTreeMap<String, BTreeMap<String, BTreeMap<String, u32>>>;
+
+/// [`QuotaLedger`] stored in a JSON file.
+///
+/// Every call re-reads and rewrites the file, so processes sharing it see
+/// each other's counts; two processes writing at the same instant may lose
+/// an increment. Days older than a week are dropped on write. The file is
+/// read and written on tokio's blocking thread pool.
+#[derive(Debug)]
+pub struct FileQuotaLedger {
+  path: PathBuf,
+  lock: Arc<Mutex<()>>,
+}
+
+impl FileQuotaLedger {
+  pub fn new(path: impl Into<PathBuf>) -> Self {
+    Self { path: path.into(), lock: Arc::new(Mutex::new(())) }
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Run `f` on the ledger file on the blocking pool, holding this
+  /// ledger's lock so its own read-modify-write cycles do not interleave
+  async fn locked<R, F>(&self, f: F) -> Result<R>
+  where
+    R: Send + 'static,
+    F: FnOnce(&Path) -> Result<R> + Send + 'static,
+  {
+    let (path, lock) = (self.path.clone(), Arc::clone(&self.lock));
+    super::blocking_io(move || {
+      let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+      f(&path)
+    })
+    .await
+  }
+}
+
+fn load(path: &Path) -> Result<Counts> {
+  let text = match std::fs::read_to_string(path) {
+    Ok(text) => text,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Counts::new()),
+    Err(e) => {
+      return Err(Error::Config(format!("Failed to read quota ledger {}: {}", path.display(), e)));
+    }
+  };
+  serde_json::from_str(&text)
+    .map_err(|e| Error::Config(format!("Invalid quota ledger {}: {}", path.display(), e)))
+}
+
+fn save(path: &Path, counts: &Counts) -> Result<()> {
+  let write = || -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+      std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(counts).map_err(std::io::Error::other)?;
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)
+  };
+  write()
+    .map_err(|e| Error::Config(format!("Failed to write quota ledger {}: {}", path.display(), e)))
+}
+
+#[async_trait]
+impl QuotaLedger for FileQuotaLedger {
+  async fn record(&self, date: NaiveDate, key: &str, function: FuncType) -> Result<()> {
+    let key = key.to_string();
+    self
+      .locked(move |path| {
+        let mut counts = load(path)?;
+        *counts
+          .entry(day(date))
+          .or_default()
+          .entry(key)
+          .or_default()
+          .entry(function.to_string())
+          .or_default() += 1;
+
+        // ISO dates compare chronologically as strings
+        let oldest = day(date.checked_sub_days(Days::new(RETAINED_DAYS - 1)).unwrap_or(date));
+        counts.retain(|d, _| *d >= oldest);
+        save(path, &counts)
+      })
+      .await
+  }
+
+  async fn usage(&self, date: NaiveDate) -> Result<Vec<QuotaUsage>> {
+    let counts = self.locked(load).await?;
+    let Some(keys) = counts.get(&day(date)) else {
+      return Ok(Vec::new());
+    };
+    Ok(
+      keys
+        .iter()
+        .flat_map(|(key, functions)| {
+          functions.iter().map(move |(function, calls)| QuotaUsage {
+            date,
+            key: key.clone(),
+            function: function.clone(),
+            calls: *calls,
+          })
+        })
+        .collect(),
+    )
+  }
+}
+
+fn day(date: NaiveDate) -> String {
+  date.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_file_ledger_counts_survive_reopening() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quota.json");
+    let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+    let ledger = FileQuotaLedger::new(&path);
+    ledger.record(today, "****AAAA", FuncType::GlobalQuote).await.unwrap();
+    ledger.record(today, "****AAAA", FuncType::GlobalQuote).await.unwrap();
+    ledger.record(today, "****AAAA", FuncType::Overview).await.unwrap();
+    ledger.record(today, "****BBBB", FuncType::Overview).await.unwrap();
+
+    let reopened = FileQuotaLedger::new(&path);
+    assert_eq!(reopened.calls(today, "****AAAA").await.unwrap(), 3);
+    assert_eq!(reopened.calls(today, "****BBBB").await.unwrap(), 1);
+    assert_eq!(reopened.calls(today.succ_opt().unwrap(), "****AAAA").await.unwrap(), 0);
+
+    let usage = reopened.usage(today).await.unwrap();
+    assert_eq!(usage.len(), 3);
+    assert!(usage.contains(&QuotaUsage {
+      date: today,
+      key: "****AAAA".to_string(),
+      function: "GLOBAL_QUOTE".to_string(),
+      calls: 2,
+    }));
+  }
+
+  #[tokio::test]
+  async fn test_file_ledger_drops_old_days() {
+    let dir = tempfile::tempdir().unwrap();
+    let ledger = FileQuotaLedger::new(dir.path().join("quota.json"));
+    let old = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+    let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+    ledger.record(old, "****AAAA", FuncType::GlobalQuote).await.unwrap();
+    ledger.record(today, "****AAAA", FuncType::GlobalQuote).await.unwrap();
+
+    assert!(ledger.usage(old).await.unwrap().is_empty());
+    assert_eq!(ledger.calls(today, "****AAAA").await.unwrap(), 1);
+  }
+}
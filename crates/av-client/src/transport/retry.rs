@@ -0,0 +1,184 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Retry policy for [`Transport`](super::Transport).
+//!
+//! | Failure                                   | Retried | Delay                        |
+//! |-------------------------------------------|---------|------------------------------|
+//! | No response (connection error, timeout)   | yes     | backoff                      |
+//! | HTTP 5xx, 408, 429                        | yes     | `Retry-After`, else backoff  |
+//! | In-body rate-limit note (`RateLimit`)     | yes     | `Retry-After`, else backoff  |
+//! | Other HTTP 4xx                            | no      |                              |
+//! | `Error Message` in the body (`Api`)       | no      |                              |
+//! | Premium endpoint, daily quota, bad key    | no      |                              |
+//! | Undecodable body (`Parse`), `Config`      | no      |                              |
+//!
+//! Backoff doubles from [`RetryPolicy::base_delay`] up to
+//! [`RetryPolicy::max_delay`]; with jitter enabled each delay is scaled by a
+//! random factor in `[0.5, 1.0]` so that parallel loaders do not retry in
+//! lockstep. A server's `Retry-After` is capped at `max_delay` too, so a
+//! misbehaving server or proxy cannot stall a request for hours.
+
+use av_core::Config;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+/// When and how often [`Transport`](super::Transport) retries a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+  /// Retries after the first attempt; `0` disables retrying
+  pub max_retries: u32,
+  /// Delay before the first retry
+  pub base_delay: Duration,
+  /// Upper bound for any delay, including a server's `Retry-After`
+  pub max_delay: Duration,
+  /// Randomize each backoff delay
+  pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self {
+      max_retries: 3,
+      base_delay: Duration::from_secs(1),
+      max_delay: Duration::from_secs(60),
+      jitter: true,
+    }
+  }
+}
+
+impl RetryPolicy {
+  /// Default policy with [`Config::max_retries`] retries.
+  pub fn from_config(config: &Config) -> Self {
+    Self { max_retries: config.max_retries, ..Self::default() }
+  }
+
+  /// A policy that never retries.
+  pub fn none() -> Self {
+    Self { max_retries: 0, ..Self::default() }
+  }
+
+  pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+    self.max_retries = max_retries;
+    self
+  }
+
+  pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+    self.base_delay = base_delay;
+    self
+  }
+
+  pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+    self.max_delay = max_delay;
+    self
+  }
+
+  pub fn with_jitter(mut self, jitter: bool) -> Self {
+    self.jitter = jitter;
+    self
+  }
+
+  /// Whether a response with this HTTP status is worth retrying.
+  pub fn is_retryable_status(status: u16) -> bool {
+    status >= 500 || status == 408 || status == 429
+  }
+
+  /// Backoff before retry number `retry` (starting at 1), without jitter.
+  pub fn backoff(&self, retry: u32) -> Duration {
+    let factor = 2_u32.saturating_pow(retry.saturating_sub(1));
+    self.base_delay.saturating_mul(factor).min(self.max_delay)
+  }
+
+  /// Delay before retry number `retry`: the server's `Retry-After` when it
+  /// sent one, otherwise the (jittered) backoff. Either way it is at most
+  /// [`max_delay`](Self::max_delay).
+  pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+    if let Some(retry_after) = retry_after {
+      return retry_after.min(self.max_delay);
+    }
+    let backoff = self.backoff(retry);
+    if self.jitter { backoff.mul_f64(0.5 + random_unit() / 2.0) } else { backoff }
+  }
+}
+
+/// A value in `[0, 1)`. `RandomState` is seeded randomly per instance, which
+/// is plenty for spreading out retries without pulling in `rand`.
+fn random_unit() -> f64 {
+  let hash = RandomState::new().build_hasher().finish();
+  (hash >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+/// Parse a `Retry-After` header: either delay seconds or an HTTP date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+  let value = value.trim();
+  if let Ok(secs) = value.parse::<u64>() {
+    return Some(Duration::from_secs(secs));
+  }
+  let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+  let at = SystemTime::from(at);
+  // A date in the past means "retry now"
+  Some(at.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_backoff_doubles_and_caps() {
+    let policy = RetryPolicy::default().with_max_delay(Duration::from_secs(5)).with_jitter(false);
+
+    assert_eq!(policy.delay(1, None), Duration::from_secs(1));
+    assert_eq!(policy.delay(2, None), Duration::from_secs(2));
+    assert_eq!(policy.delay(3, None), Duration::from_secs(4));
+    assert_eq!(policy.delay(4, None), Duration::from_secs(5));
+    assert_eq!(policy.delay(40, None), Duration::from_secs(5));
+    assert_eq!(policy.delay(2, Some(Duration::from_secs(3))), Duration::from_secs(3));
+    assert_eq!(policy.delay(2, Some(Duration::from_secs(30))), Duration::from_secs(5));
+    let default = RetryPolicy::default();
+    assert_eq!(default.delay(1, Some(Duration::from_secs(86_400))), Duration::from_secs(60));
+  }
+
+  #[test]
+  fn test_jitter_bounds() {
+    let policy = RetryPolicy::default();
+    for _ in 0..100 {
+      let delay = policy.delay(3, None);
+      assert!(delay >= Duration::from_secs(2) && delay <= Duration::from_secs(4), "{delay:?}");
+    }
+  }
+
+  #[test]
+  fn test_parse_retry_after() {
+    assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+    assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+    assert_eq!(parse_retry_after("soon"), None);
+  }
+}
@@ -1 +1,62 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
 
+//! Offline client tests that replay the cassettes in `tests/cassettes`.
+//!
+//! To refresh a cassette, rerun the request against the live API with
+//! `AV_CASSETTE_MODE=record AV_CASSETTE_DIR=tests/cassettes` and a real
+//! `ALPHA_VANTAGE_API_KEY`.
+
+use av_client::{AlphaVantageClient, CassetteTransport};
+use av_core::{Config, Error};
+use std::path::PathBuf;
+
+fn cassette_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("cassettes")
+}
+
+fn replay_client() -> AlphaVantageClient {
+  let config = Config::default_with_key("replay".to_string());
+  AlphaVantageClient::with_transport(config, CassetteTransport::replay(cassette_dir())).unwrap()
+}
+
+#[tokio::test]
+async fn test_replay_global_quote() {
+  let quote = replay_client().time_series().quote("IBM").await.unwrap();
+
+  assert_eq!(quote.global_quote.symbol, "IBM");
+  assert_eq!(quote.global_quote.latest_trading_day, "2025-07-03");
+}
+
+#[tokio::test]
+async fn test_strict_replay_rejects_unrecorded_request() {
+  let err = replay_client().time_series().quote("NOT_RECORDED").await.unwrap_err();
+
+  assert!(matches!(err, Error::Config(_)), "unexpected error: {err}");
+}
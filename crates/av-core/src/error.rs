@@ -56,13 +56,14 @@
 //!
 //! # Error categories
 //!
-//! | Category        | Variants                           | Typical cause                              |
-//! |-----------------|------------------------------------|--------------------------------------------|
-//! | **Setup**       | `EnvVar`, `Config`, `ApiKey`       | Missing or malformed configuration         |
-//! | **Parsing**     | `Serde`, `ParseDate`, `Parse`      | Malformed JSON, dates, or numeric values   |
-//! | **Validation**  | `MissingField`, `InvalidResponse`  | API response missing expected data         |
-//! | **Runtime**     | `RateLimit`, `Http`, `Api`         | Network/transport or API-level failures    |
-//! | **Catch-all**   | `Unexpected`                       | Anything that doesn't fit above            |
+//! | Category       | Variants                                             | Typical cause                            |
+//! |----------------|------------------------------------------------------|------------------------------------------|
+//! | **Setup**      | `EnvVar`, `Config`, `ApiKey`                         | Missing or malformed configuration       |
+//! | **Parsing**    | `Serde`, `ParseDate`, `Parse`                        | Malformed JSON, dates, or numeric values |
+//! | **Validation** | `MissingField`, `InvalidResponse`                    | API response missing expected data       |
+//! | **Runtime**    | `RateLimit`, `Http`, `Api`                           | Network/transport or API-level failures  |
+//! | **Access**     | `PremiumRequired`, `QuotaExhausted`, `InvalidApiKey` | Plan, quota or key rejected by the API   |
+//! | **Catch-all**  | `Unexpected`                                         | Anything that doesn't fit above          |
 //!
 //! # Examples
 //!
@@ -138,9 +139,10 @@ pub enum Error {
 
   /// The API rate limit has been exceeded.
   ///
-  /// Alpha Vantage returns a specific JSON message when the per-minute or
-  /// per-day request cap is hit. The `String` payload contains details
-  /// about the limit that was exceeded.
+  /// Alpha Vantage returns a specific JSON message when the per-minute
+  /// request cap is hit. The `String` payload contains details about the
+  /// limit that was exceeded. Waiting a few seconds is usually enough; the
+  /// per-day cap is reported as [`QuotaExhausted`](Error::QuotaExhausted).
   #[error("Rate limit exceeded: {0}")]
   RateLimit(String),
 
@@ -174,6 +176,24 @@ pub enum Error {
   #[error("API error: {0}")]
   Api(String),
 
+  /// The requested function is only available on a premium plan.
+  ///
+  /// Reported through the `"Information"` key. Retrying will not help;
+  /// other functions can still be called with the same key.
+  #[error("Premium endpoint required: {0}")]
+  PremiumRequired(String),
+
+  /// The API key has used up its daily request allowance.
+  ///
+  /// Every further request with this key fails until the quota resets, so
+  /// batch jobs should stop rather than continue with the next symbol.
+  #[error("Daily API quota exhausted: {0}")]
+  QuotaExhausted(String),
+
+  /// The API rejected the key as invalid or missing.
+  #[error("Invalid API key: {0}")]
+  InvalidApiKey(String),
+
   /// A generic value-parsing error.
   ///
   /// Used when converting string fields to numeric types (e.g., parsing a
@@ -183,6 +203,17 @@ pub enum Error {
   Parse(String),
 }
 
+impl Error {
+  /// Whether every later request with the same API key will fail the same
+  /// way ([`QuotaExhausted`](Error::QuotaExhausted) or
+  /// [`InvalidApiKey`](Error::InvalidApiKey)).
+  ///
+  /// Loaders use this to stop a run early instead of failing once per symbol.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, Error::QuotaExhausted(_) | Error::InvalidApiKey(_))
+  }
+}
+
 /// Convenience alias for `std::result::Result<T, av_core::error::Error>`.
 ///
 /// Used throughout the crate and re-exported at the crate root as
@@ -247,6 +278,16 @@ mod tests {
     assert_eq!(err.to_string(), "Parse error: invalid number");
   }
 
+  #[test]
+  fn test_error_access_variants() {
+    let err = Error::QuotaExhausted("25 requests per day".to_string());
+    assert_eq!(err.to_string(), "Daily API quota exhausted: 25 requests per day");
+    assert!(err.is_terminal());
+    assert!(Error::InvalidApiKey("apikey is invalid".to_string()).is_terminal());
+    assert!(!Error::PremiumRequired("premium endpoint".to_string()).is_terminal());
+    assert!(!Error::RateLimit("1 request per second".to_string()).is_terminal());
+  }
+
   #[test]
   fn test_error_from_env_var() {
     let env_err = std::env::VarError::NotPresent;
@@ -41,8 +41,9 @@
 //! | Export                            | Description                                                            |
 //! |-----------------------------------|------------------------------------------------------------------------|
 //! | [`Config`]                        | API configuration: key, rate limits, timeouts, base URL. Loadable from environment variables or constructed directly. |
+//! | [`ConfigFile`] / [`Profile`]      | TOML config file with named profiles, layered under the environment. |
 //! | [`Error`] / [`Result`]            | Unified error enum covering env-var, config, serde, date-parse, rate-limit, and API response errors. |
-//! | [`FuncType`]                      | Type-safe enum of all supported Alpha Vantage API function names (time series, fundamentals, news, forex, crypto, search). |
+//! | [`FuncType`]                      | Type-safe enum of all supported Alpha Vantage API function names (time series, fundamentals, news, forex, crypto, technical indicators, search). |
 //! | [`types`]                         | Shared domain types: exchanges, security types, intervals, currencies, sentiment labels, and more. |
 //! | [`ALPHA_VANTAGE_BASE_URL`]        | The canonical API endpoint (`https://www.alphavantage.co/query`).      |
 //! | [`DEFAULT_RATE_LIMIT`] / [`PREMIUM_RATE_LIMIT`] | Request-per-minute caps for free (75) and premium (600) API tiers. |
@@ -71,11 +72,14 @@
 //! av-core/src/
 //! ├── lib.rs          ← this file: FuncType, constants, re-exports
 //! ├── config.rs       → Config (API key, rate limit, timeout, retries, base URL)
+//! ├── profile.rs      → ConfigFile, Profile (TOML config file with profiles)
 //! ├── error.rs        → Error enum, Result type alias
+//! ├── hash.rs         → fnv1a (stable names for ledger keys and cache files)
 //! ├── types/
 //! │   ├── mod.rs      → re-export façade
-//! │   ├── common.rs   → DataType, Interval, OutputSize, SortOrder, TimeHorizon,
-//! │   │                  ListingState, SentimentLabel, CurrencyCode, CryptoSymbol
+//! │   ├── common.rs   → DataType, Interval, OutputSize, IndicatorInterval,
+//! │   │                  SeriesType, SortOrder, TimeHorizon, ListingState,
+//! │   │                  SentimentLabel, CurrencyCode, CryptoSymbol
 //! │   └── market/
 //! │       ├── mod.rs            → re-export façade
 //! │       ├── exchange.rs       → Exchange (25 global exchanges)
@@ -89,6 +93,14 @@
 /// See [`Config`] for the primary export.
 pub mod config;
 
+/// Config files with named profiles, applied underneath the environment.
+///
+/// See [`ConfigFile`] and [`Profile`].
+pub mod profile;
+
+/// Stable FNV-1a hashing for ledger keys and file names.
+pub mod hash;
+
 /// Unified error types for the crate.
 ///
 /// See [`Error`] for the error enum and [`Result`] for the convenience alias.
@@ -106,7 +118,10 @@ pub mod types;
 // `use av_core::{Config, Error, Result}` without navigating sub-modules.
 
 /// Re-exported from [`config`].
-pub use config::Config;
+pub use config::{ApiKeyConfig, Config, HttpConfig, HttpVersion, KeySelection};
+
+/// Re-exported from [`profile`].
+pub use profile::{ConfigFile, Profile, ProviderKeys};
 
 /// Re-exported from [`error`].
 pub use error::{Error, Result};
@@ -131,20 +146,27 @@ pub use error::{Error, Result};
 /// | `TimeSeriesWeeklyAdjusted`  | `TIME_SERIES_WEEKLY_ADJUSTED`       | Weekly adjusted bars                     |
 /// | `TimeSeriesMonthly`         | `TIME_SERIES_MONTHLY`               | Monthly aggregated bars                  |
 /// | `TimeSeriesMonthlyAdjusted` | `TIME_SERIES_MONTHLY_ADJUSTED`      | Monthly adjusted bars                    |
+/// | `GlobalQuote`               | `GLOBAL_QUOTE`                      | Latest price and change for one symbol   |
+/// | `RealtimeBulkQuotes`        | `REALTIME_BULK_QUOTES`              | Latest quotes for up to 100 symbols      |
 ///
 /// ## Fundamentals (company data & calendars)
 ///
-/// | Variant              | API function string      | Description                              |
-/// |----------------------|--------------------------|------------------------------------------|
-/// | `Overview`           | `OVERVIEW`               | Company profile, key metrics, description|
-/// | `IncomeStatement`    | `INCOME_STATEMENT`       | Annual & quarterly income statements     |
-/// | `BalanceSheet`       | `BALANCE_SHEET`          | Annual & quarterly balance sheets        |
-/// | `CashFlow`           | `CASH_FLOW`              | Annual & quarterly cash flow statements  |
-/// | `Earnings`           | `EARNINGS`               | Annual & quarterly earnings (EPS)        |
-/// | `TopGainersLosers`   | `TOP_GAINERS_LOSERS`     | Top movers by percent change             |
-/// | `ListingStatus`      | `LISTING_STATUS`         | Active/delisted securities listing       |
-/// | `EarningsCalendar`   | `EARNINGS_CALENDAR`      | Upcoming earnings dates                  |
-/// | `IpoCalendar`        | `IPO_CALENDAR`           | Upcoming IPO dates                       |
+/// | Variant                  | API function string        | Description                                  |
+/// |--------------------------|----------------------------|----------------------------------------------|
+/// | `Overview`               | `OVERVIEW`                 | Company profile, key metrics, description    |
+/// | `IncomeStatement`        | `INCOME_STATEMENT`         | Annual & quarterly income statements         |
+/// | `BalanceSheet`           | `BALANCE_SHEET`            | Annual & quarterly balance sheets            |
+/// | `CashFlow`               | `CASH_FLOW`                | Annual & quarterly cash flow statements      |
+/// | `Earnings`               | `EARNINGS`                 | Annual & quarterly earnings (EPS)            |
+/// | `Dividends`              | `DIVIDENDS`                | Historical and declared dividends            |
+/// | `Splits`                 | `SPLITS`                   | Historical stock splits                      |
+/// | `InsiderTransactions`    | `INSIDER_TRANSACTIONS`     | Executive and director trades (Form 4)       |
+/// | `EarningsCallTranscript` | `EARNINGS_CALL_TRANSCRIPT` | Call transcript with per-speaker sentiment   |
+/// | `EtfProfile`             | `ETF_PROFILE`              | ETF key metrics, holdings and sector weights |
+/// | `TopGainersLosers`       | `TOP_GAINERS_LOSERS`       | Top movers by percent change                 |
+/// | `ListingStatus`          | `LISTING_STATUS`           | Active/delisted securities listing           |
+/// | `EarningsCalendar`       | `EARNINGS_CALENDAR`        | Upcoming earnings dates                      |
+/// | `IpoCalendar`            | `IPO_CALENDAR`             | Upcoming IPO dates                           |
 ///
 /// ## News
 ///
@@ -172,6 +194,63 @@ pub use error::{Error, Result};
 /// | `CryptoWeekly`       | `DIGITAL_CURRENCY_WEEKLY`    | Weekly crypto OHLCV                  |
 /// | `CryptoMonthly`      | `DIGITAL_CURRENCY_MONTHLY`   | Monthly crypto OHLCV                 |
 ///
+/// ## Technical indicators
+///
+/// | Variant  | API function string | Description                                   |
+/// |----------|---------------------|-----------------------------------------------|
+/// | `Sma`    | `SMA`               | Simple moving average                         |
+/// | `Ema`    | `EMA`               | Exponential moving average                    |
+/// | `Rsi`    | `RSI`               | Relative strength index                       |
+/// | `Macd`   | `MACD`              | Moving average convergence/divergence         |
+/// | `Bbands` | `BBANDS`            | Bollinger bands                               |
+/// | `Adx`    | `ADX`               | Average directional movement index            |
+/// | `Stoch`  | `STOCH`             | Stochastic oscillator (slow %K / %D)          |
+/// | `Atr`    | `ATR`               | Average true range                            |
+/// | `Obv`    | `OBV`               | On-balance volume                             |
+/// | `Vwap`   | `VWAP`              | Volume-weighted average price (intraday only) |
+///
+/// ## Economic indicators
+///
+/// | Variant            | API function string  | Description                                 |
+/// |--------------------|----------------------|---------------------------------------------|
+/// | `RealGdp`          | `REAL_GDP`           | US real GDP (annual or quarterly)           |
+/// | `Cpi`              | `CPI`                | Consumer price index (monthly or semiannual) |
+/// | `Inflation`        | `INFLATION`          | Annual US inflation rate                    |
+/// | `TreasuryYield`    | `TREASURY_YIELD`     | Treasury yield for a given maturity         |
+/// | `FederalFundsRate` | `FEDERAL_FUNDS_RATE` | Effective federal funds rate                |
+/// | `Unemployment`     | `UNEMPLOYMENT`       | Monthly unemployment rate                   |
+/// | `NonfarmPayroll`   | `NONFARM_PAYROLL`    | Monthly total nonfarm payroll               |
+///
+/// ## Commodities
+///
+/// | Variant          | API function string | Description                           |
+/// |------------------|---------------------|---------------------------------------|
+/// | `Wti`            | `WTI`               | West Texas Intermediate crude oil     |
+/// | `Brent`          | `BRENT`             | Brent crude oil                       |
+/// | `NaturalGas`     | `NATURAL_GAS`       | Henry Hub natural gas spot            |
+/// | `Copper`         | `COPPER`            | Global copper price                   |
+/// | `Aluminum`       | `ALUMINUM`          | Global aluminum price                 |
+/// | `Wheat`          | `WHEAT`             | Global wheat price                    |
+/// | `Corn`           | `CORN`              | Global corn price                     |
+/// | `Cotton`         | `COTTON`            | Global cotton price                   |
+/// | `Sugar`          | `SUGAR`             | Global sugar price                    |
+/// | `Coffee`         | `COFFEE`            | Global coffee price                   |
+/// | `AllCommodities` | `ALL_COMMODITIES`   | Global price index of all commodities |
+///
+/// ## Options
+///
+/// | Variant             | API function string  | Description                                |
+/// |---------------------|----------------------|--------------------------------------------|
+/// | `RealtimeOptions`   | `REALTIME_OPTIONS`   | Live option chain, Greeks on request       |
+/// | `HistoricalOptions` | `HISTORICAL_OPTIONS` | End-of-day option chain with Greeks and IV |
+///
+/// ## Analytics
+///
+/// | Variant                  | API function string        | Description                                    |
+/// |--------------------------|----------------------------|------------------------------------------------|
+/// | `AnalyticsFixedWindow`   | `ANALYTICS_FIXED_WINDOW`   | Return statistics over one fixed date range    |
+/// | `AnalyticsSlidingWindow` | `ANALYTICS_SLIDING_WINDOW` | Rolling return statistics over a moving window |
+///
 /// ## Market status & search
 ///
 /// | Variant        | API function string | Description                              |
@@ -210,6 +289,10 @@ pub enum FuncType {
   TimeSeriesMonthly,
   /// Monthly OHLCV adjusted for splits and dividends.
   TimeSeriesMonthlyAdjusted,
+  /// Latest price, volume, and change for a single symbol.
+  GlobalQuote,
+  /// Latest quotes for up to 100 symbols in one call (premium endpoint).
+  RealtimeBulkQuotes,
 
   // ── Fundamentals ──────────────────────────────────────────────────────
   /// Company overview: profile, key metrics, and description.
@@ -222,6 +305,16 @@ pub enum FuncType {
   CashFlow,
   /// Annual and quarterly EPS (earnings per share).
   Earnings,
+  /// Historical and declared future dividend distributions.
+  Dividends,
+  /// Historical stock split events.
+  Splits,
+  /// Latest and historical trades by company insiders.
+  InsiderTransactions,
+  /// Earnings call transcript for one fiscal quarter, split by speaker.
+  EarningsCallTranscript,
+  /// ETF net assets, expense ratio, holdings, and sector allocation.
+  EtfProfile,
   /// Top gainers, losers, and most actively traded tickers.
   TopGainersLosers,
   /// Active and delisted securities listing.
@@ -260,6 +353,80 @@ pub enum FuncType {
   /// Monthly cryptocurrency OHLCV (uses `DIGITAL_CURRENCY_MONTHLY` endpoint).
   CryptoMonthly,
 
+  // ── Technical Indicators ──────────────────────────────────────────────
+  /// Simple moving average over `time_period` bars.
+  Sma,
+  /// Exponential moving average over `time_period` bars.
+  Ema,
+  /// Relative strength index (momentum oscillator, 0–100).
+  Rsi,
+  /// MACD line, signal line, and histogram.
+  Macd,
+  /// Bollinger bands (upper, middle, lower).
+  Bbands,
+  /// Average directional movement index (trend strength).
+  Adx,
+  /// Stochastic oscillator (slow %K and %D lines).
+  Stoch,
+  /// Average true range (volatility).
+  Atr,
+  /// On-balance volume.
+  Obv,
+  /// Volume-weighted average price. Only valid for intraday intervals.
+  Vwap,
+
+  // ── Economic Indicators ───────────────────────────────────────────────
+  /// US real gross domestic product.
+  RealGdp,
+  /// Consumer price index for all urban consumers.
+  Cpi,
+  /// Annual US inflation rate (consumer prices).
+  Inflation,
+  /// US treasury yield for a given [`TreasuryMaturity`](types::TreasuryMaturity).
+  TreasuryYield,
+  /// Effective federal funds interest rate.
+  FederalFundsRate,
+  /// Monthly US unemployment rate.
+  Unemployment,
+  /// Monthly US total nonfarm payroll.
+  NonfarmPayroll,
+
+  // ── Commodities ───────────────────────────────────────────────────────
+  /// West Texas Intermediate (WTI) crude oil prices.
+  Wti,
+  /// Brent (Europe) crude oil prices.
+  Brent,
+  /// Henry Hub natural gas spot prices.
+  NaturalGas,
+  /// Global price of copper.
+  Copper,
+  /// Global price of aluminum.
+  Aluminum,
+  /// Global price of wheat.
+  Wheat,
+  /// Global price of corn.
+  Corn,
+  /// Global price of cotton.
+  Cotton,
+  /// Global price of sugar.
+  Sugar,
+  /// Global price of coffee.
+  Coffee,
+  /// Global price index of all commodities.
+  AllCommodities,
+
+  // ── Options ───────────────────────────────────────────────────────────
+  /// Realtime option chain for an underlying (premium endpoint).
+  RealtimeOptions,
+  /// End-of-day option chain for an underlying on a given trading date.
+  HistoricalOptions,
+
+  // ── Analytics ─────────────────────────────────────────────────────────
+  /// Multi-symbol return statistics (mean, variance, correlation, ...) over a fixed range.
+  AnalyticsFixedWindow,
+  /// Multi-symbol return statistics computed over a sliding window.
+  AnalyticsSlidingWindow,
+
   // ── Market status & search ────────────────────────────────────────────
   /// Current open/closed state of global exchanges.
   MarketStatus,
@@ -282,6 +449,16 @@ pub enum FuncType {
   CryptoIntraDay,
 }
 
+impl FuncType {
+  /// Whether the endpoint always responds with CSV, regardless of `datatype`.
+  ///
+  /// `LISTING_STATUS`, `EARNINGS_CALENDAR`, and `IPO_CALENDAR` have no JSON
+  /// form. Error and rate-limit responses from them are still JSON.
+  pub fn returns_csv(&self) -> bool {
+    matches!(self, FuncType::ListingStatus | FuncType::EarningsCalendar | FuncType::IpoCalendar)
+  }
+}
+
 /// Produces the Alpha Vantage `function=` query-string value for each variant.
 ///
 /// Legacy aliases emit the same string as their modern counterparts:
@@ -299,6 +476,8 @@ impl std::fmt::Display for FuncType {
       FuncType::TimeSeriesWeeklyAdjusted => write!(f, "TIME_SERIES_WEEKLY_ADJUSTED"),
       FuncType::TimeSeriesMonthly => write!(f, "TIME_SERIES_MONTHLY"),
       FuncType::TimeSeriesMonthlyAdjusted => write!(f, "TIME_SERIES_MONTHLY_ADJUSTED"),
+      FuncType::GlobalQuote => write!(f, "GLOBAL_QUOTE"),
+      FuncType::RealtimeBulkQuotes => write!(f, "REALTIME_BULK_QUOTES"),
 
       // Fundamentals functions
       FuncType::Overview => write!(f, "OVERVIEW"),
@@ -307,6 +486,11 @@ impl std::fmt::Display for FuncType {
       FuncType::CashFlow => write!(f, "CASH_FLOW"),
       FuncType::Earnings => write!(f, "EARNINGS"),
       FuncType::TopGainersLosers => write!(f, "TOP_GAINERS_LOSERS"),
+      FuncType::Dividends => write!(f, "DIVIDENDS"),
+      FuncType::Splits => write!(f, "SPLITS"),
+      FuncType::InsiderTransactions => write!(f, "INSIDER_TRANSACTIONS"),
+      FuncType::EarningsCallTranscript => write!(f, "EARNINGS_CALL_TRANSCRIPT"),
+      FuncType::EtfProfile => write!(f, "ETF_PROFILE"),
       FuncType::ListingStatus => write!(f, "LISTING_STATUS"),
       FuncType::EarningsCalendar => write!(f, "EARNINGS_CALENDAR"),
       FuncType::IpoCalendar => write!(f, "IPO_CALENDAR"),
@@ -331,6 +515,48 @@ impl std::fmt::Display for FuncType {
       FuncType::CryptoWeekly => write!(f, "DIGITAL_CURRENCY_WEEKLY"),
       FuncType::CryptoMonthly => write!(f, "DIGITAL_CURRENCY_MONTHLY"),
 
+      // Technical indicators
+      FuncType::Sma => write!(f, "SMA"),
+      FuncType::Ema => write!(f, "EMA"),
+      FuncType::Rsi => write!(f, "RSI"),
+      FuncType::Macd => write!(f, "MACD"),
+      FuncType::Bbands => write!(f, "BBANDS"),
+      FuncType::Adx => write!(f, "ADX"),
+      FuncType::Stoch => write!(f, "STOCH"),
+      FuncType::Atr => write!(f, "ATR"),
+      FuncType::Obv => write!(f, "OBV"),
+      FuncType::Vwap => write!(f, "VWAP"),
+
+      // Economic indicators
+      FuncType::RealGdp => write!(f, "REAL_GDP"),
+      FuncType::Cpi => write!(f, "CPI"),
+      FuncType::Inflation => write!(f, "INFLATION"),
+      FuncType::TreasuryYield => write!(f, "TREASURY_YIELD"),
+      FuncType::FederalFundsRate => write!(f, "FEDERAL_FUNDS_RATE"),
+      FuncType::Unemployment => write!(f, "UNEMPLOYMENT"),
+      FuncType::NonfarmPayroll => write!(f, "NONFARM_PAYROLL"),
+
+      // Commodities
+      FuncType::Wti => write!(f, "WTI"),
+      FuncType::Brent => write!(f, "BRENT"),
+      FuncType::NaturalGas => write!(f, "NATURAL_GAS"),
+      FuncType::Copper => write!(f, "COPPER"),
+      FuncType::Aluminum => write!(f, "ALUMINUM"),
+      FuncType::Wheat => write!(f, "WHEAT"),
+      FuncType::Corn => write!(f, "CORN"),
+      FuncType::Cotton => write!(f, "COTTON"),
+      FuncType::Sugar => write!(f, "SUGAR"),
+      FuncType::Coffee => write!(f, "COFFEE"),
+      FuncType::AllCommodities => write!(f, "ALL_COMMODITIES"),
+
+      // Options
+      FuncType::RealtimeOptions => write!(f, "REALTIME_OPTIONS"),
+      FuncType::HistoricalOptions => write!(f, "HISTORICAL_OPTIONS"),
+
+      // Analytics
+      FuncType::AnalyticsFixedWindow => write!(f, "ANALYTICS_FIXED_WINDOW"),
+      FuncType::AnalyticsSlidingWindow => write!(f, "ANALYTICS_SLIDING_WINDOW"),
+
       // Market status and search
       FuncType::MarketStatus => write!(f, "MARKET_STATUS"),
       FuncType::SymbolSearch => write!(f, "SYMBOL_SEARCH"),
@@ -410,6 +636,16 @@ mod tests {
     assert_eq!(FuncType::TimeSeriesMonthlyAdjusted.to_string(), "TIME_SERIES_MONTHLY_ADJUSTED");
   }
 
+  #[test]
+  fn test_func_type_global_quote() {
+    assert_eq!(FuncType::GlobalQuote.to_string(), "GLOBAL_QUOTE");
+  }
+
+  #[test]
+  fn test_func_type_realtime_bulk_quotes() {
+    assert_eq!(FuncType::RealtimeBulkQuotes.to_string(), "REALTIME_BULK_QUOTES");
+  }
+
   // Fundamentals function tests
   #[test]
   fn test_func_type_overview() {
@@ -456,6 +692,31 @@ mod tests {
     assert_eq!(FuncType::IpoCalendar.to_string(), "IPO_CALENDAR");
   }
 
+  #[test]
+  fn test_func_type_dividends() {
+    assert_eq!(FuncType::Dividends.to_string(), "DIVIDENDS");
+  }
+
+  #[test]
+  fn test_func_type_splits() {
+    assert_eq!(FuncType::Splits.to_string(), "SPLITS");
+  }
+
+  #[test]
+  fn test_func_type_insider_transactions() {
+    assert_eq!(FuncType::InsiderTransactions.to_string(), "INSIDER_TRANSACTIONS");
+  }
+
+  #[test]
+  fn test_func_type_earnings_call_transcript() {
+    assert_eq!(FuncType::EarningsCallTranscript.to_string(), "EARNINGS_CALL_TRANSCRIPT");
+  }
+
+  #[test]
+  fn test_func_type_etf_profile() {
+    assert_eq!(FuncType::EtfProfile.to_string(), "ETF_PROFILE");
+  }
+
   // News function tests
   #[test]
   fn test_func_type_news_sentiment() {
@@ -520,6 +781,171 @@ mod tests {
     assert_eq!(FuncType::CryptoMonthly.to_string(), "DIGITAL_CURRENCY_MONTHLY");
   }
 
+  // Technical indicator tests
+  #[test]
+  fn test_func_type_sma() {
+    assert_eq!(FuncType::Sma.to_string(), "SMA");
+  }
+
+  #[test]
+  fn test_func_type_ema() {
+    assert_eq!(FuncType::Ema.to_string(), "EMA");
+  }
+
+  #[test]
+  fn test_func_type_rsi() {
+    assert_eq!(FuncType::Rsi.to_string(), "RSI");
+  }
+
+  #[test]
+  fn test_func_type_macd() {
+    assert_eq!(FuncType::Macd.to_string(), "MACD");
+  }
+
+  #[test]
+  fn test_func_type_bbands() {
+    assert_eq!(FuncType::Bbands.to_string(), "BBANDS");
+  }
+
+  #[test]
+  fn test_func_type_adx() {
+    assert_eq!(FuncType::Adx.to_string(), "ADX");
+  }
+
+  #[test]
+  fn test_func_type_stoch() {
+    assert_eq!(FuncType::Stoch.to_string(), "STOCH");
+  }
+
+  #[test]
+  fn test_func_type_atr() {
+    assert_eq!(FuncType::Atr.to_string(), "ATR");
+  }
+
+  #[test]
+  fn test_func_type_obv() {
+    assert_eq!(FuncType::Obv.to_string(), "OBV");
+  }
+
+  #[test]
+  fn test_func_type_vwap() {
+    assert_eq!(FuncType::Vwap.to_string(), "VWAP");
+  }
+
+  // Economic indicator tests
+  #[test]
+  fn test_func_type_real_gdp() {
+    assert_eq!(FuncType::RealGdp.to_string(), "REAL_GDP");
+  }
+
+  #[test]
+  fn test_func_type_cpi() {
+    assert_eq!(FuncType::Cpi.to_string(), "CPI");
+  }
+
+  #[test]
+  fn test_func_type_inflation() {
+    assert_eq!(FuncType::Inflation.to_string(), "INFLATION");
+  }
+
+  #[test]
+  fn test_func_type_treasury_yield() {
+    assert_eq!(FuncType::TreasuryYield.to_string(), "TREASURY_YIELD");
+  }
+
+  #[test]
+  fn test_func_type_federal_funds_rate() {
+    assert_eq!(FuncType::FederalFundsRate.to_string(), "FEDERAL_FUNDS_RATE");
+  }
+
+  #[test]
+  fn test_func_type_unemployment() {
+    assert_eq!(FuncType::Unemployment.to_string(), "UNEMPLOYMENT");
+  }
+
+  #[test]
+  fn test_func_type_nonfarm_payroll() {
+    assert_eq!(FuncType::NonfarmPayroll.to_string(), "NONFARM_PAYROLL");
+  }
+
+  // Commodity tests
+  #[test]
+  fn test_func_type_wti() {
+    assert_eq!(FuncType::Wti.to_string(), "WTI");
+  }
+
+  #[test]
+  fn test_func_type_brent() {
+    assert_eq!(FuncType::Brent.to_string(), "BRENT");
+  }
+
+  #[test]
+  fn test_func_type_natural_gas() {
+    assert_eq!(FuncType::NaturalGas.to_string(), "NATURAL_GAS");
+  }
+
+  #[test]
+  fn test_func_type_copper() {
+    assert_eq!(FuncType::Copper.to_string(), "COPPER");
+  }
+
+  #[test]
+  fn test_func_type_aluminum() {
+    assert_eq!(FuncType::Aluminum.to_string(), "ALUMINUM");
+  }
+
+  #[test]
+  fn test_func_type_wheat() {
+    assert_eq!(FuncType::Wheat.to_string(), "WHEAT");
+  }
+
+  #[test]
+  fn test_func_type_corn() {
+    assert_eq!(FuncType::Corn.to_string(), "CORN");
+  }
+
+  #[test]
+  fn test_func_type_cotton() {
+    assert_eq!(FuncType::Cotton.to_string(), "COTTON");
+  }
+
+  #[test]
+  fn test_func_type_sugar() {
+    assert_eq!(FuncType::Sugar.to_string(), "SUGAR");
+  }
+
+  #[test]
+  fn test_func_type_coffee() {
+    assert_eq!(FuncType::Coffee.to_string(), "COFFEE");
+  }
+
+  #[test]
+  fn test_func_type_all_commodities() {
+    assert_eq!(FuncType::AllCommodities.to_string(), "ALL_COMMODITIES");
+  }
+
+  // Options tests
+  #[test]
+  fn test_func_type_realtime_options() {
+    assert_eq!(FuncType::RealtimeOptions.to_string(), "REALTIME_OPTIONS");
+  }
+
+  #[test]
+  fn test_func_type_historical_options() {
+    assert_eq!(FuncType::HistoricalOptions.to_string(), "HISTORICAL_OPTIONS");
+  }
+
+  // Analytics tests
+  #[test]
+  fn test_func_type_analytics_fixed_window() {
+    assert_eq!(FuncType::AnalyticsFixedWindow.to_string(), "ANALYTICS_FIXED_WINDOW");
+  }
+
+  #[test]
+  fn test_func_type_analytics_sliding_window() {
+    assert_eq!(FuncType::AnalyticsSlidingWindow.to_string(), "ANALYTICS_SLIDING_WINDOW");
+  }
+
   // Market status and search tests
   #[test]
   fn test_func_type_market_status() {
@@ -531,6 +957,15 @@ mod tests {
     assert_eq!(FuncType::SymbolSearch.to_string(), "SYMBOL_SEARCH");
   }
 
+  #[test]
+  fn test_func_type_returns_csv() {
+    assert!(FuncType::ListingStatus.returns_csv());
+    assert!(FuncType::EarningsCalendar.returns_csv());
+    assert!(FuncType::IpoCalendar.returns_csv());
+    assert!(!FuncType::Overview.returns_csv());
+    assert!(!FuncType::TimeSeriesDaily.returns_csv());
+  }
+
   // Legacy support tests
   #[test]
   fn test_func_type_legacy_ts_intra() {
@@ -561,6 +996,7 @@ mod tests {
   #[test]
   fn test_func_type_clone() {
     let original = FuncType::TimeSeriesDaily;
+    #[allow(clippy::clone_on_copy)]
     let cloned = original.clone();
     assert_eq!(original, cloned);
   }
@@ -608,6 +1044,7 @@ mod tests {
   }
 
   #[test]
+  #[allow(clippy::assertions_on_constants)]
   fn test_premium_rate_limit() {
     assert_eq!(PREMIUM_RATE_LIMIT, 600);
     assert!(PREMIUM_RATE_LIMIT > DEFAULT_RATE_LIMIT);
@@ -0,0 +1,514 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Config files with named profiles.
+//!
+//! Settings can live in a TOML file, by default `~/.config/av/config.toml`
+//! (or under `$XDG_CONFIG_HOME`), instead of the environment. Top-level keys
+//! apply to every profile; a `[profiles.<name>]` table overrides them, with
+//! nested tables such as `[http]` merged key by key:
+//!
+//! ```toml
+//! profile = "free"    # used when no profile is requested
+//!
+//! database_url = "postgres://av@localhost/alphavantage"
+//! nasdaq_listed = "./data/nasdaq-listed.csv"
+//!
+//! [providers]
+//! coingecko = "CG-..."
+//! github = "ghp_..."
+//!
+//! [profiles.free]
+//! api_key = "FREEKEY"
+//!
+//! [profiles.premium]
+//! api_key = "PREMIUMKEY"
+//! rate_limit = 600
+//!
+//! [profiles.premium.http]
+//! proxy_url = "http://proxy.corp:3128"
+//! ```
+//!
+//! A resolved [`Profile`] is the lowest layer: [`Profile::env_vars`] names
+//! the environment variable each setting stands for, and a variable that is
+//! set in the environment wins over the file.
+//! [`Config::from_profile`](crate::Config::from_profile) reads the
+//! environment with the profile's values filling the gaps, without changing
+//! the environment itself.
+//!
+//! | Key                     | Variable                  |
+//! |-------------------------|---------------------------|
+//! | `api_key`               | `ALPHA_VANTAGE_API_KEY`   |
+//! | `key_pool`              | `ALPHA_VANTAGE_API_KEYS`  |
+//! | `key_selection`         | `AV_KEY_SELECTION`        |
+//! | `rate_limit`            | `AV_RATE_LIMIT`           |
+//! | `daily_limit`           | `AV_DAILY_LIMIT`          |
+//! | `timeout_secs`          | `AV_TIMEOUT_SECS`         |
+//! | `max_retries`           | `AV_MAX_RETRIES`          |
+//! | `base_url`              | `AV_BASE_URL`             |
+//! | `http.proxy_url`        | `AV_PROXY_URL`            |
+//! | `http.default_headers`  | `AV_DEFAULT_HEADERS`      |
+//! | `http.root_certificates`| `AV_ROOT_CERTS`           |
+//! | `http.pool_max_idle_per_host` | `AV_POOL_SIZE`      |
+//! | `http.http_version`     | `AV_HTTP_VERSION`         |
+//! | `cache_dir`             | `AV_CACHE_DIR`            |
+//! | `quota_file`            | `AV_QUOTA_FILE`           |
+//! | `quota_ledger`          | `AV_QUOTA_LEDGER` (CLI)   |
+//! | `response_cache`        | `AV_RESPONSE_CACHE` (CLI) |
+//! | `audit_log`             | `AV_AUDIT_LOG`            |
+//! | `database_url`          | `DATABASE_URL`            |
+//! | `nasdaq_listed`         | `NASDAQ_LISTED`           |
+//! | `other_listed`          | `OTHER_LISTED`            |
+//! | `providers.coingecko`   | `COINGECKO_API_KEY`       |
+//! | `providers.coinmarketcap` | `CMC_API_KEY`           |
+//! | `providers.github`      | `GITHUB_TOKEN`            |
+//! | `providers.sosovalue`   | `SOSOVALUE_API_KEY`       |
+//!
+//! The file is taken from `AV_CONFIG_FILE` when set, and the profile from
+//! `AV_PROFILE`. Cassette recording (`AV_CASSETTE_MODE`, `AV_CASSETTE_DIR`)
+//! is a test-harness switch and is only read from the environment.
+
+use crate::config::{ApiKeyConfig, HttpConfig, KeySelection};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the config file
+pub const CONFIG_FILE_ENV: &str = "AV_CONFIG_FILE";
+
+/// Environment variable naming the profile to use
+pub const PROFILE_ENV: &str = "AV_PROFILE";
+
+/// A parsed config file: shared settings and named profiles.
+#[derive(Clone, Default)]
+pub struct ConfigFile {
+  path: Option<PathBuf>,
+  default_profile: Option<String>,
+  shared: Map<String, Value>,
+  profiles: BTreeMap<String, Map<String, Value>>,
+}
+
+/// Every setting a config file profile can hold. Unset fields leave the
+/// environment (and then the built-in defaults) in charge.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+  pub api_key: Option<String>,
+  pub key_pool: Vec<ApiKeyConfig>,
+  pub key_selection: Option<KeySelection>,
+  pub rate_limit: Option<u32>,
+  pub daily_limit: Option<u32>,
+  pub timeout_secs: Option<u64>,
+  pub max_retries: Option<u32>,
+  pub base_url: Option<String>,
+  pub http: Option<HttpConfig>,
+  pub cache_dir: Option<PathBuf>,
+  pub quota_file: Option<PathBuf>,
+  /// Where the CLI keeps its call ledger, e.g. `postgres` or `off`
+  pub quota_ledger: Option<String>,
+  /// Where the CLI caches API responses besides `cache_dir`, e.g. `postgres`
+  pub response_cache: Option<String>,
+  pub audit_log: Option<PathBuf>,
+  pub database_url: Option<String>,
+  pub nasdaq_listed: Option<String>,
+  pub other_listed: Option<String>,
+  pub providers: ProviderKeys,
+}
+
+/// Keys for the non-AlphaVantage data sources used by the crypto loaders.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderKeys {
+  pub coingecko: Option<String>,
+  pub coinmarketcap: Option<String>,
+  pub github: Option<String>,
+  pub sosovalue: Option<String>,
+}
+
+impl ConfigFile {
+  /// `$XDG_CONFIG_HOME/av/config.toml`, falling back to
+  /// `$HOME/.config/av/config.toml`
+  pub fn default_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+      .filter(|dir| !dir.is_empty())
+      .map(PathBuf::from)
+      .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("av").join("config.toml"))
+  }
+
+  /// Load the config file at `path`, or else at `AV_CONFIG_FILE`, or else
+  /// at [`default_path`](Self::default_path).
+  ///
+  /// Returns `Ok(None)` if no path was given and the default file does not
+  /// exist.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if an explicitly named file is missing or any
+  /// file cannot be read or parsed.
+  pub fn discover(path: Option<&Path>) -> Result<Option<Self>> {
+    let explicit =
+      path.map(Path::to_path_buf).or_else(|| env::var_os(CONFIG_FILE_ENV).map(PathBuf::from));
+    match explicit {
+      Some(path) => Self::load(&path).map(Some),
+      None => match Self::default_path() {
+        Some(path) if path.is_file() => Self::load(&path).map(Some),
+        _ => Ok(None),
+      },
+    }
+  }
+
+  /// Read and parse the config file at `path`
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] if the file cannot be read or parsed.
+  pub fn load(path: &Path) -> Result<Self> {
+    let text = std::fs::read_to_string(path)
+      .map_err(|e| Error::Config(format!("Cannot read config file {}: {}", path.display(), e)))?;
+    let mut file = Self::parse(&text)
+      .map_err(|e| Error::Config(format!("In config file {}: {}", path.display(), e)))?;
+    file.path = Some(path.to_path_buf());
+    Ok(file)
+  }
+
+  /// Parse config file contents
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] for invalid TOML, a non-string `profile` key
+  /// or a `profiles` entry that is not a table.
+  pub fn parse(text: &str) -> Result<Self> {
+    let document: toml_edit::DocumentMut =
+      text.parse().map_err(|e| Error::Config(format!("Invalid TOML: {}", e)))?;
+    let mut shared = table_to_json(document.as_table());
+
+    let default_profile = match shared.remove("profile") {
+      Some(Value::String(name)) => Some(name),
+      Some(_) => return Err(Error::Config("'profile' must be a string".to_string())),
+      None => None,
+    };
+
+    let mut profiles = BTreeMap::new();
+    match shared.remove("profiles") {
+      Some(Value::Object(tables)) => {
+        for (name, table) in tables {
+          match table {
+            Value::Object(table) => profiles.insert(name, table),
+            _ => return Err(Error::Config(format!("Profile '{}' must be a table", name))),
+          };
+        }
+      }
+      Some(_) => return Err(Error::Config("'profiles' must be a table".to_string())),
+      None => {}
+    }
+
+    Ok(Self { path: None, default_profile, shared, profiles })
+  }
+
+  /// Where the file was loaded from, if it came from disk
+  pub fn path(&self) -> Option<&Path> {
+    self.path.as_deref()
+  }
+
+  /// The profile used when none is requested
+  pub fn default_profile(&self) -> Option<&str> {
+    self.default_profile.as_deref()
+  }
+
+  pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+    self.profiles.keys().map(String::as_str)
+  }
+
+  /// The shared settings overlaid with profile `name`, or with the file's
+  /// default profile when `name` is `None` (just the shared settings if it
+  /// has none).
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::Config`] for an unknown profile or a setting of the
+  /// wrong type.
+  pub fn profile(&self, name: Option<&str>) -> Result<Profile> {
+    let mut merged = Value::Object(self.shared.clone());
+    if let Some(name) = name.or(self.default_profile.as_deref()) {
+      let overrides = self.profiles.get(name).ok_or_else(|| {
+        let known: Vec<_> = self.profile_names().collect();
+        Error::Config(format!("Unknown profile '{}' (known: {})", name, known.join(", ")))
+      })?;
+      merge(&mut merged, Value::Object(overrides.clone()));
+    }
+    serde_json::from_value(merged).map_err(|e| match name.or(self.default_profile.as_deref()) {
+      Some(name) => Error::Config(format!("Invalid setting in profile '{}': {}", name, e)),
+      None => Error::Config(format!("Invalid setting: {}", e)),
+    })
+  }
+}
+
+impl Profile {
+  /// The environment variables this profile sets, in the formats
+  /// [`Config::from_env`](crate::Config::from_env) and the CLI read
+  pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+    let mut vars = Vec::new();
+    let mut set = |name: &'static str, value: Option<String>| {
+      if let Some(value) = value {
+        vars.push((name, value));
+      }
+    };
+
+    set("ALPHA_VANTAGE_API_KEY", self.api_key.clone());
+    set(
+      "ALPHA_VANTAGE_API_KEYS",
+      (!self.key_pool.is_empty()).then(|| key_pool_spec(&self.key_pool)),
+    );
+    set("AV_KEY_SELECTION", self.key_selection.map(|s| enum_name(&s)));
+    set("AV_RATE_LIMIT", self.rate_limit.map(|v| v.to_string()));
+    set("AV_DAILY_LIMIT", self.daily_limit.map(|v| v.to_string()));
+    set("AV_TIMEOUT_SECS", self.timeout_secs.map(|v| v.to_string()));
+    set("AV_MAX_RETRIES", self.max_retries.map(|v| v.to_string()));
+    set("AV_BASE_URL", self.base_url.clone());
+    if let Some(http) = &self.http {
+      set("AV_PROXY_URL", http.proxy_url.clone());
+      set(
+        "AV_DEFAULT_HEADERS",
+        (!http.default_headers.is_empty()).then(|| {
+          http
+            .default_headers
+            .iter()
+            .map(|(k, v)| format!("{}: {}", k, v))
+            .collect::<Vec<_>>()
+            .join(";")
+        }),
+      );
+      set(
+        "AV_ROOT_CERTS",
+        env::join_paths(&http.root_certificates)
+          .ok()
+          .filter(|paths| !paths.is_empty())
+          .map(|paths| paths.to_string_lossy().into_owned()),
+      );
+      set("AV_POOL_SIZE", http.pool_max_idle_per_host.map(|v| v.to_string()));
+      set("AV_HTTP_VERSION", Some(enum_name(&http.http_version)).filter(|v| v != "auto"));
+    }
+    let path = |path: &Option<PathBuf>| path.as_ref().map(|p| p.to_string_lossy().into_owned());
+    set("AV_CACHE_DIR", path(&self.cache_dir));
+    set("AV_QUOTA_FILE", path(&self.quota_file));
+    set("AV_QUOTA_LEDGER", self.quota_ledger.clone());
+    set("AV_RESPONSE_CACHE", self.response_cache.clone());
+    set("AV_AUDIT_LOG", path(&self.audit_log));
+    set("DATABASE_URL", self.database_url.clone());
+    set("NASDAQ_LISTED", self.nasdaq_listed.clone());
+    set("OTHER_LISTED", self.other_listed.clone());
+    set("COINGECKO_API_KEY", self.providers.coingecko.clone());
+    set("CMC_API_KEY", self.providers.coinmarketcap.clone());
+    set("GITHUB_TOKEN", self.providers.github.clone());
+    set("SOSOVALUE_API_KEY", self.providers.sosovalue.clone());
+    vars
+  }
+}
+
+// Settings include API keys and tokens, so Debug output only names them
+
+impl fmt::Debug for ConfigFile {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ConfigFile")
+      .field("path", &self.path)
+      .field("default_profile", &self.default_profile)
+      .field("shared", &self.shared.keys().collect::<Vec<_>>())
+      .field("profiles", &self.profiles.keys().collect::<Vec<_>>())
+      .finish()
+  }
+}
+
+impl fmt::Debug for Profile {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let names: Vec<_> = self.env_vars().into_iter().map(|(name, _)| name).collect();
+    f.debug_struct("Profile").field("sets", &names).finish()
+  }
+}
+
+/// `key:per_minute[:per_day]` entries, as [`ApiKeyConfig::parse_list`] reads
+fn key_pool_spec(keys: &[ApiKeyConfig]) -> String {
+  keys
+    .iter()
+    .map(|k| match k.daily_limit {
+      Some(daily) => format!("{}:{}:{}", k.key, k.rate_limit, daily),
+      None => format!("{}:{}", k.key, k.rate_limit),
+    })
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// The serde name of a unit enum variant
+fn enum_name<T: Serialize>(value: &T) -> String {
+  match serde_json::to_value(value) {
+    Ok(Value::String(name)) => name,
+    _ => String::new(),
+  }
+}
+
+/// Overlay `overrides` onto `base`, merging tables key by key
+fn merge(base: &mut Value, overrides: Value) {
+  match (base, overrides) {
+    (Value::Object(base), Value::Object(overrides)) => {
+      for (key, value) in overrides {
+        match base.get_mut(&key) {
+          Some(existing) => merge(existing, value),
+          None => {
+            base.insert(key, value);
+          }
+        }
+      }
+    }
+    (base, value) => *base = value,
+  }
+}
+
+fn table_to_json<'a>(
+  entries: impl IntoIterator<Item = (&'a str, &'a toml_edit::Item)>,
+) -> Map<String, Value> {
+  entries
+    .into_iter()
+    .filter_map(|(key, item)| item_to_json(item).map(|value| (key.to_string(), value)))
+    .collect()
+}
+
+fn item_to_json(item: &toml_edit::Item) -> Option<Value> {
+  match item {
+    toml_edit::Item::None => None,
+    toml_edit::Item::Value(value) => Some(value_to_json(value)),
+    toml_edit::Item::Table(table) => Some(Value::Object(table_to_json(table.iter()))),
+    toml_edit::Item::ArrayOfTables(tables) => {
+      Some(Value::Array(tables.iter().map(|t| Value::Object(table_to_json(t.iter()))).collect()))
+    }
+  }
+}
+
+fn value_to_json(value: &toml_edit::Value) -> Value {
+  match value {
+    toml_edit::Value::String(s) => Value::from(s.value().as_str()),
+    toml_edit::Value::Integer(i) => Value::from(*i.value()),
+    toml_edit::Value::Float(f) => Value::from(*f.value()),
+    toml_edit::Value::Boolean(b) => Value::from(*b.value()),
+    toml_edit::Value::Datetime(d) => Value::from(d.value().to_string()),
+    toml_edit::Value::Array(array) => Value::Array(array.iter().map(value_to_json).collect()),
+    toml_edit::Value::InlineTable(table) => Value::Object(
+      table.iter().map(|(key, value)| (key.to_string(), value_to_json(value))).collect(),
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const FILE: &str = r#"
+profile = "free"
+database_url = "postgres://localhost/av"
+rate_limit = 75
+cache_dir = "/var/cache/av"
+audit_log = "/var/log/av/calls.jsonl"
+
+[http]
+default_headers = { "X-Team" = "quant" }
+
+[providers]
+coingecko = "CG-1"
+
+[profiles.free]
+api_key = "FREEKEY"
+
+[profiles.premium]
+api_key = "PREMIUMKEY"
+rate_limit = 600
+key_pool = [{ key = "SPARE", rate_limit = 5, daily_limit = 25 }]
+quota_file = "/var/lib/av/quota.json"
+quota_ledger = "off"
+response_cache = "postgres"
+
+[profiles.premium.http]
+proxy_url = "http://proxy.corp:3128"
+http_version = "http1"
+"#;
+
+  #[test]
+  fn test_profiles_overlay_shared_settings() {
+    let file = ConfigFile::parse(FILE).unwrap();
+    assert_eq!(file.profile_names().collect::<Vec<_>>(), ["free", "premium"]);
+
+    let free = file.profile(None).unwrap();
+    assert_eq!(free.api_key.as_deref(), Some("FREEKEY"));
+    assert_eq!(free.rate_limit, Some(75));
+
+    let premium = file.profile(Some("premium")).unwrap();
+    assert_eq!(premium.rate_limit, Some(600));
+    assert_eq!(premium.database_url.as_deref(), Some("postgres://localhost/av"));
+    let http = premium.http.as_ref().unwrap();
+    assert_eq!(http.default_headers["X-Team"], "quant");
+    assert_eq!(http.proxy_url.as_deref(), Some("http://proxy.corp:3128"));
+
+    assert!(file.profile(Some("prod")).unwrap_err().to_string().contains("free, premium"));
+  }
+
+  #[test]
+  fn test_profile_env_vars_round_trip() {
+    let premium = ConfigFile::parse(FILE).unwrap().profile(Some("premium")).unwrap();
+    let vars: BTreeMap<_, _> = premium.env_vars().into_iter().collect();
+
+    assert_eq!(vars["ALPHA_VANTAGE_API_KEY"], "PREMIUMKEY");
+    assert_eq!(vars["AV_RATE_LIMIT"], "600");
+    assert_eq!(vars["AV_HTTP_VERSION"], "http1");
+    assert_eq!(vars["COINGECKO_API_KEY"], "CG-1");
+    assert_eq!(vars["AV_CACHE_DIR"], "/var/cache/av");
+    assert_eq!(vars["AV_QUOTA_FILE"], "/var/lib/av/quota.json");
+    assert_eq!(vars["AV_QUOTA_LEDGER"], "off");
+    assert_eq!(vars["AV_RESPONSE_CACHE"], "postgres");
+    assert_eq!(vars["AV_AUDIT_LOG"], "/var/log/av/calls.jsonl");
+    assert_eq!(
+      ApiKeyConfig::parse_list(&vars["ALPHA_VANTAGE_API_KEYS"], 75).unwrap(),
+      premium.key_pool
+    );
+    assert_eq!(HttpConfig::parse_headers(&vars["AV_DEFAULT_HEADERS"]).unwrap()["X-Team"], "quant");
+    assert!(!vars.contains_key("GITHUB_TOKEN"));
+  }
+
+  #[test]
+  fn test_invalid_files_are_rejected() {
+    assert!(ConfigFile::parse("rate_limit = ").is_err());
+    assert!(ConfigFile::parse("profile = 3").is_err());
+
+    let typo = ConfigFile::parse("[profiles.dev]\nrate_limt = 5").unwrap();
+    assert!(typo.profile(Some("dev")).is_err());
+    let wrong_type = ConfigFile::parse("rate_limit = \"fast\"").unwrap();
+    assert!(wrong_type.profile(None).is_err());
+  }
+}
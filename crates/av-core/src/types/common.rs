@@ -41,7 +41,11 @@
 //! | [`DataType`]      | 2        | Response format: JSON or CSV                 |
 //! | [`Interval`]      | 5        | Intraday bar width (1–60 minutes)            |
 //! | [`OutputSize`]    | 2        | Result set size: compact (100) or full (20y) |
+//! | [`YearMonth`]     | —        | Calendar month for intraday history (`YYYY-MM`) |
+//! | [`IndicatorInterval`] | 8    | Bar width for technical indicators (1min–monthly) |
+//! | [`SeriesType`]    | 4        | Price field an indicator is computed from    |
 //! | [`SortOrder`]     | 3        | News/search result ordering                  |
+//! | [`NewsTopic`]     | 15       | News sentiment topic filter                  |
 //! | [`TimeHorizon`]   | 3        | Calendar data look-ahead period              |
 //! | [`ListingState`]  | 2        | Active vs. delisted security status          |
 //! | [`SentimentLabel`]| 3        | News sentiment: bullish/neutral/bearish      |
@@ -102,6 +106,19 @@ impl std::fmt::Display for DataType {
   }
 }
 
+/// Parses `"json"` or `"csv"`. Case-insensitive.
+impl FromStr for DataType {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "json" => Ok(DataType::Json),
+      "csv" => Ok(DataType::Csv),
+      _ => Err(format!("Invalid data type: {}", s)),
+    }
+  }
+}
+
 /// Intraday bar interval for time-series queries.
 ///
 /// Used with the `TIME_SERIES_INTRADAY` Alpha Vantage endpoint to specify
@@ -225,6 +242,380 @@ impl std::fmt::Display for OutputSize {
   }
 }
 
+/// Parses `"compact"` or `"full"`. Case-insensitive.
+impl FromStr for OutputSize {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "compact" => Ok(OutputSize::Compact),
+      "full" => Ok(OutputSize::Full),
+      _ => Err(format!("Invalid output size: {}", s)),
+    }
+  }
+}
+
+/// A calendar month, sent as the intraday `month` parameter.
+///
+/// Intraday history is requested one month at a time; the API serves
+/// months from [`YearMonth::FIRST_INTRADAY`] (January 2000) onwards.
+///
+/// # Display output
+///
+/// Produces the API value `"YYYY-MM"`, e.g. `"2009-01"`. `FromStr` and
+/// serde use the same format, so a stored month is checked on the way back
+/// in.
+///
+/// # Examples
+///
+/// ```rust
+/// use av_core::types::common::YearMonth;
+///
+/// let month: YearMonth = "2023-12".parse().unwrap();
+/// assert_eq!(month.next().to_string(), "2024-01");
+/// assert!(YearMonth::new(2023, 13).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+  year: i32,
+  month: u32,
+}
+
+impl YearMonth {
+  /// The earliest month the intraday endpoints serve.
+  pub const FIRST_INTRADAY: YearMonth = YearMonth { year: 2000, month: 1 };
+
+  /// Returns `None` unless `month` is 1–12 and `year` has four digits.
+  pub fn new(year: i32, month: u32) -> Option<Self> {
+    ((1000..=9999).contains(&year) && (1..=12).contains(&month)).then_some(Self { year, month })
+  }
+
+  /// The month containing `date`.
+  pub fn of(date: chrono::NaiveDate) -> Self {
+    use chrono::Datelike;
+    Self { year: date.year(), month: date.month() }
+  }
+
+  pub fn year(&self) -> i32 {
+    self.year
+  }
+
+  pub fn month(&self) -> u32 {
+    self.month
+  }
+
+  /// The following month.
+  pub fn next(&self) -> Self {
+    if self.month == 12 {
+      Self { year: self.year + 1, month: 1 }
+    } else {
+      Self { year: self.year, month: self.month + 1 }
+    }
+  }
+
+  /// The first day of the month.
+  pub fn first_day(&self) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(self.year, self.month, 1)
+      .expect("YearMonth always holds a valid month")
+  }
+}
+
+/// Formats as `"YYYY-MM"`.
+impl std::fmt::Display for YearMonth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{:04}-{:02}", self.year, self.month)
+  }
+}
+
+/// Parses `"YYYY-MM"`.
+impl FromStr for YearMonth {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || format!("Invalid month (expected YYYY-MM): {}", s);
+    let (year, month) = s.trim().split_once('-').ok_or_else(invalid)?;
+    if year.len() != 4 || month.len() != 2 {
+      return Err(invalid());
+    }
+    let year = year.parse().map_err(|_| invalid())?;
+    let month = month.parse().map_err(|_| invalid())?;
+    Self::new(year, month).ok_or_else(invalid)
+  }
+}
+
+/// Serializes as `"YYYY-MM"`.
+impl Serialize for YearMonth {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+/// Deserializes from `"YYYY-MM"`, rejecting months outside 1–12.
+impl<'de> Deserialize<'de> for YearMonth {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    text.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+/// Bar width for technical indicator endpoints.
+///
+/// Technical indicators accept every intraday [`Interval`] plus the daily,
+/// weekly, and monthly aggregations. An intraday [`Interval`] converts
+/// losslessly via `From`.
+///
+/// # Display output
+///
+/// Produces the API value: `"1min"` … `"60min"`, `"daily"`, `"weekly"`, or
+/// `"monthly"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndicatorInterval {
+  /// 1-minute bars.
+  Min1,
+  /// 5-minute bars.
+  Min5,
+  /// 15-minute bars.
+  Min15,
+  /// 30-minute bars.
+  Min30,
+  /// 60-minute bars.
+  Min60,
+  /// Daily bars.
+  Daily,
+  /// Weekly bars.
+  Weekly,
+  /// Monthly bars.
+  Monthly,
+}
+
+/// Formats as the API query-string value (e.g., `"daily"`).
+impl std::fmt::Display for IndicatorInterval {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      IndicatorInterval::Min1 => write!(f, "1min"),
+      IndicatorInterval::Min5 => write!(f, "5min"),
+      IndicatorInterval::Min15 => write!(f, "15min"),
+      IndicatorInterval::Min30 => write!(f, "30min"),
+      IndicatorInterval::Min60 => write!(f, "60min"),
+      IndicatorInterval::Daily => write!(f, "daily"),
+      IndicatorInterval::Weekly => write!(f, "weekly"),
+      IndicatorInterval::Monthly => write!(f, "monthly"),
+    }
+  }
+}
+
+/// Parses an indicator interval from the API string format.
+///
+/// Returns `Err` for unrecognized strings. Parsing is case-sensitive.
+impl FromStr for IndicatorInterval {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "daily" => Ok(IndicatorInterval::Daily),
+      "weekly" => Ok(IndicatorInterval::Weekly),
+      "monthly" => Ok(IndicatorInterval::Monthly),
+      other => other
+        .parse::<Interval>()
+        .map(IndicatorInterval::from)
+        .map_err(|_| format!("Invalid indicator interval: {}", s)),
+    }
+  }
+}
+
+impl From<Interval> for IndicatorInterval {
+  fn from(interval: Interval) -> Self {
+    match interval {
+      Interval::Min1 => IndicatorInterval::Min1,
+      Interval::Min5 => IndicatorInterval::Min5,
+      Interval::Min15 => IndicatorInterval::Min15,
+      Interval::Min30 => IndicatorInterval::Min30,
+      Interval::Min60 => IndicatorInterval::Min60,
+    }
+  }
+}
+
+impl IndicatorInterval {
+  /// Returns `true` for the minute-based intervals.
+  pub fn is_intraday(&self) -> bool {
+    !matches!(
+      self,
+      IndicatorInterval::Daily | IndicatorInterval::Weekly | IndicatorInterval::Monthly
+    )
+  }
+}
+
+/// The price field a technical indicator is computed from.
+///
+/// Sent as the `series_type` query parameter for indicators such as SMA,
+/// EMA, RSI, MACD, and BBANDS.
+///
+/// # Display output
+///
+/// Produces the lowercase API value: `"close"`, `"open"`, `"high"`, or `"low"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SeriesType {
+  /// Closing price (the usual choice).
+  Close,
+  /// Opening price.
+  Open,
+  /// Bar high.
+  High,
+  /// Bar low.
+  Low,
+}
+
+/// Formats as the API query-string value (e.g., `"close"`).
+impl std::fmt::Display for SeriesType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SeriesType::Close => write!(f, "close"),
+      SeriesType::Open => write!(f, "open"),
+      SeriesType::High => write!(f, "high"),
+      SeriesType::Low => write!(f, "low"),
+    }
+  }
+}
+
+/// Parses a series type from its API string. Case-insensitive.
+impl FromStr for SeriesType {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "close" => Ok(SeriesType::Close),
+      "open" => Ok(SeriesType::Open),
+      "high" => Ok(SeriesType::High),
+      "low" => Ok(SeriesType::Low),
+      _ => Err(format!("Invalid series type: {}", s)),
+    }
+  }
+}
+
+/// Sampling interval for economic indicator and commodity series.
+///
+/// Each macro endpoint accepts only a subset of these; for example `REAL_GDP`
+/// supports `Annual` and `Quarterly`, while `TREASURY_YIELD` supports `Daily`,
+/// `Weekly`, and `Monthly`.
+///
+/// # Display output
+///
+/// Produces the lowercase API value: `"daily"`, `"weekly"`, `"monthly"`,
+/// `"quarterly"`, `"semiannual"`, or `"annual"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EconomicInterval {
+  /// Daily observations.
+  Daily,
+  /// Weekly observations.
+  Weekly,
+  /// Monthly observations.
+  Monthly,
+  /// Quarterly observations.
+  Quarterly,
+  /// Semiannual observations (CPI only).
+  Semiannual,
+  /// Annual observations.
+  Annual,
+}
+
+/// Formats as the API query-string value (e.g., `"quarterly"`).
+impl std::fmt::Display for EconomicInterval {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      EconomicInterval::Daily => write!(f, "daily"),
+      EconomicInterval::Weekly => write!(f, "weekly"),
+      EconomicInterval::Monthly => write!(f, "monthly"),
+      EconomicInterval::Quarterly => write!(f, "quarterly"),
+      EconomicInterval::Semiannual => write!(f, "semiannual"),
+      EconomicInterval::Annual => write!(f, "annual"),
+    }
+  }
+}
+
+/// Parses an economic interval from its API string. Case-insensitive.
+impl FromStr for EconomicInterval {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "daily" => Ok(EconomicInterval::Daily),
+      "weekly" => Ok(EconomicInterval::Weekly),
+      "monthly" => Ok(EconomicInterval::Monthly),
+      "quarterly" => Ok(EconomicInterval::Quarterly),
+      "semiannual" => Ok(EconomicInterval::Semiannual),
+      "annual" => Ok(EconomicInterval::Annual),
+      _ => Err(format!("Invalid economic interval: {}", s)),
+    }
+  }
+}
+
+/// Constant maturity of a US treasury security.
+///
+/// Sent as the `maturity` query parameter for `TREASURY_YIELD`.
+///
+/// # Display output
+///
+/// Produces the API value: `"3month"`, `"2year"`, `"5year"`, `"7year"`,
+/// `"10year"`, or `"30year"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TreasuryMaturity {
+  /// 3-month bill.
+  ThreeMonth,
+  /// 2-year note.
+  TwoYear,
+  /// 5-year note.
+  FiveYear,
+  /// 7-year note.
+  SevenYear,
+  /// 10-year note (the API default).
+  TenYear,
+  /// 30-year bond.
+  ThirtyYear,
+}
+
+/// Formats as the API query-string value (e.g., `"10year"`).
+impl std::fmt::Display for TreasuryMaturity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      TreasuryMaturity::ThreeMonth => write!(f, "3month"),
+      TreasuryMaturity::TwoYear => write!(f, "2year"),
+      TreasuryMaturity::FiveYear => write!(f, "5year"),
+      TreasuryMaturity::SevenYear => write!(f, "7year"),
+      TreasuryMaturity::TenYear => write!(f, "10year"),
+      TreasuryMaturity::ThirtyYear => write!(f, "30year"),
+    }
+  }
+}
+
+/// Parses a treasury maturity from its API string. Case-insensitive.
+impl FromStr for TreasuryMaturity {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "3month" => Ok(TreasuryMaturity::ThreeMonth),
+      "2year" => Ok(TreasuryMaturity::TwoYear),
+      "5year" => Ok(TreasuryMaturity::FiveYear),
+      "7year" => Ok(TreasuryMaturity::SevenYear),
+      "10year" => Ok(TreasuryMaturity::TenYear),
+      "30year" => Ok(TreasuryMaturity::ThirtyYear),
+      _ => Err(format!("Invalid treasury maturity: {}", s)),
+    }
+  }
+}
+
+impl TreasuryMaturity {
+  /// All maturities published by the API, shortest first.
+  pub const ALL: [TreasuryMaturity; 6] = [
+    TreasuryMaturity::ThreeMonth,
+    TreasuryMaturity::TwoYear,
+    TreasuryMaturity::FiveYear,
+    TreasuryMaturity::SevenYear,
+    TreasuryMaturity::TenYear,
+    TreasuryMaturity::ThirtyYear,
+  ];
+}
+
 /// Ordering for news and search result endpoints.
 ///
 /// Used with the `NEWS_SENTIMENT` and similar Alpha Vantage endpoints to
@@ -254,6 +645,130 @@ impl std::fmt::Display for SortOrder {
   }
 }
 
+/// Parses a sort order from its API string. Case-insensitive.
+impl FromStr for SortOrder {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_uppercase().as_str() {
+      "LATEST" => Ok(SortOrder::Latest),
+      "EARLIEST" => Ok(SortOrder::Earliest),
+      "RELEVANCE" => Ok(SortOrder::Relevance),
+      _ => Err(format!("Invalid sort order: {}", s)),
+    }
+  }
+}
+
+/// Topic filter for the `NEWS_SENTIMENT` endpoint.
+///
+/// # Display output
+///
+/// Produces the API value, e.g. `"earnings"` or `"economy_monetary"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NewsTopic {
+  /// Blockchain.
+  Blockchain,
+  /// Earnings.
+  Earnings,
+  /// IPO.
+  Ipo,
+  /// Mergers & Acquisitions.
+  MergersAndAcquisitions,
+  /// Financial Markets.
+  FinancialMarkets,
+  /// Economy - Fiscal Policy (e.g., tax reform, government spending).
+  EconomyFiscal,
+  /// Economy - Monetary Policy (e.g., interest rates, inflation).
+  EconomyMonetary,
+  /// Economy - Macro/Overall.
+  EconomyMacro,
+  /// Energy & Transportation.
+  EnergyTransportation,
+  /// Finance.
+  Finance,
+  /// Life Sciences.
+  LifeSciences,
+  /// Manufacturing.
+  Manufacturing,
+  /// Real Estate & Construction.
+  RealEstate,
+  /// Retail & Wholesale.
+  RetailWholesale,
+  /// Technology.
+  Technology,
+}
+
+impl NewsTopic {
+  /// Every topic, in the order the API documents them.
+  pub const ALL: [NewsTopic; 15] = [
+    NewsTopic::Blockchain,
+    NewsTopic::Earnings,
+    NewsTopic::Ipo,
+    NewsTopic::MergersAndAcquisitions,
+    NewsTopic::FinancialMarkets,
+    NewsTopic::EconomyFiscal,
+    NewsTopic::EconomyMonetary,
+    NewsTopic::EconomyMacro,
+    NewsTopic::EnergyTransportation,
+    NewsTopic::Finance,
+    NewsTopic::LifeSciences,
+    NewsTopic::Manufacturing,
+    NewsTopic::RealEstate,
+    NewsTopic::RetailWholesale,
+    NewsTopic::Technology,
+  ];
+}
+
+/// Formats as the API query-string value (e.g., `"financial_markets"`).
+impl std::fmt::Display for NewsTopic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      NewsTopic::Blockchain => "blockchain",
+      NewsTopic::Earnings => "earnings",
+      NewsTopic::Ipo => "ipo",
+      NewsTopic::MergersAndAcquisitions => "mergers_and_acquisitions",
+      NewsTopic::FinancialMarkets => "financial_markets",
+      NewsTopic::EconomyFiscal => "economy_fiscal",
+      NewsTopic::EconomyMonetary => "economy_monetary",
+      NewsTopic::EconomyMacro => "economy_macro",
+      NewsTopic::EnergyTransportation => "energy_transportation",
+      NewsTopic::Finance => "finance",
+      NewsTopic::LifeSciences => "life_sciences",
+      NewsTopic::Manufacturing => "manufacturing",
+      NewsTopic::RealEstate => "real_estate",
+      NewsTopic::RetailWholesale => "retail_wholesale",
+      NewsTopic::Technology => "technology",
+    };
+    f.write_str(value)
+  }
+}
+
+/// Parses a topic from its API string. Case-insensitive.
+impl FromStr for NewsTopic {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().to_lowercase().as_str() {
+      "blockchain" => Ok(NewsTopic::Blockchain),
+      "earnings" => Ok(NewsTopic::Earnings),
+      "ipo" => Ok(NewsTopic::Ipo),
+      "mergers_and_acquisitions" => Ok(NewsTopic::MergersAndAcquisitions),
+      "financial_markets" => Ok(NewsTopic::FinancialMarkets),
+      "economy_fiscal" => Ok(NewsTopic::EconomyFiscal),
+      "economy_monetary" => Ok(NewsTopic::EconomyMonetary),
+      "economy_macro" => Ok(NewsTopic::EconomyMacro),
+      "energy_transportation" => Ok(NewsTopic::EnergyTransportation),
+      "finance" => Ok(NewsTopic::Finance),
+      "life_sciences" => Ok(NewsTopic::LifeSciences),
+      "manufacturing" => Ok(NewsTopic::Manufacturing),
+      "real_estate" => Ok(NewsTopic::RealEstate),
+      "retail_wholesale" => Ok(NewsTopic::RetailWholesale),
+      "technology" => Ok(NewsTopic::Technology),
+      _ => Err(format!("Invalid news topic: {}", s)),
+    }
+  }
+}
+
 /// Look-ahead period for calendar-based endpoints (earnings, IPO, dividends).
 ///
 /// Controls how far into the future the API returns scheduled events.
@@ -282,6 +797,20 @@ impl std::fmt::Display for TimeHorizon {
   }
 }
 
+/// Parses `"3month"`, `"6month"`, or `"12month"`. Case-insensitive.
+impl FromStr for TimeHorizon {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "3month" => Ok(TimeHorizon::ThreeMonth),
+      "6month" => Ok(TimeHorizon::SixMonth),
+      "12month" => Ok(TimeHorizon::TwelveMonth),
+      _ => Err(format!("Invalid time horizon: {}", s)),
+    }
+  }
+}
+
 /// Indicates whether a security is currently listed or has been removed from trading.
 ///
 /// Used with the `LISTING_STATUS` Alpha Vantage endpoint to filter results
@@ -309,6 +838,19 @@ impl std::fmt::Display for ListingState {
   }
 }
 
+/// Parses `"active"` or `"delisted"`. Case-insensitive.
+impl FromStr for ListingState {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "active" => Ok(ListingState::Active),
+      "delisted" => Ok(ListingState::Delisted),
+      _ => Err(format!("Invalid listing state: {}", s)),
+    }
+  }
+}
+
 /// Qualitative sentiment classification for news articles and ticker mentions.
 ///
 /// Alpha Vantage's `NEWS_SENTIMENT` endpoint returns a numeric sentiment score
@@ -731,6 +1273,77 @@ mod tests {
     assert_eq!(Interval::Min15.minutes(), 15);
   }
 
+  #[test]
+  fn test_indicator_interval() {
+    assert_eq!("daily".parse::<IndicatorInterval>(), Ok(IndicatorInterval::Daily));
+    assert_eq!("15min".parse::<IndicatorInterval>(), Ok(IndicatorInterval::Min15));
+    assert!("quarterly".parse::<IndicatorInterval>().is_err());
+    assert_eq!(IndicatorInterval::from(Interval::Min60).to_string(), "60min");
+    assert!(IndicatorInterval::Min5.is_intraday());
+    assert!(!IndicatorInterval::Weekly.is_intraday());
+  }
+
+  #[test]
+  fn test_year_month() {
+    let month: YearMonth = "2009-12".parse().unwrap();
+    assert_eq!((month.year(), month.month()), (2009, 12));
+    assert_eq!(month.next(), YearMonth::new(2010, 1).unwrap());
+    assert_eq!(month.to_string(), "2009-12");
+    assert!(YearMonth::FIRST_INTRADAY < month);
+    for invalid in ["2009-13", "2009-1", "09-01", "2009/01", ""] {
+      assert!(invalid.parse::<YearMonth>().is_err(), "{}", invalid);
+    }
+  }
+
+  #[test]
+  fn test_year_month_serde() {
+    let month = YearMonth::new(2024, 3).unwrap();
+    assert_eq!(serde_json::to_string(&month).unwrap(), r#""2024-03""#);
+    assert_eq!(serde_json::from_str::<YearMonth>(r#""2024-03""#).unwrap(), month);
+
+    for invalid in [r#""2024-00""#, r#""2024-13""#, r#"{"year":2024,"month":13}"#] {
+      assert!(serde_json::from_str::<YearMonth>(invalid).is_err(), "{}", invalid);
+    }
+  }
+
+  #[test]
+  fn test_request_enum_parsing() {
+    assert_eq!("FULL".parse::<OutputSize>(), Ok(OutputSize::Full));
+    assert_eq!("csv".parse::<DataType>(), Ok(DataType::Csv));
+    assert_eq!("latest".parse::<SortOrder>(), Ok(SortOrder::Latest));
+    assert_eq!("IPO".parse::<NewsTopic>(), Ok(NewsTopic::Ipo));
+    for topic in NewsTopic::ALL {
+      assert_eq!(topic.to_string().parse::<NewsTopic>(), Ok(topic));
+    }
+    assert_eq!("12month".parse::<TimeHorizon>(), Ok(TimeHorizon::TwelveMonth));
+    assert_eq!("Delisted".parse::<ListingState>(), Ok(ListingState::Delisted));
+    assert!("huge".parse::<OutputSize>().is_err());
+  }
+
+  #[test]
+  fn test_series_type() {
+    assert_eq!("CLOSE".parse::<SeriesType>(), Ok(SeriesType::Close));
+    assert_eq!(SeriesType::High.to_string(), "high");
+    assert!("median".parse::<SeriesType>().is_err());
+  }
+
+  #[test]
+  fn test_economic_interval() {
+    assert_eq!("Quarterly".parse::<EconomicInterval>(), Ok(EconomicInterval::Quarterly));
+    assert_eq!(EconomicInterval::Semiannual.to_string(), "semiannual");
+    assert!("hourly".parse::<EconomicInterval>().is_err());
+  }
+
+  #[test]
+  fn test_treasury_maturity() {
+    assert_eq!("10year".parse::<TreasuryMaturity>(), Ok(TreasuryMaturity::TenYear));
+    assert_eq!(TreasuryMaturity::ThreeMonth.to_string(), "3month");
+    assert!("1year".parse::<TreasuryMaturity>().is_err());
+    for maturity in TreasuryMaturity::ALL {
+      assert_eq!(maturity.to_string().parse::<TreasuryMaturity>(), Ok(maturity));
+    }
+  }
+
   #[test]
   fn test_currency_code_parsing() {
     assert_eq!("USD".parse::<CurrencyCode>(), Ok(CurrencyCode::USD));
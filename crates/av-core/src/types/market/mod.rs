@@ -30,7 +30,7 @@
 //! Market-related types for financial data.
 //!
 //! This is the public facade for the `market` module group within `av_core::types`.
-//! It hides internal organization (three private submodules) behind a flat
+//! It hides internal organization (four private submodules) behind a flat
 //! re-export surface so consumers can write `use av_core::types::market::Exchange`
 //! rather than `use av_core::types::market::exchange::Exchange`. This indirection
 //! exists primarily for **backward compatibility** — earlier versions of the
@@ -38,7 +38,7 @@
 //!
 //! ## Type Categories
 //!
-//! The types exported here cover three orthogonal aspects of financial market data:
+//! The types exported here cover four orthogonal aspects of financial market data:
 //!
 //! ### Exchange Identification
 //!
@@ -73,6 +73,16 @@
 //!   the 32-bit ID space. Round-trip via [`SecurityType::encode`] and
 //!   [`SecurityIdentifier::decode`].
 //!
+//! ### Option Contracts
+//!
+//! - [`OptionType`] — `Call` / `Put`, serialized as Alpha Vantage's lowercase
+//!   `type` field. `FromStr` also accepts the OCC letters `C` / `P`.
+//!
+//! - [`OccSymbol`] — Parsed OCC contract symbol (root, expiration, side,
+//!   strike in thousandths). Parses both the compact form Alpha Vantage
+//!   returns (`IBM270115C00390000`) and the six-character-padded OSI form;
+//!   `to_osi()` produces the latter.
+//!
 //! ### Market Classifications
 //!
 //! - [`TopType`] — Enum for top-mover queries: `Gainers`, `Losers`, `MostActive`.
@@ -98,6 +108,7 @@
 //! ├── mod.rs           ← this file (public facade, re-exports only)
 //! ├── classifications.rs   → TopType, Sector, MarketCap
 //! ├── exchange.rs          → Exchange
+//! ├── options.rs           → OptionType, OccSymbol
 //! └── security_type.rs     → SecurityType, SecurityIdentifier
 //! ```
 //!
@@ -114,7 +125,7 @@
 //! | Trait                  | Purpose                              |
 //! |------------------------|--------------------------------------|
 //! | `Debug`                | Diagnostic printing                  |
-//! | `Clone`, `Copy`        | Value semantics (all are POD-sized)  |
+//! | `Clone`, `Copy`        | Value semantics (`OccSymbol` is `Clone` only) |
 //! | `PartialEq`, `Eq`      | Equality comparison                  |
 //! | `Hash`                 | Use as `HashMap` / `HashSet` key     |
 //! | `Serialize`, `Deserialize` | JSON/serde interoperability      |
@@ -122,8 +133,10 @@
 
 mod classifications;
 mod exchange;
+mod options;
 mod security_type;
 
 pub use classifications::{MarketCap, Sector, TopType};
 pub use exchange::Exchange;
+pub use options::{OccSymbol, OptionType};
 pub use security_type::{SecurityIdentifier, SecurityType};
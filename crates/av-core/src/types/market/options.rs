@@ -0,0 +1,225 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Listed option contract types: call/put side and OCC contract symbols.
+//!
+//! Alpha Vantage identifies option contracts by their OCC symbol in the
+//! compact form (no root padding), e.g. `IBM270115C00390000`:
+//!
+//! ```text
+//! IBM     270115   C   00390000
+//! │       │        │   └── strike × 1000, 8 digits ($390.000)
+//! │       │        └────── C = call, P = put
+//! │       └─────────────── expiration, YYMMDD (2027-01-15)
+//! └─────────────────────── underlying root, 1–6 characters
+//! ```
+//!
+//! The OSI form used by clearing firms pads the root to six characters
+//! (`IBM   270115C00390000`). [`OccSymbol`] parses either form; its
+//! `Display` emits the compact form Alpha Vantage returns, and
+//! [`OccSymbol::to_osi`] emits the padded form.
+//!
+//! ```rust
+//! use av_core::types::market::{OccSymbol, OptionType};
+//!
+//! let occ: OccSymbol = "IBM270115C00390000".parse().unwrap();
+//! assert_eq!(occ.root, "IBM");
+//! assert_eq!(occ.option_type, OptionType::Call);
+//! assert_eq!(occ.strike(), 390.0);
+//! assert_eq!(occ.to_osi(), "IBM   270115C00390000");
+//! ```
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Length of the date + side + strike suffix shared by every OCC symbol.
+const OCC_SUFFIX_LEN: usize = 15;
+/// Maximum root length; the OSI form pads roots to this width.
+const OCC_ROOT_LEN: usize = 6;
+
+/// Side of an option contract.
+///
+/// Serializes and displays in the lowercase form used by the `type` field of
+/// Alpha Vantage option responses (`"call"` / `"put"`). `FromStr` also
+/// accepts the single-letter OCC codes `C` and `P`, case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionType {
+  Call,
+  Put,
+}
+
+impl OptionType {
+  /// The single-letter OCC code (`'C'` or `'P'`).
+  pub fn occ_code(&self) -> char {
+    match self {
+      OptionType::Call => 'C',
+      OptionType::Put => 'P',
+    }
+  }
+}
+
+impl std::fmt::Display for OptionType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      OptionType::Call => write!(f, "call"),
+      OptionType::Put => write!(f, "put"),
+    }
+  }
+}
+
+impl FromStr for OptionType {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "call" | "c" => Ok(OptionType::Call),
+      "put" | "p" => Ok(OptionType::Put),
+      _ => Err(format!("Invalid option type: {}", s)),
+    }
+  }
+}
+
+/// A parsed OCC option contract symbol.
+///
+/// The strike is kept in thousandths of a dollar, exactly as encoded in the
+/// symbol, so that parsing and formatting round-trip without floating-point
+/// error and the type can be used as a map key. Use [`strike`](Self::strike)
+/// for the dollar value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OccSymbol {
+  /// Underlying root symbol, without padding (e.g. `"IBM"`, `"BRKB"`).
+  pub root: String,
+  /// Contract expiration date.
+  pub expiration: NaiveDate,
+  /// Call or put.
+  pub option_type: OptionType,
+  /// Strike price in thousandths of a dollar (`390000` = $390.00).
+  pub strike_thousandths: u32,
+}
+
+impl OccSymbol {
+  /// Strike price in dollars.
+  pub fn strike(&self) -> f64 {
+    self.strike_thousandths as f64 / 1000.0
+  }
+
+  /// The 21-character OSI form with the root right-padded to six characters.
+  pub fn to_osi(&self) -> String {
+    format!("{:<width$}{}", self.root, self.suffix(), width = OCC_ROOT_LEN)
+  }
+
+  fn suffix(&self) -> String {
+    format!(
+      "{}{}{:08}",
+      self.expiration.format("%y%m%d"),
+      self.option_type.occ_code(),
+      self.strike_thousandths
+    )
+  }
+}
+
+/// Formats in the compact form Alpha Vantage uses as `contractID`.
+impl std::fmt::Display for OccSymbol {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}{}", self.root, self.suffix())
+  }
+}
+
+impl FromStr for OccSymbol {
+  type Err = String;
+
+  /// Parses the compact or OSI-padded form. Surrounding whitespace is
+  /// ignored and the root is upper-cased.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim();
+    if !s.is_ascii() || s.len() <= OCC_SUFFIX_LEN {
+      return Err(format!("Invalid OCC symbol: {}", s));
+    }
+
+    let (root, suffix) = s.split_at(s.len() - OCC_SUFFIX_LEN);
+    let root = root.trim_end().to_uppercase();
+    if root.is_empty() || root.len() > OCC_ROOT_LEN {
+      return Err(format!("Invalid OCC root in symbol: {}", s));
+    }
+
+    let expiration = NaiveDate::parse_from_str(&suffix[..6], "%y%m%d")
+      .map_err(|_| format!("Invalid OCC expiration in symbol: {}", s))?;
+    let option_type = suffix[6..7].parse::<OptionType>()?;
+    let strike_digits = &suffix[7..];
+    if !strike_digits.bytes().all(|b| b.is_ascii_digit()) {
+      return Err(format!("Invalid OCC strike in symbol: {}", s));
+    }
+    let strike_thousandths =
+      strike_digits.parse().map_err(|_| format!("Invalid OCC strike in symbol: {}", s))?;
+
+    Ok(OccSymbol { root, expiration, option_type, strike_thousandths })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_option_type_parsing() {
+    assert_eq!("call".parse::<OptionType>().unwrap(), OptionType::Call);
+    assert_eq!("PUT".parse::<OptionType>().unwrap(), OptionType::Put);
+    assert_eq!("c".parse::<OptionType>().unwrap(), OptionType::Call);
+    assert!("straddle".parse::<OptionType>().is_err());
+    assert_eq!(OptionType::Put.to_string(), "put");
+  }
+
+  #[test]
+  fn test_occ_symbol_round_trip() {
+    let occ: OccSymbol = "IBM270115C00390000".parse().unwrap();
+    assert_eq!(occ.root, "IBM");
+    assert_eq!(occ.expiration, NaiveDate::from_ymd_opt(2027, 1, 15).unwrap());
+    assert_eq!(occ.option_type, OptionType::Call);
+    assert_eq!(occ.strike_thousandths, 390_000);
+    assert_eq!(occ.to_string(), "IBM270115C00390000");
+    assert_eq!(occ.to_osi(), "IBM   270115C00390000");
+
+    let padded: OccSymbol = "SPXW  250321P05512500".parse().unwrap();
+    assert_eq!(padded.root, "SPXW");
+    assert_eq!(padded.strike(), 5512.5);
+    assert_eq!(padded.to_string(), "SPXW250321P05512500");
+  }
+
+  #[test]
+  fn test_occ_symbol_invalid() {
+    assert!("IBM".parse::<OccSymbol>().is_err());
+    assert!("270115C00390000".parse::<OccSymbol>().is_err());
+    assert!("IBM271315C00390000".parse::<OccSymbol>().is_err());
+    assert!("IBM270115X00390000".parse::<OccSymbol>().is_err());
+    assert!("IBM270115C0039000A".parse::<OccSymbol>().is_err());
+    assert!("TOOLONG270115C00390000".parse::<OccSymbol>().is_err());
+  }
+}
@@ -365,7 +365,7 @@ impl SecurityType {
   /// assert_eq!(SecurityType::decode_type(sid), SecurityType::Equity);
   /// ```
   pub fn encode(st: SecurityType, id: u32) -> i64 {
-    let unsigned_result = match st {
+    match st {
       // High-volume types (4-bit prefix, 60 bits for ID)
       SecurityType::Equity => (TYPE_COMMON_STOCK as i64) << SHIFT_4BIT | id as i64,
       SecurityType::PreferredStock => (TYPE_PREFERRED as i64) << SHIFT_4BIT | id as i64,
@@ -391,8 +391,7 @@ impl SecurityType {
       SecurityType::CD => (TYPE_CD as i64) << SHIFT_6BIT | id as i64,
       SecurityType::TreasuryBill => (TYPE_T_BILL as i64) << SHIFT_6BIT | id as i64,
       SecurityType::Other => (TYPE_OTHER as i64) << SHIFT_6BIT | id as i64,
-    };
-    unsigned_result
+    }
   }
 
   /// Extracts the [`SecurityType`] from a bitmap-encoded `i64` SID.
@@ -59,6 +59,11 @@
 //! | [`DataType`]      | Response format selector: `Json` or `Csv`.                        |
 //! | [`Interval`]      | Intraday bar width: `Min1`, `Min5`, `Min15`, `Min30`, `Min60`.    |
 //! | [`OutputSize`]    | Result set size: `Compact` (latest 100 points) or `Full` (up to 20 years). |
+//! | [`YearMonth`]     | Calendar month (`YYYY-MM`) for intraday history requests.         |
+//! | [`IndicatorInterval`] | Technical indicator bar width: intraday minutes or `Daily`/`Weekly`/`Monthly`. |
+//! | [`SeriesType`]    | Price field for indicators: `Close`, `Open`, `High`, `Low`.       |
+//! | [`EconomicInterval`] | Macro series sampling: `Daily` through `Annual`.               |
+//! | [`TreasuryMaturity`] | Treasury yield maturity: `ThreeMonth` through `ThirtyYear`.    |
 //! | `SortOrder`       | Ordering for news/search results: `Latest`, `Earliest`, `Relevance`. |
 //! | `TimeHorizon`     | Calendar data range: `ThreeMonth`, `SixMonth`, `TwelveMonth`.    |
 //! | `ListingState`    | Security listing status: `Active` or `Delisted`.                  |
@@ -78,6 +83,8 @@
 //! | [`TopType`]            | Top-mover query type: `Gainers`, `Losers`, `MostActive`.    |
 //! | [`Sector`]             | 12 GICS-style market sectors with cyclical/defensive classification and typical P/E ranges. |
 //! | [`MarketCap`]          | 6 market-capitalization tiers from `NanoCap` to `MegaCap` with USD range boundaries. |
+//! | [`OptionType`]         | Option contract side: `Call` or `Put`.                      |
+//! | [`OccSymbol`]          | Parsed OCC option contract symbol (root, expiry, side, strike). |
 //!
 //! # Re-exports
 //!
@@ -103,10 +110,16 @@ pub mod market;
 // code doesn't need to spell out the sub-module path for everyday imports.
 
 /// Re-exported from [`common`]: API response format (`Json` / `Csv`), intraday
-/// bar interval, and result set size.
-pub use common::{DataType, Interval, OutputSize};
+/// bar interval, result set size, intraday history month, technical indicator
+/// parameters, and economic series parameters.
+pub use common::{
+  DataType, EconomicInterval, IndicatorInterval, Interval, OutputSize, SeriesType,
+  TreasuryMaturity, YearMonth,
+};
 
 /// Re-exported from [`market`]: exchange identifiers, security type enum and
-/// bitmap identifier, top-mover query type, GICS sector classification, and
-/// market-capitalization tiers.
-pub use market::{Exchange, MarketCap, Sector, SecurityIdentifier, SecurityType, TopType};
+/// bitmap identifier, top-mover query type, GICS sector classification,
+/// market-capitalization tiers, and option contract identifiers.
+pub use market::{
+  Exchange, MarketCap, OccSymbol, OptionType, Sector, SecurityIdentifier, SecurityType, TopType,
+};
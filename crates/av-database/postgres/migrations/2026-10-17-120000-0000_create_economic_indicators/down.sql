@@ -0,0 +1,2 @@
+-- Drop the economic_indicators hypertable
+DROP TABLE IF EXISTS economic_indicators;
@@ -0,0 +1,21 @@
+-- US economic indicator observations (REAL_GDP, CPI, TREASURY_YIELD, ...)
+-- One row per (indicator, interval, maturity, date). Non-treasury series use
+-- an empty maturity so the natural key stays NOT NULL.
+CREATE TABLE economic_indicators (
+  indicator VARCHAR(50) NOT NULL,        -- API function name, e.g. 'TREASURY_YIELD'
+  series_interval VARCHAR(20) NOT NULL,  -- 'daily', 'monthly', 'quarterly', ...
+  maturity VARCHAR(10) NOT NULL DEFAULT '',  -- '10year' etc. for TREASURY_YIELD
+  date DATE NOT NULL,
+  value DOUBLE PRECISION,                -- NULL when the API publishes '.'
+  unit VARCHAR(100) NOT NULL,
+  name TEXT NOT NULL,
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (indicator, series_interval, maturity, date)
+);
+
+SELECT create_hypertable('economic_indicators', 'date', chunk_time_interval => INTERVAL '1 year');
+
+CREATE INDEX idx_economic_indicators_series_date
+  ON economic_indicators (indicator, series_interval, maturity, date DESC);
+
+COMMENT ON TABLE economic_indicators IS 'US macro series from the Alpha Vantage economic indicator endpoints';
@@ -0,0 +1,2 @@
+-- Drop the commodityprices hypertable
+DROP TABLE IF EXISTS commodityprices;
@@ -0,0 +1,19 @@
+-- Commodity price observations (WTI, BRENT, COPPER, ...), stored alongside
+-- summaryprices. Each commodity has a symbols row with sec_type 'Commodity'
+-- and a SID from the SecurityType bitmap encoding.
+CREATE TABLE commodityprices (
+  tstamp TIMESTAMPTZ NOT NULL,
+  date DATE NOT NULL,
+  sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  symbol VARCHAR(64) NOT NULL,            -- API function name, e.g. 'WTI'
+  series_interval VARCHAR(20) NOT NULL,   -- 'daily', 'weekly', 'monthly', ...
+  price DOUBLE PRECISION,                 -- NULL when the API publishes '.'
+  unit VARCHAR(100) NOT NULL,
+  PRIMARY KEY (tstamp, sid, series_interval)
+);
+
+SELECT create_hypertable('commodityprices', 'tstamp', chunk_time_interval => INTERVAL '1 year');
+
+CREATE INDEX idx_commodityprices_sid_date ON commodityprices (sid, series_interval, date DESC);
+
+COMMENT ON TABLE commodityprices IS 'Commodity price series from the Alpha Vantage commodity endpoints';
@@ -0,0 +1,2 @@
+-- Drop the optionprices hypertable
+DROP TABLE IF EXISTS optionprices;
@@ -0,0 +1,38 @@
+-- End-of-day option chain snapshots (HISTORICAL_OPTIONS / REALTIME_OPTIONS).
+-- One row per OCC contract per snapshot date, linked to the underlying's
+-- symbols row. Contracts are not registered in symbols themselves; the OCC
+-- symbol is the contract identifier.
+CREATE TABLE optionprices (
+  snapshot_date DATE NOT NULL,
+  contract_id VARCHAR(32) NOT NULL,       -- compact OCC symbol, e.g. 'IBM270115C00390000'
+  underlying_sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  underlying VARCHAR(20) NOT NULL,
+  occ_root VARCHAR(6) NOT NULL,           -- differs from underlying for adjusted contracts
+  expiration DATE NOT NULL,
+  strike DOUBLE PRECISION NOT NULL,
+  option_type VARCHAR(4) NOT NULL CHECK (option_type IN ('call', 'put')),
+  last DOUBLE PRECISION,
+  mark DOUBLE PRECISION,
+  bid DOUBLE PRECISION,
+  bid_size INTEGER,
+  ask DOUBLE PRECISION,
+  ask_size INTEGER,
+  volume BIGINT,
+  open_interest BIGINT,
+  implied_volatility DOUBLE PRECISION,
+  delta DOUBLE PRECISION,
+  gamma DOUBLE PRECISION,
+  theta DOUBLE PRECISION,
+  vega DOUBLE PRECISION,
+  rho DOUBLE PRECISION,
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (snapshot_date, contract_id)
+);
+
+-- A liquid underlying has thousands of contracts per day, so keep chunks small.
+SELECT create_hypertable('optionprices', 'snapshot_date', chunk_time_interval => INTERVAL '7 days');
+
+CREATE INDEX idx_optionprices_underlying_date ON optionprices (underlying_sid, snapshot_date DESC);
+CREATE INDEX idx_optionprices_underlying_expiry ON optionprices (underlying_sid, expiration, strike);
+
+COMMENT ON TABLE optionprices IS 'Daily option chain snapshots keyed by OCC contract symbol';
@@ -0,0 +1,2 @@
+-- Drop the corporate_actions table
+DROP TABLE IF EXISTS corporate_actions;
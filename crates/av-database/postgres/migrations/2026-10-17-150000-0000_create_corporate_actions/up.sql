@@ -0,0 +1,21 @@
+-- Dividends and stock splits (DIVIDENDS / SPLITS), one row per event.
+-- Both action types share the table so that a single range query returns
+-- everything needed to adjust a price series. For splits, ex_date is the
+-- effective date and only split_ratio is populated.
+CREATE TABLE corporate_actions (
+  sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  symbol VARCHAR(20) NOT NULL,
+  action_type VARCHAR(8) NOT NULL CHECK (action_type IN ('dividend', 'split')),
+  ex_date DATE NOT NULL,
+  declaration_date DATE,
+  record_date DATE,
+  pay_date DATE,
+  amount DOUBLE PRECISION,                -- cash per share, dividends only
+  split_ratio DOUBLE PRECISION,           -- new shares per old share, splits only
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (sid, action_type, ex_date)
+);
+
+CREATE INDEX idx_corporate_actions_sid_date ON corporate_actions (sid, ex_date DESC);
+
+COMMENT ON TABLE corporate_actions IS 'Dividend and split events per security';
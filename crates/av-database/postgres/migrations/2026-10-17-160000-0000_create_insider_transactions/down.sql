@@ -0,0 +1,2 @@
+-- Drop the insider_transactions table
+DROP TABLE IF EXISTS insider_transactions;
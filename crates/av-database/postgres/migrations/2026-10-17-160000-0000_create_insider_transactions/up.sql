@@ -0,0 +1,25 @@
+-- Trades by company insiders (INSIDER_TRANSACTIONS), linked to symbols.
+-- The API has no stable transaction identifier, so a row is identified by
+-- its full content; repeated loads of the same filing history are no-ops.
+CREATE TABLE insider_transactions (
+  id BIGSERIAL PRIMARY KEY,
+  sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  symbol VARCHAR(20) NOT NULL,
+  transaction_date DATE NOT NULL,
+  executive VARCHAR(255) NOT NULL,
+  executive_title VARCHAR(255) NOT NULL DEFAULT '',
+  security_type VARCHAR(255) NOT NULL DEFAULT '',
+  acquisition_or_disposal CHAR(1) NOT NULL CHECK (acquisition_or_disposal IN ('A', 'D')),
+  shares DOUBLE PRECISION NOT NULL,
+  share_price DOUBLE PRECISION NOT NULL,  -- 0 for grants and awards
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX uq_insider_transactions_trade ON insider_transactions (
+  sid, transaction_date, executive, security_type, acquisition_or_disposal, shares, share_price
+);
+
+CREATE INDEX idx_insider_transactions_sid_date ON insider_transactions (sid, transaction_date DESC);
+CREATE INDEX idx_insider_transactions_date ON insider_transactions (transaction_date DESC);
+
+COMMENT ON TABLE insider_transactions IS 'Insider purchases and sales per security';
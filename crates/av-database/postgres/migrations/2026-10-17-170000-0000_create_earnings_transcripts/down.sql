@@ -0,0 +1,3 @@
+-- Drop the earnings transcript tables
+DROP TABLE IF EXISTS transcript_segments;
+DROP TABLE IF EXISTS earnings_transcripts;
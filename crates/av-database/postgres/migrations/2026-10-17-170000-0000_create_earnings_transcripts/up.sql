@@ -0,0 +1,32 @@
+-- Earnings call transcripts (EARNINGS_CALL_TRANSCRIPT), stored next to the
+-- news schema: one header row per symbol and fiscal quarter, and one row
+-- per speaker segment with its sentiment score. Segment text is indexed
+-- for full-text search so transcripts can be queried alongside articles.
+CREATE TABLE earnings_transcripts (
+  id SERIAL PRIMARY KEY,
+  sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  symbol VARCHAR(20) NOT NULL,
+  quarter VARCHAR(6) NOT NULL,            -- 'YYYYQn'
+  fiscal_year INTEGER NOT NULL,
+  fiscal_quarter SMALLINT NOT NULL CHECK (fiscal_quarter BETWEEN 1 AND 4),
+  segment_count INTEGER NOT NULL,
+  avg_sentiment DOUBLE PRECISION,
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  UNIQUE (sid, quarter)
+);
+
+CREATE TABLE transcript_segments (
+  transcript_id INTEGER NOT NULL REFERENCES earnings_transcripts(id) ON DELETE CASCADE,
+  seq INTEGER NOT NULL,                   -- position in the call, from 0
+  speaker VARCHAR(255) NOT NULL,
+  title VARCHAR(255) NOT NULL DEFAULT '',
+  content TEXT NOT NULL,
+  sentiment DOUBLE PRECISION,
+  PRIMARY KEY (transcript_id, seq)
+);
+
+CREATE INDEX idx_earnings_transcripts_sid ON earnings_transcripts (sid, fiscal_year DESC, fiscal_quarter DESC);
+CREATE INDEX idx_transcript_segments_fts ON transcript_segments USING GIN (to_tsvector('english', content));
+
+COMMENT ON TABLE earnings_transcripts IS 'Earnings call transcript headers per symbol and fiscal quarter';
+COMMENT ON TABLE transcript_segments IS 'Speaker segments of earnings call transcripts with sentiment';
@@ -0,0 +1,4 @@
+-- Drop the ETF profile tables
+DROP TABLE IF EXISTS etf_sector_weights;
+DROP TABLE IF EXISTS etf_holdings;
+DROP TABLE IF EXISTS etf_profiles;
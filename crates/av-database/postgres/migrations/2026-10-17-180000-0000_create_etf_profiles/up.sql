@@ -0,0 +1,48 @@
+-- ETF profile snapshots (ETF_PROFILE). Each load writes one snapshot per
+-- ETF and date: key metrics in etf_profiles, constituents in etf_holdings,
+-- and sector weights in etf_sector_weights. Constituents whose ticker is
+-- not yet in symbols are stored with a NULL constituent_sid and linked
+-- once the missing-symbols flow has added them.
+CREATE TABLE etf_profiles (
+  etf_sid BIGINT NOT NULL REFERENCES symbols(sid) ON DELETE CASCADE,
+  snapshot_date DATE NOT NULL,
+  symbol VARCHAR(20) NOT NULL,
+  net_assets DOUBLE PRECISION,
+  net_expense_ratio DOUBLE PRECISION,
+  portfolio_turnover DOUBLE PRECISION,
+  dividend_yield DOUBLE PRECISION,
+  inception_date DATE,
+  leveraged BOOLEAN,
+  loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (etf_sid, snapshot_date)
+);
+
+CREATE TABLE etf_holdings (
+  etf_sid BIGINT NOT NULL,
+  snapshot_date DATE NOT NULL,
+  position INTEGER NOT NULL,              -- rank by weight, from 0
+  holding_symbol VARCHAR(20),             -- NULL for cash, futures, and other untickered lines
+  constituent_sid BIGINT REFERENCES symbols(sid) ON DELETE SET NULL,
+  description TEXT NOT NULL DEFAULT '',
+  weight DOUBLE PRECISION NOT NULL,       -- fraction of net assets
+  PRIMARY KEY (etf_sid, snapshot_date, position),
+  FOREIGN KEY (etf_sid, snapshot_date) REFERENCES etf_profiles(etf_sid, snapshot_date) ON DELETE CASCADE
+);
+
+CREATE TABLE etf_sector_weights (
+  etf_sid BIGINT NOT NULL,
+  snapshot_date DATE NOT NULL,
+  sector VARCHAR(100) NOT NULL,
+  weight DOUBLE PRECISION NOT NULL,
+  PRIMARY KEY (etf_sid, snapshot_date, sector),
+  FOREIGN KEY (etf_sid, snapshot_date) REFERENCES etf_profiles(etf_sid, snapshot_date) ON DELETE CASCADE
+);
+
+-- "Which ETFs hold X?" lookups
+CREATE INDEX idx_etf_holdings_constituent ON etf_holdings (constituent_sid, snapshot_date DESC);
+CREATE INDEX idx_etf_holdings_unlinked ON etf_holdings (holding_symbol)
+  WHERE constituent_sid IS NULL AND holding_symbol IS NOT NULL;
+
+COMMENT ON TABLE etf_profiles IS 'ETF key metrics per snapshot date';
+COMMENT ON TABLE etf_holdings IS 'ETF constituents and weights per snapshot date';
+COMMENT ON TABLE etf_sector_weights IS 'ETF sector allocation per snapshot date';
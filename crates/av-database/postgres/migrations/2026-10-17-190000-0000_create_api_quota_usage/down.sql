@@ -0,0 +1,2 @@
+-- Drop the API call ledger
+DROP TABLE IF EXISTS api_quota_usage;
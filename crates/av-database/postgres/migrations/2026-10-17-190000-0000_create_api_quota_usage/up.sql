@@ -0,0 +1,14 @@
+-- AlphaVantage call ledger. One row per UTC day, API key and function,
+-- incremented on every request so the daily quota survives restarts and
+-- is shared by every process using the same database. Keys are stored
+-- masked (last four characters plus a hash of the key), never in full.
+CREATE TABLE api_quota_usage (
+  usage_date DATE NOT NULL,
+  api_key VARCHAR(32) NOT NULL,
+  function VARCHAR(64) NOT NULL,
+  calls INTEGER NOT NULL DEFAULT 0,
+  last_call_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (usage_date, api_key, function)
+);
+
+COMMENT ON TABLE api_quota_usage IS 'AlphaVantage calls per UTC day, masked API key and function';
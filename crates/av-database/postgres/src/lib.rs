@@ -45,9 +45,16 @@
 //! ├── models/        → Diesel structs: Queryable, Insertable, AsChangeset
 //! │   ├── security   → symbols, overviews, overviewexts, equity_details, symbol_mappings
 //! │   ├── price      → intradayprices, summaryprices, topstats (TimescaleDB hypertables)
+//! │   ├── commodities → commodityprices (TimescaleDB hypertable, keyed by commodity SID)
+//! │   ├── corporate_actions → corporate_actions (dividends and splits by SID and ex-date)
 //! │   ├── news       → newsoverviews, feeds, articles, authors, sources, sentiment, topics
+//! │   ├── transcripts → earnings_transcripts, transcript_segments (full-text indexed)
+//! │   ├── options    → optionprices (TimescaleDB hypertable, by snapshot date and OCC symbol)
 //! │   ├── crypto     → crypto_overview_basic/metrics, crypto_technical/social, crypto_api_map
 //! │   ├── crypto_markets → crypto exchange/trading-pair market data
+//! │   ├── economics  → economic_indicators (TimescaleDB hypertable)
+//! │   ├── etf        → etf_profiles, etf_holdings, etf_sector_weights (snapshots by date)
+//! │   ├── insiders   → insider_transactions (deduplicated on full trade content)
 //! │   └── missing_symbols → unresolved symbol tracking & resolution workflow
 //! ├── repository     → DbPool, RepositoryError, traits (Repository, CacheRepository, etc.)
 //! └── repositories/  → concrete async repository implementations (SymbolRepository)
@@ -109,6 +116,8 @@ pub mod connection;
 ///
 /// Organized by domain: [`models::security`], [`models::price`],
 /// [`models::news`], [`models::crypto`], [`models::crypto_markets`],
+/// [`models::commodities`], [`models::corporate_actions`], [`models::economics`],
+/// [`models::etf`], [`models::insiders`], [`models::options`], [`models::transcripts`],
 /// [`models::missing_symbols`]. See the [`models`] module documentation
 /// for the full type inventory.
 pub mod models;
@@ -128,7 +137,11 @@ pub mod repositories;
 /// and domain traits ([`Repository`](repository::Repository),
 /// [`OverviewRepository`](repository::OverviewRepository),
 /// [`NewsRepository`](repository::NewsRepository),
-/// [`CryptoRepository`](repository::CryptoRepository)).
+/// [`CryptoRepository`](repository::CryptoRepository),
+/// [`CorporateActionRepository`](repository::CorporateActionRepository),
+/// [`EtfRepository`](repository::EtfRepository),
+/// [`InsiderRepository`](repository::InsiderRepository),
+/// [`TranscriptRepository`](repository::TranscriptRepository)).
 pub mod repository;
 
 /// Auto-generated Diesel `table!` macros.
@@ -154,7 +167,8 @@ pub use repositories::SymbolRepository;
 /// Re-exported from [`repository`]: pool management, error types, traits,
 /// and the [`DatabaseContext`] entry point.
 pub use repository::{
-  CacheRepository, CacheRepositoryExt, CryptoRepository, DatabaseContext, NewsRepository,
-  OverviewRepository, OverviewSymbolFilter, Repository, RepositoryError, RepositoryResult,
-  SymbolInfo, Transactional,
+  CacheRepository, CacheRepositoryExt, CorporateActionRepository, CryptoRepository,
+  DatabaseContext, EtfRepository, InsiderRepository, NewsRepository, OverviewRepository,
+  OverviewSymbolFilter, QuotaRepository, Repository, RepositoryError, RepositoryResult, SymbolInfo,
+  Transactional, TranscriptRepository,
 };
@@ -0,0 +1,168 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for commodity price series.
+//!
+//! Commodity prices live in the `commodityprices` TimescaleDB hypertable,
+//! next to the equity `summaryprices` table. Every commodity is a row in
+//! `symbols` with `sec_type = 'Commodity'`, so prices are keyed by a SID
+//! from the same bitmap encoding used for equities and crypto.
+//!
+//! | Table             | Model              | Description                             |
+//! |-------------------|--------------------|-----------------------------------------|
+//! | `commodityprices` | [`CommodityPrice`] | One dated price for one commodity series |
+//!
+//! Unlike `summaryprices`, the upstream series carry a single price per
+//! period (no OHLC or volume), and the same commodity may be stored at
+//! several intervals, so `series_interval` is part of the primary key.
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewCommodityPriceOwned::upsert_batch`].
+//! - **Query:** [`CommodityPrice::get_range`], [`CommodityPrice::latest_date`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::commodityprices;
+
+// ─── Queryable model ────────────────────────────────────────────────────────
+
+/// A single commodity price observation.
+///
+/// # Key fields
+///
+/// | Field             | Type            | Description                                |
+/// |-------------------|-----------------|--------------------------------------------|
+/// | `tstamp`          | `DateTime<Utc>` | Observation date at midnight UTC           |
+/// | `date`            | `NaiveDate`     | Observation date                           |
+/// | `sid`             | `i64`           | Commodity security ID (FK to `symbols`)    |
+/// | `symbol`          | `String`        | API function name (e.g., `"WTI"`)          |
+/// | `series_interval` | `String`        | Sampling interval (e.g., `"daily"`)        |
+/// | `price`           | `Option<f64>`   | `None` when the API published `"."`        |
+/// | `unit`            | `String`        | Unit of measure (e.g., `"dollars per barrel"`) |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = commodityprices)]
+#[diesel(primary_key(tstamp, sid, series_interval))]
+pub struct CommodityPrice {
+  pub tstamp: DateTime<Utc>,
+  pub date: NaiveDate,
+  pub sid: i64,
+  pub symbol: String,
+  pub series_interval: String,
+  pub price: Option<f64>,
+  pub unit: String,
+}
+
+// ─── Insertable model ───────────────────────────────────────────────────────
+
+/// Insertable form of [`CommodityPrice`].
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = commodityprices)]
+pub struct NewCommodityPriceOwned {
+  pub tstamp: DateTime<Utc>,
+  pub date: NaiveDate,
+  pub sid: i64,
+  pub symbol: String,
+  pub series_interval: String,
+  pub price: Option<f64>,
+  pub unit: String,
+}
+
+impl NewCommodityPriceOwned {
+  /// Inserts prices in chunks of 1000, overwriting `price` and `unit` on
+  /// key conflicts so that revised observations replace earlier ones.
+  /// Returns the total number of rows written.
+  pub fn upsert_batch(
+    conn: &mut PgConnection,
+    records: &[Self],
+  ) -> Result<usize, diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+    let mut total_written = 0;
+
+    for chunk in records.chunks(BATCH_SIZE) {
+      total_written += diesel::insert_into(commodityprices::table)
+        .values(chunk)
+        .on_conflict((
+          commodityprices::tstamp,
+          commodityprices::sid,
+          commodityprices::series_interval,
+        ))
+        .do_update()
+        .set((
+          commodityprices::price.eq(excluded(commodityprices::price)),
+          commodityprices::unit.eq(excluded(commodityprices::unit)),
+        ))
+        .execute(conn)?;
+    }
+
+    Ok(total_written)
+  }
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for commodity prices.
+impl CommodityPrice {
+  /// Returns one commodity series between `start` and `end` (inclusive),
+  /// oldest first.
+  pub fn get_range(
+    conn: &mut PgConnection,
+    sid: i64,
+    series_interval: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    commodityprices::table
+      .filter(commodityprices::sid.eq(sid))
+      .filter(commodityprices::series_interval.eq(series_interval))
+      .filter(commodityprices::date.between(start, end))
+      .order_by(commodityprices::date.asc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the most recent stored date for one commodity series, or
+  /// `None` if it has never been loaded.
+  pub fn latest_date(
+    conn: &mut PgConnection,
+    sid: i64,
+    series_interval: &str,
+  ) -> Result<Option<NaiveDate>, diesel::result::Error> {
+    commodityprices::table
+      .filter(commodityprices::sid.eq(sid))
+      .filter(commodityprices::series_interval.eq(series_interval))
+      .select(diesel::dsl::max(commodityprices::date))
+      .first(conn)
+  }
+}
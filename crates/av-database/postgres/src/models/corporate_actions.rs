@@ -0,0 +1,174 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for corporate actions (dividends and stock splits).
+//!
+//! Both action types share the `corporate_actions` table so that a single
+//! range query returns every event needed to adjust a price series. Rows
+//! are keyed by `(sid, action_type, ex_date)`; for splits the `ex_date` is
+//! the effective date.
+//!
+//! | Table               | Model               | Description                         |
+//! |---------------------|---------------------|-------------------------------------|
+//! | `corporate_actions` | [`CorporateAction`] | One dividend or split for one security |
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewCorporateActionOwned::upsert_batch`].
+//! - **Query:** [`CorporateAction::get_range`], [`CorporateAction::latest_ex_date`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::corporate_actions;
+
+/// `action_type` value for cash dividends.
+pub const ACTION_DIVIDEND: &str = "dividend";
+
+/// `action_type` value for stock splits (including reverse splits).
+pub const ACTION_SPLIT: &str = "split";
+
+// ─── Queryable model ────────────────────────────────────────────────────────
+
+/// A single dividend or split event.
+///
+/// # Key fields
+///
+/// | Field         | Type          | Description                                   |
+/// |---------------|---------------|-----------------------------------------------|
+/// | `action_type` | `String`      | [`ACTION_DIVIDEND`] or [`ACTION_SPLIT`]       |
+/// | `ex_date`     | `NaiveDate`   | Ex-dividend date, or split effective date     |
+/// | `amount`      | `Option<f64>` | Cash per share; `None` for splits             |
+/// | `split_ratio` | `Option<f64>` | New shares per old share; `None` for dividends |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = corporate_actions)]
+#[diesel(primary_key(sid, action_type, ex_date))]
+pub struct CorporateAction {
+  pub sid: i64,
+  pub symbol: String,
+  pub action_type: String,
+  pub ex_date: NaiveDate,
+  pub declaration_date: Option<NaiveDate>,
+  pub record_date: Option<NaiveDate>,
+  pub pay_date: Option<NaiveDate>,
+  pub amount: Option<f64>,
+  pub split_ratio: Option<f64>,
+  pub loaded_at: DateTime<Utc>,
+}
+
+// ─── Insertable model ───────────────────────────────────────────────────────
+
+/// Insertable form of [`CorporateAction`]; `loaded_at` defaults to `NOW()`.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = corporate_actions)]
+pub struct NewCorporateActionOwned {
+  pub sid: i64,
+  pub symbol: String,
+  pub action_type: String,
+  pub ex_date: NaiveDate,
+  pub declaration_date: Option<NaiveDate>,
+  pub record_date: Option<NaiveDate>,
+  pub pay_date: Option<NaiveDate>,
+  pub amount: Option<f64>,
+  pub split_ratio: Option<f64>,
+}
+
+impl NewCorporateActionOwned {
+  /// Inserts events in chunks of 1000. On key conflicts the dates and
+  /// values are overwritten, since declared dividends gain their record
+  /// and pay dates only after the first load. Returns the rows written.
+  pub fn upsert_batch(
+    conn: &mut PgConnection,
+    records: &[Self],
+  ) -> Result<usize, diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+    let mut total_written = 0;
+
+    for chunk in records.chunks(BATCH_SIZE) {
+      total_written += diesel::insert_into(corporate_actions::table)
+        .values(chunk)
+        .on_conflict((
+          corporate_actions::sid,
+          corporate_actions::action_type,
+          corporate_actions::ex_date,
+        ))
+        .do_update()
+        .set((
+          corporate_actions::declaration_date.eq(excluded(corporate_actions::declaration_date)),
+          corporate_actions::record_date.eq(excluded(corporate_actions::record_date)),
+          corporate_actions::pay_date.eq(excluded(corporate_actions::pay_date)),
+          corporate_actions::amount.eq(excluded(corporate_actions::amount)),
+          corporate_actions::split_ratio.eq(excluded(corporate_actions::split_ratio)),
+          corporate_actions::loaded_at.eq(diesel::dsl::now),
+        ))
+        .execute(conn)?;
+    }
+
+    Ok(total_written)
+  }
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for corporate actions.
+impl CorporateAction {
+  /// Returns all dividends and splits for `sid` with an `ex_date` between
+  /// `start` and `end` (inclusive), oldest first.
+  pub fn get_range(
+    conn: &mut PgConnection,
+    sid: i64,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    corporate_actions::table
+      .filter(corporate_actions::sid.eq(sid))
+      .filter(corporate_actions::ex_date.between(start, end))
+      .order_by((corporate_actions::ex_date.asc(), corporate_actions::action_type.asc()))
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the most recent `ex_date` stored for `sid` and `action_type`,
+  /// or `None` if nothing has been loaded yet.
+  pub fn latest_ex_date(
+    conn: &mut PgConnection,
+    sid: i64,
+    action_type: &str,
+  ) -> Result<Option<NaiveDate>, diesel::result::Error> {
+    corporate_actions::table
+      .filter(corporate_actions::sid.eq(sid))
+      .filter(corporate_actions::action_type.eq(action_type))
+      .select(diesel::dsl::max(corporate_actions::ex_date))
+      .first(conn)
+  }
+}
@@ -415,6 +415,9 @@ pub struct NewCryptoSocial {
 
 // ─── Crypto API Mapping ─────────────────────────────────────────────────────
 
+/// `(sid, symbol, name, api_id)` row returned by mapping lookups.
+pub type CryptoMappingRow = (i64, String, String, Option<String>);
+
 /// Maps an internal security ID (`sid`) to an external API's identifier.
 ///
 /// Maps to the `crypto_api_map` table with a **composite primary key**
@@ -649,7 +652,7 @@ impl CryptoApiMap {
   pub fn get_active_cryptos_with_mappings(
     conn: &mut PgConnection,
     api_source_param: &str,
-  ) -> Result<Vec<(i64, String, String, Option<String>)>, diesel::result::Error> {
+  ) -> Result<Vec<CryptoMappingRow>, diesel::result::Error> {
     use crate::schema::{crypto_api_map, crypto_markets, symbols};
 
     symbols::table
@@ -666,7 +669,7 @@ impl CryptoApiMap {
       .filter(crypto_markets::is_active.eq(Some(true)))
       .select((symbols::sid, symbols::symbol, symbols::name, crypto_api_map::api_id.nullable()))
       .distinct()
-      .load::<(i64, String, String, Option<String>)>(conn)
+      .load::<CryptoMappingRow>(conn)
   }
 
   /// Builds an aggregate [`CryptoSummary`] with counts of total crypto symbols,
@@ -0,0 +1,182 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for US economic indicator series.
+//!
+//! Every Alpha Vantage economic endpoint (`REAL_GDP`, `CPI`, `INFLATION`,
+//! `TREASURY_YIELD`, `FEDERAL_FUNDS_RATE`, `UNEMPLOYMENT`, `NONFARM_PAYROLL`)
+//! lands in the single `economic_indicators` TimescaleDB hypertable,
+//! partitioned by observation `date`.
+//!
+//! | Table                 | Model                  | Description                          |
+//! |-----------------------|------------------------|--------------------------------------|
+//! | `economic_indicators` | [`EconomicIndicator`]  | One observation of one macro series  |
+//!
+//! # Series identity
+//!
+//! A series is identified by `(indicator, series_interval, maturity)`.
+//! `maturity` is only meaningful for `TREASURY_YIELD` and is stored as an
+//! empty string for every other indicator, so the composite primary key
+//! never contains `NULL`.
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`), matching the loader
+//! write path:
+//!
+//! - **Write:** [`NewEconomicIndicatorOwned::upsert_batch`] — revised
+//!   observations overwrite earlier values.
+//! - **Query:** [`EconomicIndicator::get_series`],
+//!   [`EconomicIndicator::latest_date`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::economic_indicators;
+
+// ─── Queryable model ────────────────────────────────────────────────────────
+
+/// A single observation of an economic indicator series.
+///
+/// # Key fields
+///
+/// | Field             | Type            | Description                                     |
+/// |-------------------|-----------------|-------------------------------------------------|
+/// | `indicator`       | `String`        | API function name (e.g., `"CPI"`)               |
+/// | `series_interval` | `String`        | Sampling interval (e.g., `"monthly"`)           |
+/// | `maturity`        | `String`        | Treasury maturity, or `""` for other indicators |
+/// | `date`            | `NaiveDate`     | Observation date                                |
+/// | `value`           | `Option<f64>`   | `None` when the API published `"."`             |
+/// | `unit` / `name`   | `String`        | Unit of measure and series name from the API    |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = economic_indicators)]
+#[diesel(primary_key(indicator, series_interval, maturity, date))]
+pub struct EconomicIndicator {
+  pub indicator: String,
+  pub series_interval: String,
+  pub maturity: String,
+  pub date: NaiveDate,
+  pub value: Option<f64>,
+  pub unit: String,
+  pub name: String,
+  pub loaded_at: DateTime<Utc>,
+}
+
+// ─── Insertable model ───────────────────────────────────────────────────────
+
+/// Insertable form of [`EconomicIndicator`]. `loaded_at` uses the database
+/// default.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = economic_indicators)]
+pub struct NewEconomicIndicatorOwned {
+  pub indicator: String,
+  pub series_interval: String,
+  pub maturity: String,
+  pub date: NaiveDate,
+  pub value: Option<f64>,
+  pub unit: String,
+  pub name: String,
+}
+
+impl NewEconomicIndicatorOwned {
+  /// Inserts observations in chunks of 1000, updating `value`, `unit`, and
+  /// `name` on key conflicts so that revised figures replace earlier ones.
+  /// Returns the total number of rows written.
+  pub fn upsert_batch(
+    conn: &mut PgConnection,
+    records: &[Self],
+  ) -> Result<usize, diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+    let mut total_written = 0;
+
+    for chunk in records.chunks(BATCH_SIZE) {
+      total_written += diesel::insert_into(economic_indicators::table)
+        .values(chunk)
+        .on_conflict((
+          economic_indicators::indicator,
+          economic_indicators::series_interval,
+          economic_indicators::maturity,
+          economic_indicators::date,
+        ))
+        .do_update()
+        .set((
+          economic_indicators::value.eq(excluded(economic_indicators::value)),
+          economic_indicators::unit.eq(excluded(economic_indicators::unit)),
+          economic_indicators::name.eq(excluded(economic_indicators::name)),
+          economic_indicators::loaded_at.eq(diesel::dsl::now),
+        ))
+        .execute(conn)?;
+    }
+
+    Ok(total_written)
+  }
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for economic indicator series.
+impl EconomicIndicator {
+  /// Returns the observations of one series between `start` and `end`
+  /// (inclusive), oldest first.
+  pub fn get_series(
+    conn: &mut PgConnection,
+    indicator: &str,
+    series_interval: &str,
+    maturity: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    economic_indicators::table
+      .filter(economic_indicators::indicator.eq(indicator))
+      .filter(economic_indicators::series_interval.eq(series_interval))
+      .filter(economic_indicators::maturity.eq(maturity))
+      .filter(economic_indicators::date.between(start, end))
+      .order_by(economic_indicators::date.asc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the most recent stored observation date for one series, or
+  /// `None` if the series has never been loaded.
+  pub fn latest_date(
+    conn: &mut PgConnection,
+    indicator: &str,
+    series_interval: &str,
+    maturity: &str,
+  ) -> Result<Option<NaiveDate>, diesel::result::Error> {
+    economic_indicators::table
+      .filter(economic_indicators::indicator.eq(indicator))
+      .filter(economic_indicators::series_interval.eq(series_interval))
+      .filter(economic_indicators::maturity.eq(maturity))
+      .select(diesel::dsl::max(economic_indicators::date))
+      .first(conn)
+  }
+}
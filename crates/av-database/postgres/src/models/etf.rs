@@ -0,0 +1,324 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for ETF profile snapshots.
+//!
+//! Each `ETF_PROFILE` load writes one snapshot per ETF and date across
+//! three tables. Holdings reference the constituent's `symbols` row when
+//! the ticker is known; unknown tickers are stored with a `NULL`
+//! `constituent_sid`, recorded in `missing_symbols`, and linked later by
+//! [`EtfHolding::link_constituents`].
+//!
+//! ```text
+//! symbols ──1:N──► etf_profiles ──1:N──► etf_holdings ──N:1──► symbols (constituent)
+//!                       └────────1:N──► etf_sector_weights
+//! ```
+//!
+//! | Table                | Query model         | Insertable                   |
+//! |----------------------|---------------------|------------------------------|
+//! | `etf_profiles`       | [`EtfProfile`]      | [`NewEtfProfileOwned`]       |
+//! | `etf_holdings`       | [`EtfHolding`]      | [`NewEtfHoldingOwned`]       |
+//! | `etf_sector_weights` | [`EtfSectorWeight`] | [`NewEtfSectorWeightOwned`]  |
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewEtfProfileOwned::save_snapshot`] replaces one ETF's
+//!   snapshot for a date in a single transaction;
+//!   [`EtfHolding::link_constituents`] fills in missing constituent SIDs.
+//! - **Query:** [`EtfProfile::latest`], [`EtfHolding::for_snapshot`],
+//!   [`EtfHolding::holders_of`], [`EtfSectorWeight::for_snapshot`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{etf_holdings, etf_profiles, etf_sector_weights};
+
+// ─── Queryable models ───────────────────────────────────────────────────────
+
+/// Key metrics of one ETF on one snapshot date.
+///
+/// Ratios are fractions: a `net_expense_ratio` of `0.002` is 0.20%.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = etf_profiles)]
+#[diesel(primary_key(etf_sid, snapshot_date))]
+pub struct EtfProfile {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub symbol: String,
+  pub net_assets: Option<f64>,
+  pub net_expense_ratio: Option<f64>,
+  pub portfolio_turnover: Option<f64>,
+  pub dividend_yield: Option<f64>,
+  pub inception_date: Option<NaiveDate>,
+  pub leveraged: Option<bool>,
+  pub loaded_at: DateTime<Utc>,
+}
+
+/// One constituent line of an ETF snapshot.
+///
+/// # Key fields
+///
+/// | Field             | Type             | Description                                  |
+/// |-------------------|------------------|----------------------------------------------|
+/// | `position`        | `i32`            | Rank by weight within the snapshot, from 0   |
+/// | `holding_symbol`  | `Option<String>` | `None` for cash, futures, and other untickered lines |
+/// | `constituent_sid` | `Option<i64>`    | `None` until the ticker exists in `symbols`  |
+/// | `weight`          | `f64`            | Fraction of net assets                       |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = etf_holdings)]
+#[diesel(primary_key(etf_sid, snapshot_date, position))]
+pub struct EtfHolding {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub position: i32,
+  pub holding_symbol: Option<String>,
+  pub constituent_sid: Option<i64>,
+  pub description: String,
+  pub weight: f64,
+}
+
+/// Share of one ETF snapshot in one sector.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = etf_sector_weights)]
+#[diesel(primary_key(etf_sid, snapshot_date, sector))]
+pub struct EtfSectorWeight {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub sector: String,
+  pub weight: f64,
+}
+
+// ─── Insertable models ──────────────────────────────────────────────────────
+
+/// Insertable form of [`EtfProfile`]; `loaded_at` defaults to `NOW()`.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = etf_profiles)]
+pub struct NewEtfProfileOwned {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub symbol: String,
+  pub net_assets: Option<f64>,
+  pub net_expense_ratio: Option<f64>,
+  pub portfolio_turnover: Option<f64>,
+  pub dividend_yield: Option<f64>,
+  pub inception_date: Option<NaiveDate>,
+  pub leveraged: Option<bool>,
+}
+
+/// Insertable form of [`EtfHolding`]. `etf_sid` and `snapshot_date` are
+/// overwritten from the profile by [`NewEtfProfileOwned::save_snapshot`].
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = etf_holdings)]
+pub struct NewEtfHoldingOwned {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub position: i32,
+  pub holding_symbol: Option<String>,
+  pub constituent_sid: Option<i64>,
+  pub description: String,
+  pub weight: f64,
+}
+
+/// Insertable form of [`EtfSectorWeight`]. `etf_sid` and `snapshot_date`
+/// are overwritten from the profile by [`NewEtfProfileOwned::save_snapshot`].
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = etf_sector_weights)]
+pub struct NewEtfSectorWeightOwned {
+  pub etf_sid: i64,
+  pub snapshot_date: NaiveDate,
+  pub sector: String,
+  pub weight: f64,
+}
+
+impl NewEtfProfileOwned {
+  /// Upserts the profile and replaces its holdings and sector weights for
+  /// the same snapshot date, all in one transaction. Returns the number of
+  /// holdings and sector rows written.
+  pub fn save_snapshot(
+    &self,
+    conn: &mut PgConnection,
+    holdings: &[NewEtfHoldingOwned],
+    sectors: &[NewEtfSectorWeightOwned],
+  ) -> Result<(usize, usize), diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+
+    let holdings: Vec<NewEtfHoldingOwned> = holdings
+      .iter()
+      .cloned()
+      .map(|h| NewEtfHoldingOwned { etf_sid: self.etf_sid, snapshot_date: self.snapshot_date, ..h })
+      .collect();
+    let sectors: Vec<NewEtfSectorWeightOwned> = sectors
+      .iter()
+      .cloned()
+      .map(|s| NewEtfSectorWeightOwned {
+        etf_sid: self.etf_sid,
+        snapshot_date: self.snapshot_date,
+        ..s
+      })
+      .collect();
+
+    conn.transaction(|conn| {
+      diesel::insert_into(etf_profiles::table)
+        .values(self)
+        .on_conflict((etf_profiles::etf_sid, etf_profiles::snapshot_date))
+        .do_update()
+        .set((
+          etf_profiles::net_assets.eq(excluded(etf_profiles::net_assets)),
+          etf_profiles::net_expense_ratio.eq(excluded(etf_profiles::net_expense_ratio)),
+          etf_profiles::portfolio_turnover.eq(excluded(etf_profiles::portfolio_turnover)),
+          etf_profiles::dividend_yield.eq(excluded(etf_profiles::dividend_yield)),
+          etf_profiles::inception_date.eq(excluded(etf_profiles::inception_date)),
+          etf_profiles::leveraged.eq(excluded(etf_profiles::leveraged)),
+          etf_profiles::loaded_at.eq(diesel::dsl::now),
+        ))
+        .execute(conn)?;
+
+      diesel::delete(
+        etf_holdings::table
+          .filter(etf_holdings::etf_sid.eq(self.etf_sid))
+          .filter(etf_holdings::snapshot_date.eq(self.snapshot_date)),
+      )
+      .execute(conn)?;
+      diesel::delete(
+        etf_sector_weights::table
+          .filter(etf_sector_weights::etf_sid.eq(self.etf_sid))
+          .filter(etf_sector_weights::snapshot_date.eq(self.snapshot_date)),
+      )
+      .execute(conn)?;
+
+      let mut holdings_written = 0;
+      for chunk in holdings.chunks(BATCH_SIZE) {
+        holdings_written += diesel::insert_into(etf_holdings::table).values(chunk).execute(conn)?;
+      }
+      let sectors_written =
+        diesel::insert_into(etf_sector_weights::table).values(&sectors).execute(conn)?;
+
+      Ok((holdings_written, sectors_written))
+    })
+  }
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for ETF profiles.
+impl EtfProfile {
+  /// Returns the most recent profile snapshot for an ETF.
+  pub fn latest(
+    conn: &mut PgConnection,
+    etf_sid: i64,
+  ) -> Result<Option<Self>, diesel::result::Error> {
+    etf_profiles::table
+      .filter(etf_profiles::etf_sid.eq(etf_sid))
+      .order_by(etf_profiles::snapshot_date.desc())
+      .select(Self::as_select())
+      .first(conn)
+      .optional()
+  }
+}
+
+/// Synchronous read and maintenance operations for ETF holdings.
+impl EtfHolding {
+  /// Returns the holdings of one snapshot, largest weight first.
+  pub fn for_snapshot(
+    conn: &mut PgConnection,
+    etf_sid: i64,
+    snapshot_date: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    etf_holdings::table
+      .filter(etf_holdings::etf_sid.eq(etf_sid))
+      .filter(etf_holdings::snapshot_date.eq(snapshot_date))
+      .order_by(etf_holdings::position.asc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns every ETF's most recent holding of `constituent_sid`, largest
+  /// weight first.
+  pub fn holders_of(
+    conn: &mut PgConnection,
+    constituent_sid: i64,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    let rows: Vec<Self> = etf_holdings::table
+      .filter(etf_holdings::constituent_sid.eq(constituent_sid))
+      .order_by((etf_holdings::etf_sid.asc(), etf_holdings::snapshot_date.desc()))
+      .select(Self::as_select())
+      .load(conn)?;
+
+    let mut latest: Vec<Self> = Vec::new();
+    for row in rows {
+      if latest.last().is_none_or(|prev| prev.etf_sid != row.etf_sid) {
+        latest.push(row);
+      }
+    }
+    latest.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    Ok(latest)
+  }
+
+  /// Sets `constituent_sid` on holdings whose ticker has since been added
+  /// to `symbols`, preferring the highest-priority listing. Returns the
+  /// number of rows linked.
+  pub fn link_constituents(conn: &mut PgConnection) -> Result<usize, diesel::result::Error> {
+    diesel::sql_query(
+      r#"
+            UPDATE etf_holdings h
+            SET constituent_sid = s.sid
+            FROM (
+                SELECT DISTINCT ON (symbol) symbol, sid
+                FROM symbols
+                WHERE sec_type NOT IN ('Cryptocurrency', 'Commodity')
+                ORDER BY symbol, priority
+            ) s
+            WHERE h.constituent_sid IS NULL
+                AND h.holding_symbol = s.symbol
+            "#,
+    )
+    .execute(conn)
+  }
+}
+
+/// Synchronous read operations for ETF sector weights.
+impl EtfSectorWeight {
+  /// Returns the sector allocation of one snapshot, largest weight first.
+  pub fn for_snapshot(
+    conn: &mut PgConnection,
+    etf_sid: i64,
+    snapshot_date: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    etf_sector_weights::table
+      .filter(etf_sector_weights::etf_sid.eq(etf_sid))
+      .filter(etf_sector_weights::snapshot_date.eq(snapshot_date))
+      .order_by(etf_sector_weights::weight.desc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+}
@@ -0,0 +1,233 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for insider transactions.
+//!
+//! Each row is one trade by an officer, director, or major holder, linked
+//! to the issuer's `symbols` row. AlphaVantage provides no transaction
+//! identifier, so rows are deduplicated on their full content through a
+//! unique index, and reloading the same history inserts nothing.
+//!
+//! | Table                  | Model                  | Description                       |
+//! |------------------------|------------------------|-----------------------------------|
+//! | `insider_transactions` | [`InsiderTransaction`] | One insider purchase or sale      |
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewInsiderTransactionOwned::insert_batch`].
+//! - **Query:** [`InsiderTransaction::get_range`],
+//!   [`InsiderTransaction::latest_date`], [`InsiderTransaction::cluster_buys`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::insider_transactions;
+
+// ─── Queryable model ────────────────────────────────────────────────────────
+
+/// A single insider trade.
+///
+/// # Key fields
+///
+/// | Field                     | Type        | Description                              |
+/// |---------------------------|-------------|------------------------------------------|
+/// | `sid`                     | `i64`       | Issuer security ID (FK to `symbols`)     |
+/// | `executive`               | `String`    | Insider name as filed                    |
+/// | `acquisition_or_disposal` | `String`    | `"A"` (acquired) or `"D"` (disposed)     |
+/// | `share_price`             | `f64`       | `0.0` for grants and awards              |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = insider_transactions)]
+pub struct InsiderTransaction {
+  pub id: i64,
+  pub sid: i64,
+  pub symbol: String,
+  pub transaction_date: NaiveDate,
+  pub executive: String,
+  pub executive_title: String,
+  pub security_type: String,
+  pub acquisition_or_disposal: String,
+  pub shares: f64,
+  pub share_price: f64,
+  pub loaded_at: DateTime<Utc>,
+}
+
+impl InsiderTransaction {
+  /// True for open-market purchases: acquisitions with a non-zero price.
+  pub fn is_purchase(&self) -> bool {
+    self.acquisition_or_disposal == "A" && self.share_price > 0.0
+  }
+}
+
+// ─── Insertable model ───────────────────────────────────────────────────────
+
+/// Insertable form of [`InsiderTransaction`]; `id` and `loaded_at` are
+/// generated by the database.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = insider_transactions)]
+pub struct NewInsiderTransactionOwned {
+  pub sid: i64,
+  pub symbol: String,
+  pub transaction_date: NaiveDate,
+  pub executive: String,
+  pub executive_title: String,
+  pub security_type: String,
+  pub acquisition_or_disposal: String,
+  pub shares: f64,
+  pub share_price: f64,
+}
+
+impl NewInsiderTransactionOwned {
+  /// Inserts trades in chunks of 1000, skipping rows that already exist.
+  /// Returns the number of new rows.
+  pub fn insert_batch(
+    conn: &mut PgConnection,
+    records: &[Self],
+  ) -> Result<usize, diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+    let mut total_inserted = 0;
+
+    for chunk in records.chunks(BATCH_SIZE) {
+      total_inserted += diesel::insert_into(insider_transactions::table)
+        .values(chunk)
+        .on_conflict((
+          insider_transactions::sid,
+          insider_transactions::transaction_date,
+          insider_transactions::executive,
+          insider_transactions::security_type,
+          insider_transactions::acquisition_or_disposal,
+          insider_transactions::shares,
+          insider_transactions::share_price,
+        ))
+        .do_nothing()
+        .execute(conn)?;
+    }
+
+    Ok(total_inserted)
+  }
+}
+
+// ─── Aggregations ───────────────────────────────────────────────────────────
+
+/// Open-market buying by several distinct insiders of one issuer within a
+/// window, as returned by [`InsiderTransaction::cluster_buys`].
+#[derive(QueryableByName, Debug, Clone, Serialize)]
+pub struct InsiderCluster {
+  #[diesel(sql_type = diesel::sql_types::BigInt)]
+  pub sid: i64,
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub symbol: String,
+  /// Distinct insiders who bought
+  #[diesel(sql_type = diesel::sql_types::BigInt)]
+  pub insiders: i64,
+  #[diesel(sql_type = diesel::sql_types::BigInt)]
+  pub transactions: i64,
+  #[diesel(sql_type = diesel::sql_types::Double)]
+  pub total_shares: f64,
+  /// Sum of shares × price
+  #[diesel(sql_type = diesel::sql_types::Double)]
+  pub total_value: f64,
+  #[diesel(sql_type = diesel::sql_types::Date)]
+  pub first_date: NaiveDate,
+  #[diesel(sql_type = diesel::sql_types::Date)]
+  pub last_date: NaiveDate,
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for insider trades.
+impl InsiderTransaction {
+  /// Returns the trades for `sid` dated between `start` and `end`
+  /// (inclusive), newest first.
+  pub fn get_range(
+    conn: &mut PgConnection,
+    sid: i64,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    insider_transactions::table
+      .filter(insider_transactions::sid.eq(sid))
+      .filter(insider_transactions::transaction_date.between(start, end))
+      .order_by((insider_transactions::transaction_date.desc(), insider_transactions::id.asc()))
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the most recent `transaction_date` stored for `sid`, or `None`
+  /// if nothing has been loaded yet.
+  pub fn latest_date(
+    conn: &mut PgConnection,
+    sid: i64,
+  ) -> Result<Option<NaiveDate>, diesel::result::Error> {
+    insider_transactions::table
+      .filter(insider_transactions::sid.eq(sid))
+      .select(diesel::dsl::max(insider_transactions::transaction_date))
+      .first(conn)
+  }
+
+  /// Returns issuers where at least `min_insiders` distinct insiders made
+  /// open-market purchases on or after `since`, ordered by the number of
+  /// insiders and then by purchase value.
+  ///
+  /// Grants and awards (zero share price) are excluded.
+  pub fn cluster_buys(
+    conn: &mut PgConnection,
+    since: NaiveDate,
+    min_insiders: i64,
+  ) -> Result<Vec<InsiderCluster>, diesel::result::Error> {
+    use diesel::sql_query;
+    use diesel::sql_types::{BigInt, Date};
+
+    sql_query(
+      r#"
+            SELECT
+                sid,
+                symbol,
+                COUNT(DISTINCT executive) AS insiders,
+                COUNT(*) AS transactions,
+                SUM(shares) AS total_shares,
+                SUM(shares * share_price) AS total_value,
+                MIN(transaction_date) AS first_date,
+                MAX(transaction_date) AS last_date
+            FROM insider_transactions
+            WHERE transaction_date >= $1
+                AND acquisition_or_disposal = 'A'
+                AND share_price > 0
+            GROUP BY sid, symbol
+            HAVING COUNT(DISTINCT executive) >= $2
+            ORDER BY insiders DESC, total_value DESC
+            "#,
+    )
+    .bind::<Date, _>(since)
+    .bind::<BigInt, _>(min_insiders)
+    .load::<InsiderCluster>(conn)
+  }
+}
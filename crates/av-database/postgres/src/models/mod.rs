@@ -51,16 +51,38 @@
 //! ```text
 //! models/
 //! ├── mod.rs              ← this file (public façade, re-exports)
+//! ├── commodities.rs      → commodity price series keyed by commodity SID
+//! ├── corporate_actions.rs → dividends and stock splits per security
 //! ├── crypto.rs           → cryptocurrency overview, technical, social, and API mapping
 //! ├── crypto_markets.rs   → crypto exchange/trading-pair market data
+//! ├── economics.rs        → US economic indicator series (GDP, CPI, yields, ...)
+//! ├── etf.rs              → ETF profile snapshots: metrics, holdings, sector weights
+//! ├── insiders.rs         → insider purchases and sales per issuer
 //! ├── missing_symbols.rs  → unresolved symbol tracking and resolution workflow
 //! ├── news.rs             → news articles, feeds, authors, sources, sentiment, topics
+//! ├── options.rs          → daily option chain snapshots keyed by OCC contract symbol
 //! ├── price.rs            → intraday & summary OHLCV, top movers, sector performance
-//! └── security.rs         → symbols, company overviews, equity details, symbol mappings
+//! ├── quota.rs            → AlphaVantage calls per day, API key and function
+//! ├── security.rs         → symbols, company overviews, equity details, symbol mappings
+//! └── transcripts.rs      → earnings call transcripts with per-segment sentiment
 //! ```
 //!
 //! # Type inventory by sub-module
 //!
+//! ## [`commodities`] — Commodity prices
+//!
+//! | Type                     | Role                                                        |
+//! |--------------------------|-------------------------------------------------------------|
+//! | `CommodityPrice`         | One dated price of a commodity series (hypertable row)      |
+//! | `NewCommodityPriceOwned` | Insertable with batched upsert                              |
+//!
+//! ## [`corporate_actions`] — Dividends and splits
+//!
+//! | Type                      | Role                                                        |
+//! |---------------------------|-------------------------------------------------------------|
+//! | `CorporateAction`         | One dividend or split event for a security                  |
+//! | `NewCorporateActionOwned` | Insertable with batched upsert                              |
+//!
 //! ## [`crypto`] — Cryptocurrency fundamentals
 //!
 //! | Type                       | Role                                                          |
@@ -85,6 +107,30 @@
 //! | `ExchangeStats`        | Per-exchange aggregated metrics                               |
 //! | `CryptoMarketInput`    | Input DTO for market data ingestion                           |
 //!
+//! ## [`economics`] — Economic indicator series
+//!
+//! | Type                        | Role                                                     |
+//! |-----------------------------|----------------------------------------------------------|
+//! | `EconomicIndicator`         | One observation of a macro series (hypertable row)       |
+//! | `NewEconomicIndicatorOwned` | Insertable with batched upsert for revised observations  |
+//!
+//! ## [`etf`] — ETF profiles
+//!
+//! | Type                      | Role                                                        |
+//! |---------------------------|-------------------------------------------------------------|
+//! | `EtfProfile`              | Net assets, expense ratio, and yield on one snapshot date   |
+//! | `EtfHolding`              | One constituent line, linked to its SID when known          |
+//! | `EtfSectorWeight`         | Sector allocation on one snapshot date                      |
+//! | `NewEtfProfileOwned`      | Insertable; `save_snapshot` replaces a snapshot transactionally |
+//!
+//! ## [`insiders`] — Insider transactions
+//!
+//! | Type                         | Role                                                     |
+//! |------------------------------|----------------------------------------------------------|
+//! | `InsiderTransaction`         | One insider trade linked to the issuer's SID             |
+//! | `NewInsiderTransactionOwned` | Insertable with batched, duplicate-skipping insert       |
+//! | `InsiderCluster`             | Aggregate: distinct insiders buying one issuer in a window |
+//!
 //! ## [`missing_symbols`] — Symbol resolution tracking
 //!
 //! | Type                   | Role                                                          |
@@ -112,6 +158,13 @@
 //! | `NewsData` / `NewsItem`| Deserialization DTOs for API responses                        |
 //! | `New*` / `New*Owned`   | Insertable structs (borrowed and owned variants)              |
 //!
+//! ## [`options`] — Option chain snapshots
+//!
+//! | Type                  | Role                                                        |
+//! |-----------------------|-------------------------------------------------------------|
+//! | `OptionPrice`         | One OCC contract quote with Greeks/IV on one date (hypertable row) |
+//! | `NewOptionPriceOwned` | Insertable with batched upsert                              |
+//!
 //! ## [`price`] — OHLCV price data
 //!
 //! | Type                | Role                                                          |
@@ -125,6 +178,12 @@
 //! | `SectorPerformance` | Per-sector aggregated performance metrics                     |
 //! | `New*` / `New*Owned`| Insertable structs for each record type                       |
 //!
+//! ## [`quota`] — API call ledger
+//!
+//! | Type            | Role                                                          |
+//! |-----------------|---------------------------------------------------------------|
+//! | `ApiQuotaUsage` | Calls per UTC day, masked key and function; upsert increment  |
+//!
 //! ## [`security`] — Securities and company data
 //!
 //! | Type                | Role                                                          |
@@ -136,6 +195,15 @@
 //! | `SymbolMapping`     | Maps external identifiers to internal symbol IDs              |
 //! | `New*` / `New*Owned`| Insertable structs (borrowed and owned variants)              |
 //!
+//! ## [`transcripts`] — Earnings call transcripts
+//!
+//! | Type                         | Role                                                     |
+//! |------------------------------|----------------------------------------------------------|
+//! | `EarningsTranscript`         | Header row per symbol and fiscal quarter                 |
+//! | `TranscriptSegment`          | One speaker passage with sentiment                       |
+//! | `NewEarningsTranscriptOwned` | Insertable; `save` replaces a quarter transactionally    |
+//! | `TranscriptSearchHit`        | Full-text search result with highlighted excerpt         |
+//!
 //! # Common patterns
 //!
 //! - **Diesel derives:** All query types implement `Queryable`, `Selectable`,
@@ -154,6 +222,14 @@
 //! `av_database_postgres::models::*`. The full sub-modules remain accessible
 //! for types not re-exported here.
 
+/// Commodity price series (energy, metals, agriculture) stored next to
+/// `summaryprices` and keyed by commodity SID.
+pub mod commodities;
+
+/// Dividend and stock split events, stored together so price series can be
+/// adjusted from a single range query.
+pub mod corporate_actions;
+
 /// Cryptocurrency fundamental data: overviews, technical blockchain metrics,
 /// social metrics, and Alpha Vantage API symbol mapping.
 pub mod crypto;
@@ -162,6 +238,17 @@ pub mod crypto;
 /// and per-exchange aggregated statistics.
 pub mod crypto_markets;
 
+/// US economic indicator observations (real GDP, CPI, inflation, treasury
+/// yields, federal funds rate, unemployment, nonfarm payroll).
+pub mod economics;
+
+/// ETF profile snapshots: key metrics, constituent holdings keyed by
+/// constituent SID, and sector allocation.
+pub mod etf;
+
+/// Insider trades from Form 4 filings, with cluster-buying aggregation.
+pub mod insiders;
+
 /// Tracks unresolved symbol references encountered during data ingestion.
 /// Supports a resolution workflow with status transitions
 /// (`Pending` → `Found` / `NotFound` / `Skipped`).
@@ -171,19 +258,37 @@ pub mod missing_symbols;
 /// topic taxonomy, and aggregated sentiment analytics.
 pub mod news;
 
+/// Daily option chain snapshots with Greeks and implied volatility, keyed
+/// by snapshot date and OCC contract symbol.
+pub mod options;
+
 /// OHLCV price data: intraday bars, daily summaries, top gainers/losers,
 /// TimescaleDB time-bucket aggregations, and sector performance.
 pub mod price;
 
+/// AlphaVantage call counters per UTC day, masked API key and function,
+/// used to enforce the daily quota across processes.
+pub mod quota;
+
 /// Core security records: ticker symbols, company overviews, extended
 /// fundamentals, equity details, and external-to-internal symbol mappings.
 pub mod security;
 
+/// Earnings call transcripts split into speaker segments with sentiment,
+/// searchable with PostgreSQL full-text search.
+pub mod transcripts;
+
 // ─── Convenience re-exports ─────────────────────────────────────────────────
 //
 // Hoist the most frequently used types so downstream code can import from
 // `models::*` without spelling out individual sub-module paths.
 
+/// Re-exported from [`commodities`]: price row and its upsert insertable.
+pub use commodities::{CommodityPrice, NewCommodityPriceOwned};
+
+/// Re-exported from [`corporate_actions`]: event row and its upsert insertable.
+pub use corporate_actions::{CorporateAction, NewCorporateActionOwned};
+
 /// Re-exported from [`crypto`]: overview, technical, social, and API-map types
 /// with their insertable counterparts.
 pub use crypto::{
@@ -192,18 +297,42 @@ pub use crypto::{
   NewCryptoSocial, NewCryptoTechnical,
 };
 
+/// Re-exported from [`economics`]: observation row and its upsert insertable.
+pub use economics::{EconomicIndicator, NewEconomicIndicatorOwned};
+
+/// Re-exported from [`etf`]: profile, holding, and sector rows with their insertables.
+pub use etf::{
+  EtfHolding, EtfProfile, EtfSectorWeight, NewEtfHoldingOwned, NewEtfProfileOwned,
+  NewEtfSectorWeightOwned,
+};
+
+/// Re-exported from [`insiders`]: trade row, insertable, and cluster aggregate.
+pub use insiders::{InsiderCluster, InsiderTransaction, NewInsiderTransactionOwned};
+
 /// Re-exported from [`missing_symbols`]: resolution status enum, query/insert/update types.
 pub use missing_symbols::{MissingSymbol, NewMissingSymbol, ResolutionStatus, UpdateMissingSymbol};
 
 /// Re-exported from [`news`]: core article, feed, overview, and sentiment types.
 pub use news::{Article, Feed, NewsOverview, TickerSentiment};
 
+/// Re-exported from [`options`]: contract quote row and its upsert insertable.
+pub use options::{NewOptionPriceOwned, OptionPrice};
+
 /// Re-exported from [`price`]: intraday and summary OHLCV, top-mover snapshots.
 pub use price::{IntradayPrice, SummaryPrice, TopStat};
 
+/// Re-exported from [`quota`]: daily call counter.
+pub use quota::ApiQuotaUsage;
+
 /// Re-exported from [`security`]: symbol records, company overviews (including
 /// extended), symbol mappings, and their owned insertable variants.
 pub use security::{
   NewOverviewOwned, NewOverviewextOwned, NewSymbol, NewSymbolMapping, NewSymbolOwned, Overview,
   Overviewext, Symbol, SymbolMapping,
 };
+
+/// Re-exported from [`transcripts`]: transcript header, segment, and search hit.
+pub use transcripts::{
+  EarningsTranscript, NewEarningsTranscriptOwned, NewTranscriptSegmentOwned, TranscriptSearchHit,
+  TranscriptSegment,
+};
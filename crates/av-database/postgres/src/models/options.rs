@@ -0,0 +1,229 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for option chain snapshots.
+//!
+//! Option quotes live in the `optionprices` TimescaleDB hypertable,
+//! partitioned by `snapshot_date`. Each row is one OCC contract on one
+//! trading date, linked to the underlying's `symbols` row through
+//! `underlying_sid`. Contracts are not registered in `symbols`; the OCC
+//! symbol (`contract_id`) already identifies them uniquely.
+//!
+//! | Table          | Model           | Description                                  |
+//! |----------------|-----------------|----------------------------------------------|
+//! | `optionprices` | [`OptionPrice`] | One contract quote with Greeks and IV for one date |
+//!
+//! `occ_root` is stored separately from `underlying` because adjusted
+//! contracts (after splits or special dividends) trade under a modified
+//! root such as `IBM1`.
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewOptionPriceOwned::upsert_batch`].
+//! - **Query:** [`OptionPrice::get_chain`], [`OptionPrice::get_contract_history`],
+//!   [`OptionPrice::snapshot_dates`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::optionprices;
+
+// ─── Queryable model ────────────────────────────────────────────────────────
+
+/// A single option contract quote on one snapshot date.
+///
+/// # Key fields
+///
+/// | Field            | Type          | Description                                 |
+/// |------------------|---------------|---------------------------------------------|
+/// | `snapshot_date`  | `NaiveDate`   | Trading date of the quote                   |
+/// | `contract_id`    | `String`      | Compact OCC symbol (`IBM270115C00390000`)   |
+/// | `underlying_sid` | `i64`         | Underlying security ID (FK to `symbols`)    |
+/// | `occ_root`       | `String`      | OCC root parsed from `contract_id`          |
+/// | `option_type`    | `String`      | `"call"` or `"put"`                         |
+/// | `implied_volatility` .. `rho` | `Option<f64>` | `None` when not published    |
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = optionprices)]
+#[diesel(primary_key(snapshot_date, contract_id))]
+pub struct OptionPrice {
+  pub snapshot_date: NaiveDate,
+  pub contract_id: String,
+  pub underlying_sid: i64,
+  pub underlying: String,
+  pub occ_root: String,
+  pub expiration: NaiveDate,
+  pub strike: f64,
+  pub option_type: String,
+  pub last: Option<f64>,
+  pub mark: Option<f64>,
+  pub bid: Option<f64>,
+  pub bid_size: Option<i32>,
+  pub ask: Option<f64>,
+  pub ask_size: Option<i32>,
+  pub volume: Option<i64>,
+  pub open_interest: Option<i64>,
+  pub implied_volatility: Option<f64>,
+  pub delta: Option<f64>,
+  pub gamma: Option<f64>,
+  pub theta: Option<f64>,
+  pub vega: Option<f64>,
+  pub rho: Option<f64>,
+  pub loaded_at: DateTime<Utc>,
+}
+
+// ─── Insertable model ───────────────────────────────────────────────────────
+
+/// Insertable form of [`OptionPrice`]; `loaded_at` defaults to `NOW()`.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = optionprices)]
+pub struct NewOptionPriceOwned {
+  pub snapshot_date: NaiveDate,
+  pub contract_id: String,
+  pub underlying_sid: i64,
+  pub underlying: String,
+  pub occ_root: String,
+  pub expiration: NaiveDate,
+  pub strike: f64,
+  pub option_type: String,
+  pub last: Option<f64>,
+  pub mark: Option<f64>,
+  pub bid: Option<f64>,
+  pub bid_size: Option<i32>,
+  pub ask: Option<f64>,
+  pub ask_size: Option<i32>,
+  pub volume: Option<i64>,
+  pub open_interest: Option<i64>,
+  pub implied_volatility: Option<f64>,
+  pub delta: Option<f64>,
+  pub gamma: Option<f64>,
+  pub theta: Option<f64>,
+  pub vega: Option<f64>,
+  pub rho: Option<f64>,
+}
+
+impl NewOptionPriceOwned {
+  /// Inserts quotes in chunks of 1000, overwriting the market data and
+  /// Greeks on key conflicts so that a re-loaded snapshot replaces the
+  /// earlier one. Returns the total number of rows written.
+  pub fn upsert_batch(
+    conn: &mut PgConnection,
+    records: &[Self],
+  ) -> Result<usize, diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+    let mut total_written = 0;
+
+    for chunk in records.chunks(BATCH_SIZE) {
+      total_written += diesel::insert_into(optionprices::table)
+        .values(chunk)
+        .on_conflict((optionprices::snapshot_date, optionprices::contract_id))
+        .do_update()
+        .set((
+          optionprices::last.eq(excluded(optionprices::last)),
+          optionprices::mark.eq(excluded(optionprices::mark)),
+          optionprices::bid.eq(excluded(optionprices::bid)),
+          optionprices::bid_size.eq(excluded(optionprices::bid_size)),
+          optionprices::ask.eq(excluded(optionprices::ask)),
+          optionprices::ask_size.eq(excluded(optionprices::ask_size)),
+          optionprices::volume.eq(excluded(optionprices::volume)),
+          optionprices::open_interest.eq(excluded(optionprices::open_interest)),
+          optionprices::implied_volatility.eq(excluded(optionprices::implied_volatility)),
+          optionprices::delta.eq(excluded(optionprices::delta)),
+          optionprices::gamma.eq(excluded(optionprices::gamma)),
+          optionprices::theta.eq(excluded(optionprices::theta)),
+          optionprices::vega.eq(excluded(optionprices::vega)),
+          optionprices::rho.eq(excluded(optionprices::rho)),
+          optionprices::loaded_at.eq(diesel::dsl::now),
+        ))
+        .execute(conn)?;
+    }
+
+    Ok(total_written)
+  }
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for option snapshots.
+impl OptionPrice {
+  /// Returns the full chain for one underlying on one date, ordered by
+  /// expiration, strike, then side.
+  pub fn get_chain(
+    conn: &mut PgConnection,
+    underlying_sid: i64,
+    snapshot_date: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    optionprices::table
+      .filter(optionprices::underlying_sid.eq(underlying_sid))
+      .filter(optionprices::snapshot_date.eq(snapshot_date))
+      .order_by((
+        optionprices::expiration.asc(),
+        optionprices::strike.asc(),
+        optionprices::option_type.asc(),
+      ))
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the daily history of one contract between `start` and `end`
+  /// (inclusive), oldest first.
+  pub fn get_contract_history(
+    conn: &mut PgConnection,
+    contract_id: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    optionprices::table
+      .filter(optionprices::contract_id.eq(contract_id))
+      .filter(optionprices::snapshot_date.between(start, end))
+      .order_by(optionprices::snapshot_date.asc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the distinct snapshot dates stored for an underlying between
+  /// `start` and `end` (inclusive), ascending.
+  pub fn snapshot_dates(
+    conn: &mut PgConnection,
+    underlying_sid: i64,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<NaiveDate>, diesel::result::Error> {
+    optionprices::table
+      .filter(optionprices::underlying_sid.eq(underlying_sid))
+      .filter(optionprices::snapshot_date.between(start, end))
+      .select(optionprices::snapshot_date)
+      .distinct()
+      .order_by(optionprices::snapshot_date.asc())
+      .load(conn)
+  }
+}
@@ -0,0 +1,128 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel model for the AlphaVantage call ledger.
+//!
+//! `api_quota_usage` holds one counter per UTC day, masked API key and
+//! function. Clients increment it for every request so the daily quota is
+//! tracked across restarts and across processes sharing the database.
+//!
+//! | Table             | Query model       | Write                       |
+//! |-------------------|-------------------|-----------------------------|
+//! | `api_quota_usage` | [`ApiQuotaUsage`] | [`ApiQuotaUsage::record`]   |
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`ApiQuotaUsage::record`] adds one call with a single
+//!   upsert, so concurrent writers never lose an increment.
+//! - **Query:** [`ApiQuotaUsage::for_date`], [`ApiQuotaUsage::calls_for_key`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::api_quota_usage;
+
+/// Calls made with one API key to one function on one UTC day.
+///
+/// `api_key` is the key's ledger key: `****`, its last four characters and
+/// a hash of the full key.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = api_quota_usage)]
+#[diesel(primary_key(usage_date, api_key, function))]
+pub struct ApiQuotaUsage {
+  pub usage_date: NaiveDate,
+  pub api_key: String,
+  pub function: String,
+  pub calls: i32,
+  pub last_call_at: DateTime<Utc>,
+}
+
+/// Insertable for the first call of a day; later calls increment `calls`.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = api_quota_usage)]
+struct NewApiQuotaUsage<'a> {
+  usage_date: NaiveDate,
+  api_key: &'a str,
+  function: &'a str,
+  calls: i32,
+}
+
+impl ApiQuotaUsage {
+  /// Counts one call made with `api_key` to `function` on `usage_date`.
+  pub fn record(
+    conn: &mut PgConnection,
+    usage_date: NaiveDate,
+    api_key: &str,
+    function: &str,
+  ) -> Result<(), diesel::result::Error> {
+    diesel::insert_into(api_quota_usage::table)
+      .values(&NewApiQuotaUsage { usage_date, api_key, function, calls: 1 })
+      .on_conflict((
+        api_quota_usage::usage_date,
+        api_quota_usage::api_key,
+        api_quota_usage::function,
+      ))
+      .do_update()
+      .set((
+        api_quota_usage::calls.eq(api_quota_usage::calls + 1),
+        api_quota_usage::last_call_at.eq(diesel::dsl::now),
+      ))
+      .execute(conn)?;
+    Ok(())
+  }
+
+  /// Returns every counter for `usage_date`, ordered by key and function.
+  pub fn for_date(
+    conn: &mut PgConnection,
+    usage_date: NaiveDate,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    api_quota_usage::table
+      .filter(api_quota_usage::usage_date.eq(usage_date))
+      .order_by((api_quota_usage::api_key.asc(), api_quota_usage::function.asc()))
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Returns the total calls made with `api_key` on `usage_date`.
+  pub fn calls_for_key(
+    conn: &mut PgConnection,
+    usage_date: NaiveDate,
+    api_key: &str,
+  ) -> Result<i64, diesel::result::Error> {
+    let total: Option<i64> = api_quota_usage::table
+      .filter(api_quota_usage::usage_date.eq(usage_date))
+      .filter(api_quota_usage::api_key.eq(api_key))
+      .select(diesel::dsl::sum(api_quota_usage::calls))
+      .first(conn)?;
+    Ok(total.unwrap_or(0))
+  }
+}
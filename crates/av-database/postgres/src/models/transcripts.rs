@@ -0,0 +1,297 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Diesel models for earnings call transcripts.
+//!
+//! Transcripts sit next to the news schema: a header row per symbol and
+//! fiscal quarter in `earnings_transcripts`, and the speaker segments with
+//! their sentiment scores in `transcript_segments`. Segment text carries a
+//! GIN full-text index, so [`TranscriptSegment::search`] can be used the
+//! same way as article search.
+//!
+//! ```text
+//! symbols ──1:N──► earnings_transcripts ──1:N──► transcript_segments
+//! ```
+//!
+//! | Table                  | Query model            | Insertable                      |
+//! |------------------------|------------------------|---------------------------------|
+//! | `earnings_transcripts` | [`EarningsTranscript`] | [`NewEarningsTranscriptOwned`]  |
+//! | `transcript_segments`  | [`TranscriptSegment`]  | [`NewTranscriptSegmentOwned`]   |
+//!
+//! # Key operations
+//!
+//! All methods are synchronous (`&mut PgConnection`):
+//!
+//! - **Write:** [`NewEarningsTranscriptOwned::save`] replaces a quarter's
+//!   header and segments in one transaction.
+//! - **Query:** [`EarningsTranscript::get`], [`EarningsTranscript::loaded_quarters`],
+//!   [`TranscriptSegment::for_transcript`], [`TranscriptSegment::search`].
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{earnings_transcripts, transcript_segments};
+
+// ─── Queryable models ───────────────────────────────────────────────────────
+
+/// Header row for one earnings call.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = earnings_transcripts)]
+pub struct EarningsTranscript {
+  pub id: i32,
+  pub sid: i64,
+  pub symbol: String,
+  /// Fiscal quarter in `YYYYQn` form
+  pub quarter: String,
+  pub fiscal_year: i32,
+  pub fiscal_quarter: i16,
+  pub segment_count: i32,
+  /// Mean of the segment sentiment scores that were published
+  pub avg_sentiment: Option<f64>,
+  pub loaded_at: DateTime<Utc>,
+}
+
+/// One speaker passage within a call.
+#[derive(
+  Queryable, Selectable, Identifiable, Associations, Debug, Clone, Serialize, Deserialize,
+)]
+#[diesel(table_name = transcript_segments)]
+#[diesel(primary_key(transcript_id, seq))]
+#[diesel(belongs_to(EarningsTranscript, foreign_key = transcript_id))]
+pub struct TranscriptSegment {
+  pub transcript_id: i32,
+  /// Position in the call, from 0
+  pub seq: i32,
+  pub speaker: String,
+  pub title: String,
+  pub content: String,
+  pub sentiment: Option<f64>,
+}
+
+// ─── Insertable models ──────────────────────────────────────────────────────
+
+/// Insertable form of [`EarningsTranscript`].
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = earnings_transcripts)]
+pub struct NewEarningsTranscriptOwned {
+  pub sid: i64,
+  pub symbol: String,
+  pub quarter: String,
+  pub fiscal_year: i32,
+  pub fiscal_quarter: i16,
+  pub segment_count: i32,
+  pub avg_sentiment: Option<f64>,
+}
+
+/// Segment content for [`NewEarningsTranscriptOwned::save`]; the
+/// `transcript_id` is filled in when the header is written.
+#[derive(Debug, Clone)]
+pub struct NewTranscriptSegmentOwned {
+  pub seq: i32,
+  pub speaker: String,
+  pub title: String,
+  pub content: String,
+  pub sentiment: Option<f64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = transcript_segments)]
+struct SegmentRow<'a> {
+  transcript_id: i32,
+  seq: i32,
+  speaker: &'a str,
+  title: &'a str,
+  content: &'a str,
+  sentiment: Option<f64>,
+}
+
+impl NewEarningsTranscriptOwned {
+  /// Upserts the header on `(sid, quarter)` and replaces its segments, all
+  /// in one transaction. Returns the transcript id and the number of
+  /// segments written.
+  pub fn save(
+    &self,
+    conn: &mut PgConnection,
+    segments: &[NewTranscriptSegmentOwned],
+  ) -> Result<(i32, usize), diesel::result::Error> {
+    const BATCH_SIZE: usize = 1000;
+
+    conn.transaction(|conn| {
+      let id: i32 = diesel::insert_into(earnings_transcripts::table)
+        .values(self)
+        .on_conflict((earnings_transcripts::sid, earnings_transcripts::quarter))
+        .do_update()
+        .set((
+          earnings_transcripts::segment_count.eq(excluded(earnings_transcripts::segment_count)),
+          earnings_transcripts::avg_sentiment.eq(excluded(earnings_transcripts::avg_sentiment)),
+          earnings_transcripts::loaded_at.eq(diesel::dsl::now),
+        ))
+        .returning(earnings_transcripts::id)
+        .get_result(conn)?;
+
+      diesel::delete(transcript_segments::table.filter(transcript_segments::transcript_id.eq(id)))
+        .execute(conn)?;
+
+      let rows: Vec<SegmentRow> = segments
+        .iter()
+        .map(|s| SegmentRow {
+          transcript_id: id,
+          seq: s.seq,
+          speaker: &s.speaker,
+          title: &s.title,
+          content: &s.content,
+          sentiment: s.sentiment,
+        })
+        .collect();
+
+      let mut written = 0;
+      for chunk in rows.chunks(BATCH_SIZE) {
+        written += diesel::insert_into(transcript_segments::table).values(chunk).execute(conn)?;
+      }
+
+      Ok((id, written))
+    })
+  }
+}
+
+// ─── Search results ─────────────────────────────────────────────────────────
+
+/// A segment matching a full-text query, as returned by
+/// [`TranscriptSegment::search`].
+#[derive(QueryableByName, Debug, Clone, Serialize)]
+pub struct TranscriptSearchHit {
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub symbol: String,
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub quarter: String,
+  #[diesel(sql_type = diesel::sql_types::Integer)]
+  pub seq: i32,
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub speaker: String,
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub title: String,
+  /// Matching excerpt with search terms wrapped in `**`
+  #[diesel(sql_type = diesel::sql_types::Text)]
+  pub excerpt: String,
+  #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Double>)]
+  pub sentiment: Option<f64>,
+  #[diesel(sql_type = diesel::sql_types::Float4)]
+  pub rank: f32,
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+/// Synchronous read operations for transcript headers.
+impl EarningsTranscript {
+  /// Returns the transcript header for `sid` and `quarter`, if loaded.
+  pub fn get(
+    conn: &mut PgConnection,
+    sid: i64,
+    quarter: &str,
+  ) -> Result<Option<Self>, diesel::result::Error> {
+    earnings_transcripts::table
+      .filter(earnings_transcripts::sid.eq(sid))
+      .filter(earnings_transcripts::quarter.eq(quarter))
+      .select(Self::as_select())
+      .first(conn)
+      .optional()
+  }
+
+  /// Returns the quarters already stored for `sid`, newest first.
+  pub fn loaded_quarters(
+    conn: &mut PgConnection,
+    sid: i64,
+  ) -> Result<Vec<String>, diesel::result::Error> {
+    earnings_transcripts::table
+      .filter(earnings_transcripts::sid.eq(sid))
+      .order_by((
+        earnings_transcripts::fiscal_year.desc(),
+        earnings_transcripts::fiscal_quarter.desc(),
+      ))
+      .select(earnings_transcripts::quarter)
+      .load(conn)
+  }
+}
+
+/// Synchronous read operations for transcript segments.
+impl TranscriptSegment {
+  /// Returns the segments of one transcript in call order.
+  pub fn for_transcript(
+    conn: &mut PgConnection,
+    transcript_id: i32,
+  ) -> Result<Vec<Self>, diesel::result::Error> {
+    transcript_segments::table
+      .filter(transcript_segments::transcript_id.eq(transcript_id))
+      .order_by(transcript_segments::seq.asc())
+      .select(Self::as_select())
+      .load(conn)
+  }
+
+  /// Full-text search over segment content, optionally limited to one
+  /// security, best matches first.
+  ///
+  /// `query` uses `websearch_to_tsquery` syntax: quoted phrases, `or`, and
+  /// `-term` exclusions.
+  pub fn search(
+    conn: &mut PgConnection,
+    query: &str,
+    sid: Option<i64>,
+    limit: i64,
+  ) -> Result<Vec<TranscriptSearchHit>, diesel::result::Error> {
+    use diesel::sql_query;
+    use diesel::sql_types::{BigInt, Nullable, Text};
+
+    sql_query(
+      r#"
+            SELECT
+                t.symbol::text AS symbol,
+                t.quarter::text AS quarter,
+                s.seq,
+                s.speaker::text AS speaker,
+                s.title::text AS title,
+                ts_headline('english', s.content, q, 'StartSel=**, StopSel=**, MaxFragments=2') AS excerpt,
+                s.sentiment,
+                ts_rank(to_tsvector('english', s.content), q) AS rank
+            FROM transcript_segments s
+            JOIN earnings_transcripts t ON t.id = s.transcript_id,
+                websearch_to_tsquery('english', $1) q
+            WHERE to_tsvector('english', s.content) @@ q
+                AND ($2 IS NULL OR t.sid = $2)
+            ORDER BY rank DESC, t.fiscal_year DESC, t.fiscal_quarter DESC
+            LIMIT $3
+            "#,
+    )
+    .bind::<Text, _>(query)
+    .bind::<Nullable<BigInt>, _>(sid)
+    .bind::<BigInt, _>(limit)
+    .load::<TranscriptSearchHit>(conn)
+  }
+}
@@ -56,6 +56,11 @@
 //! | [`OverviewRepository`] | Company overview CRUD + ingestion queue                  |
 //! | [`NewsRepository`]     | Symbol lookups and missing-symbol tracking for news      |
 //! | [`CryptoRepository`]   | API mappings, metadata, social/technical data, markets   |
+//! | [`CorporateActionRepository`] | Dividend and split events per security            |
+//! | [`InsiderRepository`]  | Insider trades per symbol and cluster-buying screens     |
+//! | [`TranscriptRepository`] | Earnings call transcripts and full-text segment search |
+//! | [`EtfRepository`]      | ETF profile snapshots and reverse constituent lookups    |
+//! | [`QuotaRepository`]    | AlphaVantage calls per day, API key and function         |
 //!
 //! ## DTOs
 //!
@@ -88,10 +93,11 @@
 //! let news_repo = db.news_repository();
 //! let crypto_repo = db.crypto_repository();
 //! let cache_repo = db.cache_repository();
+//! let actions_repo = db.corporate_action_repository();
 //! ```
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
 use diesel::result::Error as DieselError;
@@ -102,7 +108,12 @@ use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::models::corporate_actions::{CorporateAction, NewCorporateActionOwned};
 use crate::models::crypto::CryptoSummary;
+use crate::models::etf::{EtfHolding, EtfProfile, EtfSectorWeight};
+use crate::models::insiders::{InsiderCluster, InsiderTransaction};
+use crate::models::quota::ApiQuotaUsage;
+use crate::models::transcripts::{EarningsTranscript, TranscriptSearchHit, TranscriptSegment};
 
 // ─── Pool type aliases ──────────────────────────────────────────────────────
 
@@ -344,6 +355,11 @@ pub trait Transactional {
 /// | [`news_repository`]     | `impl NewsRepository`      |
 /// | [`crypto_repository`]   | `impl CryptoRepository`    |
 /// | [`cache_repository`]    | `impl CacheRepository`     |
+/// | [`corporate_action_repository`] | `impl CorporateActionRepository` |
+/// | [`insider_repository`]  | `impl InsiderRepository`   |
+/// | [`transcript_repository`] | `impl TranscriptRepository` |
+/// | [`etf_repository`]      | `impl EtfRepository`       |
+/// | [`quota_repository`]    | `impl QuotaRepository`     |
 ///
 /// Also provides direct pool access ([`get_connection`], [`pool`]),
 /// transaction support ([`transaction`]), and an async helper ([`run`]).
@@ -354,6 +370,11 @@ pub trait Transactional {
 /// [`news_repository`]: DatabaseContext::news_repository
 /// [`crypto_repository`]: DatabaseContext::crypto_repository
 /// [`cache_repository`]: DatabaseContext::cache_repository
+/// [`corporate_action_repository`]: DatabaseContext::corporate_action_repository
+/// [`insider_repository`]: DatabaseContext::insider_repository
+/// [`transcript_repository`]: DatabaseContext::transcript_repository
+/// [`etf_repository`]: DatabaseContext::etf_repository
+/// [`quota_repository`]: DatabaseContext::quota_repository
 /// [`get_connection`]: DatabaseContext::get_connection
 /// [`pool`]: DatabaseContext::pool
 /// [`transaction`]: DatabaseContext::transaction
@@ -1137,6 +1158,7 @@ impl NewsRepository for NewsRepositoryImpl {
 /// - **Market data:** `upsert_market_data`.
 /// - **Analytics:** `get_crypto_summary`, `get_cryptos_with_coingecko_ids`.
 #[async_trait]
+#[allow(clippy::too_many_arguments)]
 pub trait CryptoRepository: Send + Sync {
   // API Mapping operations
   /// Get API ID for a symbol from a specific source (e.g., "CoinGecko")
@@ -1844,6 +1866,375 @@ impl DatabaseContext {
   }
 }
 
+// ─── Corporate action repository ────────────────────────────────────────────
+
+/// Async trait for dividend and split events.
+///
+/// Obtained via [`DatabaseContext::corporate_action_repository`]. Dividends
+/// and splits share one table, so [`get_actions`](Self::get_actions)
+/// returns both kinds interleaved by `ex_date`.
+#[async_trait]
+pub trait CorporateActionRepository: Send + Sync {
+  /// Get all dividends and splits for a security with an ex-date in
+  /// `start..=end`, oldest first.
+  async fn get_actions(
+    &self,
+    sid: i64,
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+  ) -> RepositoryResult<Vec<CorporateAction>>;
+
+  /// Insert or update a batch of events; returns the number of rows written.
+  async fn upsert_actions(&self, actions: &[NewCorporateActionOwned]) -> RepositoryResult<usize>;
+}
+
+/// PostgreSQL implementation of [`CorporateActionRepository`].
+struct CorporateActionRepositoryImpl {
+  pool: Arc<DbPool>,
+}
+
+#[async_trait]
+impl CorporateActionRepository for CorporateActionRepositoryImpl {
+  async fn get_actions(
+    &self,
+    sid: i64,
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+  ) -> RepositoryResult<Vec<CorporateAction>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(CorporateAction::get_range(&mut conn, sid, start, end)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn upsert_actions(&self, actions: &[NewCorporateActionOwned]) -> RepositoryResult<usize> {
+    let pool = self.pool.clone();
+    let actions = actions.to_vec();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(NewCorporateActionOwned::upsert_batch(&mut conn, &actions)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+}
+
+impl DatabaseContext {
+  /// Returns a [`CorporateActionRepository`] for dividend and split queries.
+  pub fn corporate_action_repository(&self) -> impl CorporateActionRepository {
+    CorporateActionRepositoryImpl { pool: Arc::clone(&self.pool) }
+  }
+}
+
+// ─── Insider repository ─────────────────────────────────────────────────────
+
+/// Async trait for insider trade queries.
+///
+/// Obtained via [`DatabaseContext::insider_repository`]. Writes go through
+/// the loader, which uses the synchronous model methods directly.
+#[async_trait]
+pub trait InsiderRepository: Send + Sync {
+  /// Get the trades for a security dated in `start..=end`, newest first.
+  async fn get_transactions(
+    &self,
+    sid: i64,
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+  ) -> RepositoryResult<Vec<InsiderTransaction>>;
+
+  /// Get issuers where at least `min_insiders` distinct insiders made
+  /// open-market purchases on or after `since`.
+  async fn cluster_buys(
+    &self,
+    since: chrono::NaiveDate,
+    min_insiders: i64,
+  ) -> RepositoryResult<Vec<InsiderCluster>>;
+}
+
+/// PostgreSQL implementation of [`InsiderRepository`].
+struct InsiderRepositoryImpl {
+  pool: Arc<DbPool>,
+}
+
+#[async_trait]
+impl InsiderRepository for InsiderRepositoryImpl {
+  async fn get_transactions(
+    &self,
+    sid: i64,
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+  ) -> RepositoryResult<Vec<InsiderTransaction>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(InsiderTransaction::get_range(&mut conn, sid, start, end)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn cluster_buys(
+    &self,
+    since: chrono::NaiveDate,
+    min_insiders: i64,
+  ) -> RepositoryResult<Vec<InsiderCluster>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(InsiderTransaction::cluster_buys(&mut conn, since, min_insiders)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+}
+
+impl DatabaseContext {
+  /// Returns an [`InsiderRepository`] for insider trade queries.
+  pub fn insider_repository(&self) -> impl InsiderRepository {
+    InsiderRepositoryImpl { pool: Arc::clone(&self.pool) }
+  }
+}
+
+// ─── Transcript repository ──────────────────────────────────────────────────
+
+/// Async trait for earnings call transcript queries.
+///
+/// Obtained via [`DatabaseContext::transcript_repository`].
+#[async_trait]
+pub trait TranscriptRepository: Send + Sync {
+  /// Get one quarter's transcript header and its segments in call order.
+  async fn get_transcript(
+    &self,
+    sid: i64,
+    quarter: &str,
+  ) -> RepositoryResult<Option<(EarningsTranscript, Vec<TranscriptSegment>)>>;
+
+  /// Get the quarters already loaded for a security, newest first.
+  async fn loaded_quarters(&self, sid: i64) -> RepositoryResult<Vec<String>>;
+
+  /// Full-text search across segments, optionally limited to one security.
+  async fn search(
+    &self,
+    query: &str,
+    sid: Option<i64>,
+    limit: i64,
+  ) -> RepositoryResult<Vec<TranscriptSearchHit>>;
+}
+
+/// PostgreSQL implementation of [`TranscriptRepository`].
+struct TranscriptRepositoryImpl {
+  pool: Arc<DbPool>,
+}
+
+#[async_trait]
+impl TranscriptRepository for TranscriptRepositoryImpl {
+  async fn get_transcript(
+    &self,
+    sid: i64,
+    quarter: &str,
+  ) -> RepositoryResult<Option<(EarningsTranscript, Vec<TranscriptSegment>)>> {
+    let pool = self.pool.clone();
+    let quarter = quarter.to_string();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      let Some(header) = EarningsTranscript::get(&mut conn, sid, &quarter)? else {
+        return Ok(None);
+      };
+      let segments = TranscriptSegment::for_transcript(&mut conn, header.id)?;
+      Ok(Some((header, segments)))
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn loaded_quarters(&self, sid: i64) -> RepositoryResult<Vec<String>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(EarningsTranscript::loaded_quarters(&mut conn, sid)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn search(
+    &self,
+    query: &str,
+    sid: Option<i64>,
+    limit: i64,
+  ) -> RepositoryResult<Vec<TranscriptSearchHit>> {
+    let pool = self.pool.clone();
+    let query = query.to_string();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(TranscriptSegment::search(&mut conn, &query, sid, limit)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+}
+
+impl DatabaseContext {
+  /// Returns a [`TranscriptRepository`] for earnings call transcripts.
+  pub fn transcript_repository(&self) -> impl TranscriptRepository {
+    TranscriptRepositoryImpl { pool: Arc::clone(&self.pool) }
+  }
+}
+
+// ─── ETF repository ─────────────────────────────────────────────────────────
+
+/// Async trait for ETF profile snapshot queries.
+///
+/// Obtained via [`DatabaseContext::etf_repository`].
+#[async_trait]
+pub trait EtfRepository: Send + Sync {
+  /// Get an ETF's most recent profile with that snapshot's holdings and
+  /// sector weights.
+  async fn get_latest_snapshot(
+    &self,
+    etf_sid: i64,
+  ) -> RepositoryResult<Option<(EtfProfile, Vec<EtfHolding>, Vec<EtfSectorWeight>)>>;
+
+  /// Get every ETF's most recent holding of a constituent, largest weight
+  /// first.
+  async fn get_holders(&self, constituent_sid: i64) -> RepositoryResult<Vec<EtfHolding>>;
+}
+
+/// PostgreSQL implementation of [`EtfRepository`].
+struct EtfRepositoryImpl {
+  pool: Arc<DbPool>,
+}
+
+#[async_trait]
+impl EtfRepository for EtfRepositoryImpl {
+  async fn get_latest_snapshot(
+    &self,
+    etf_sid: i64,
+  ) -> RepositoryResult<Option<(EtfProfile, Vec<EtfHolding>, Vec<EtfSectorWeight>)>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      let Some(profile) = EtfProfile::latest(&mut conn, etf_sid)? else {
+        return Ok(None);
+      };
+      let holdings = EtfHolding::for_snapshot(&mut conn, etf_sid, profile.snapshot_date)?;
+      let sectors = EtfSectorWeight::for_snapshot(&mut conn, etf_sid, profile.snapshot_date)?;
+      Ok(Some((profile, holdings, sectors)))
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn get_holders(&self, constituent_sid: i64) -> RepositoryResult<Vec<EtfHolding>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(EtfHolding::holders_of(&mut conn, constituent_sid)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+}
+
+impl DatabaseContext {
+  /// Returns an [`EtfRepository`] for ETF profile snapshots.
+  pub fn etf_repository(&self) -> impl EtfRepository {
+    EtfRepositoryImpl { pool: Arc::clone(&self.pool) }
+  }
+}
+
+// ─── Quota repository ───────────────────────────────────────────────────────
+
+/// Async trait for the AlphaVantage call ledger.
+///
+/// Keys are passed as ledger keys (masked, plus a hash of the full key).
+/// Obtained via [`DatabaseContext::quota_repository`].
+#[async_trait]
+pub trait QuotaRepository: Send + Sync {
+  /// Count one call made with `api_key` to `function` on `date`.
+  async fn record_call(
+    &self,
+    date: NaiveDate,
+    api_key: &str,
+    function: &str,
+  ) -> RepositoryResult<()>;
+
+  /// Get every counter for `date`, ordered by key and function.
+  async fn usage_for(&self, date: NaiveDate) -> RepositoryResult<Vec<ApiQuotaUsage>>;
+
+  /// Get the total calls made with `api_key` on `date`.
+  async fn calls_for_key(&self, date: NaiveDate, api_key: &str) -> RepositoryResult<i64>;
+}
+
+/// PostgreSQL implementation of [`QuotaRepository`].
+#[derive(Clone)]
+struct QuotaRepositoryImpl {
+  pool: Arc<DbPool>,
+}
+
+#[async_trait]
+impl QuotaRepository for QuotaRepositoryImpl {
+  async fn record_call(
+    &self,
+    date: NaiveDate,
+    api_key: &str,
+    function: &str,
+  ) -> RepositoryResult<()> {
+    let pool = self.pool.clone();
+    let api_key = api_key.to_string();
+    let function = function.to_string();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(ApiQuotaUsage::record(&mut conn, date, &api_key, &function)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn usage_for(&self, date: NaiveDate) -> RepositoryResult<Vec<ApiQuotaUsage>> {
+    let pool = self.pool.clone();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(ApiQuotaUsage::for_date(&mut conn, date)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+
+  async fn calls_for_key(&self, date: NaiveDate, api_key: &str) -> RepositoryResult<i64> {
+    let pool = self.pool.clone();
+    let api_key = api_key.to_string();
+
+    tokio::task::spawn_blocking(move || {
+      let mut conn = pool.get()?;
+      Ok(ApiQuotaUsage::calls_for_key(&mut conn, date, &api_key)?)
+    })
+    .await
+    .map_err(|e| RepositoryError::QueryError(format!("Task join error: {}", e)))?
+  }
+}
+
+impl DatabaseContext {
+  /// Returns a [`QuotaRepository`] for the API call ledger.
+  pub fn quota_repository(&self) -> impl QuotaRepository + Clone + 'static {
+    QuotaRepositoryImpl { pool: Arc::clone(&self.pool) }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
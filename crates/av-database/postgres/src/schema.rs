@@ -1,5 +1,19 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    api_quota_usage (usage_date, api_key, function) {
+        usage_date -> Date,
+        #[max_length = 32]
+        api_key -> Varchar,
+        #[max_length = 64]
+        function -> Varchar,
+        calls -> Int4,
+        last_call_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -131,6 +145,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    commodityprices (tstamp, sid, series_interval) {
+        tstamp -> Timestamptz,
+        date -> Date,
+        sid -> Int8,
+        #[max_length = 64]
+        symbol -> Varchar,
+        #[max_length = 20]
+        series_interval -> Varchar,
+        price -> Nullable<Float8>,
+        #[max_length = 100]
+        unit -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    corporate_actions (sid, action_type, ex_date) {
+        sid -> Int8,
+        #[max_length = 20]
+        symbol -> Varchar,
+        #[max_length = 8]
+        action_type -> Varchar,
+        ex_date -> Date,
+        declaration_date -> Nullable<Date>,
+        record_date -> Nullable<Date>,
+        pay_date -> Nullable<Date>,
+        amount -> Nullable<Float8>,
+        split_ratio -> Nullable<Float8>,
+        loaded_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -330,6 +380,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    earnings_transcripts (id) {
+        id -> Int4,
+        sid -> Int8,
+        #[max_length = 20]
+        symbol -> Varchar,
+        #[max_length = 6]
+        quarter -> Varchar,
+        fiscal_year -> Int4,
+        fiscal_quarter -> Int2,
+        segment_count -> Int4,
+        avg_sentiment -> Nullable<Float8>,
+        loaded_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    economic_indicators (indicator, series_interval, maturity, date) {
+        #[max_length = 50]
+        indicator -> Varchar,
+        #[max_length = 20]
+        series_interval -> Varchar,
+        #[max_length = 10]
+        maturity -> Varchar,
+        date -> Date,
+        value -> Nullable<Float8>,
+        #[max_length = 100]
+        unit -> Varchar,
+        name -> Text,
+        loaded_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -346,6 +433,51 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    etf_holdings (etf_sid, snapshot_date, position) {
+        etf_sid -> Int8,
+        snapshot_date -> Date,
+        position -> Int4,
+        #[max_length = 20]
+        holding_symbol -> Nullable<Varchar>,
+        constituent_sid -> Nullable<Int8>,
+        description -> Text,
+        weight -> Float8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    etf_profiles (etf_sid, snapshot_date) {
+        etf_sid -> Int8,
+        snapshot_date -> Date,
+        #[max_length = 20]
+        symbol -> Varchar,
+        net_assets -> Nullable<Float8>,
+        net_expense_ratio -> Nullable<Float8>,
+        portfolio_turnover -> Nullable<Float8>,
+        dividend_yield -> Nullable<Float8>,
+        inception_date -> Nullable<Date>,
+        leveraged -> Nullable<Bool>,
+        loaded_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    etf_sector_weights (etf_sid, snapshot_date, sector) {
+        etf_sid -> Int8,
+        snapshot_date -> Date,
+        #[max_length = 100]
+        sector -> Varchar,
+        weight -> Float8,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -362,6 +494,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    insider_transactions (id) {
+        id -> Int8,
+        sid -> Int8,
+        #[max_length = 20]
+        symbol -> Varchar,
+        transaction_date -> Date,
+        #[max_length = 255]
+        executive -> Varchar,
+        #[max_length = 255]
+        executive_title -> Varchar,
+        #[max_length = 255]
+        security_type -> Varchar,
+        #[max_length = 1]
+        acquisition_or_disposal -> Bpchar,
+        shares -> Float8,
+        share_price -> Float8,
+        loaded_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -411,6 +566,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    optionprices (snapshot_date, contract_id) {
+        snapshot_date -> Date,
+        #[max_length = 32]
+        contract_id -> Varchar,
+        underlying_sid -> Int8,
+        #[max_length = 20]
+        underlying -> Varchar,
+        #[max_length = 6]
+        occ_root -> Varchar,
+        expiration -> Date,
+        strike -> Float8,
+        #[max_length = 4]
+        option_type -> Varchar,
+        last -> Nullable<Float8>,
+        mark -> Nullable<Float8>,
+        bid -> Nullable<Float8>,
+        bid_size -> Nullable<Int4>,
+        ask -> Nullable<Float8>,
+        ask_size -> Nullable<Int4>,
+        volume -> Nullable<Int8>,
+        open_interest -> Nullable<Int8>,
+        implied_volatility -> Nullable<Float8>,
+        delta -> Nullable<Float8>,
+        gamma -> Nullable<Float8>,
+        theta -> Nullable<Float8>,
+        vega -> Nullable<Float8>,
+        rho -> Nullable<Float8>,
+        loaded_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
 
@@ -648,6 +837,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+
+    transcript_segments (transcript_id, seq) {
+        transcript_id -> Int4,
+        seq -> Int4,
+        #[max_length = 255]
+        speaker -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        content -> Text,
+        sentiment -> Nullable<Float8>,
+    }
+}
+
 diesel::joinable!(article_media -> articles (articleid));
 diesel::joinable!(article_quotes -> articles (articleid));
 diesel::joinable!(article_symbols -> articles (articleid));
@@ -658,6 +862,8 @@ diesel::joinable!(articles -> authors (author));
 diesel::joinable!(articles -> sources (sourceid));
 diesel::joinable!(authormaps -> authors (authorid));
 diesel::joinable!(authormaps -> feeds (feedid));
+diesel::joinable!(commodityprices -> symbols (sid));
+diesel::joinable!(corporate_actions -> symbols (sid));
 diesel::joinable!(crypto_api_map -> symbols (sid));
 diesel::joinable!(crypto_markets -> symbols (sid));
 diesel::joinable!(crypto_metadata -> symbols (sid));
@@ -665,11 +871,16 @@ diesel::joinable!(crypto_overview_basic -> symbols (sid));
 diesel::joinable!(crypto_overview_metrics -> symbols (sid));
 diesel::joinable!(crypto_social -> symbols (sid));
 diesel::joinable!(crypto_technical -> symbols (sid));
+diesel::joinable!(earnings_transcripts -> symbols (sid));
 diesel::joinable!(equity_details -> symbols (sid));
+diesel::joinable!(etf_holdings -> symbols (constituent_sid));
+diesel::joinable!(etf_profiles -> symbols (etf_sid));
 diesel::joinable!(feeds -> symbols (sid));
+diesel::joinable!(insider_transactions -> symbols (sid));
 diesel::joinable!(intradayprices -> price_sources (price_source_id));
 diesel::joinable!(intradayprices -> symbols (sid));
 diesel::joinable!(newsoverviews -> symbols (sid));
+diesel::joinable!(optionprices -> symbols (underlying_sid));
 diesel::joinable!(overviewexts -> symbols (sid));
 diesel::joinable!(overviews -> symbols (sid));
 diesel::joinable!(procstates -> proctypes (proc_id));
@@ -683,8 +894,10 @@ diesel::joinable!(topicmaps -> feeds (feedid));
 diesel::joinable!(topicmaps -> symbols (sid));
 diesel::joinable!(topicmaps -> topicrefs (topicid));
 diesel::joinable!(topstats -> symbols (sid));
+diesel::joinable!(transcript_segments -> earnings_transcripts (transcript_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+  api_quota_usage,
   api_response_cache,
   article_media,
   article_quotes,
@@ -694,6 +907,8 @@ diesel::allow_tables_to_appear_in_same_query!(
   articles,
   authormaps,
   authors,
+  commodityprices,
+  corporate_actions,
   crypto_api_map,
   crypto_markets,
   crypto_metadata,
@@ -701,11 +916,18 @@ diesel::allow_tables_to_appear_in_same_query!(
   crypto_overview_metrics,
   crypto_social,
   crypto_technical,
+  earnings_transcripts,
+  economic_indicators,
   equity_details,
+  etf_holdings,
+  etf_profiles,
+  etf_sector_weights,
   feeds,
+  insider_transactions,
   intradayprices,
   missing_symbols,
   newsoverviews,
+  optionprices,
   overviewexts,
   overviews,
   price_sources,
@@ -720,4 +942,5 @@ diesel::allow_tables_to_appear_in_same_query!(
   topicmaps,
   topicrefs,
   topstats,
+  transcript_segments,
 );
@@ -46,11 +46,17 @@
 //! // Store data in cache
 //! cache.set(&cache_repo, "my_key", "alphavantage", "endpoint", &data).await;
 //! ```
+//!
+//! The same table can back the client's own response cache: see
+//! [`CacheHelper::response_cache`].
 
+use async_trait::async_trait;
+use av_client::ResponseCache;
 use av_database_postgres::repository::{CacheRepository, CacheRepositoryExt};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 use crate::error::LoaderError;
@@ -372,6 +378,73 @@ impl CacheHelper {
       Err(e) => Err(LoaderError::DatabaseError(format!("Cache cleanup failed: {}", e))),
     }
   }
+
+  /// A client [`ResponseCache`] stored in `cache_repo` under this helper's
+  /// `api_source`.
+  ///
+  /// Reads and writes honor `enable_cache` and `force_refresh`; lifetimes
+  /// come from the client's cache policy rather than `cache_ttl_hours`,
+  /// rounded down to whole hours. Responses the policy keeps for less than
+  /// an hour are not stored.
+  pub fn response_cache(&self, cache_repo: Arc<dyn CacheRepository>) -> RepositoryResponseCache {
+    RepositoryResponseCache { helper: self.clone(), cache_repo }
+  }
+}
+
+/// [`ResponseCache`] backed by the `api_response_cache` table, created with
+/// [`CacheHelper::response_cache`].
+#[derive(Clone)]
+pub struct RepositoryResponseCache {
+  helper: CacheHelper,
+  cache_repo: Arc<dyn CacheRepository>,
+}
+
+impl std::fmt::Debug for RepositoryResponseCache {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RepositoryResponseCache").field("config", self.helper.config()).finish()
+  }
+}
+
+#[async_trait]
+impl ResponseCache for RepositoryResponseCache {
+  async fn get(&self, key: &str) -> av_core::Result<Option<String>> {
+    match self.helper.get_json(&self.cache_repo, key).await {
+      CacheResult::Hit(serde_json::Value::String(body)) => Ok(Some(body)),
+      CacheResult::Error(e) => Err(av_core::Error::Unexpected(e)),
+      _ => Ok(None),
+    }
+  }
+
+  async fn put(&self, key: &str, body: &str, ttl: Option<Duration>) -> av_core::Result<()> {
+    if !self.helper.is_write_enabled() {
+      return Ok(());
+    }
+    // The table stores expiry in whole hours; shorter-lived responses such
+    // as quotes would be served stale, so they are not cached here
+    let ttl_hours = match ttl {
+      Some(ttl) => match whole_hours(ttl) {
+        Some(hours) => hours,
+        None => return Ok(()),
+      },
+      None => ttl::IMMUTABLE,
+    };
+    self
+      .cache_repo
+      .set_json(
+        key,
+        &self.helper.config().api_source,
+        key,
+        serde_json::Value::String(body.to_string()),
+        ttl_hours,
+      )
+      .await
+      .map_err(|e| av_core::Error::Unexpected(format!("Cache write failed for {}: {}", key, e)))
+  }
+}
+
+/// Whole hours within `ttl`, or `None` if it is under an hour
+fn whole_hours(ttl: Duration) -> Option<i64> {
+  Some((ttl.as_secs() / 3600) as i64).filter(|&hours| hours > 0)
 }
 
 /// Trait for loader configurations that support caching.
@@ -429,6 +502,20 @@ pub mod keys {
   pub const CRYPTO_MARKETS: &str = "crypto_markets";
   /// CoinGecko details
   pub const COINGECKO_DETAILS: &str = "coingecko_details";
+  /// Economic indicator series
+  pub const ECONOMICS: &str = "economics";
+  /// Commodity price series
+  pub const COMMODITIES: &str = "commodities";
+  /// Historical option chains
+  pub const OPTIONS: &str = "options";
+  /// Dividends and splits
+  pub const CORPORATE_ACTIONS: &str = "corporate_actions";
+  /// Insider transactions
+  pub const INSIDERS: &str = "insiders";
+  /// Earnings call transcripts
+  pub const TRANSCRIPTS: &str = "transcripts";
+  /// ETF profiles and holdings
+  pub const ETF_PROFILE: &str = "etf_profile";
 }
 
 /// Default TTL values for different data types (in hours)
@@ -449,6 +536,22 @@ pub mod ttl {
   pub const CRYPTO_INTRADAY: i64 = 2;
   /// Crypto metadata - changes occasionally (1 day)
   pub const CRYPTO_METADATA: i64 = 24;
+  /// Economic indicators - published monthly or less often, daily at most (1 day)
+  pub const ECONOMICS: i64 = 24;
+  /// Commodity prices - energy series update daily (1 day)
+  pub const COMMODITIES: i64 = 24;
+  /// Option chains - large payloads, past snapshots are fetched once (1 day)
+  pub const OPTIONS: i64 = 24;
+  /// Dividends and splits - new declarations are rare (7 days)
+  pub const CORPORATE_ACTIONS: i64 = 168;
+  /// Insider transactions - new filings arrive daily (1 day)
+  pub const INSIDERS: i64 = 24;
+  /// Earnings call transcripts - immutable once published (30 days)
+  pub const TRANSCRIPTS: i64 = 720;
+  /// Data that can no longer change, e.g. a finished month of bars (10 years)
+  pub const IMMUTABLE: i64 = 87_600;
+  /// ETF profiles - holdings are rebalanced periodically (7 days)
+  pub const ETF_PROFILE: i64 = 168;
 }
 
 #[cfg(test)]
@@ -506,6 +609,15 @@ mod tests {
     assert!(helper_force.is_write_enabled()); // Force refresh still writes
   }
 
+  #[test]
+  fn test_response_cache_ttl_hours() {
+    assert_eq!(whole_hours(Duration::from_secs(60)), None);
+    assert_eq!(whole_hours(Duration::from_secs(3599)), None);
+    assert_eq!(whole_hours(Duration::from_secs(3600)), Some(1));
+    assert_eq!(whole_hours(Duration::from_secs(5400)), Some(1));
+    assert_eq!(whole_hours(Duration::from_secs(30 * 24 * 3600)), Some(ttl::OVERVIEW));
+  }
+
   #[test]
   fn test_cache_result() {
     let hit: CacheResult<String> = CacheResult::Hit("data".to_string());
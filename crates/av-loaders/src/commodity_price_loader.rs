@@ -0,0 +1,419 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Commodity price loader for the energy, metals, and agricultural
+//! commodity endpoints.
+//!
+//! Each commodity is registered in `symbols` as `sec_type = 'Commodity'`
+//! (using the API function name as its ticker) with a SID allocated from
+//! the [`SecurityType::Commodity`] bitmap range. Prices are then upserted
+//! into `commodityprices` under that SID.
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, TimeZone, Utc};
+use diesel::PgConnection;
+use tracing::{debug, error, info, warn};
+
+use av_client::CommodityEndpoints;
+use av_core::FuncType;
+use av_core::types::common::EconomicInterval;
+use av_core::types::market::{SecurityIdentifier, SecurityType};
+use av_database_postgres::{
+  establish_connection,
+  models::{commodities::NewCommodityPriceOwned, security::NewSymbolOwned},
+  repository::CacheRepositoryExt,
+};
+use av_models::commodities::{CommodityDataPoint, CommoditySeries};
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+const NO_PRIORITY: i32 = 9_999_999;
+
+pub struct CommodityPriceLoader {
+  config: CommodityPriceConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommodityPriceConfig {
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for CommodityPriceConfig {
+  fn default() -> Self {
+    Self { enable_cache: true, cache_ttl_hours: ttl::COMMODITIES, force_refresh: false }
+  }
+}
+
+impl CacheConfigProvider for CommodityPriceConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+impl CommodityPriceLoader {
+  pub fn new(config: CommodityPriceConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  /// The interval actually requested for a commodity: the override when the
+  /// commodity publishes it, otherwise the API default.
+  pub fn effective_interval(
+    function: FuncType,
+    interval: Option<EconomicInterval>,
+  ) -> Option<EconomicInterval> {
+    interval.filter(|i| CommodityEndpoints::supported_intervals(function).contains(i))
+  }
+
+  fn cache_key(function: FuncType, interval: Option<EconomicInterval>) -> String {
+    format!(
+      "{}:{}:{}",
+      keys::COMMODITIES,
+      function,
+      interval.map(|i| i.to_string()).unwrap_or_else(|| "default".to_string())
+    )
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<CommoditySeries> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<CommoditySeries>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+    function: FuncType,
+    data: &CommoditySeries,
+  ) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = function.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Returns the SID of a commodity's `symbols` row, creating the row with
+  /// the next free commodity SID if it does not exist yet.
+  /// TODO: This should be moved to a SymbolRepository trait method
+  fn ensure_commodity_symbol(
+    conn: &mut PgConnection,
+    symbol: &str,
+    name: &str,
+  ) -> Result<i64, LoaderError> {
+    use av_database_postgres::schema::symbols;
+    use diesel::prelude::*;
+
+    let sec_type = SecurityType::Commodity.to_string();
+
+    let existing: Vec<(String, i64)> = symbols::table
+      .filter(symbols::sec_type.eq(&sec_type))
+      .select((symbols::symbol, symbols::sid))
+      .load(conn)
+      .map_err(|e| {
+        LoaderError::DatabaseError(format!("Failed to load commodity symbols: {}", e))
+      })?;
+
+    if let Some((_, sid)) = existing.iter().find(|(s, _)| s == symbol) {
+      return Ok(*sid);
+    }
+
+    let next_raw_id = existing
+      .iter()
+      .filter_map(|(_, sid)| SecurityIdentifier::decode(*sid))
+      .filter(|id| id.security_type == SecurityType::Commodity)
+      .map(|id| id.raw_id)
+      .max()
+      .unwrap_or(0)
+      + 1;
+    let sid = SecurityType::encode(SecurityType::Commodity, next_raw_id);
+
+    let new_symbol =
+      NewSymbolOwned::from_symbol_data(symbol, NO_PRIORITY, name, &sec_type, "Global", "USD", sid);
+
+    diesel::insert_into(symbols::table).values(&new_symbol).execute(conn).map_err(|e| {
+      LoaderError::DatabaseError(format!("Failed to insert commodity symbol {}: {}", symbol, e))
+    })?;
+
+    info!("Registered commodity {} with SID {}", symbol, sid);
+    Ok(sid)
+  }
+
+  /// Convert an API series to database rows, skipping unparseable dates
+  pub fn to_records(
+    function: FuncType,
+    sid: i64,
+    series: &CommoditySeries,
+  ) -> Vec<NewCommodityPriceOwned> {
+    let symbol = function.to_string();
+    let series_interval = series.interval.to_lowercase();
+
+    series
+      .data
+      .iter()
+      .filter_map(|point| {
+        let Some(date) = point.parsed_date() else {
+          warn!("Skipping {} observation with invalid date '{}'", symbol, point.date);
+          return None;
+        };
+
+        Some(NewCommodityPriceOwned {
+          tstamp: Self::midnight_utc(date),
+          date,
+          sid,
+          symbol: symbol.clone(),
+          series_interval: series_interval.clone(),
+          price: point.value_as_f64(),
+          unit: series.unit.clone(),
+        })
+      })
+      .collect()
+  }
+
+  fn midnight_utc(date: NaiveDate) -> chrono::DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_time(chrono::NaiveTime::MIN))
+  }
+
+  fn save_series(
+    db_url: &str,
+    function: FuncType,
+    series: &CommoditySeries,
+  ) -> LoaderResult<(i64, usize)> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    let sid = Self::ensure_commodity_symbol(&mut conn, &function.to_string(), &series.name)?;
+    let records = Self::to_records(function, sid, series);
+
+    let saved = NewCommodityPriceOwned::upsert_batch(&mut conn, &records).map_err(|e| {
+      LoaderError::DatabaseError(format!("Failed to upsert commodity prices: {}", e))
+    })?;
+
+    Ok((sid, saved))
+  }
+}
+
+#[async_trait]
+impl DataLoader for CommodityPriceLoader {
+  type Input = CommodityPriceLoaderInput;
+  type Output = CommodityPriceLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading {} commodity series", input.commodities.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("commodity_price_loader").await?;
+    }
+
+    let mut output = CommodityPriceLoaderOutput::default();
+
+    for &function in &input.commodities {
+      let interval = Self::effective_interval(function, input.interval);
+      let cache_key = Self::cache_key(function, interval);
+
+      let (series, from_cache) = match self.get_cached_response(context, &cache_key).await {
+        Some(cached) => (cached, true),
+        None => match context.client.commodities().series(function, interval).await {
+          Ok(data) => {
+            self.cache_response(context, &cache_key, function, &data).await;
+            (data, false)
+          }
+          Err(e) => {
+            warn!("Failed to fetch {}: {}", function, e);
+            output.errors.push(format!("{}: {}", function, e));
+            if e.is_terminal() {
+              error!("Stopping early: {}", e);
+              break;
+            }
+            continue;
+          }
+        },
+      };
+
+      let (sid, records_saved) = match &self.database_url {
+        Some(db_url) if !series.is_empty() => match Self::save_series(db_url, function, &series) {
+          Ok((sid, saved)) => (Some(sid), saved),
+          Err(e) => {
+            warn!("Failed to save {}: {}", function, e);
+            output.errors.push(format!("{}: {}", function, e));
+            (None, 0)
+          }
+        },
+        _ => (None, 0),
+      };
+
+      info!("{}: {} observations, {} rows saved", function, series.len(), records_saved);
+
+      output.records_saved += records_saved;
+      output.series.push(CommoditySeriesResult {
+        function,
+        sid,
+        observations: series.len(),
+        latest: series.latest().cloned(),
+        records_saved,
+        from_cache,
+        name: series.name,
+        interval: series.interval,
+        unit: series.unit,
+      });
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.series.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "CommodityPriceLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct CommodityPriceLoaderInput {
+  /// Commodity functions to load (see `av_client::COMMODITY_FUNCTIONS`)
+  pub commodities: Vec<FuncType>,
+  /// Interval override, applied only to commodities that publish it
+  pub interval: Option<EconomicInterval>,
+}
+
+/// Per-commodity outcome of a [`CommodityPriceLoader`] run
+#[derive(Debug)]
+pub struct CommoditySeriesResult {
+  pub function: FuncType,
+  /// Commodity SID (`None` in dry-run mode or when saving failed)
+  pub sid: Option<i64>,
+  pub name: String,
+  pub interval: String,
+  pub unit: String,
+  pub observations: usize,
+  pub latest: Option<CommodityDataPoint>,
+  pub records_saved: usize,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct CommodityPriceLoaderOutput {
+  pub series: Vec<CommoditySeriesResult>,
+  pub records_saved: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_effective_interval() {
+    let daily = Some(EconomicInterval::Daily);
+    assert_eq!(CommodityPriceLoader::effective_interval(FuncType::Wti, daily), daily);
+    assert_eq!(CommodityPriceLoader::effective_interval(FuncType::Copper, daily), None);
+    assert_eq!(CommodityPriceLoader::effective_interval(FuncType::Corn, None), None);
+  }
+
+  #[test]
+  fn test_to_records() {
+    let sid = SecurityType::encode(SecurityType::Commodity, 1);
+    let series = CommoditySeries {
+      name: "Henry Hub Natural Gas Spot Price".to_string(),
+      interval: "Daily".to_string(),
+      unit: "dollars per million BTU".to_string(),
+      data: vec![
+        CommodityDataPoint { date: "2024-01-16".to_string(), value: "2.93".to_string() },
+        CommodityDataPoint { date: "2024-01-15".to_string(), value: ".".to_string() },
+      ],
+    };
+
+    let records = CommodityPriceLoader::to_records(FuncType::NaturalGas, sid, &series);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].symbol, "NATURAL_GAS");
+    assert_eq!(records[0].series_interval, "daily");
+    assert_eq!(records[0].price, Some(2.93));
+    assert_eq!(records[0].tstamp.date_naive(), records[0].date);
+    assert_eq!(records[1].price, None);
+    assert_eq!(SecurityType::decode_type(records[0].sid), SecurityType::Commodity);
+  }
+}
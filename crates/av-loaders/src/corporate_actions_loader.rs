@@ -0,0 +1,445 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Corporate action loader for the `DIVIDENDS` and `SPLITS` endpoints.
+//!
+//! Both endpoints return a symbol's full history in one call, so each run
+//! re-fetches everything and upserts it into `corporate_actions`. Declared
+//! dividends are loaded before their record and pay dates are known; the
+//! upsert fills those in on a later run.
+
+use async_trait::async_trait;
+use serde::{Serialize, de::DeserializeOwned};
+use tracing::{debug, error, info, warn};
+
+use av_core::FuncType;
+use av_database_postgres::{
+  establish_connection,
+  models::corporate_actions::{ACTION_DIVIDEND, ACTION_SPLIT, NewCorporateActionOwned},
+  repository::CacheRepositoryExt,
+};
+use av_models::fundamentals::{Dividends, Splits};
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+
+pub struct CorporateActionsLoader {
+  config: CorporateActionsConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CorporateActionsConfig {
+  /// Fetch the `DIVIDENDS` endpoint
+  pub dividends: bool,
+  /// Fetch the `SPLITS` endpoint
+  pub splits: bool,
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for CorporateActionsConfig {
+  fn default() -> Self {
+    Self {
+      dividends: true,
+      splits: true,
+      enable_cache: true,
+      cache_ttl_hours: ttl::CORPORATE_ACTIONS,
+      force_refresh: false,
+    }
+  }
+}
+
+impl CacheConfigProvider for CorporateActionsConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+impl CorporateActionsLoader {
+  pub fn new(config: CorporateActionsConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  fn cache_key(function: FuncType, symbol: &str) -> String {
+    format!("{}:{}:{}", keys::CORPORATE_ACTIONS, function, symbol)
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response<T>(&self, context: &LoaderContext, cache_key: &str) -> Option<T>
+  where
+    T: DeserializeOwned + Send + 'static,
+  {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<T>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response<T>(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+    function: FuncType,
+    data: &T,
+  ) where
+    T: Serialize + Send + Sync,
+  {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = function.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Convert a `DIVIDENDS` response to database rows, skipping events
+  /// without a parseable ex-date
+  pub fn dividend_records(sid: i64, dividends: &Dividends) -> Vec<NewCorporateActionOwned> {
+    dividends
+      .data
+      .iter()
+      .filter_map(|event| {
+        let Some(ex_date) = event.ex_date() else {
+          warn!(
+            "Skipping {} dividend with invalid ex-date '{}'",
+            dividends.symbol, event.ex_dividend_date
+          );
+          return None;
+        };
+
+        Some(NewCorporateActionOwned {
+          sid,
+          symbol: dividends.symbol.clone(),
+          action_type: ACTION_DIVIDEND.to_string(),
+          ex_date,
+          declaration_date: event.declaration_date(),
+          record_date: event.record_date(),
+          pay_date: event.payment_date(),
+          amount: event.amount_as_f64(),
+          split_ratio: None,
+        })
+      })
+      .collect()
+  }
+
+  /// Convert a `SPLITS` response to database rows, skipping events without
+  /// a parseable effective date
+  pub fn split_records(sid: i64, splits: &Splits) -> Vec<NewCorporateActionOwned> {
+    splits
+      .data
+      .iter()
+      .filter_map(|event| {
+        let Some(ex_date) = event.effective_date() else {
+          warn!("Skipping {} split with invalid date '{}'", splits.symbol, event.effective_date);
+          return None;
+        };
+
+        Some(NewCorporateActionOwned {
+          sid,
+          symbol: splits.symbol.clone(),
+          action_type: ACTION_SPLIT.to_string(),
+          ex_date,
+          declaration_date: None,
+          record_date: None,
+          pay_date: None,
+          amount: None,
+          split_ratio: event.split_factor_as_f64(),
+        })
+      })
+      .collect()
+  }
+
+  fn save_records(db_url: &str, records: &[NewCorporateActionOwned]) -> LoaderResult<usize> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    NewCorporateActionOwned::upsert_batch(&mut conn, records)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to upsert corporate actions: {}", e)))
+  }
+
+  async fn fetch_dividends(
+    &self,
+    context: &LoaderContext,
+    symbol: &str,
+  ) -> LoaderResult<(Dividends, bool)> {
+    let cache_key = Self::cache_key(FuncType::Dividends, symbol);
+    if let Some(cached) = self.get_cached_response(context, &cache_key).await {
+      return Ok((cached, true));
+    }
+
+    let data = context.client.fundamentals().dividends(symbol).await?;
+    self.cache_response(context, &cache_key, FuncType::Dividends, &data).await;
+    Ok((data, false))
+  }
+
+  async fn fetch_splits(
+    &self,
+    context: &LoaderContext,
+    symbol: &str,
+  ) -> LoaderResult<(Splits, bool)> {
+    let cache_key = Self::cache_key(FuncType::Splits, symbol);
+    if let Some(cached) = self.get_cached_response(context, &cache_key).await {
+      return Ok((cached, true));
+    }
+
+    let data = context.client.fundamentals().splits(symbol).await?;
+    self.cache_response(context, &cache_key, FuncType::Splits, &data).await;
+    Ok((data, false))
+  }
+}
+
+#[async_trait]
+impl DataLoader for CorporateActionsLoader {
+  type Input = CorporateActionsLoaderInput;
+  type Output = CorporateActionsLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading corporate actions for {} symbols", input.symbols.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("corporate_actions_loader").await?;
+    }
+
+    let mut output = CorporateActionsLoaderOutput::default();
+
+    for target in &input.symbols {
+      let mut result = CorporateActionsResult {
+        sid: target.sid,
+        symbol: target.symbol.clone(),
+        dividends: 0,
+        splits: 0,
+        records_saved: 0,
+        from_cache: true,
+      };
+      let mut records = Vec::new();
+      let mut terminal = None;
+
+      if self.config.dividends {
+        match self.fetch_dividends(context, &target.symbol).await {
+          Ok((data, from_cache)) => {
+            result.dividends = data.data.len();
+            result.from_cache &= from_cache;
+            records.extend(Self::dividend_records(target.sid, &data));
+          }
+          Err(e) => {
+            warn!("Failed to fetch dividends for {}: {}", target.symbol, e);
+            output.errors.push(format!("{} dividends: {}", target.symbol, e));
+            terminal = e.is_terminal().then_some(e);
+          }
+        }
+      }
+
+      if self.config.splits && terminal.is_none() {
+        match self.fetch_splits(context, &target.symbol).await {
+          Ok((data, from_cache)) => {
+            result.splits = data.data.len();
+            result.from_cache &= from_cache;
+            records.extend(Self::split_records(target.sid, &data));
+          }
+          Err(e) => {
+            warn!("Failed to fetch splits for {}: {}", target.symbol, e);
+            output.errors.push(format!("{} splits: {}", target.symbol, e));
+            terminal = e.is_terminal().then_some(e);
+          }
+        }
+      }
+
+      match &self.database_url {
+        Some(db_url) if !records.is_empty() => match Self::save_records(db_url, &records) {
+          Ok(saved) => result.records_saved = saved,
+          Err(e) => {
+            warn!("Failed to save corporate actions for {}: {}", target.symbol, e);
+            output.errors.push(format!("{}: {}", target.symbol, e));
+          }
+        },
+        _ => {}
+      }
+
+      info!(
+        "{}: {} dividends, {} splits, {} rows saved",
+        target.symbol, result.dividends, result.splits, result.records_saved
+      );
+
+      output.records_saved += result.records_saved;
+      output.results.push(result);
+
+      if let Some(e) = terminal {
+        error!("Stopping early: {}", e);
+        break;
+      }
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if output.records_saved > 0 {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "CorporateActionsLoader"
+  }
+}
+
+/// A security to load corporate actions for
+#[derive(Debug, Clone)]
+pub struct CorporateActionSymbol {
+  pub sid: i64,
+  pub symbol: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CorporateActionsLoaderInput {
+  pub symbols: Vec<CorporateActionSymbol>,
+}
+
+/// Per-symbol outcome of a [`CorporateActionsLoader`] run
+#[derive(Debug)]
+pub struct CorporateActionsResult {
+  pub sid: i64,
+  pub symbol: String,
+  /// Dividend events returned by the API
+  pub dividends: usize,
+  /// Split events returned by the API
+  pub splits: usize,
+  pub records_saved: usize,
+  /// True when every requested endpoint was served from cache
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct CorporateActionsLoaderOutput {
+  pub results: Vec<CorporateActionsResult>,
+  pub records_saved: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use av_models::fundamentals::{DividendEvent, SplitEvent};
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_dividend_records() {
+    let dividends = Dividends {
+      symbol: "IBM".to_string(),
+      data: vec![
+        DividendEvent {
+          ex_dividend_date: "2024-11-12".to_string(),
+          declaration_date: "2024-10-29".to_string(),
+          record_date: "None".to_string(),
+          payment_date: "None".to_string(),
+          amount: "1.67".to_string(),
+        },
+        DividendEvent {
+          ex_dividend_date: "None".to_string(),
+          declaration_date: "None".to_string(),
+          record_date: "None".to_string(),
+          payment_date: "None".to_string(),
+          amount: "0.5".to_string(),
+        },
+      ],
+    };
+
+    let records = CorporateActionsLoader::dividend_records(42, &dividends);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].action_type, ACTION_DIVIDEND);
+    assert_eq!(records[0].ex_date, NaiveDate::from_ymd_opt(2024, 11, 12).unwrap());
+    assert_eq!(records[0].declaration_date, NaiveDate::from_ymd_opt(2024, 10, 29));
+    assert_eq!(records[0].pay_date, None);
+    assert_eq!(records[0].amount, Some(1.67));
+    assert_eq!(records[0].split_ratio, None);
+  }
+
+  #[test]
+  fn test_split_records() {
+    let splits = Splits {
+      symbol: "NVDA".to_string(),
+      data: vec![SplitEvent {
+        effective_date: "2024-06-10".to_string(),
+        split_factor: "10.0000".to_string(),
+      }],
+    };
+
+    let records = CorporateActionsLoader::split_records(7, &splits);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].sid, 7);
+    assert_eq!(records[0].action_type, ACTION_SPLIT);
+    assert_eq!(records[0].split_ratio, Some(10.0));
+    assert_eq!(records[0].amount, None);
+  }
+}
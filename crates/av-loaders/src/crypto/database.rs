@@ -204,8 +204,6 @@ impl CryptoDbLoader {
 
   /// Save all crypto tokens without aggressive deduplication
   /// Allow multiple tokens with same trading symbol but different source IDs
-  ///
-
   async fn save_all_crypto_tokens(
     &self,
     symbols: Vec<CryptoSymbol>,
@@ -514,7 +514,6 @@ impl DataLoader for CryptoIntradayLoader {
     let mut tasks = stream::iter(symbols_to_process.into_iter())
       .map(|symbol_info| {
         let loader = self.clone();
-        let context = context;
         let symbol = symbol_info.symbol.clone();
         let sid = symbol_info.sid;
         let interval_str = interval.as_str().to_string();
@@ -213,12 +213,14 @@ mod tests {
   }
 
   #[test]
+  #[allow(clippy::drop_non_drop)]
   fn test_csv_processor_new() {
     let processor = CsvProcessor::new();
     drop(processor);
   }
 
   #[test]
+  #[allow(clippy::default_constructed_unit_structs, clippy::drop_non_drop)]
   fn test_csv_processor_default() {
     let processor = CsvProcessor::default();
     drop(processor);
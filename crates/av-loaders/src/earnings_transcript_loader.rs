@@ -0,0 +1,430 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Earnings call transcript loader for the `EARNINGS_CALL_TRANSCRIPT`
+//! endpoint.
+//!
+//! Backfills the last N fiscal quarters for each symbol, one API call per
+//! quarter. Quarters already stored in `earnings_transcripts` are skipped
+//! unless `skip_existing` is off. Quarters without a published call return
+//! an empty transcript, which is counted but not stored, so they are
+//! retried on the next run.
+
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
+use tracing::{debug, error, info, warn};
+
+use av_core::FuncType;
+use av_database_postgres::{
+  establish_connection,
+  models::transcripts::{
+    EarningsTranscript, NewEarningsTranscriptOwned, NewTranscriptSegmentOwned,
+  },
+  repository::CacheRepositoryExt,
+};
+use av_models::fundamentals::EarningsCallTranscript;
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+
+pub struct EarningsTranscriptLoader {
+  config: EarningsTranscriptConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EarningsTranscriptConfig {
+  /// Skip quarters that are already stored
+  pub skip_existing: bool,
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for EarningsTranscriptConfig {
+  fn default() -> Self {
+    Self {
+      skip_existing: true,
+      enable_cache: true,
+      cache_ttl_hours: ttl::TRANSCRIPTS,
+      force_refresh: false,
+    }
+  }
+}
+
+impl CacheConfigProvider for EarningsTranscriptConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+impl EarningsTranscriptLoader {
+  pub fn new(config: EarningsTranscriptConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  /// The `count` most recent completed quarters before `today`, newest
+  /// first, in `YYYYQn` form.
+  ///
+  /// The quarter containing `today` is excluded because its call has not
+  /// happened yet.
+  pub fn recent_quarters(today: NaiveDate, count: usize) -> Vec<String> {
+    let mut year = today.year();
+    let mut quarter = today.month0() / 3 + 1;
+
+    (0..count)
+      .map(|_| {
+        if quarter == 1 {
+          year -= 1;
+          quarter = 4;
+        } else {
+          quarter -= 1;
+        }
+        format!("{}Q{}", year, quarter)
+      })
+      .collect()
+  }
+
+  fn cache_key(symbol: &str, quarter: &str) -> String {
+    format!("{}:{}:{}", keys::TRANSCRIPTS, symbol, quarter)
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<EarningsCallTranscript> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<EarningsCallTranscript>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+    data: &EarningsCallTranscript,
+  ) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = FuncType::EarningsCallTranscript.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Convert an API transcript to a header and its segment rows.
+  ///
+  /// Returns `None` if the quarter label cannot be parsed.
+  pub fn to_records(
+    sid: i64,
+    transcript: &EarningsCallTranscript,
+  ) -> Option<(NewEarningsTranscriptOwned, Vec<NewTranscriptSegmentOwned>)> {
+    let (fiscal_year, fiscal_quarter) = transcript.fiscal_quarter()?;
+
+    let segments: Vec<NewTranscriptSegmentOwned> = transcript
+      .transcript
+      .iter()
+      .enumerate()
+      .map(|(seq, segment)| NewTranscriptSegmentOwned {
+        seq: seq as i32,
+        speaker: segment.speaker.trim().to_string(),
+        title: segment.title.trim().to_string(),
+        content: segment.content.clone(),
+        sentiment: segment.sentiment_as_f64(),
+      })
+      .collect();
+
+    let scores: Vec<f64> = segments.iter().filter_map(|s| s.sentiment).collect();
+    let avg_sentiment =
+      (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64);
+
+    let header = NewEarningsTranscriptOwned {
+      sid,
+      symbol: transcript.symbol.clone(),
+      quarter: format!("{}Q{}", fiscal_year, fiscal_quarter),
+      fiscal_year,
+      fiscal_quarter: fiscal_quarter as i16,
+      segment_count: segments.len() as i32,
+      avg_sentiment,
+    };
+
+    Some((header, segments))
+  }
+
+  fn loaded_quarters(db_url: &str, sid: i64) -> LoaderResult<Vec<String>> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    EarningsTranscript::loaded_quarters(&mut conn, sid)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to read loaded quarters: {}", e)))
+  }
+
+  fn save_transcript(
+    db_url: &str,
+    sid: i64,
+    transcript: &EarningsCallTranscript,
+  ) -> LoaderResult<usize> {
+    let Some((header, segments)) = Self::to_records(sid, transcript) else {
+      return Err(LoaderError::InvalidData(format!(
+        "Unparseable quarter '{}' for {}",
+        transcript.quarter, transcript.symbol
+      )));
+    };
+
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    let (_, written) = header
+      .save(&mut conn, &segments)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to save transcript: {}", e)))?;
+
+    Ok(written)
+  }
+}
+
+#[async_trait]
+impl DataLoader for EarningsTranscriptLoader {
+  type Input = EarningsTranscriptLoaderInput;
+  type Output = EarningsTranscriptLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!(
+      "Loading {} quarters of transcripts for {} symbols",
+      input.quarters.len(),
+      input.symbols.len()
+    );
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("earnings_transcript_loader").await?;
+    }
+
+    let mut output = EarningsTranscriptLoaderOutput::default();
+
+    'symbols: for (sid, symbol) in &input.symbols {
+      let loaded = match &self.database_url {
+        Some(db_url) if self.config.skip_existing => match Self::loaded_quarters(db_url, *sid) {
+          Ok(loaded) => loaded,
+          Err(e) => {
+            warn!("Failed to read loaded quarters for {}: {}", symbol, e);
+            Vec::new()
+          }
+        },
+        _ => Vec::new(),
+      };
+
+      for quarter in &input.quarters {
+        if loaded.contains(quarter) {
+          debug!("Skipping {} {}: already stored", symbol, quarter);
+          output.quarters_skipped += 1;
+          continue;
+        }
+
+        let cache_key = Self::cache_key(symbol, quarter);
+        let (transcript, from_cache) = match self.get_cached_response(context, &cache_key).await {
+          Some(cached) => (cached, true),
+          None => {
+            match context.client.fundamentals().earnings_call_transcript(symbol, quarter).await {
+              Ok(data) => {
+                self.cache_response(context, &cache_key, &data).await;
+                (data, false)
+              }
+              Err(e) => {
+                warn!("Failed to fetch {} {} transcript: {}", symbol, quarter, e);
+                output.errors.push(format!("{} {}: {}", symbol, quarter, e));
+                if e.is_terminal() {
+                  error!("Stopping early: {}", e);
+                  break 'symbols;
+                }
+                continue;
+              }
+            }
+          }
+        };
+
+        if transcript.is_empty() {
+          debug!("No transcript published for {} {}", symbol, quarter);
+          output.empty_quarters += 1;
+          continue;
+        }
+
+        let segments_saved = match &self.database_url {
+          Some(db_url) => match Self::save_transcript(db_url, *sid, &transcript) {
+            Ok(saved) => saved,
+            Err(e) => {
+              warn!("Failed to save {} {} transcript: {}", symbol, quarter, e);
+              output.errors.push(format!("{} {}: {}", symbol, quarter, e));
+              0
+            }
+          },
+          None => 0,
+        };
+
+        output.segments_saved += segments_saved;
+        output.transcripts.push(TranscriptResult {
+          sid: *sid,
+          symbol: symbol.clone(),
+          quarter: quarter.clone(),
+          segments: transcript.transcript.len(),
+          segments_saved,
+          from_cache,
+        });
+      }
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.transcripts.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "EarningsTranscriptLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct EarningsTranscriptLoaderInput {
+  /// `(sid, symbol)` pairs to load
+  pub symbols: Vec<(i64, String)>,
+  /// Quarters to fetch in `YYYYQn` form (see
+  /// [`EarningsTranscriptLoader::recent_quarters`])
+  pub quarters: Vec<String>,
+}
+
+/// Outcome for one symbol and quarter
+#[derive(Debug)]
+pub struct TranscriptResult {
+  pub sid: i64,
+  pub symbol: String,
+  pub quarter: String,
+  pub segments: usize,
+  pub segments_saved: usize,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct EarningsTranscriptLoaderOutput {
+  pub transcripts: Vec<TranscriptResult>,
+  pub segments_saved: usize,
+  /// Quarters skipped because they were already stored
+  pub quarters_skipped: usize,
+  /// Quarters for which no call was published
+  pub empty_quarters: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use av_models::fundamentals::TranscriptSegment;
+
+  #[test]
+  fn test_recent_quarters() {
+    let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+    assert_eq!(
+      EarningsTranscriptLoader::recent_quarters(today, 3),
+      vec!["2024Q1", "2023Q4", "2023Q3"]
+    );
+
+    let january = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    assert_eq!(EarningsTranscriptLoader::recent_quarters(january, 1), vec!["2023Q4"]);
+  }
+
+  #[test]
+  fn test_to_records() {
+    let segment = |speaker: &str, sentiment: &str| TranscriptSegment {
+      speaker: speaker.to_string(),
+      title: "CEO".to_string(),
+      content: "Strong quarter.".to_string(),
+      sentiment: sentiment.to_string(),
+    };
+    let transcript = EarningsCallTranscript {
+      symbol: "IBM".to_string(),
+      quarter: "2024q1".to_string(),
+      transcript: vec![segment("A", "0.4"), segment("B", ""), segment("C", "0.8")],
+    };
+
+    let (header, segments) = EarningsTranscriptLoader::to_records(9, &transcript).unwrap();
+    assert_eq!(header.quarter, "2024Q1");
+    assert_eq!(header.fiscal_quarter, 1);
+    assert_eq!(header.segment_count, 3);
+    assert!((header.avg_sentiment.unwrap() - 0.6).abs() < 1e-9);
+    assert_eq!(segments[2].seq, 2);
+    assert_eq!(segments[1].sentiment, None);
+  }
+}
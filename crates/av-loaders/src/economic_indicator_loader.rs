@@ -0,0 +1,491 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Economic indicator loader for the US macro series
+//! (`REAL_GDP`, `CPI`, `INFLATION`, `TREASURY_YIELD`, `FEDERAL_FUNDS_RATE`,
+//! `UNEMPLOYMENT`, `NONFARM_PAYROLL`).
+//!
+//! Each requested [`EconomicSeriesSpec`] is one API call. Responses are
+//! cached, converted to `economic_indicators` rows, and upserted so that
+//! revised observations replace earlier values.
+
+use async_trait::async_trait;
+use std::fmt;
+use tracing::{debug, error, info, warn};
+
+use av_core::FuncType;
+use av_core::types::common::{EconomicInterval, TreasuryMaturity};
+use av_database_postgres::{
+  establish_connection, models::economics::NewEconomicIndicatorOwned,
+  repository::CacheRepositoryExt,
+};
+use av_models::economics::{EconomicDataPoint, EconomicSeries};
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+
+/// Every economic indicator function, in the order they are loaded by default.
+pub const ECONOMIC_FUNCTIONS: [FuncType; 7] = [
+  FuncType::RealGdp,
+  FuncType::Cpi,
+  FuncType::Inflation,
+  FuncType::TreasuryYield,
+  FuncType::FederalFundsRate,
+  FuncType::Unemployment,
+  FuncType::NonfarmPayroll,
+];
+
+pub struct EconomicIndicatorLoader {
+  config: EconomicIndicatorConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EconomicIndicatorConfig {
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for EconomicIndicatorConfig {
+  fn default() -> Self {
+    Self { enable_cache: true, cache_ttl_hours: ttl::ECONOMICS, force_refresh: false }
+  }
+}
+
+impl CacheConfigProvider for EconomicIndicatorConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+/// One economic series to fetch.
+///
+/// `interval` of `None` uses the API default for the function. `maturity`
+/// only applies to `TREASURY_YIELD`; the API defaults to the 10-year note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EconomicSeriesSpec {
+  pub function: FuncType,
+  pub interval: Option<EconomicInterval>,
+  pub maturity: Option<TreasuryMaturity>,
+}
+
+impl EconomicSeriesSpec {
+  pub fn new(function: FuncType) -> Self {
+    Self { function, interval: None, maturity: None }
+  }
+
+  pub fn with_interval(mut self, interval: EconomicInterval) -> Self {
+    self.interval = Some(interval);
+    self
+  }
+
+  pub fn with_maturity(mut self, maturity: TreasuryMaturity) -> Self {
+    self.maturity = Some(maturity);
+    self
+  }
+
+  /// The maturity stored in the `maturity` column: the requested (or
+  /// default 10-year) maturity for treasury yields, empty otherwise.
+  pub fn maturity_key(&self) -> String {
+    match self.function {
+      FuncType::TreasuryYield => self.maturity.unwrap_or(TreasuryMaturity::TenYear).to_string(),
+      _ => String::new(),
+    }
+  }
+
+  fn cache_key(&self) -> String {
+    format!(
+      "{}:{}:{}:{}",
+      keys::ECONOMICS,
+      self.function,
+      self.interval.map(|i| i.to_string()).unwrap_or_else(|| "default".to_string()),
+      self.maturity_key()
+    )
+  }
+}
+
+impl fmt::Display for EconomicSeriesSpec {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.function)?;
+    if let Some(interval) = self.interval {
+      write!(f, " ({})", interval)?;
+    }
+    if self.function == FuncType::TreasuryYield {
+      write!(f, " [{}]", self.maturity_key())?;
+    }
+    Ok(())
+  }
+}
+
+impl EconomicIndicatorLoader {
+  pub fn new(config: EconomicIndicatorConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  /// Dispatch a spec to the matching `economics()` endpoint
+  async fn fetch_series(
+    context: &LoaderContext,
+    spec: &EconomicSeriesSpec,
+  ) -> Result<EconomicSeries, LoaderError> {
+    let economics = context.client.economics();
+    let result = match spec.function {
+      FuncType::RealGdp => economics.real_gdp(spec.interval).await,
+      FuncType::Cpi => economics.cpi(spec.interval).await,
+      FuncType::Inflation => economics.inflation().await,
+      FuncType::TreasuryYield => economics.treasury_yield(spec.interval, spec.maturity).await,
+      FuncType::FederalFundsRate => economics.federal_funds_rate(spec.interval).await,
+      FuncType::Unemployment => economics.unemployment().await,
+      FuncType::NonfarmPayroll => economics.nonfarm_payroll().await,
+      other => {
+        return Err(LoaderError::ConfigurationError(format!(
+          "{} is not an economic indicator function",
+          other
+        )));
+      }
+    };
+
+    result.map_err(LoaderError::from)
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<EconomicSeries> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<EconomicSeries>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+    spec: &EconomicSeriesSpec,
+    data: &EconomicSeries,
+  ) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = spec.function.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Convert an API series to database rows, skipping unparseable dates
+  pub fn to_records(
+    spec: &EconomicSeriesSpec,
+    series: &EconomicSeries,
+  ) -> Vec<NewEconomicIndicatorOwned> {
+    let indicator = spec.function.to_string();
+    let series_interval = series.interval.to_lowercase();
+    let maturity = spec.maturity_key();
+
+    series
+      .data
+      .iter()
+      .filter_map(|point| {
+        let Some(date) = point.parsed_date() else {
+          warn!("Skipping {} observation with invalid date '{}'", indicator, point.date);
+          return None;
+        };
+
+        Some(NewEconomicIndicatorOwned {
+          indicator: indicator.clone(),
+          series_interval: series_interval.clone(),
+          maturity: maturity.clone(),
+          date,
+          value: point.value_as_f64(),
+          unit: series.unit.clone(),
+          name: series.name.clone(),
+        })
+      })
+      .collect()
+  }
+
+  fn save_records(db_url: &str, records: &[NewEconomicIndicatorOwned]) -> LoaderResult<usize> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    NewEconomicIndicatorOwned::upsert_batch(&mut conn, records).map_err(|e| {
+      LoaderError::DatabaseError(format!("Failed to upsert economic indicators: {}", e))
+    })
+  }
+}
+
+#[async_trait]
+impl DataLoader for EconomicIndicatorLoader {
+  type Input = EconomicIndicatorLoaderInput;
+  type Output = EconomicIndicatorLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading {} economic indicator series", input.series.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("economic_indicator_loader").await?;
+    }
+
+    let mut output = EconomicIndicatorLoaderOutput::default();
+
+    for spec in &input.series {
+      let cache_key = spec.cache_key();
+
+      let (series, from_cache) = match self.get_cached_response(context, &cache_key).await {
+        Some(cached) => (cached, true),
+        None => match Self::fetch_series(context, spec).await {
+          Ok(data) => {
+            self.cache_response(context, &cache_key, spec, &data).await;
+            (data, false)
+          }
+          Err(e) => {
+            warn!("Failed to fetch {}: {}", spec, e);
+            output.errors.push(format!("{}: {}", spec, e));
+            if e.is_terminal() {
+              error!("Stopping early: {}", e);
+              break;
+            }
+            continue;
+          }
+        },
+      };
+
+      let records = Self::to_records(spec, &series);
+      let records_saved = match &self.database_url {
+        Some(db_url) if !records.is_empty() => match Self::save_records(db_url, &records) {
+          Ok(count) => count,
+          Err(e) => {
+            warn!("Failed to save {}: {}", spec, e);
+            output.errors.push(format!("{}: {}", spec, e));
+            0
+          }
+        },
+        _ => 0,
+      };
+
+      info!("{}: {} observations, {} rows saved", spec, series.len(), records_saved);
+
+      output.records_saved += records_saved;
+      output.series.push(EconomicSeriesResult {
+        spec: *spec,
+        observations: series.len(),
+        latest: series.latest().cloned(),
+        records_saved,
+        from_cache,
+        name: series.name,
+        interval: series.interval,
+        unit: series.unit,
+      });
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.series.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "EconomicIndicatorLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct EconomicIndicatorLoaderInput {
+  pub series: Vec<EconomicSeriesSpec>,
+}
+
+/// Per-series outcome of an [`EconomicIndicatorLoader`] run
+#[derive(Debug)]
+pub struct EconomicSeriesResult {
+  pub spec: EconomicSeriesSpec,
+  pub name: String,
+  pub interval: String,
+  pub unit: String,
+  pub observations: usize,
+  pub latest: Option<EconomicDataPoint>,
+  pub records_saved: usize,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct EconomicIndicatorLoaderOutput {
+  pub series: Vec<EconomicSeriesResult>,
+  pub records_saved: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn point(date: &str, value: &str) -> EconomicDataPoint {
+    EconomicDataPoint { date: date.to_string(), value: value.to_string() }
+  }
+
+  #[test]
+  fn test_maturity_key() {
+    assert_eq!(EconomicSeriesSpec::new(FuncType::Cpi).maturity_key(), "");
+    assert_eq!(EconomicSeriesSpec::new(FuncType::TreasuryYield).maturity_key(), "10year");
+    assert_eq!(
+      EconomicSeriesSpec::new(FuncType::TreasuryYield)
+        .with_maturity(TreasuryMaturity::TwoYear)
+        .maturity_key(),
+      "2year"
+    );
+  }
+
+  #[test]
+  fn test_to_records() {
+    let spec = EconomicSeriesSpec::new(FuncType::TreasuryYield)
+      .with_interval(EconomicInterval::Daily)
+      .with_maturity(TreasuryMaturity::FiveYear);
+    let series = EconomicSeries {
+      name: "5-Year Treasury Constant Maturity Rate".to_string(),
+      interval: "daily".to_string(),
+      unit: "percent".to_string(),
+      data: vec![point("2024-01-15", "."), point("2024-01-12", "3.92"), point("bad", "1.0")],
+    };
+
+    let records = EconomicIndicatorLoader::to_records(&spec, &series);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].indicator, "TREASURY_YIELD");
+    assert_eq!(records[0].maturity, "5year");
+    assert_eq!(records[0].value, None);
+    assert_eq!(records[1].value, Some(3.92));
+  }
+
+  #[tokio::test]
+  async fn test_load_from_fixture() {
+    use av_client::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+    use std::sync::Arc;
+
+    let body = r#"{
+            "name": "Consumer Price Index for all Urban Consumers",
+            "interval": "monthly",
+            "unit": "index 1982-1984=100",
+            "data": [
+                { "date": "2024-02-01", "value": "310.326" },
+                { "date": "2024-01-01", "value": "308.417" }
+            ]
+        }"#;
+    let fixture = Arc::new(FixtureTransport::new().with_response(FuncType::Cpi, body));
+    let client = AlphaVantageClient::with_transport(
+      Config::default_with_key("test".to_string()),
+      fixture.clone(),
+    )
+    .unwrap();
+    let context = LoaderContext::new(Arc::new(client), crate::LoaderConfig::default());
+
+    let loader = EconomicIndicatorLoader::new(EconomicIndicatorConfig::default(), None);
+    let input =
+      EconomicIndicatorLoaderInput { series: vec![EconomicSeriesSpec::new(FuncType::Cpi)] };
+    let output = loader.load(&context, input).await.unwrap();
+
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+    assert_eq!(output.series[0].observations, 2);
+    assert!(!output.series[0].from_cache);
+    assert_eq!(fixture.requests()[0].function, FuncType::Cpi);
+  }
+
+  #[tokio::test]
+  async fn test_load_stops_when_quota_is_exhausted() {
+    use av_client::{AlphaVantageClient, FixtureTransport};
+    use av_core::Config;
+    use std::sync::Arc;
+
+    let limit = r#"{"Information": "You have reached the 25 requests per day limit."}"#;
+    let fixture = Arc::new(
+      FixtureTransport::new()
+        .with_response(FuncType::Cpi, limit)
+        .with_response(FuncType::Inflation, limit),
+    );
+    let config = Config { max_retries: 0, ..Config::default_with_key("test".to_string()) };
+    let client = AlphaVantageClient::with_transport(config, fixture.clone()).unwrap();
+    let context = LoaderContext::new(Arc::new(client), crate::LoaderConfig::default());
+
+    let loader = EconomicIndicatorLoader::new(EconomicIndicatorConfig::default(), None);
+    let series =
+      vec![EconomicSeriesSpec::new(FuncType::Cpi), EconomicSeriesSpec::new(FuncType::Inflation)];
+    let output = loader.load(&context, EconomicIndicatorLoaderInput { series }).await.unwrap();
+
+    assert_eq!(output.errors.len(), 1, "{:?}", output.errors);
+    assert!(output.series.is_empty());
+    assert_eq!(fixture.requests().len(), 1);
+  }
+}
@@ -60,6 +60,23 @@ pub enum LoaderError {
 
   #[error("Configuration error: {0}")]
   ConfigurationError(String),
+
+  #[error("Premium endpoint required: {0}")]
+  PremiumRequired(String),
+
+  #[error("Daily API quota exhausted: {0}")]
+  QuotaExhausted(String),
+
+  #[error("Invalid API key: {0}")]
+  InvalidApiKey(String),
+}
+
+impl LoaderError {
+  /// Whether the rest of a run would fail the same way (daily quota
+  /// exhausted or the key rejected), so a loader should stop early.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, LoaderError::QuotaExhausted(_) | LoaderError::InvalidApiKey(_))
+  }
 }
 
 // Implement conversions manually
@@ -83,7 +100,12 @@ impl From<serde_json::Error> for LoaderError {
 
 impl From<av_core::Error> for LoaderError {
   fn from(err: av_core::Error) -> Self {
-    LoaderError::ApiError(err.to_string())
+    match err {
+      av_core::Error::PremiumRequired(msg) => LoaderError::PremiumRequired(msg),
+      av_core::Error::QuotaExhausted(msg) => LoaderError::QuotaExhausted(msg),
+      av_core::Error::InvalidApiKey(msg) => LoaderError::InvalidApiKey(msg),
+      other => LoaderError::ApiError(other.to_string()),
+    }
   }
 }
 
@@ -189,6 +211,14 @@ mod tests {
     assert!(err.to_string().contains("Configuration error"));
   }
 
+  #[test]
+  fn test_loader_error_from_quota_exhausted() {
+    let err = LoaderError::from(av_core::Error::QuotaExhausted("25 per day".to_string()));
+    assert!(matches!(err, LoaderError::QuotaExhausted(_)));
+    assert!(err.is_terminal());
+    assert!(!LoaderError::from(av_core::Error::Api("bad symbol".to_string())).is_terminal());
+  }
+
   #[test]
   fn test_loader_error_clone() {
     let err = LoaderError::ApiError("test".to_string());
@@ -205,6 +235,7 @@ mod tests {
   }
 
   #[test]
+  #[allow(clippy::unnecessary_literal_unwrap)]
   fn test_loader_result_ok() {
     let result: LoaderResult<i32> = Ok(42);
     assert!(result.is_ok());
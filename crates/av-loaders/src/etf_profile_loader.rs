@@ -0,0 +1,478 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! ETF profile loader for the `ETF_PROFILE` endpoint.
+//!
+//! Fetches key metrics, sector allocation, and constituent holdings for
+//! each ETF and stores them as one snapshot per ETF and day. Holding
+//! tickers are resolved against the `symbols` table; tickers that are not
+//! there yet are stored unlinked and recorded in `missing_symbols`, and
+//! are linked on a later run once they have been added.
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+
+use av_core::FuncType;
+use av_database_postgres::{
+  establish_connection,
+  models::etf::{
+    EtfHolding as EtfHoldingRow, NewEtfHoldingOwned, NewEtfProfileOwned, NewEtfSectorWeightOwned,
+  },
+  repository::{CacheRepositoryExt, NewsRepository},
+};
+use av_models::fundamentals::EtfProfile;
+use diesel::PgConnection;
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const SOURCE_NAME: &str = "etf_profile";
+const API_SOURCE: &str = "alphavantage";
+
+pub struct EtfProfileLoader {
+  config: EtfProfileConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EtfProfileConfig {
+  /// Whether to record holding tickers that are not in `symbols`
+  pub track_missing_symbols: bool,
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for EtfProfileConfig {
+  fn default() -> Self {
+    Self {
+      track_missing_symbols: true,
+      enable_cache: true,
+      cache_ttl_hours: ttl::ETF_PROFILE,
+      force_refresh: false,
+    }
+  }
+}
+
+impl CacheConfigProvider for EtfProfileConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+/// A profile converted to rows, plus the tickers that could not be linked.
+pub type EtfSnapshotRecords =
+  (NewEtfProfileOwned, Vec<NewEtfHoldingOwned>, Vec<NewEtfSectorWeightOwned>, Vec<String>);
+
+impl EtfProfileLoader {
+  pub fn new(config: EtfProfileConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  fn cache_key(symbol: &str) -> String {
+    format!("{}:{}", keys::ETF_PROFILE, symbol)
+  }
+
+  /// Map each ticker to its highest-priority listing, so that a holding
+  /// links to the primary share class rather than a foreign duplicate.
+  fn load_constituent_symbols(
+    conn: &mut PgConnection,
+  ) -> Result<HashMap<String, i64>, LoaderError> {
+    use av_database_postgres::schema::symbols;
+    use diesel::prelude::*;
+
+    let results: Vec<(String, i64)> = symbols::table
+      .filter(symbols::sec_type.ne_all(vec!["Cryptocurrency", "Commodity"]))
+      .order_by((symbols::symbol.asc(), symbols::priority.asc()))
+      .select((symbols::symbol, symbols::sid))
+      .load(conn)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to load symbols: {}", e)))?;
+
+    let mut map = HashMap::with_capacity(results.len());
+    for (symbol, sid) in results {
+      map.entry(symbol).or_insert(sid);
+    }
+    Ok(map)
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<EtfProfile> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<EtfProfile>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(&self, context: &LoaderContext, cache_key: &str, data: &EtfProfile) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = FuncType::EtfProfile.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Record missing symbols to the database
+  async fn record_missing_symbols(
+    &self,
+    news_repo: &Arc<dyn NewsRepository>,
+    symbols: &[String],
+  ) -> usize {
+    if !self.config.track_missing_symbols || symbols.is_empty() {
+      return 0;
+    }
+
+    let mut logged_count = 0;
+    for symbol in symbols {
+      match news_repo.record_missing_symbol(symbol, SOURCE_NAME).await {
+        Ok(_) => {
+          logged_count += 1;
+          debug!("Recorded missing symbol: {}", symbol);
+        }
+        Err(e) => {
+          warn!("Failed to record missing symbol {}: {}", symbol, e);
+        }
+      }
+    }
+
+    logged_count
+  }
+
+  /// Convert an API profile to database rows.
+  ///
+  /// Holdings keep the API's order as their `position`. Holdings with an
+  /// unparseable weight are dropped. Tickers absent from `known_symbols`
+  /// are returned (deduplicated) so they can be recorded as missing.
+  pub fn to_records(
+    etf_sid: i64,
+    symbol: &str,
+    snapshot_date: NaiveDate,
+    profile: &EtfProfile,
+    known_symbols: &HashMap<String, i64>,
+  ) -> EtfSnapshotRecords {
+    let header = NewEtfProfileOwned {
+      etf_sid,
+      snapshot_date,
+      symbol: symbol.to_string(),
+      net_assets: profile.net_assets_as_f64(),
+      net_expense_ratio: profile.net_expense_ratio_as_f64(),
+      portfolio_turnover: profile.portfolio_turnover_as_f64(),
+      dividend_yield: profile.dividend_yield_as_f64(),
+      inception_date: profile.inception_date(),
+      leveraged: profile.is_leveraged(),
+    };
+
+    let mut missing: Vec<String> = Vec::new();
+    let mut seen_missing: HashSet<String> = HashSet::new();
+    let holdings: Vec<NewEtfHoldingOwned> = profile
+      .holdings
+      .iter()
+      .filter_map(|h| {
+        let weight = h.weight_as_f64()?;
+        let ticker = h.ticker().map(str::to_uppercase);
+        let constituent_sid = ticker.as_ref().and_then(|t| known_symbols.get(t).copied());
+        if let Some(t) = &ticker {
+          if constituent_sid.is_none() && seen_missing.insert(t.clone()) {
+            missing.push(t.clone());
+          }
+        }
+        Some((ticker, constituent_sid, h.description.trim().to_string(), weight))
+      })
+      .enumerate()
+      .map(|(position, (holding_symbol, constituent_sid, description, weight))| {
+        NewEtfHoldingOwned {
+          etf_sid,
+          snapshot_date,
+          position: position as i32,
+          holding_symbol,
+          constituent_sid,
+          description,
+          weight,
+        }
+      })
+      .collect();
+
+    let sectors: Vec<NewEtfSectorWeightOwned> = profile
+      .sectors
+      .iter()
+      .filter_map(|s| {
+        Some(NewEtfSectorWeightOwned {
+          etf_sid,
+          snapshot_date,
+          sector: s.sector.trim().to_string(),
+          weight: s.weight_as_f64()?,
+        })
+      })
+      .collect();
+
+    (header, holdings, sectors, missing)
+  }
+}
+
+#[async_trait]
+impl DataLoader for EtfProfileLoader {
+  type Input = EtfProfileLoaderInput;
+  type Output = EtfProfileLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading ETF profiles for {} symbols", input.symbols.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("etf_profile_loader").await?;
+    }
+
+    let mut output = EtfProfileLoaderOutput::default();
+    let snapshot_date = Utc::now().date_naive();
+
+    let mut conn = match &self.database_url {
+      Some(db_url) => Some(establish_connection(db_url).map_err(|e| {
+        LoaderError::DatabaseError(format!("Failed to connect to database: {}", e))
+      })?),
+      None => None,
+    };
+    let known_symbols = match conn.as_mut() {
+      Some(conn) => Self::load_constituent_symbols(conn)?,
+      None => HashMap::new(),
+    };
+
+    let mut all_missing: Vec<String> = Vec::new();
+    let mut seen_missing: HashSet<String> = HashSet::new();
+
+    for (sid, symbol) in &input.symbols {
+      let cache_key = Self::cache_key(symbol);
+      let (profile, from_cache) = match self.get_cached_response(context, &cache_key).await {
+        Some(cached) => (cached, true),
+        None => match context.client.fundamentals().etf_profile(symbol).await {
+          Ok(data) => {
+            self.cache_response(context, &cache_key, &data).await;
+            (data, false)
+          }
+          Err(e) => {
+            warn!("Failed to fetch ETF profile for {}: {}", symbol, e);
+            output.errors.push(format!("{}: {}", symbol, e));
+            if e.is_terminal() {
+              error!("Stopping early: {}", e);
+              break;
+            }
+            continue;
+          }
+        },
+      };
+
+      let (header, holdings, sectors, missing) =
+        Self::to_records(*sid, symbol, snapshot_date, &profile, &known_symbols);
+      let linked = holdings.iter().filter(|h| h.constituent_sid.is_some()).count();
+
+      let holdings_saved = match conn.as_mut() {
+        Some(conn) => match header.save_snapshot(conn, &holdings, &sectors) {
+          Ok((saved, _)) => saved,
+          Err(e) => {
+            warn!("Failed to save ETF profile for {}: {}", symbol, e);
+            output.errors.push(format!("{}: {}", symbol, e));
+            0
+          }
+        },
+        None => 0,
+      };
+
+      for ticker in missing {
+        if seen_missing.insert(ticker.clone()) {
+          all_missing.push(ticker);
+        }
+      }
+
+      output.holdings_saved += holdings_saved;
+      output.profiles.push(EtfProfileResult {
+        sid: *sid,
+        symbol: symbol.clone(),
+        holdings: holdings.len(),
+        linked_holdings: linked,
+        sectors: sectors.len(),
+        holdings_saved,
+        from_cache,
+      });
+    }
+
+    if let Some(conn) = conn.as_mut() {
+      match EtfHoldingRow::link_constituents(conn) {
+        Ok(count) => output.constituents_linked = count,
+        Err(e) => warn!("Failed to link ETF constituents: {}", e),
+      }
+    }
+
+    if let Some(news_repo) = &context.news_repository {
+      output.missing_recorded = self.record_missing_symbols(news_repo, &all_missing).await;
+    }
+    output.missing_symbols = all_missing;
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.profiles.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "EtfProfileLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct EtfProfileLoaderInput {
+  /// `(sid, symbol)` pairs of ETFs to load
+  pub symbols: Vec<(i64, String)>,
+}
+
+/// Outcome for one ETF
+#[derive(Debug)]
+pub struct EtfProfileResult {
+  pub sid: i64,
+  pub symbol: String,
+  pub holdings: usize,
+  /// Holdings whose ticker was found in `symbols`
+  pub linked_holdings: usize,
+  pub sectors: usize,
+  pub holdings_saved: usize,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct EtfProfileLoaderOutput {
+  pub profiles: Vec<EtfProfileResult>,
+  pub holdings_saved: usize,
+  /// Previously unlinked holdings linked to a newly added symbol
+  pub constituents_linked: usize,
+  /// Holding tickers not found in `symbols`
+  pub missing_symbols: Vec<String>,
+  pub missing_recorded: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use av_models::fundamentals::{EtfHolding, EtfSectorWeight};
+
+  fn holding(symbol: &str, weight: &str) -> EtfHolding {
+    EtfHolding {
+      symbol: symbol.to_string(),
+      description: format!("{} INC", symbol),
+      weight: weight.to_string(),
+    }
+  }
+
+  #[test]
+  fn test_to_records() {
+    let profile = EtfProfile {
+      net_assets: "341000000000".to_string(),
+      net_expense_ratio: "0.002".to_string(),
+      portfolio_turnover: "n/a".to_string(),
+      dividend_yield: "0.0059".to_string(),
+      inception_date: "1999-03-10".to_string(),
+      leveraged: "NO".to_string(),
+      sectors: vec![EtfSectorWeight {
+        sector: "INFORMATION TECHNOLOGY".to_string(),
+        weight: "0.503".to_string(),
+      }],
+      holdings: vec![
+        holding("NVDA", "0.0902"),
+        holding("n/a", "0.0011"),
+        holding("NEWCO", "0.0005"),
+        holding("BAD", "n/a"),
+        holding("NEWCO", "0.0001"),
+      ],
+    };
+    let known = HashMap::from([("NVDA".to_string(), 42_i64)]);
+    let date = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+
+    let (header, holdings, sectors, missing) =
+      EtfProfileLoader::to_records(7, "QQQ", date, &profile, &known);
+
+    assert_eq!(header.net_expense_ratio, Some(0.002));
+    assert_eq!(header.portfolio_turnover, None);
+    assert_eq!(header.leveraged, Some(false));
+    assert_eq!(holdings.len(), 4);
+    assert_eq!(holdings[0].constituent_sid, Some(42));
+    assert_eq!(holdings[1].holding_symbol, None);
+    assert_eq!(holdings[2].constituent_sid, None);
+    assert_eq!(holdings[3].position, 3);
+    assert_eq!(sectors.len(), 1);
+    assert_eq!(missing, vec!["NEWCO"]);
+  }
+}
@@ -0,0 +1,403 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Insider transaction loader for the `INSIDER_TRANSACTIONS` endpoint.
+//!
+//! The endpoint returns a symbol's entire filing history on every call.
+//! Loading is incremental: only trades dated on or after the latest stored
+//! `transaction_date` are sent to the database, where a unique index drops
+//! the ones already present. The latest day is included again because
+//! filings for it may have arrived after the previous run.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tracing::{debug, error, info, warn};
+
+use av_core::FuncType;
+use av_database_postgres::{
+  establish_connection,
+  models::insiders::{InsiderTransaction, NewInsiderTransactionOwned},
+  repository::CacheRepositoryExt,
+};
+use av_models::fundamentals::InsiderTransactions;
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+
+pub struct InsiderTransactionsLoader {
+  config: InsiderTransactionsConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InsiderTransactionsConfig {
+  /// Send the whole history to the database instead of only trades on or
+  /// after the latest stored date
+  pub full_history: bool,
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for InsiderTransactionsConfig {
+  fn default() -> Self {
+    Self {
+      full_history: false,
+      enable_cache: true,
+      cache_ttl_hours: ttl::INSIDERS,
+      force_refresh: false,
+    }
+  }
+}
+
+impl CacheConfigProvider for InsiderTransactionsConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+impl InsiderTransactionsLoader {
+  pub fn new(config: InsiderTransactionsConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  fn cache_key(symbol: &str) -> String {
+    format!("{}:{}", keys::INSIDERS, symbol)
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<InsiderTransactions> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<InsiderTransactions>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+    data: &InsiderTransactions,
+  ) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    let endpoint_url = FuncType::InsiderTransactions.to_string();
+    if let Err(e) =
+      cache_repo.set(cache_key, API_SOURCE, &endpoint_url, data, self.config.cache_ttl_hours).await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  /// Convert an API response to database rows dated on or after `since`,
+  /// skipping trades with an unparseable date, share count, or direction
+  pub fn to_records(
+    sid: i64,
+    symbol: &str,
+    data: &InsiderTransactions,
+    since: Option<NaiveDate>,
+  ) -> Vec<NewInsiderTransactionOwned> {
+    data
+      .data
+      .iter()
+      .filter_map(|trade| {
+        let (Some(date), Some(shares)) = (trade.date(), trade.shares_as_f64()) else {
+          warn!("Skipping malformed {} insider trade: {:?}", symbol, trade);
+          return None;
+        };
+        if since.is_some_and(|since| date < since) {
+          return None;
+        }
+
+        let direction = trade.acquisition_or_disposal.trim().to_uppercase();
+        if direction != "A" && direction != "D" {
+          warn!("Skipping {} insider trade with direction '{}'", symbol, direction);
+          return None;
+        }
+
+        Some(NewInsiderTransactionOwned {
+          sid,
+          symbol: symbol.to_string(),
+          transaction_date: date,
+          executive: trade.executive.trim().to_string(),
+          executive_title: trade.executive_title.trim().to_string(),
+          security_type: trade.security_type.trim().to_string(),
+          acquisition_or_disposal: direction,
+          shares,
+          share_price: trade.share_price_as_f64().unwrap_or(0.0),
+        })
+      })
+      .collect()
+  }
+
+  /// Inserts the new trades for one symbol, returning `(candidates, inserted)`
+  fn save_trades(
+    &self,
+    db_url: &str,
+    sid: i64,
+    symbol: &str,
+    data: &InsiderTransactions,
+  ) -> LoaderResult<(usize, usize)> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+
+    let since = if self.config.full_history {
+      None
+    } else {
+      InsiderTransaction::latest_date(&mut conn, sid)
+        .map_err(|e| LoaderError::DatabaseError(format!("Failed to read latest trade: {}", e)))?
+    };
+
+    let records = Self::to_records(sid, symbol, data, since);
+    let inserted = NewInsiderTransactionOwned::insert_batch(&mut conn, &records).map_err(|e| {
+      LoaderError::DatabaseError(format!("Failed to insert insider transactions: {}", e))
+    })?;
+
+    Ok((records.len(), inserted))
+  }
+}
+
+#[async_trait]
+impl DataLoader for InsiderTransactionsLoader {
+  type Input = InsiderTransactionsLoaderInput;
+  type Output = InsiderTransactionsLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading insider transactions for {} symbols", input.symbols.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("insider_transactions_loader").await?;
+    }
+
+    let mut output = InsiderTransactionsLoaderOutput::default();
+
+    for (sid, symbol) in &input.symbols {
+      let cache_key = Self::cache_key(symbol);
+
+      let (data, from_cache) = match self.get_cached_response(context, &cache_key).await {
+        Some(cached) => (cached, true),
+        None => match context.client.fundamentals().insider_transactions(symbol).await {
+          Ok(data) => {
+            self.cache_response(context, &cache_key, &data).await;
+            (data, false)
+          }
+          Err(e) => {
+            warn!("Failed to fetch insider transactions for {}: {}", symbol, e);
+            output.errors.push(format!("{}: {}", symbol, e));
+            if e.is_terminal() {
+              error!("Stopping early: {}", e);
+              break;
+            }
+            continue;
+          }
+        },
+      };
+
+      let (candidates, inserted) = match &self.database_url {
+        Some(db_url) if !data.data.is_empty() => {
+          match self.save_trades(db_url, *sid, symbol, &data) {
+            Ok(counts) => counts,
+            Err(e) => {
+              warn!("Failed to save insider transactions for {}: {}", symbol, e);
+              output.errors.push(format!("{}: {}", symbol, e));
+              (0, 0)
+            }
+          }
+        }
+        _ => (0, 0),
+      };
+
+      info!("{}: {} trades fetched, {} new", symbol, data.data.len(), inserted);
+
+      output.records_saved += inserted;
+      output.results.push(InsiderTransactionsResult {
+        sid: *sid,
+        symbol: symbol.clone(),
+        fetched: data.data.len(),
+        candidates,
+        inserted,
+        latest: data.data.iter().filter_map(|t| t.date()).max(),
+        from_cache,
+      });
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.results.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "InsiderTransactionsLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct InsiderTransactionsLoaderInput {
+  /// `(sid, symbol)` pairs of the issuers to load
+  pub symbols: Vec<(i64, String)>,
+}
+
+/// Per-symbol outcome of an [`InsiderTransactionsLoader`] run
+#[derive(Debug)]
+pub struct InsiderTransactionsResult {
+  pub sid: i64,
+  pub symbol: String,
+  /// Trades returned by the API
+  pub fetched: usize,
+  /// Trades on or after the latest stored date, sent to the database
+  pub candidates: usize,
+  /// Trades that were not already stored
+  pub inserted: usize,
+  /// Most recent trade date in the response
+  pub latest: Option<NaiveDate>,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct InsiderTransactionsLoaderOutput {
+  pub results: Vec<InsiderTransactionsResult>,
+  pub records_saved: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use av_models::fundamentals::InsiderTransaction as ApiTrade;
+
+  fn trade(date: &str, direction: &str, shares: &str) -> ApiTrade {
+    ApiTrade {
+      transaction_date: date.to_string(),
+      ticker: "IBM".to_string(),
+      executive: " SMITH, JANE ".to_string(),
+      executive_title: "Director".to_string(),
+      security_type: "Common Stock".to_string(),
+      acquisition_or_disposal: direction.to_string(),
+      shares: shares.to_string(),
+      share_price: "0.0".to_string(),
+    }
+  }
+
+  #[test]
+  fn test_to_records() {
+    let data = InsiderTransactions {
+      data: vec![
+        trade("2024-11-01", "a", "100.0"),
+        trade("2024-10-31", "D", "50.0"),
+        trade("2024-10-01", "A", "25.0"),
+        trade("2024-11-02", "X", "10.0"),
+        trade("2024-11-03", "A", "n/a"),
+      ],
+    };
+
+    let all = InsiderTransactionsLoader::to_records(1, "IBM", &data, None);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].acquisition_or_disposal, "A");
+    assert_eq!(all[0].executive, "SMITH, JANE");
+
+    let since = NaiveDate::from_ymd_opt(2024, 10, 31);
+    let recent = InsiderTransactionsLoader::to_records(1, "IBM", &data, since);
+    assert_eq!(recent.len(), 2);
+    assert!(recent.iter().all(|r| r.transaction_date >= since.unwrap()));
+  }
+
+  #[tokio::test]
+  async fn test_stops_on_daily_quota() {
+    use av_client::{AlphaVantageClient, FixtureTransport};
+    use av_core::{Config, FuncType};
+    use std::sync::Arc;
+
+    let quota = r#"{"Information": "Thank you for using Alpha Vantage! Our standard API rate limit is 25 requests per day."}"#;
+    let fixture =
+      Arc::new(FixtureTransport::new().with_response(FuncType::InsiderTransactions, quota));
+    let client = AlphaVantageClient::with_transport(
+      Config::default_with_key("test".to_string()),
+      fixture.clone(),
+    )
+    .unwrap();
+    let context = LoaderContext::new(Arc::new(client), crate::LoaderConfig::default());
+
+    let loader = InsiderTransactionsLoader::new(InsiderTransactionsConfig::default(), None);
+    let symbols = vec![(1, "IBM".to_string()), (2, "MSFT".to_string()), (3, "AAPL".to_string())];
+    let output = loader.load(&context, InsiderTransactionsLoaderInput { symbols }).await.unwrap();
+
+    assert_eq!(fixture.requests().len(), 1);
+    assert_eq!(output.errors.len(), 1);
+    assert!(output.errors[0].contains("quota"), "{:?}", output.errors);
+  }
+}
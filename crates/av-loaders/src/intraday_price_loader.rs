@@ -30,7 +30,9 @@
 //! Intraday price loader for TIME_SERIES_INTRADAY data using CSV format
 
 use crate::cache::{CacheConfigProvider, ttl};
-use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+use crate::{
+  ApiHalt, DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState,
+};
 use async_trait::async_trait;
 use av_database_postgres::repository::CacheRepository;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
@@ -519,6 +521,13 @@ impl IntradayPriceLoader {
       .await
       .map_err(|e| LoaderError::ApiError(format!("Failed to read response: {}", e)))?;
 
+    // Quota, premium and key notices come back as JSON instead of CSV
+    if let Some(e) = av_client::response_error(&csv_data) {
+      if !matches!(e, av_core::Error::Api(_)) {
+        return Err(e.into());
+      }
+    }
+
     // Check for API error messages
     if csv_data.contains("Error Message") || csv_data.contains("Invalid API call") {
       return Err(LoaderError::ApiError(format!("API error for {}: {}", symbol, csv_data)));
@@ -573,25 +582,30 @@ impl DataLoader for IntradayPriceLoader {
     let mut all_prices = Vec::new();
     let mut symbols_loaded = 0;
     let mut symbols_failed = 0;
-    let symbols_skipped = 0;
+    let mut symbols_skipped = 0;
     let mut failed_symbols = Vec::new();
+    let halt = ApiHalt::new();
 
     // Process symbols concurrently
     let symbols_owned: Vec<_> = input.symbols.into_iter().collect();
     let mut tasks = stream::iter(symbols_owned.into_iter())
       .map(|symbol_info| {
         let loader = self.clone();
-        let context = context;
         let symbol = symbol_info.symbol.clone();
         let sid = symbol_info.sid;
         let interval_str = interval.as_str().to_string();
         let month = input.month.clone();
         let progress = progress.clone();
+        let halt = halt.clone();
 
         async move {
+          if halt.is_halted() {
+            progress.inc(1);
+            return None;
+          }
           progress.set_message(format!("Loading {}", symbol));
 
-          match loader
+          let result = match loader
             .fetch_intraday_csv(context, &symbol, &interval_str, month.as_deref(), sid)
             .await
           {
@@ -602,10 +616,14 @@ impl DataLoader for IntradayPriceLoader {
             }
             Err(e) => {
               error!("Failed to fetch data for {}: {}", symbol, e);
+              if halt.check(&e) {
+                error!("Stopping early, remaining symbols will be skipped: {}", e);
+              }
               progress.inc(1);
               Err((symbol, e))
             }
-          }
+          };
+          Some(result)
         }
       })
       .buffer_unordered(self.config.max_concurrent);
@@ -613,12 +631,13 @@ impl DataLoader for IntradayPriceLoader {
     // Collect results
     while let Some(result) = tasks.next().await {
       match result {
-        Ok((symbol, prices, count)) => {
+        None => symbols_skipped += 1,
+        Some(Ok((symbol, prices, count))) => {
           info!("✅ Loaded {} price points for {}", count, symbol);
           all_prices.extend(prices);
           symbols_loaded += 1;
         }
-        Err((symbol, _e)) => {
+        Some(Err((symbol, _e))) => {
           failed_symbols.push(symbol);
           symbols_failed += 1;
         }
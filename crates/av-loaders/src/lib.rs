@@ -37,6 +37,9 @@
 //! - Intraday and daily price data
 //! - News articles with sentiment analysis
 //! - Market movers (top gainers/losers)
+//! - US economic indicators (GDP, CPI, treasury yields, ...)
+//! - Commodity prices (energy, metals, agriculture)
+//! - Historical option chains with Greeks and implied volatility
 //! - Crypto markets and social data
 //!
 //! The loaders fetch data from various APIs (AlphaVantage, CoinGecko, etc.)
@@ -45,12 +48,19 @@
 
 pub mod batch_processor;
 pub mod cache;
+pub mod commodity_price_loader;
+pub mod corporate_actions_loader;
 pub mod crypto;
 pub mod csv_processor;
+pub mod earnings_transcript_loader;
+pub mod economic_indicator_loader;
 pub mod error;
+pub mod etf_profile_loader;
+pub mod insider_transactions_loader;
 pub mod intraday_price_loader;
 pub mod loader;
 pub mod news_loader;
+pub mod options_loader;
 pub mod overview_loader;
 pub mod process_tracker;
 pub mod security_loader;
@@ -72,15 +82,52 @@ pub use summary_price_loader::{
   SummaryPriceLoaderOutput,
 };
 
+pub use commodity_price_loader::{
+  CommodityPriceConfig, CommodityPriceLoader, CommodityPriceLoaderInput,
+  CommodityPriceLoaderOutput, CommoditySeriesResult,
+};
+
+pub use corporate_actions_loader::{
+  CorporateActionSymbol, CorporateActionsConfig, CorporateActionsLoader,
+  CorporateActionsLoaderInput, CorporateActionsLoaderOutput, CorporateActionsResult,
+};
+
+pub use earnings_transcript_loader::{
+  EarningsTranscriptConfig, EarningsTranscriptLoader, EarningsTranscriptLoaderInput,
+  EarningsTranscriptLoaderOutput, TranscriptResult,
+};
+
+pub use economic_indicator_loader::{
+  ECONOMIC_FUNCTIONS, EconomicIndicatorConfig, EconomicIndicatorLoader,
+  EconomicIndicatorLoaderInput, EconomicIndicatorLoaderOutput, EconomicSeriesResult,
+  EconomicSeriesSpec,
+};
+
+pub use etf_profile_loader::{
+  EtfProfileConfig, EtfProfileLoader, EtfProfileLoaderInput, EtfProfileLoaderOutput,
+  EtfProfileResult,
+};
+
+pub use insider_transactions_loader::{
+  InsiderTransactionsConfig, InsiderTransactionsLoader, InsiderTransactionsLoaderInput,
+  InsiderTransactionsLoaderOutput, InsiderTransactionsResult,
+};
+
+pub use options_loader::{
+  OptionSnapshotResult, OptionsConfig, OptionsLoader, OptionsLoaderInput, OptionsLoaderOutput,
+};
+
 pub use top_movers_loader::{
   TopMoversConfig, TopMoversLoader, TopMoversLoaderInput, TopMoversLoaderOutput,
 };
 
 // Re-export commonly used types
 pub use batch_processor::{BatchConfig, BatchProcessor};
-pub use cache::{CacheConfig, CacheConfigProvider, CacheHelper, CacheResult};
+pub use cache::{
+  CacheConfig, CacheConfigProvider, CacheHelper, CacheResult, RepositoryResponseCache,
+};
 pub use error::{LoaderError, LoaderResult};
-pub use loader::{DataLoader, LoaderConfig, LoaderContext};
+pub use loader::{ApiHalt, DataLoader, LoaderConfig, LoaderContext};
 pub use process_tracker::{ProcessState, ProcessTracker};
 
 // Re-export loaders with their data types
@@ -124,6 +171,7 @@ pub use crypto::{
 
 pub mod prelude {
   pub use crate::{
+    ApiHalt,
     BatchConfig,
     BatchProcessor,
     // Cache types
@@ -131,6 +179,10 @@ pub mod prelude {
     CacheConfigProvider,
     CacheHelper,
     CacheResult,
+    CommodityPriceConfig,
+    CommodityPriceLoader,
+    CorporateActionsConfig,
+    CorporateActionsLoader,
     // Include crypto types in prelude
     CryptoDataSource,
     CryptoIntradayConfig,
@@ -142,6 +194,15 @@ pub mod prelude {
     CryptoMetadataLoader,
     CryptoSymbolLoader,
     DataLoader,
+    EarningsTranscriptConfig,
+    EarningsTranscriptLoader,
+    EconomicIndicatorConfig,
+    EconomicIndicatorLoader,
+    EconomicSeriesSpec,
+    EtfProfileConfig,
+    EtfProfileLoader,
+    InsiderTransactionsConfig,
+    InsiderTransactionsLoader,
     IntradayInterval,
     IntradayPriceConfig,
     IntradayPriceLoader,
@@ -151,6 +212,8 @@ pub mod prelude {
     LoaderResult,
     NewsLoader,
     NewsLoaderConfig,
+    OptionsConfig,
+    OptionsLoader,
     ProcessState,
     ProcessTracker,
     SummaryPriceConfig,
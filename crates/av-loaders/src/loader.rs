@@ -29,11 +29,11 @@
 
 //! Base traits and types for data loaders
 
-use crate::{LoaderResult, ProcessTracker};
+use crate::{LoaderError, LoaderResult, ProcessTracker};
 use async_trait::async_trait;
 use av_client::AlphaVantageClient;
 use av_database_postgres::repository::{CacheRepository, NewsRepository};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Configuration for data loaders
 #[derive(Debug, Clone)]
@@ -103,6 +103,44 @@ impl LoaderContext {
   }
 }
 
+/// Stop signal shared by the requests of one loader run.
+///
+/// The first terminal error ([`LoaderError::is_terminal`] or
+/// [`av_core::Error::is_terminal`], e.g. an exhausted daily quota) trips it;
+/// requests that have not started yet check [`is_halted`](Self::is_halted)
+/// and skip their symbol instead of failing against the API one by one.
+/// Clones share the same signal.
+#[derive(Debug, Clone, Default)]
+pub struct ApiHalt(Arc<OnceLock<String>>);
+
+impl ApiHalt {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Trip the signal if `err` is terminal. Returns whether the run should stop.
+  pub fn check(&self, err: &LoaderError) -> bool {
+    if err.is_terminal() {
+      self.trip(err);
+    }
+    err.is_terminal()
+  }
+
+  /// Trip the signal unconditionally; only the first reason is kept.
+  pub fn trip(&self, reason: impl std::fmt::Display) {
+    let _ = self.0.set(reason.to_string());
+  }
+
+  pub fn is_halted(&self) -> bool {
+    self.0.get().is_some()
+  }
+
+  /// Why the signal was tripped
+  pub fn reason(&self) -> Option<&str> {
+    self.0.get().map(String::as_str)
+  }
+}
+
 /// Base trait for all data loaders
 #[async_trait]
 pub trait DataLoader: Send + Sync {
@@ -173,6 +211,19 @@ mod tests {
     assert!(debug_str.contains("max_concurrent_requests"));
   }
 
+  #[test]
+  fn test_api_halt() {
+    let halt = ApiHalt::new();
+    let shared = halt.clone();
+
+    assert!(!halt.check(&LoaderError::ApiError("unknown symbol".to_string())));
+    assert!(!shared.is_halted());
+
+    assert!(halt.check(&LoaderError::QuotaExhausted("25 requests per day".to_string())));
+    assert!(shared.is_halted());
+    assert!(shared.reason().unwrap().contains("25 requests per day"));
+  }
+
   #[test]
   fn test_loader_context_new() {
     let av_config = av_core::Config::default_with_key("test_key".to_string());
@@ -470,7 +470,12 @@ impl DataLoader for NewsLoader {
         } else {
           info!("  Found {} cached articles for {}", cached_news.feed.len(), symbol_info.symbol);
           let batch_data = self
-            .convert_news_to_data(&cached_news, &[symbol_info.clone()], &symbol_to_sid, news_repo)
+            .convert_news_to_data(
+              &cached_news,
+              std::slice::from_ref(symbol_info),
+              &symbol_to_sid,
+              news_repo,
+            )
             .await;
           output.articles_processed += cached_news.feed.len();
           output.loaded_count += batch_data.len();
@@ -511,7 +516,12 @@ impl DataLoader for NewsLoader {
 
               // Convert to internal format - pass as single-element slice
               let batch_data = self
-                .convert_news_to_data(&news, &[symbol_info.clone()], &symbol_to_sid, news_repo)
+                .convert_news_to_data(
+                  &news,
+                  std::slice::from_ref(symbol_info),
+                  &symbol_to_sid,
+                  news_repo,
+                )
                 .await;
 
               output.articles_processed += news.feed.len();
@@ -519,6 +529,12 @@ impl DataLoader for NewsLoader {
               all_news_data.extend(batch_data);
             }
           }
+          Err(e) if e.is_terminal() => {
+            let remaining = total_symbols - idx - 1;
+            error!("Stopping early, skipping {} remaining symbols: {}", remaining, e);
+            output.errors.push(format!("{}: {}", symbol_info.symbol, e));
+            break;
+          }
           Err(e) => {
             // Log the error but continue processing
             let error_msg = e.to_string();
@@ -0,0 +1,455 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Option chain loader for HISTORICAL_OPTIONS snapshots.
+//!
+//! Walks a date range one weekday at a time for each underlying, fetching
+//! the end-of-day chain and upserting it into `optionprices`. Dates already
+//! stored for an underlying are skipped unless `skip_existing` is off, so an
+//! interrupted backfill can simply be re-run. Weekdays that return an empty
+//! chain (exchange holidays) are counted but not treated as errors.
+
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate, Weekday};
+use tracing::{debug, error, info, warn};
+
+use av_core::types::market::OccSymbol;
+use av_database_postgres::{
+  establish_connection,
+  models::options::{NewOptionPriceOwned, OptionPrice},
+  repository::CacheRepositoryExt,
+};
+use av_models::options::{OptionChain, OptionContract};
+
+use crate::cache::{CacheConfigProvider, keys, ttl};
+use crate::{DataLoader, LoaderContext, LoaderError, LoaderResult, process_tracker::ProcessState};
+
+const API_SOURCE: &str = "alphavantage";
+
+pub struct OptionsLoader {
+  config: OptionsConfig,
+  database_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionsConfig {
+  /// Skip snapshot dates already stored for an underlying
+  pub skip_existing: bool,
+  /// Enable caching of API responses
+  pub enable_cache: bool,
+  /// Cache TTL in hours
+  pub cache_ttl_hours: i64,
+  /// Force refresh (bypass cache)
+  pub force_refresh: bool,
+}
+
+impl Default for OptionsConfig {
+  fn default() -> Self {
+    Self {
+      skip_existing: true,
+      enable_cache: true,
+      cache_ttl_hours: ttl::OPTIONS,
+      force_refresh: false,
+    }
+  }
+}
+
+impl CacheConfigProvider for OptionsConfig {
+  fn cache_enabled(&self) -> bool {
+    self.enable_cache
+  }
+
+  fn cache_ttl_hours(&self) -> i64 {
+    self.cache_ttl_hours
+  }
+
+  fn force_refresh(&self) -> bool {
+    self.force_refresh
+  }
+}
+
+impl OptionsLoader {
+  pub fn new(config: OptionsConfig, database_url: Option<String>) -> Self {
+    Self { config, database_url }
+  }
+
+  /// Weekdays between `start` and `end` inclusive. Exchange holidays are
+  /// not known here; the API returns an empty chain for them.
+  pub fn weekdays(start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    start
+      .iter_days()
+      .take_while(|date| *date <= end)
+      .filter(|date| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+      .collect()
+  }
+
+  fn cache_key(symbol: &str, date: Option<NaiveDate>) -> String {
+    format!(
+      "{}:{}:{}",
+      keys::OPTIONS,
+      symbol.to_uppercase(),
+      date.map(|d| d.to_string()).unwrap_or_else(|| "latest".to_string())
+    )
+  }
+
+  /// Try to get cached response
+  async fn get_cached_response(
+    &self,
+    context: &LoaderContext,
+    cache_key: &str,
+  ) -> Option<OptionChain> {
+    if !self.config.enable_cache || self.config.force_refresh {
+      return None;
+    }
+
+    let cache_repo = context.cache_repository.as_ref()?;
+    match cache_repo.get::<OptionChain>(cache_key, API_SOURCE).await {
+      Ok(Some(data)) => {
+        debug!("Cache hit for key: {}", cache_key);
+        Some(data)
+      }
+      Ok(None) => {
+        debug!("Cache miss for key: {}", cache_key);
+        None
+      }
+      Err(e) => {
+        warn!("Cache retrieval error: {}", e);
+        None
+      }
+    }
+  }
+
+  /// Cache the response
+  async fn cache_response(&self, context: &LoaderContext, cache_key: &str, data: &OptionChain) {
+    if !self.config.enable_cache {
+      return;
+    }
+
+    let Some(cache_repo) = &context.cache_repository else {
+      debug!("Cache repository not available");
+      return;
+    };
+
+    if let Err(e) = cache_repo
+      .set(cache_key, API_SOURCE, "HISTORICAL_OPTIONS", data, self.config.cache_ttl_hours)
+      .await
+    {
+      warn!("Failed to cache response: {}", e);
+    }
+  }
+
+  async fn fetch_chain(
+    &self,
+    context: &LoaderContext,
+    symbol: &str,
+    date: Option<NaiveDate>,
+  ) -> LoaderResult<(OptionChain, bool)> {
+    let cache_key = Self::cache_key(symbol, date);
+    if let Some(cached) = self.get_cached_response(context, &cache_key).await {
+      return Ok((cached, true));
+    }
+
+    let chain = context.client.options().historical(symbol, date).await?;
+    // Holidays come back empty; don't pin that in the cache.
+    if !chain.is_empty() {
+      self.cache_response(context, &cache_key, &chain).await;
+    }
+    Ok((chain, false))
+  }
+
+  /// Convert one contract to a database row.
+  ///
+  /// Expiration, strike, and side are taken from the OCC symbol rather than
+  /// the redundant string fields. Returns `None` for contracts whose symbol
+  /// does not parse or that carry no quote date when `fallback_date` is
+  /// `None`.
+  pub fn to_record(
+    underlying_sid: i64,
+    contract: &OptionContract,
+    fallback_date: Option<NaiveDate>,
+  ) -> Option<NewOptionPriceOwned> {
+    let occ: OccSymbol = match contract.contract_id.parse() {
+      Ok(occ) => occ,
+      Err(e) => {
+        warn!("Skipping contract: {}", e);
+        return None;
+      }
+    };
+    let snapshot_date = contract.quote_date().or(fallback_date)?;
+    let greeks = contract.greeks();
+
+    Some(NewOptionPriceOwned {
+      snapshot_date,
+      contract_id: occ.to_string(),
+      underlying_sid,
+      underlying: contract.symbol.to_uppercase(),
+      occ_root: occ.root.clone(),
+      expiration: occ.expiration,
+      strike: occ.strike(),
+      option_type: occ.option_type.to_string(),
+      last: contract.last_f64(),
+      mark: contract.mark_f64(),
+      bid: contract.bid_f64(),
+      bid_size: contract.bid_size_i64().and_then(|v| i32::try_from(v).ok()),
+      ask: contract.ask_f64(),
+      ask_size: contract.ask_size_i64().and_then(|v| i32::try_from(v).ok()),
+      volume: contract.volume_i64(),
+      open_interest: contract.open_interest_i64(),
+      implied_volatility: contract.implied_volatility_f64(),
+      delta: greeks.delta,
+      gamma: greeks.gamma,
+      theta: greeks.theta,
+      vega: greeks.vega,
+      rho: greeks.rho,
+    })
+  }
+
+  fn existing_dates(
+    db_url: &str,
+    sid: i64,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> LoaderResult<Vec<NaiveDate>> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+    OptionPrice::snapshot_dates(&mut conn, sid, start, end)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to query snapshot dates: {}", e)))
+  }
+
+  fn save_records(db_url: &str, records: &[NewOptionPriceOwned]) -> LoaderResult<usize> {
+    let mut conn = establish_connection(db_url)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
+    NewOptionPriceOwned::upsert_batch(&mut conn, records)
+      .map_err(|e| LoaderError::DatabaseError(format!("Failed to upsert option prices: {}", e)))
+  }
+
+  /// Dates to request for one underlying: `[None]` (latest session) when no
+  /// range was given, otherwise the weekdays in range minus stored dates.
+  fn dates_to_load(
+    &self,
+    input: &OptionsLoaderInput,
+    sid: i64,
+    output: &mut OptionsLoaderOutput,
+  ) -> LoaderResult<Vec<Option<NaiveDate>>> {
+    let Some(start) = input.start_date else {
+      return Ok(vec![None]);
+    };
+    let end = input.end_date.unwrap_or(start);
+    let mut dates = Self::weekdays(start, end);
+
+    if let (true, Some(db_url)) = (self.config.skip_existing, &self.database_url) {
+      let existing = Self::existing_dates(db_url, sid, start, end)?;
+      let before = dates.len();
+      dates.retain(|date| existing.binary_search(date).is_err());
+      output.dates_skipped += before - dates.len();
+    }
+
+    Ok(dates.into_iter().map(Some).collect())
+  }
+}
+
+#[async_trait]
+impl DataLoader for OptionsLoader {
+  type Input = OptionsLoaderInput;
+  type Output = OptionsLoaderOutput;
+
+  async fn load(&self, context: &LoaderContext, input: Self::Input) -> LoaderResult<Self::Output> {
+    info!("Loading option chains for {} underlyings", input.symbols.len());
+
+    if let Some(tracker) = &context.process_tracker {
+      tracker.start("options_loader").await?;
+    }
+
+    let mut output = OptionsLoaderOutput::default();
+
+    'symbols: for (sid, symbol) in &input.symbols {
+      let dates = match self.dates_to_load(&input, *sid, &mut output) {
+        Ok(dates) => dates,
+        Err(e) => {
+          output.errors.push(format!("{}: {}", symbol, e));
+          continue;
+        }
+      };
+
+      for date in dates {
+        let label = date.map(|d| d.to_string()).unwrap_or_else(|| "latest".to_string());
+
+        let (chain, from_cache) = match self.fetch_chain(context, symbol, date).await {
+          Ok(result) => result,
+          Err(e) => {
+            warn!("Failed to fetch {} options for {}: {}", symbol, label, e);
+            output.errors.push(format!("{} {}: {}", symbol, label, e));
+            if e.is_terminal() {
+              error!("Stopping early: {}", e);
+              break 'symbols;
+            }
+            continue;
+          }
+        };
+
+        if chain.is_empty() {
+          debug!("No {} contracts for {} (non-trading day?)", symbol, label);
+          output.empty_dates += 1;
+          continue;
+        }
+
+        let records: Vec<NewOptionPriceOwned> =
+          chain.data.iter().filter_map(|c| Self::to_record(*sid, c, date)).collect();
+        let snapshot_date = records.first().map(|r| r.snapshot_date).or(date);
+
+        let records_saved = match &self.database_url {
+          Some(db_url) => match Self::save_records(db_url, &records) {
+            Ok(saved) => saved,
+            Err(e) => {
+              warn!("Failed to save {} options for {}: {}", symbol, label, e);
+              output.errors.push(format!("{} {}: {}", symbol, label, e));
+              0
+            }
+          },
+          None => 0,
+        };
+
+        info!("{} {}: {} contracts, {} rows saved", symbol, label, chain.len(), records_saved);
+
+        output.records_saved += records_saved;
+        output.snapshots.push(OptionSnapshotResult {
+          symbol: symbol.clone(),
+          snapshot_date,
+          contracts: chain.len(),
+          expirations: chain.expirations().len(),
+          records_saved,
+          from_cache,
+        });
+      }
+    }
+
+    if let Some(tracker) = &context.process_tracker {
+      let state = if output.errors.is_empty() {
+        ProcessState::Success
+      } else if !output.snapshots.is_empty() {
+        ProcessState::CompletedWithErrors
+      } else {
+        ProcessState::Failed
+      };
+      tracker.complete(state).await?;
+    }
+
+    Ok(output)
+  }
+
+  fn name(&self) -> &'static str {
+    "OptionsLoader"
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct OptionsLoaderInput {
+  /// Underlyings to load as `(sid, symbol)` pairs
+  pub symbols: Vec<(i64, String)>,
+  /// First snapshot date; `None` loads only the latest session
+  pub start_date: Option<NaiveDate>,
+  /// Last snapshot date (inclusive); defaults to `start_date`
+  pub end_date: Option<NaiveDate>,
+}
+
+/// Outcome of one chain fetched by an [`OptionsLoader`] run
+#[derive(Debug)]
+pub struct OptionSnapshotResult {
+  pub symbol: String,
+  pub snapshot_date: Option<NaiveDate>,
+  pub contracts: usize,
+  pub expirations: usize,
+  pub records_saved: usize,
+  pub from_cache: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct OptionsLoaderOutput {
+  pub snapshots: Vec<OptionSnapshotResult>,
+  pub records_saved: usize,
+  /// Dates skipped because they were already stored
+  pub dates_skipped: usize,
+  /// Weekdays for which the API returned no contracts
+  pub empty_dates: usize,
+  pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_weekdays() {
+    // Fri 2024-01-12 through Tue 2024-01-16
+    let start = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+    let days = OptionsLoader::weekdays(start, end);
+
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[1], NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+    assert!(OptionsLoader::weekdays(end, start).is_empty());
+  }
+
+  #[test]
+  fn test_to_record() {
+    let contract: OptionContract = serde_json::from_str(
+      r#"{
+        "contractID": "IBM240119P00100000",
+        "symbol": "IBM",
+        "expiration": "2024-01-19",
+        "strike": "100.00",
+        "type": "put",
+        "last": "0.01",
+        "mark": "0.02",
+        "bid": "0.00",
+        "bid_size": "0",
+        "ask": "0.04",
+        "ask_size": "40",
+        "volume": "0",
+        "open_interest": "2136",
+        "implied_volatility": "0.68432",
+        "delta": "-0.00128"
+      }"#,
+    )
+    .unwrap();
+
+    let fallback = NaiveDate::from_ymd_opt(2023, 11, 17);
+    let record = OptionsLoader::to_record(42, &contract, fallback).unwrap();
+
+    assert_eq!(record.snapshot_date, fallback.unwrap());
+    assert_eq!(record.occ_root, "IBM");
+    assert_eq!(record.option_type, "put");
+    assert_eq!(record.strike, 100.0);
+    assert_eq!(record.ask_size, Some(40));
+    assert_eq!(record.delta, Some(-0.00128));
+    assert_eq!(record.gamma, None);
+
+    assert!(OptionsLoader::to_record(42, &contract, None).is_none());
+  }
+}
@@ -36,7 +36,7 @@ use tokio::sync::Semaphore;
 use tracing::{debug, error, info, warn};
 
 use crate::cache::{CacheConfigProvider, ttl};
-use crate::{DataLoader, LoaderContext, LoaderResult, process_tracker::ProcessState};
+use crate::{ApiHalt, DataLoader, LoaderContext, LoaderResult, process_tracker::ProcessState};
 use av_database_postgres::repository::CacheRepositoryExt;
 use av_models::fundamentals::CompanyOverview;
 
@@ -188,6 +188,8 @@ impl OverviewLoader {
           Ok(Some(overview))
         }
       }
+      // The daily-quota message also mentions the rate limit; waiting won't help
+      Err(e) if e.is_terminal() => Err(e),
       Err(e) => {
         if e.to_string().contains("rate limit") {
           warn!("Rate limit hit for {}, waiting before retry...", symbol);
@@ -256,6 +258,8 @@ impl DataLoader for OverviewLoader {
 
     // Get cache repository if available
     let cache_repo_opt = context.cache_repository.clone();
+    let halt = ApiHalt::new();
+    let halt_for_tasks = halt.clone();
 
     // Process symbols concurrently
     let results = stream::iter(input.symbols.into_iter())
@@ -265,78 +269,100 @@ impl DataLoader for OverviewLoader {
         let progress = progress.clone();
         let cache_repo_opt = cache_repo_opt.clone();
         let loader = self.clone();
+        let halt = halt_for_tasks.clone();
 
         async move {
           let _permit =
             semaphore.acquire().await.expect("Semaphore should not be closed during operation");
 
-          if let Some(pb) = &progress {
-            pb.set_message(format!("Processing {}", symbol_info.symbol));
+          if halt.is_halted() {
+            if let Some(pb) = &progress {
+              pb.inc(1);
+            }
+            return None;
           }
 
-          // Generate cache key
-          let cache_key = loader.generate_cache_key(&symbol_info.symbol);
+          let result: Result<(Option<OverviewData>, bool), (av_client::Error, bool)> = async {
+            if let Some(pb) = &progress {
+              pb.set_message(format!("Processing {}", symbol_info.symbol));
+            }
 
-          if let Some(pb) = &progress {
-            pb.inc(1);
-          }
+            // Generate cache key
+            let cache_key = loader.generate_cache_key(&symbol_info.symbol);
 
-          // Check cache first (if cache repository is available)
-          let (overview_result, from_cache) = if let Some(cache_repo) = &cache_repo_opt {
-            if let Some(cached_overview) = loader.get_cached_response(&cache_key, cache_repo).await
-            {
-              info!("📦 Using cached overview for {} (no API call needed)", symbol_info.symbol);
-              (Ok(cached_overview), true)
+            if let Some(pb) = &progress {
+              pb.inc(1);
+            }
+
+            // Check cache first (if cache repository is available)
+            let (overview_result, from_cache) = if let Some(cache_repo) = &cache_repo_opt {
+              if let Some(cached_overview) =
+                loader.get_cached_response(&cache_key, cache_repo).await
+              {
+                info!("📦 Using cached overview for {} (no API call needed)", symbol_info.symbol);
+                (Ok(cached_overview), true)
+              } else {
+                // Cache miss - call API
+                info!("🌐 Cache miss - calling API for overview {}", symbol_info.symbol);
+                match loader
+                  .fetch_overview_from_api(&client, &symbol_info.symbol, retry_delay)
+                  .await
+                {
+                  Ok(Some(overview)) => {
+                    // Cache successful response
+                    loader
+                      .cache_response(&cache_key, &overview, &symbol_info.symbol, cache_repo)
+                      .await;
+                    (Ok(overview), false)
+                  }
+                  Ok(None) => return Ok((None, false)),
+                  Err(e) => return Err((e, false)),
+                }
+              }
             } else {
-              // Cache miss - call API
-              info!("🌐 Cache miss - calling API for overview {}", symbol_info.symbol);
+              // No cache - directly call API
+              debug!("No cache repository available - calling API directly");
               match loader.fetch_overview_from_api(&client, &symbol_info.symbol, retry_delay).await
               {
-                Ok(Some(overview)) => {
-                  // Cache successful response
-                  loader
-                    .cache_response(&cache_key, &overview, &symbol_info.symbol, cache_repo)
-                    .await;
-                  (Ok(overview), false)
-                }
+                Ok(Some(overview)) => (Ok(overview), false),
                 Ok(None) => return Ok((None, false)),
                 Err(e) => return Err((e, false)),
               }
+            };
+
+            // Add delay to respect rate limits (only if not from cache)
+            if !from_cache {
+              tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
             }
-          } else {
-            // No cache - directly call API
-            debug!("No cache repository available - calling API directly");
-            match loader.fetch_overview_from_api(&client, &symbol_info.symbol, retry_delay).await {
-              Ok(Some(overview)) => (Ok(overview), false),
-              Ok(None) => return Ok((None, false)),
-              Err(e) => return Err((e, false)),
-            }
-          };
 
-          // Add delay to respect rate limits (only if not from cache)
-          if !from_cache {
-            tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
+            // Process result
+            match overview_result {
+              Ok(overview) => {
+                if overview.symbol.is_empty() || overview.symbol == "None" {
+                  info!("No overview data available for {}", symbol_info.symbol);
+                  Ok((None, from_cache))
+                } else {
+                  Ok((
+                    Some(OverviewData {
+                      sid: symbol_info.sid,
+                      symbol: symbol_info.symbol.clone(),
+                      overview,
+                    }),
+                    from_cache,
+                  ))
+                }
+              }
+              Err(e) => Err((e, from_cache)),
+            }
           }
+          .await;
 
-          // Process result
-          match overview_result {
-            Ok(overview) => {
-              if overview.symbol.is_empty() || overview.symbol == "None" {
-                info!("No overview data available for {}", symbol_info.symbol);
-                Ok((None, from_cache))
-              } else {
-                Ok((
-                  Some(OverviewData {
-                    sid: symbol_info.sid,
-                    symbol: symbol_info.symbol.clone(),
-                    overview,
-                  }),
-                  from_cache,
-                ))
-              }
+          if let Err((e, _)) = &result {
+            if e.is_terminal() {
+              halt.trip(e);
             }
-            Err(e) => Err((e, from_cache)),
           }
+          Some(result)
         }
       })
       .buffer_unordered(max_concurrent)
@@ -353,8 +379,13 @@ impl DataLoader for OverviewLoader {
     let mut no_data = 0;
     let mut cache_hits = 0usize;
     let mut api_calls = 0usize;
+    let mut skipped = 0usize;
 
     for result in results {
+      let Some(result) = result else {
+        skipped += 1;
+        continue;
+      };
       match result {
         Ok((Some(data), from_cache)) => {
           if from_cache {
@@ -393,6 +424,10 @@ impl DataLoader for OverviewLoader {
         .await?;
     }
 
+    if let Some(reason) = halt.reason() {
+      error!("Stopped early, {} symbols skipped: {}", skipped, reason);
+    }
+
     info!(
       "Overview loading complete: {} loaded, {} no data, {} errors, {} cache hits, {} API calls",
       loaded.len(),
@@ -403,10 +438,11 @@ impl DataLoader for OverviewLoader {
     );
 
     Ok(OverviewLoaderOutput {
-      total_symbols: loaded.len() + no_data + errors,
+      total_symbols: loaded.len() + no_data + errors + skipped,
       loaded_count: loaded.len(),
       no_data_count: no_data,
       errors,
+      skipped_count: skipped,
       cache_hits,
       api_calls,
       data: loaded,
@@ -442,6 +478,8 @@ pub struct OverviewLoaderOutput {
   pub loaded_count: usize,
   pub no_data_count: usize,
   pub errors: usize,
+  /// Symbols not requested because the run stopped early (see [`ApiHalt`])
+  pub skipped_count: usize,
   pub cache_hits: usize,
   pub api_calls: usize,
   pub data: Vec<OverviewData>,
@@ -146,6 +146,7 @@ mod tests {
   }
 
   #[test]
+  #[allow(clippy::assertions_on_constants, clippy::drop_non_drop)]
   fn test_process_tracker_new() {
     let tracker = ProcessTracker::new();
     // Just verify it can be created
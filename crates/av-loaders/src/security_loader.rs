@@ -44,7 +44,7 @@ use tracing::{debug, error, info, warn};
 use crate::cache::{CacheConfigProvider, ttl};
 use crate::error::LoaderError;
 use crate::{
-  DataLoader, LoaderContext, LoaderResult, csv_processor::CsvProcessor,
+  ApiHalt, DataLoader, LoaderContext, LoaderResult, csv_processor::CsvProcessor,
   process_tracker::ProcessState,
 };
 use av_database_postgres::repository::CacheRepositoryExt;
@@ -277,6 +277,9 @@ impl DataLoader for SecurityLoader {
     let mut cache_hits = 0usize;
     let mut api_calls = 0usize;
 
+    let halt = ApiHalt::new();
+    let halt_for_tasks = halt.clone();
+
     // Query AlphaVantage API for each symbol
     let results = stream::iter(symbols.into_iter())
       .map(move |symbol| {
@@ -287,24 +290,54 @@ impl DataLoader for SecurityLoader {
         let original_symbol = symbol.clone();
         let loader = self.clone();
         let cache_repo_opt = cache_repo_opt.clone();
+        let halt = halt_for_tasks.clone();
 
         async move {
           let _permit =
             semaphore.acquire().await.expect("Semaphore should not be closed during operation");
 
-          // Generate cache key
-          let cache_key = loader.generate_cache_key(&symbol);
+          if halt.is_halted() {
+            if let Some(pb) = &progress {
+              pb.inc(1);
+            }
+            return None;
+          }
 
-          // Check cache first (if cache repository is available)
-          let (search_results, from_cache) = if let Some(cache_repo) = &cache_repo_opt {
-            if let Some(cached_search) = loader.get_cached_response(&cache_key, cache_repo).await {
-              info!("📦 Using cached data for {} (no API call needed)", symbol);
-              (cached_search, true)
-            } else {
-              // Cache miss - need to call API
-              info!("🌐 Cache miss - calling API for {}", symbol);
+          let result: Result<(Vec<SecurityData>, bool), (av_client::Error, bool)> = async {
+            // Generate cache key
+            let cache_key = loader.generate_cache_key(&symbol);
+
+            // Check cache first (if cache repository is available)
+            let (search_results, from_cache) = if let Some(cache_repo) = &cache_repo_opt {
+              if let Some(cached_search) = loader.get_cached_response(&cache_key, cache_repo).await
+              {
+                info!("📦 Using cached data for {} (no API call needed)", symbol);
+                (cached_search, true)
+              } else {
+                // Cache miss - need to call API
+                info!("🌐 Cache miss - calling API for {}", symbol);
+
+                // Search for the symbol
+                let search_res = match client.time_series().symbol_search(&symbol).await {
+                  Ok(results) => results,
+                  Err(e) => {
+                    warn!("Symbol search failed for {}: {}", symbol, e);
+                    if let Some(pb) = &progress {
+                      pb.inc(1);
+                    }
+                    tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
+                    return Err((e, false));
+                  }
+                };
 
-              // Search for the symbol
+                // Cache the successful response
+                loader.cache_response(&cache_key, &search_res, &symbol, cache_repo).await;
+
+                (search_res, false)
+              }
+            } else {
+              // No cache repository - just call API
+              debug!("No cache repository available - calling API directly");
               let search_res = match client.time_series().symbol_search(&symbol).await {
                 Ok(results) => results,
                 Err(e) => {
@@ -316,96 +349,85 @@ impl DataLoader for SecurityLoader {
                   return Err((e, false));
                 }
               };
+              (search_res, false)
+            };
 
-              // Cache the successful response
-              loader.cache_response(&cache_key, &search_res, &symbol, cache_repo).await;
+            // Get matching symbols based on mode
+            let matches = loader.get_matching_symbols(&symbol, search_results);
 
-              (search_res, false)
+            if matches.is_empty() {
+              warn!("No matches found for symbol {}", symbol);
+              if let Some(pb) = &progress {
+                pb.inc(1);
+              }
+              tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
+              return Ok((vec![], from_cache));
             }
-          } else {
-            // No cache repository - just call API
-            debug!("No cache repository available - calling API directly");
-            let search_res = match client.time_series().symbol_search(&symbol).await {
-              Ok(results) => results,
-              Err(e) => {
-                warn!("Symbol search failed for {}: {}", symbol, e);
-                if let Some(pb) = &progress {
-                  pb.inc(1);
-                }
-                tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
-                return Err((e, false));
+
+            // Convert matches to SecurityData
+            let mut security_data = Vec::new();
+
+            for symbol_match in matches {
+              // Validate the symbol match data from API
+              if symbol_match.symbol.len() > 20 {
+                error!(
+                  "API PARSING ERROR: Received symbol '{}' with length {} from symbol search",
+                  symbol_match.symbol,
+                  symbol_match.symbol.len()
+                );
+                error!("  API Response: {:?}", symbol_match);
+                error!("  Original query was: '{}'", original_symbol);
+                continue; // Skip this malformed result
               }
-            };
-            (search_res, false)
-          };
+              debug!(
+                "Found match for {}: {} (score: {}, type: {}, region: {})",
+                original_symbol,
+                symbol_match.symbol,
+                symbol_match.match_score,
+                symbol_match.stock_type,
+                symbol_match.region
+              );
 
-          // Get matching symbols based on mode
-          let matches = loader.get_matching_symbols(&symbol, search_results);
+              // Additional validation
+              if symbol_match.symbol.is_empty() {
+                warn!("Received empty symbol from API for query '{}'", original_symbol);
+                continue;
+              }
+              security_data.push(SecurityData {
+                symbol: symbol_match.symbol,
+                name: symbol_match.name,
+                stock_type: symbol_match.stock_type,
+                region: symbol_match.region,
+                market_open: symbol_match.market_open,
+                market_close: symbol_match.market_close,
+                timezone: symbol_match.timezone,
+                currency: symbol_match.currency,
+                exchange: exchange.clone(),
+                match_score: symbol_match.match_score.parse::<f64>().ok(),
+                original_query: Some(original_symbol.clone()),
+                from_cache, // Pass through whether this came from cache
+              });
+            }
 
-          if matches.is_empty() {
-            warn!("No matches found for symbol {}", symbol);
             if let Some(pb) = &progress {
               pb.inc(1);
             }
-            tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
-            return Ok((vec![], from_cache));
-          }
 
-          // Convert matches to SecurityData
-          let mut security_data = Vec::new();
-
-          for symbol_match in matches {
-            // Validate the symbol match data from API
-            if symbol_match.symbol.len() > 20 {
-              error!(
-                "API PARSING ERROR: Received symbol '{}' with length {} from symbol search",
-                symbol_match.symbol,
-                symbol_match.symbol.len()
-              );
-              error!("  API Response: {:?}", symbol_match);
-              error!("  Original query was: '{}'", original_symbol);
-              continue; // Skip this malformed result
-            }
-            debug!(
-              "Found match for {}: {} (score: {}, type: {}, region: {})",
-              original_symbol,
-              symbol_match.symbol,
-              symbol_match.match_score,
-              symbol_match.stock_type,
-              symbol_match.region
-            );
-
-            // Additional validation
-            if symbol_match.symbol.is_empty() {
-              warn!("Received empty symbol from API for query '{}'", original_symbol);
-              continue;
+            // Add delay to respect rate limits (only if not from cache)
+            if !from_cache {
+              tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
             }
-            security_data.push(SecurityData {
-              symbol: symbol_match.symbol,
-              name: symbol_match.name,
-              stock_type: symbol_match.stock_type,
-              region: symbol_match.region,
-              market_open: symbol_match.market_open,
-              market_close: symbol_match.market_close,
-              timezone: symbol_match.timezone,
-              currency: symbol_match.currency,
-              exchange: exchange.clone(),
-              match_score: symbol_match.match_score.parse::<f64>().ok(),
-              original_query: Some(original_symbol.clone()),
-              from_cache, // Pass through whether this came from cache
-            });
-          }
 
-          if let Some(pb) = &progress {
-            pb.inc(1);
+            Ok((security_data, from_cache))
           }
+          .await;
 
-          // Add delay to respect rate limits (only if not from cache)
-          if !from_cache {
-            tokio::time::sleep(tokio::time::Duration::from_millis(retry_delay)).await;
+          if let Err((e, _)) = &result {
+            if e.is_terminal() {
+              halt.trip(e);
+            }
           }
-
-          Ok((security_data, from_cache))
+          Some(result)
         }
       })
       .buffer_unordered(max_concurrent)
@@ -420,8 +442,13 @@ impl DataLoader for SecurityLoader {
     let mut loaded = Vec::new();
     let mut errors = 0;
     let mut skipped = 0;
+    let mut halted = 0;
 
     for result in results {
+      let Some(result) = result else {
+        halted += 1;
+        continue;
+      };
       match result {
         Ok((data_vec, from_cache)) => {
           if from_cache {
@@ -459,7 +486,11 @@ impl DataLoader for SecurityLoader {
         .await?;
     }
 
-    let total_symbols = loaded.len() + errors + skipped;
+    if let Some(reason) = halt.reason() {
+      error!("Stopped early, {} symbols not searched: {}", halted, reason);
+    }
+
+    let total_symbols = loaded.len() + errors + skipped + halted;
 
     info!(
       "Security loading complete: {} loaded, {} errors, {} skipped, {} cache hits, {} API calls",
@@ -474,7 +505,7 @@ impl DataLoader for SecurityLoader {
       total_symbols,
       loaded_count: loaded.len(),
       errors,
-      skipped_count: skipped,
+      skipped_count: skipped + halted,
       duplicates_prevented: 0, // TODO: Implement duplicate tracking
       cache_hits,
       api_calls,
@@ -375,6 +375,13 @@ impl SummaryPriceLoader {
       .await
       .map_err(|e| LoaderError::ApiError(format!("Failed to read response: {}", e)))?;
 
+    // Quota, premium and key notices come back as JSON instead of CSV
+    if let Some(e) = av_client::response_error(&csv_data) {
+      if !matches!(e, av_core::Error::Api(_)) {
+        return Err(e.into());
+      }
+    }
+
     // Check for API error messages
     if csv_data.contains("Error Message") || csv_data.contains("Invalid API call") {
       return Err(LoaderError::ApiError(format!("API error for {}: {}", symbol, csv_data)));
@@ -438,10 +445,10 @@ impl DataLoader for SummaryPriceLoader {
     let mut all_prices = Vec::new();
     let mut loaded_count = 0;
     let mut error_count = 0;
-    let skipped_count = 0;
+    let mut skipped_count = 0;
 
     // Process symbols sequentially with rate limiting
-    for (sid, symbol) in input.symbols.iter() {
+    for (idx, (sid, symbol)) in input.symbols.iter().enumerate() {
       if let Some(ref pb) = progress {
         pb.set_message(format!("Loading {}", symbol));
       }
@@ -464,6 +471,12 @@ impl DataLoader for SummaryPriceLoader {
             error!("API error for {}: {}", symbol, e);
           }
           error_count += 1;
+
+          if e.is_terminal() {
+            skipped_count = input.symbols.len() - idx - 1;
+            error!("Stopping early, skipping {} remaining symbols: {}", skipped_count, e);
+            break;
+          }
         }
       }
 
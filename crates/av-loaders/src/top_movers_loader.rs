@@ -392,7 +392,9 @@ impl DataLoader for TopMoversLoader {
     }
 
     // Save to database if we have data
-    let records_inserted = if !all_parsed_data.is_empty() && self.database_url.is_some() {
+    let records_inserted = if let Some(db_url) =
+      self.database_url.as_ref().filter(|_| !all_parsed_data.is_empty())
+    {
       // Convert NaiveDate to DateTime<Utc> for the database
       let date_time = input
         .date
@@ -408,7 +410,6 @@ impl DataLoader for TopMoversLoader {
       use av_database_postgres::schema::topstats;
       use diesel::prelude::*;
 
-      let db_url = self.database_url.as_ref().unwrap();
       let mut conn = establish_connection(db_url)
         .map_err(|e| LoaderError::DatabaseError(format!("Failed to connect to database: {}", e)))?;
 
@@ -0,0 +1,337 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Server-side returns analytics models.
+//!
+//! `ANALYTICS_FIXED_WINDOW` and `ANALYTICS_SLIDING_WINDOW` compute return
+//! statistics across several symbols in one call. Both respond with a
+//! `meta_data` block and a `payload.RETURNS_CALCULATIONS` map keyed by the
+//! requested calculation (e.g. `"MEAN"`, `"STDDEV(ANNUALIZED=TRUE)"`).
+//!
+//! # Result shapes
+//!
+//! | Calculation                          | Fixed window                    | Sliding window                          |
+//! |--------------------------------------|---------------------------------|-----------------------------------------|
+//! | `MEAN`, `STDDEV`, `VARIANCE`, ...    | symbol → value                  | `RUNNING_*` → symbol → date → value     |
+//! | `MAX_DRAWDOWN`                       | symbol → [`MaxDrawdown`]        | —                                       |
+//! | `HISTOGRAM`                          | symbol → [`ReturnsHistogram`]   | —                                       |
+//! | `CORRELATION`, `COVARIANCE`          | [`ReturnsMatrix`]               | `RUNNING_*` → [`RunningReturnsMatrix`]  |
+//!
+//! [`CalculationResult`] covers all of these. The accessors on
+//! [`ReturnsAnalytics`] look calculations up by name, ignoring any
+//! parenthesised options, so `scalar("STDDEV", "AAPL")` finds
+//! `"STDDEV(ANNUALIZED=TRUE)"`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// ─── Response container ─────────────────────────────────────────────────────
+
+/// Response from `ANALYTICS_FIXED_WINDOW` or `ANALYTICS_SLIDING_WINDOW`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnsAnalytics {
+  pub meta_data: AnalyticsMetaData,
+  pub payload: AnalyticsPayload,
+}
+
+/// Echo of the request parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsMetaData {
+  /// Comma-separated symbols
+  #[serde(default)]
+  pub symbols: String,
+
+  /// First date of the analysed range
+  #[serde(default)]
+  pub min_dt: String,
+
+  /// Last date of the analysed range
+  #[serde(default)]
+  pub max_dt: String,
+
+  /// Price field the returns were computed from (e.g., `"Close"`)
+  #[serde(default)]
+  pub ohlc: String,
+
+  #[serde(default)]
+  pub interval: String,
+}
+
+/// Calculation results, keyed by calculation name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsPayload {
+  #[serde(rename = "RETURNS_CALCULATIONS", default)]
+  pub returns_calculations: BTreeMap<String, CalculationResult>,
+}
+
+// ─── Calculation results ────────────────────────────────────────────────────
+
+/// One calculation's output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CalculationResult {
+  /// Pairwise statistic over the fixed window
+  Matrix(ReturnsMatrix),
+  /// Pairwise statistic per window end date
+  RunningMatrix(RunningReturnsMatrix),
+  /// Per-symbol statistic
+  PerSymbol(BTreeMap<String, SymbolStatistic>),
+  /// Sliding-window wrapper, keyed `RUNNING_<CALCULATION>`
+  Running(BTreeMap<String, CalculationResult>),
+}
+
+/// A per-symbol value within a [`CalculationResult::PerSymbol`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SymbolStatistic {
+  Value(f64),
+  Drawdown(MaxDrawdown),
+  Histogram(ReturnsHistogram),
+  /// Sliding-window series, date → value
+  Series(BTreeMap<String, f64>),
+}
+
+/// Lower-triangular correlation or covariance matrix.
+///
+/// Row `i` holds the values against symbols `0..=i` of `index`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnsMatrix {
+  pub index: Vec<String>,
+  #[serde(alias = "correlation", alias = "covariance")]
+  pub values: Vec<Vec<f64>>,
+}
+
+/// Pairwise matrices per window end date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunningReturnsMatrix {
+  pub index: Vec<String>,
+  #[serde(alias = "correlation", alias = "covariance")]
+  pub values: BTreeMap<String, Vec<Vec<f64>>>,
+}
+
+/// Largest peak-to-trough decline of one symbol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaxDrawdown {
+  /// Negative fraction (e.g., `-0.12` = 12% decline)
+  pub max_drawdown: f64,
+  pub drawdown_range: DrawdownRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawdownRange {
+  pub start_drawdown: String,
+  pub end_drawdown: String,
+}
+
+/// Distribution of one symbol's returns.
+///
+/// `bin_edges` has one more entry than `bin_count`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnsHistogram {
+  pub bin_count: Vec<u64>,
+  pub bin_edges: Vec<f64>,
+}
+
+// ─── Helpers ────────────────────────────────────────────────────────────────
+
+/// Matches `key` against a calculation name, ignoring case and any
+/// parenthesised options (`"STDDEV(ANNUALIZED=TRUE)"` matches `"stddev"`).
+fn calculation_matches(key: &str, name: &str) -> bool {
+  let base = key.split('(').next().unwrap_or(key).trim();
+  base.eq_ignore_ascii_case(name.trim())
+}
+
+impl AnalyticsMetaData {
+  pub fn symbols(&self) -> Vec<&str> {
+    self.symbols.split(',').map(str::trim).filter(|s| !s.is_empty()).collect()
+  }
+}
+
+/// Lookup helpers for [`ReturnsAnalytics`].
+impl ReturnsAnalytics {
+  /// The result for a calculation, matched by name without options.
+  pub fn calculation(&self, name: &str) -> Option<&CalculationResult> {
+    self
+      .payload
+      .returns_calculations
+      .iter()
+      .find(|(key, _)| calculation_matches(key, name))
+      .map(|(_, result)| result)
+  }
+
+  /// A fixed-window per-symbol value (`MEAN`, `STDDEV`, `CUMULATIVE_RETURN`, ...).
+  pub fn scalar(&self, name: &str, symbol: &str) -> Option<f64> {
+    match self.calculation(name)? {
+      CalculationResult::PerSymbol(values) => match values.get(symbol)? {
+        SymbolStatistic::Value(v) => Some(*v),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  /// A fixed-window pairwise matrix (`CORRELATION` or `COVARIANCE`).
+  pub fn matrix(&self, name: &str) -> Option<&ReturnsMatrix> {
+    match self.calculation(name)? {
+      CalculationResult::Matrix(matrix) => Some(matrix),
+      _ => None,
+    }
+  }
+
+  pub fn max_drawdown(&self, symbol: &str) -> Option<&MaxDrawdown> {
+    match self.calculation("MAX_DRAWDOWN")? {
+      CalculationResult::PerSymbol(values) => match values.get(symbol)? {
+        SymbolStatistic::Drawdown(d) => Some(d),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  pub fn histogram(&self, symbol: &str) -> Option<&ReturnsHistogram> {
+    match self.calculation("HISTOGRAM")? {
+      CalculationResult::PerSymbol(values) => match values.get(symbol)? {
+        SymbolStatistic::Histogram(h) => Some(h),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  /// A sliding-window series for one symbol, window end date → value.
+  pub fn running_series(&self, name: &str, symbol: &str) -> Option<&BTreeMap<String, f64>> {
+    let CalculationResult::Running(inner) = self.calculation(name)? else {
+      return None;
+    };
+    inner.values().find_map(|result| match result {
+      CalculationResult::PerSymbol(values) => match values.get(symbol)? {
+        SymbolStatistic::Series(series) => Some(series),
+        _ => None,
+      },
+      _ => None,
+    })
+  }
+
+  /// Sliding-window pairwise matrices (`CORRELATION` or `COVARIANCE`).
+  pub fn running_matrix(&self, name: &str) -> Option<&RunningReturnsMatrix> {
+    let CalculationResult::Running(inner) = self.calculation(name)? else {
+      return None;
+    };
+    inner.values().find_map(|result| match result {
+      CalculationResult::RunningMatrix(matrix) => Some(matrix),
+      _ => None,
+    })
+  }
+}
+
+impl ReturnsMatrix {
+  /// The value for a pair of symbols, in either order.
+  pub fn get(&self, a: &str, b: &str) -> Option<f64> {
+    let i = self.index.iter().position(|s| s == a)?;
+    let j = self.index.iter().position(|s| s == b)?;
+    let (row, col) = if i >= j { (i, j) } else { (j, i) };
+    self.values.get(row)?.get(col).copied()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fixed_window_deserialization() {
+    let json = r#"{
+            "meta_data": {
+                "symbols": "AAPL,MSFT",
+                "min_dt": "2023-07-03",
+                "max_dt": "2023-08-31",
+                "ohlc": "Close",
+                "interval": "DAILY"
+            },
+            "payload": {
+                "RETURNS_CALCULATIONS": {
+                    "MEAN": { "AAPL": 0.0011, "MSFT": 0.0004 },
+                    "STDDEV(ANNUALIZED=TRUE)": { "AAPL": 0.18, "MSFT": 0.21 },
+                    "MAX_DRAWDOWN": {
+                        "AAPL": {
+                            "max_drawdown": -0.0952,
+                            "drawdown_range": { "start_drawdown": "2023-07-31", "end_drawdown": "2023-08-18" }
+                        }
+                    },
+                    "HISTOGRAM": { "AAPL": { "bin_count": [3, 5], "bin_edges": [-0.02, 0.0, 0.02] } },
+                    "CORRELATION": { "index": ["AAPL", "MSFT"], "correlation": [[1.0], [0.61, 1.0]] }
+                }
+            }
+        }"#;
+
+    let analytics: ReturnsAnalytics = serde_json::from_str(json).unwrap();
+    assert_eq!(analytics.meta_data.symbols(), vec!["AAPL", "MSFT"]);
+    assert_eq!(analytics.scalar("MEAN", "AAPL"), Some(0.0011));
+    assert_eq!(analytics.scalar("stddev", "MSFT"), Some(0.21));
+    assert_eq!(analytics.max_drawdown("AAPL").unwrap().drawdown_range.end_drawdown, "2023-08-18");
+    assert_eq!(analytics.histogram("AAPL").unwrap().bin_count, vec![3, 5]);
+
+    let corr = analytics.matrix("CORRELATION").unwrap();
+    assert_eq!(corr.get("MSFT", "AAPL"), Some(0.61));
+    assert_eq!(corr.get("AAPL", "MSFT"), Some(0.61));
+    assert_eq!(corr.get("AAPL", "IBM"), None);
+  }
+
+  #[test]
+  fn test_sliding_window_deserialization() {
+    let json = r#"{
+            "meta_data": { "symbols": "AAPL,IBM", "min_dt": "2023-07-03", "max_dt": "2023-08-31" },
+            "payload": {
+                "RETURNS_CALCULATIONS": {
+                    "MEAN": {
+                        "RUNNING_MEAN": {
+                            "AAPL": { "2023-08-29": 0.0012, "2023-08-30": 0.0015 },
+                            "IBM": { "2023-08-29": -0.0003 }
+                        }
+                    },
+                    "CORRELATION": {
+                        "RUNNING_CORRELATION": {
+                            "index": ["AAPL", "IBM"],
+                            "correlation": { "2023-08-30": [[1.0], [0.2, 1.0]] }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+    let analytics: ReturnsAnalytics = serde_json::from_str(json).unwrap();
+    let series = analytics.running_series("MEAN", "AAPL").unwrap();
+    assert_eq!(series.get("2023-08-30"), Some(&0.0015));
+    assert!(analytics.running_series("MEAN", "MSFT").is_none());
+    assert_eq!(analytics.scalar("MEAN", "AAPL"), None);
+
+    let corr = analytics.running_matrix("CORRELATION").unwrap();
+    assert_eq!(corr.values["2023-08-30"][1][0], 0.2);
+  }
+}
@@ -0,0 +1,75 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Commodity price models.
+//!
+//! The commodity endpoints return the same `{ name, interval, unit, data }`
+//! shape as the economic indicators, so the response types here are aliases
+//! of [`EconomicSeries`] and [`EconomicDataPoint`]. Each observation is a
+//! single price (no OHLC), and `"."` marks a missing value.
+//!
+//! # Endpoint mapping
+//!
+//! | Endpoint                                   | Intervals                        | Typical unit         |
+//! |--------------------------------------------|----------------------------------|----------------------|
+//! | `WTI`, `BRENT`                             | `daily`, `weekly`, `monthly`     | dollars per barrel   |
+//! | `NATURAL_GAS`                              | `daily`, `weekly`, `monthly`     | dollars per million BTU |
+//! | `COPPER`, `ALUMINUM`                       | `monthly`, `quarterly`, `annual` | dollars per metric ton |
+//! | `WHEAT`, `CORN`, `COTTON`, `SUGAR`, `COFFEE` | `monthly`, `quarterly`, `annual` | dollars or cents per unit |
+//! | `ALL_COMMODITIES`                          | `monthly`, `quarterly`, `annual` | index 2016=100       |
+
+use crate::economics::{EconomicDataPoint, EconomicSeries};
+
+/// Response from any commodity price endpoint.
+pub type CommoditySeries = EconomicSeries;
+
+/// A single dated commodity price.
+pub type CommodityDataPoint = EconomicDataPoint;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_commodity_series_deserialization() {
+    let json = r#"{
+      "name": "Crude Oil Prices WTI",
+      "interval": "weekly",
+      "unit": "dollars per barrel",
+      "data": [
+        { "date": "2024-01-12", "value": "72.73" },
+        { "date": "2024-01-05", "value": "72.16" }
+      ]
+    }"#;
+
+    let series: CommoditySeries = serde_json::from_str(json).unwrap();
+    assert_eq!(series.unit, "dollars per barrel");
+    assert_eq!(series.latest().and_then(|p| p.value_as_f64()), Some(72.73));
+  }
+}
@@ -0,0 +1,152 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! US economic indicator models.
+//!
+//! All Alpha Vantage economic indicator endpoints share one response shape:
+//! a series name, sampling interval, unit, and a `data` array of
+//! `{ "date", "value" }` observations ordered newest first. A single
+//! [`EconomicSeries`] struct therefore covers every endpoint.
+//!
+//! # Endpoint mapping
+//!
+//! | Endpoint             | Intervals                     | Typical unit          |
+//! |----------------------|-------------------------------|-----------------------|
+//! | `REAL_GDP`           | `annual`, `quarterly`         | billions of dollars   |
+//! | `CPI`                | `monthly`, `semiannual`       | index 1982-1984=100   |
+//! | `INFLATION`          | `annual` (fixed)              | percent               |
+//! | `TREASURY_YIELD`     | `daily`, `weekly`, `monthly`  | percent               |
+//! | `FEDERAL_FUNDS_RATE` | `daily`, `weekly`, `monthly`  | percent               |
+//! | `UNEMPLOYMENT`       | `monthly` (fixed)             | percent               |
+//! | `NONFARM_PAYROLL`    | `monthly` (fixed)             | thousands of persons  |
+//!
+//! # Missing observations
+//!
+//! The upstream source publishes `"."` for observations that are not yet
+//! available (e.g., treasury yields on bond-market holidays).
+//! [`EconomicDataPoint::value_as_f64`] returns `None` for these.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+// ─── Response container ─────────────────────────────────────────────────────
+
+/// Response from any economic indicator endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomicSeries {
+  /// Human-readable series name (e.g., `"Real Gross Domestic Product"`)
+  pub name: String,
+
+  /// Sampling interval as reported by the API (e.g., `"quarterly"`)
+  pub interval: String,
+
+  /// Unit of measure (e.g., `"percent"`)
+  pub unit: String,
+
+  /// Observations, newest first
+  pub data: Vec<EconomicDataPoint>,
+}
+
+/// A single dated observation in an [`EconomicSeries`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomicDataPoint {
+  /// Observation date in `YYYY-MM-DD` format
+  pub date: String,
+
+  /// Observation value, or `"."` when not available
+  pub value: String,
+}
+
+// ─── Helper methods ─────────────────────────────────────────────────────────
+
+impl EconomicDataPoint {
+  /// Parses [`date`](Self::date) as a calendar date.
+  pub fn parsed_date(&self) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").ok()
+  }
+
+  /// Parses [`value`](Self::value), returning `None` for the `"."`
+  /// placeholder or any other non-numeric string.
+  pub fn value_as_f64(&self) -> Option<f64> {
+    self.value.trim().parse::<f64>().ok()
+  }
+}
+
+/// Access helpers for [`EconomicSeries`].
+impl EconomicSeries {
+  /// Returns the most recent observation that has a numeric value.
+  pub fn latest(&self) -> Option<&EconomicDataPoint> {
+    self.data.iter().find(|point| point.value_as_f64().is_some())
+  }
+
+  pub fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample_treasury_json() -> &'static str {
+    r#"{
+      "name": "10-Year Treasury Constant Maturity Rate",
+      "interval": "daily",
+      "unit": "percent",
+      "data": [
+        { "date": "2024-01-15", "value": "." },
+        { "date": "2024-01-12", "value": "3.94" },
+        { "date": "2024-01-11", "value": "3.98" }
+      ]
+    }"#
+  }
+
+  #[test]
+  fn test_economic_series_deserialization() {
+    let series: EconomicSeries = serde_json::from_str(sample_treasury_json()).unwrap();
+
+    assert_eq!(series.len(), 3);
+    assert_eq!(series.interval, "daily");
+    assert_eq!(series.unit, "percent");
+    assert_eq!(series.data[1].parsed_date(), NaiveDate::from_ymd_opt(2024, 1, 12));
+  }
+
+  #[test]
+  fn test_missing_value_placeholder() {
+    let series: EconomicSeries = serde_json::from_str(sample_treasury_json()).unwrap();
+
+    assert_eq!(series.data[0].value_as_f64(), None);
+    assert_eq!(series.latest().map(|p| p.date.as_str()), Some("2024-01-12"));
+    assert_eq!(series.latest().and_then(|p| p.value_as_f64()), Some(3.94));
+  }
+}
@@ -42,10 +42,15 @@
 //! | `BALANCE_SHEET`       | [`BalanceSheet`] → [`BalanceSheetReport`]    | Annual + quarterly arrays          |
 //! | `CASH_FLOW`           | [`CashFlow`] → [`CashFlowReport`]           | Annual + quarterly arrays          |
 //! | `EARNINGS`            | [`Earnings`] → [`AnnualEarnings`] / [`QuarterlyEarnings`] | EPS + surprise data  |
+//! | `DIVIDENDS`           | [`Dividends`] → [`DividendEvent`]           | `"None"` for unknown dates         |
+//! | `SPLITS`              | [`Splits`] → [`SplitEvent`]                 | `split_factor` = new / old shares  |
+//! | `INSIDER_TRANSACTIONS`| [`InsiderTransactions`] → [`InsiderTransaction`] | Newest first, full history    |
+//! | `EARNINGS_CALL_TRANSCRIPT` | [`EarningsCallTranscript`] → [`TranscriptSegment`] | One quarter, per-speaker sentiment |
+//! | `ETF_PROFILE`         | [`EtfProfile`] → [`EtfHolding`] / [`EtfSectorWeight`] | Weights are fractions, not percent |
 //! | `TOP_GAINERS_LOSERS`  | [`TopGainersLosers`] → [`StockMover`]       | Three lists: gainers, losers, most active |
-//! | `LISTING_STATUS`      | [`ListingStatus`] → [`SecurityListing`]     | CSV-based (parsed externally)      |
-//! | `EARNINGS_CALENDAR`   | [`EarningsCalendar`] → [`EarningsEvent`]    | CSV-based (parsed externally)      |
-//! | `IPO_CALENDAR`        | [`IpoCalendar`] → [`IpoEvent`]              | CSV-based (parsed externally)      |
+//! | `LISTING_STATUS`      | [`ListingStatus`] → [`SecurityListing`]     | CSV, decoded by the client transport |
+//! | `EARNINGS_CALENDAR`   | [`EarningsCalendar`] → [`EarningsEvent`]    | CSV, decoded by the client transport |
+//! | `IPO_CALENDAR`        | [`IpoCalendar`] → [`IpoEvent`]              | CSV, decoded by the client transport |
 //!
 //! # Financial statement pattern
 //!
@@ -63,6 +68,7 @@
 //! Each report is a flat object with `fiscalDateEnding`, `reportedCurrency`,
 //! and ~20–30 line-item fields specific to that statement type.
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 // ─── Company Overview ───────────────────────────────────────────────────────
@@ -670,6 +676,310 @@ pub struct QuarterlyEarnings {
   pub surprise_percentage: String,
 }
 
+// ─── Corporate Actions ──────────────────────────────────────────────────────
+
+/// Response from the `DIVIDENDS` endpoint, newest first.
+///
+/// Includes declared dividends whose ex-date is still in the future.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dividends {
+  /// Stock symbol
+  pub symbol: String,
+
+  /// Dividend distributions
+  pub data: Vec<DividendEvent>,
+}
+
+/// A single dividend distribution.
+///
+/// Dates the API does not know are reported as the literal `"None"`; use
+/// the `*_date()` helpers to get `Option<NaiveDate>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DividendEvent {
+  pub ex_dividend_date: String,
+  pub declaration_date: String,
+  pub record_date: String,
+  pub payment_date: String,
+
+  /// Cash amount per share
+  pub amount: String,
+}
+
+/// Response from the `SPLITS` endpoint, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Splits {
+  /// Stock symbol
+  pub symbol: String,
+
+  /// Split events
+  pub data: Vec<SplitEvent>,
+}
+
+/// A single stock split.
+///
+/// `split_factor` is new shares per old share: `"4.0000"` for a 4-for-1
+/// split, `"0.1000"` for a 1-for-10 reverse split.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitEvent {
+  pub effective_date: String,
+  pub split_factor: String,
+}
+
+fn parse_action_date(value: &str) -> Option<NaiveDate> {
+  NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+/// Parsing helpers for [`DividendEvent`].
+impl DividendEvent {
+  pub fn ex_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.ex_dividend_date)
+  }
+
+  pub fn declaration_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.declaration_date)
+  }
+
+  pub fn record_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.record_date)
+  }
+
+  pub fn payment_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.payment_date)
+  }
+
+  pub fn amount_as_f64(&self) -> Option<f64> {
+    self.amount.trim().parse().ok()
+  }
+}
+
+/// Parsing helpers for [`SplitEvent`].
+impl SplitEvent {
+  pub fn effective_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.effective_date)
+  }
+
+  pub fn split_factor_as_f64(&self) -> Option<f64> {
+    self.split_factor.trim().parse().ok()
+  }
+}
+
+// ─── Insider Transactions ───────────────────────────────────────────────────
+
+/// Response from the `INSIDER_TRANSACTIONS` endpoint, newest first.
+///
+/// The endpoint always returns the symbol's full filing history; there is
+/// no date filter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsiderTransactions {
+  /// Individual trades
+  pub data: Vec<InsiderTransaction>,
+}
+
+/// A single trade reported by an officer, director, or major holder.
+///
+/// `acquisition_or_disposal` is `"A"` for purchases and grants and `"D"`
+/// for sales. Option exercises appear as separate rows with their own
+/// `security_type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsiderTransaction {
+  pub transaction_date: String,
+  pub ticker: String,
+  pub executive: String,
+  pub executive_title: String,
+  pub security_type: String,
+  pub acquisition_or_disposal: String,
+  pub shares: String,
+
+  /// Price per share; `"0.0"` for grants and awards
+  pub share_price: String,
+}
+
+/// Parsing helpers for [`InsiderTransaction`].
+impl InsiderTransaction {
+  pub fn date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.transaction_date)
+  }
+
+  pub fn shares_as_f64(&self) -> Option<f64> {
+    self.shares.trim().parse().ok()
+  }
+
+  pub fn share_price_as_f64(&self) -> Option<f64> {
+    self.share_price.trim().parse().ok()
+  }
+
+  /// True for purchases and grants (`"A"`).
+  pub fn is_acquisition(&self) -> bool {
+    self.acquisition_or_disposal.trim().eq_ignore_ascii_case("A")
+  }
+}
+
+// ─── Earnings Call Transcripts ──────────────────────────────────────────────
+
+/// Response from the `EARNINGS_CALL_TRANSCRIPT` endpoint.
+///
+/// `quarter` uses the `YYYYQn` form, e.g. `"2024Q1"`. A quarter without a
+/// published call comes back with an empty `transcript`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EarningsCallTranscript {
+  /// Stock symbol
+  pub symbol: String,
+
+  /// Fiscal quarter (`YYYYQn`)
+  pub quarter: String,
+
+  /// Speaker segments in call order
+  #[serde(default)]
+  pub transcript: Vec<TranscriptSegment>,
+}
+
+/// One uninterrupted passage by a single speaker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+  pub speaker: String,
+
+  /// Speaker role, e.g. `"CEO"` or `"Analyst"`
+  #[serde(default)]
+  pub title: String,
+
+  pub content: String,
+
+  /// Sentiment score in `[-1, 1]`
+  #[serde(default)]
+  pub sentiment: String,
+}
+
+/// Parses a `YYYYQn` quarter into `(year, quarter)`.
+///
+/// Returns `None` unless the quarter number is 1–4.
+pub fn parse_fiscal_quarter(value: &str) -> Option<(i32, u8)> {
+  let (year, quarter) = value
+    .trim()
+    .to_uppercase()
+    .split_once('Q')
+    .map(|(y, q)| (y.parse::<i32>().ok(), q.parse::<u8>().ok()))?;
+  match (year, quarter) {
+    (Some(year), Some(quarter)) if (1..=4).contains(&quarter) && year > 0 => Some((year, quarter)),
+    _ => None,
+  }
+}
+
+/// Parsing helpers for [`EarningsCallTranscript`].
+impl EarningsCallTranscript {
+  pub fn fiscal_quarter(&self) -> Option<(i32, u8)> {
+    parse_fiscal_quarter(&self.quarter)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.transcript.is_empty()
+  }
+}
+
+/// Parsing helpers for [`TranscriptSegment`].
+impl TranscriptSegment {
+  pub fn sentiment_as_f64(&self) -> Option<f64> {
+    self.sentiment.trim().parse().ok()
+  }
+}
+
+// ─── ETF Profile ────────────────────────────────────────────────────────────
+
+/// Response from the `ETF_PROFILE` endpoint.
+///
+/// Ratios and weights are fractions (`"0.0945"` = 9.45%). Values the API
+/// does not know are reported as `"n/a"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtfProfile {
+  /// Net assets in USD
+  pub net_assets: String,
+  pub net_expense_ratio: String,
+  pub portfolio_turnover: String,
+  pub dividend_yield: String,
+  pub inception_date: String,
+
+  /// `"YES"` or `"NO"`
+  pub leveraged: String,
+
+  /// Sector allocation
+  #[serde(default)]
+  pub sectors: Vec<EtfSectorWeight>,
+
+  /// Constituents, largest weight first
+  #[serde(default)]
+  pub holdings: Vec<EtfHolding>,
+}
+
+/// Share of an ETF's assets in one sector.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtfSectorWeight {
+  pub sector: String,
+  pub weight: String,
+}
+
+/// One constituent of an ETF.
+///
+/// `symbol` is `"n/a"` for positions without a ticker, such as cash and
+/// futures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtfHolding {
+  pub symbol: String,
+  pub description: String,
+  pub weight: String,
+}
+
+fn parse_ratio(value: &str) -> Option<f64> {
+  value.trim().parse().ok()
+}
+
+/// Parsing helpers for [`EtfProfile`].
+impl EtfProfile {
+  pub fn net_assets_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.net_assets)
+  }
+
+  pub fn net_expense_ratio_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.net_expense_ratio)
+  }
+
+  pub fn portfolio_turnover_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.portfolio_turnover)
+  }
+
+  pub fn dividend_yield_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.dividend_yield)
+  }
+
+  pub fn inception_date(&self) -> Option<NaiveDate> {
+    parse_action_date(&self.inception_date)
+  }
+
+  pub fn is_leveraged(&self) -> Option<bool> {
+    match self.leveraged.trim().to_uppercase().as_str() {
+      "YES" => Some(true),
+      "NO" => Some(false),
+      _ => None,
+    }
+  }
+}
+
+impl EtfSectorWeight {
+  pub fn weight_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.weight)
+  }
+}
+
+impl EtfHolding {
+  pub fn weight_as_f64(&self) -> Option<f64> {
+    parse_ratio(&self.weight)
+  }
+
+  /// The ticker, or `None` for untickered positions (`"n/a"` or blank).
+  pub fn ticker(&self) -> Option<&str> {
+    let symbol = self.symbol.trim();
+    (!symbol.is_empty() && !symbol.eq_ignore_ascii_case("n/a")).then_some(symbol)
+  }
+}
+
 // ─── Top Movers ─────────────────────────────────────────────────────────────
 
 /// Response from the `TOP_GAINERS_LOSERS` endpoint.
@@ -720,9 +1030,8 @@ pub struct StockMover {
 
 /// Parsed response from the `LISTING_STATUS` endpoint.
 ///
-/// The raw API returns CSV; this struct represents the parsed result.
-/// Contains a list of [`SecurityListing`] records for active or delisted
-/// securities.
+/// The raw API returns CSV; the client transport decodes each row into a
+/// [`SecurityListing`] (with `snake_case` column names) under `data`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListingStatus {
   /// List of securities
@@ -750,13 +1059,22 @@ pub struct SecurityListing {
   /// IPO date
   pub ipo_date: String,
 
-  /// Delisting date (if applicable)
+  /// Delisting date; the API sends the string `"null"` for active securities
+  #[serde(default, deserialize_with = "deserialize_null_string")]
   pub delisting_date: Option<String>,
 
   /// Status (Active/Delisted)
   pub status: String,
 }
 
+fn deserialize_null_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let value: Option<String> = Option::deserialize(deserializer)?;
+  Ok(value.filter(|v| !v.trim().is_empty() && !v.trim().eq_ignore_ascii_case("null")))
+}
+
 /// Parsed response from the `EARNINGS_CALENDAR` endpoint (CSV-based).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EarningsCalendar {
@@ -881,4 +1199,137 @@ mod tests {
     assert_eq!(overview.name, "Apple Inc");
     assert_eq!(overview.sector, "TECHNOLOGY");
   }
+
+  #[test]
+  fn test_corporate_actions_deserialization() {
+    let json = r#"{
+            "symbol": "IBM",
+            "data": [
+                {
+                    "ex_dividend_date": "2024-11-12",
+                    "declaration_date": "2024-10-29",
+                    "record_date": "2024-11-12",
+                    "payment_date": "2024-12-10",
+                    "amount": "1.67"
+                },
+                {
+                    "ex_dividend_date": "1962-02-06",
+                    "declaration_date": "None",
+                    "record_date": "None",
+                    "payment_date": "None",
+                    "amount": "0.2"
+                }
+            ]
+        }"#;
+
+    let dividends: Dividends = serde_json::from_str(json).unwrap();
+    assert_eq!(dividends.data.len(), 2);
+    assert_eq!(dividends.data[0].ex_date(), NaiveDate::from_ymd_opt(2024, 11, 12));
+    assert_eq!(dividends.data[0].amount_as_f64(), Some(1.67));
+    assert_eq!(dividends.data[1].payment_date(), None);
+
+    let json = r#"{
+            "symbol": "IBM",
+            "data": [ { "effective_date": "1999-05-27", "split_factor": "2.0000" } ]
+        }"#;
+
+    let splits: Splits = serde_json::from_str(json).unwrap();
+    assert_eq!(splits.data[0].effective_date(), NaiveDate::from_ymd_opt(1999, 5, 27));
+    assert_eq!(splits.data[0].split_factor_as_f64(), Some(2.0));
+  }
+
+  #[test]
+  fn test_insider_transactions_deserialization() {
+    let json = r#"{
+            "data": [
+                {
+                    "transaction_date": "2024-11-01",
+                    "ticker": "IBM",
+                    "executive": "KAVANAUGH, JAMES J",
+                    "executive_title": "SVP, CFO",
+                    "security_type": "Common Stock",
+                    "acquisition_or_disposal": "D",
+                    "shares": "12500.0",
+                    "share_price": "206.5"
+                }
+            ]
+        }"#;
+
+    let insiders: InsiderTransactions = serde_json::from_str(json).unwrap();
+    let trade = &insiders.data[0];
+    assert_eq!(trade.date(), NaiveDate::from_ymd_opt(2024, 11, 1));
+    assert_eq!(trade.shares_as_f64(), Some(12500.0));
+    assert_eq!(trade.share_price_as_f64(), Some(206.5));
+    assert!(!trade.is_acquisition());
+  }
+
+  #[test]
+  fn test_earnings_call_transcript_deserialization() {
+    let json = r#"{
+            "symbol": "IBM",
+            "quarter": "2024Q1",
+            "transcript": [
+                {
+                    "speaker": "Olympia McNerney",
+                    "title": "Global Head of Investor Relations",
+                    "content": "Welcome to IBM's first quarter 2024 earnings presentation.",
+                    "sentiment": "0.6"
+                },
+                {
+                    "speaker": "Arvind Krishna",
+                    "title": "CEO",
+                    "content": "Thank you for joining us today."
+                }
+            ]
+        }"#;
+
+    let call: EarningsCallTranscript = serde_json::from_str(json).unwrap();
+    assert_eq!(call.fiscal_quarter(), Some((2024, 1)));
+    assert_eq!(call.transcript.len(), 2);
+    assert_eq!(call.transcript[0].sentiment_as_f64(), Some(0.6));
+    assert_eq!(call.transcript[1].sentiment_as_f64(), None);
+
+    assert_eq!(parse_fiscal_quarter("2023q4"), Some((2023, 4)));
+    assert_eq!(parse_fiscal_quarter("2023Q5"), None);
+    assert_eq!(parse_fiscal_quarter("2023"), None);
+  }
+
+  #[test]
+  fn test_etf_profile_deserialization() {
+    let json = r#"{
+            "net_assets": "341000000000",
+            "net_expense_ratio": "0.002",
+            "portfolio_turnover": "0.08",
+            "dividend_yield": "0.0059",
+            "inception_date": "1999-03-10",
+            "leveraged": "NO",
+            "sectors": [ { "sector": "INFORMATION TECHNOLOGY", "weight": "0.503" } ],
+            "holdings": [
+                { "symbol": "NVDA", "description": "NVIDIA CORP", "weight": "0.0902" },
+                { "symbol": "n/a", "description": "CASH", "weight": "0.0011" }
+            ]
+        }"#;
+
+    let profile: EtfProfile = serde_json::from_str(json).unwrap();
+    assert_eq!(profile.net_expense_ratio_as_f64(), Some(0.002));
+    assert_eq!(profile.inception_date(), NaiveDate::from_ymd_opt(1999, 3, 10));
+    assert_eq!(profile.is_leveraged(), Some(false));
+    assert_eq!(profile.sectors[0].weight_as_f64(), Some(0.503));
+    assert_eq!(profile.holdings[0].ticker(), Some("NVDA"));
+    assert_eq!(profile.holdings[1].ticker(), None);
+  }
+
+  #[test]
+  fn test_listing_status_null_delisting_date() {
+    let json = r#"{"data": [
+            { "symbol": "IBM", "name": "International Business Machines Corp", "exchange": "NYSE",
+              "asset_type": "Stock", "ipo_date": "1962-01-02", "delisting_date": "null", "status": "Active" },
+            { "symbol": "XYZ", "name": "Gone Inc", "exchange": "NASDAQ",
+              "asset_type": "Stock", "ipo_date": "2001-05-01", "delisting_date": "2020-03-02", "status": "Delisted" }
+        ]}"#;
+
+    let listing: ListingStatus = serde_json::from_str(json).unwrap();
+    assert_eq!(listing.data[0].delisting_date, None);
+    assert_eq!(listing.data[1].delisting_date.as_deref(), Some("2020-03-02"));
+  }
 }
@@ -0,0 +1,343 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Typed technical indicator models.
+//!
+//! Every Alpha Vantage technical indicator response has the same shape: a
+//! `"Meta Data"` object plus one `"Technical Analysis: <NAME>"` object keyed
+//! by timestamp. [`IndicatorSeries<T>`] captures that shape once and is
+//! parameterized by the per-timestamp data point.
+//!
+//! # Endpoint mapping
+//!
+//! | Endpoint | Response alias     | Data point             | Data point keys                                    |
+//! |----------|--------------------|------------------------|----------------------------------------------------|
+//! | `SMA`    | [`SmaSeries`]      | [`SmaData`]            | `SMA`                                              |
+//! | `EMA`    | [`EmaSeries`]      | [`EmaData`]            | `EMA`                                              |
+//! | `RSI`    | [`RsiSeries`]      | [`RsiData`]            | `RSI`                                              |
+//! | `MACD`   | [`MacdSeries`]     | [`MacdData`]           | `MACD`, `MACD_Signal`, `MACD_Hist`                 |
+//! | `BBANDS` | [`BbandsSeries`]   | [`BollingerBandsData`] | `Real Upper Band`, `Real Middle Band`, `Real Lower Band` |
+//! | `ADX`    | [`AdxSeries`]      | [`AdxData`]            | `ADX`                                              |
+//! | `STOCH`  | [`StochSeries`]    | [`StochData`]          | `SlowK`, `SlowD`                                   |
+//! | `ATR`    | [`AtrSeries`]      | [`AtrData`]            | `ATR`                                              |
+//! | `OBV`    | [`ObvSeries`]      | [`ObvData`]            | `OBV`                                              |
+//! | `VWAP`   | [`VwapSeries`]     | [`VwapData`]           | `VWAP`                                             |
+//!
+//! [`RsiData`], [`MacdData`], and [`BollingerBandsData`] are shared with the
+//! untyped [`TechnicalIndicator`](crate::time_series::TechnicalIndicator)
+//! container in [`time_series`](crate::time_series).
+//!
+//! # Metadata
+//!
+//! The numbered metadata keys differ between indicators (MACD has
+//! `"5.1: Fast Period"`, BBANDS has `"5: Time Period"` and
+//! `"6.1: Deviation multiplier for upper band"`, VWAP has no period at all),
+//! so [`IndicatorMetadata`] keeps the raw map and offers lookups by label.
+
+use crate::time_series::{BollingerBandsData, MacdData, RsiData};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+// ─── Response container ─────────────────────────────────────────────────────
+
+/// Response from any technical indicator endpoint.
+///
+/// Uses a **custom `Deserialize` implementation** because the data key
+/// embeds the indicator name (e.g., `"Technical Analysis: SMA"`). The
+/// deserializer takes the first top-level key starting with
+/// `"Technical Analysis"`.
+///
+/// `values` is keyed by the API timestamp string (`"2024-01-15"` or
+/// `"2024-01-15 16:00"`), which sorts chronologically.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndicatorSeries<T> {
+  pub meta_data: IndicatorMetadata,
+
+  pub values: BTreeMap<String, T>,
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for IndicatorSeries<T> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let mut map = serde_json::Map::deserialize(deserializer)?;
+
+    let meta_data: IndicatorMetadata = map
+      .remove("Meta Data")
+      .ok_or_else(|| serde::de::Error::missing_field("Meta Data"))
+      .and_then(|v| serde_json::from_value(v).map_err(serde::de::Error::custom))?;
+
+    let analysis_key = map
+      .keys()
+      .find(|k| k.starts_with("Technical Analysis"))
+      .cloned()
+      .ok_or_else(|| serde::de::Error::custom("No technical analysis data found in response"))?;
+
+    let values: BTreeMap<String, T> = map
+      .remove(&analysis_key)
+      .ok_or_else(|| serde::de::Error::missing_field("Technical Analysis"))
+      .and_then(|v| serde_json::from_value(v).map_err(serde::de::Error::custom))?;
+
+    Ok(IndicatorSeries { meta_data, values })
+  }
+}
+
+/// Metadata block of a technical indicator response.
+///
+/// Keys look like `"1: Symbol"` or `"5.1: Fast Period"`; [`get`](Self::get)
+/// matches on the label after the `": "` separator, case-insensitively.
+/// Numeric values (the API sends `"5: Time Period": 20` unquoted) are
+/// stored in their string form.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct IndicatorMetadata {
+  pub fields: BTreeMap<String, String>,
+}
+
+impl<'de> Deserialize<'de> for IndicatorMetadata {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let raw = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    let fields = raw
+      .into_iter()
+      .map(|(key, value)| match value {
+        serde_json::Value::String(s) => (key, s),
+        other => (key, other.to_string()),
+      })
+      .collect();
+
+    Ok(IndicatorMetadata { fields })
+  }
+}
+
+impl IndicatorMetadata {
+  /// Looks up a metadata value by its label (e.g., `"Time Period"`).
+  pub fn get(&self, label: &str) -> Option<&str> {
+    self
+      .fields
+      .iter()
+      .find(|(key, _)| {
+        key.split_once(": ").map(|(_, name)| name).unwrap_or(key).eq_ignore_ascii_case(label)
+      })
+      .map(|(_, value)| value.as_str())
+  }
+
+  pub fn symbol(&self) -> Option<&str> {
+    self.get("Symbol")
+  }
+
+  pub fn indicator(&self) -> Option<&str> {
+    self.get("Indicator")
+  }
+
+  pub fn last_refreshed(&self) -> Option<&str> {
+    self.get("Last Refreshed")
+  }
+
+  pub fn interval(&self) -> Option<&str> {
+    self.get("Interval")
+  }
+
+  pub fn time_zone(&self) -> Option<&str> {
+    self.get("Time Zone")
+  }
+}
+
+// ─── Data points ────────────────────────────────────────────────────────────
+
+/// A single simple moving average value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmaData {
+  #[serde(rename = "SMA")]
+  pub sma: String,
+}
+
+/// A single exponential moving average value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmaData {
+  #[serde(rename = "EMA")]
+  pub ema: String,
+}
+
+/// A single average directional movement index value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdxData {
+  #[serde(rename = "ADX")]
+  pub adx: String,
+}
+
+/// A single slow stochastic data point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StochData {
+  /// Slow %K line
+  #[serde(rename = "SlowK")]
+  pub slow_k: String,
+
+  /// Slow %D line (moving average of %K)
+  #[serde(rename = "SlowD")]
+  pub slow_d: String,
+}
+
+/// A single average true range value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AtrData {
+  #[serde(rename = "ATR")]
+  pub atr: String,
+}
+
+/// A single on-balance volume value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObvData {
+  #[serde(rename = "OBV")]
+  pub obv: String,
+}
+
+/// A single volume-weighted average price value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VwapData {
+  #[serde(rename = "VWAP")]
+  pub vwap: String,
+}
+
+// ─── Response aliases ───────────────────────────────────────────────────────
+
+/// Response from the `SMA` endpoint.
+pub type SmaSeries = IndicatorSeries<SmaData>;
+/// Response from the `EMA` endpoint.
+pub type EmaSeries = IndicatorSeries<EmaData>;
+/// Response from the `RSI` endpoint.
+pub type RsiSeries = IndicatorSeries<RsiData>;
+/// Response from the `MACD` endpoint.
+pub type MacdSeries = IndicatorSeries<MacdData>;
+/// Response from the `BBANDS` endpoint.
+pub type BbandsSeries = IndicatorSeries<BollingerBandsData>;
+/// Response from the `ADX` endpoint.
+pub type AdxSeries = IndicatorSeries<AdxData>;
+/// Response from the `STOCH` endpoint.
+pub type StochSeries = IndicatorSeries<StochData>;
+/// Response from the `ATR` endpoint.
+pub type AtrSeries = IndicatorSeries<AtrData>;
+/// Response from the `OBV` endpoint.
+pub type ObvSeries = IndicatorSeries<ObvData>;
+/// Response from the `VWAP` endpoint.
+pub type VwapSeries = IndicatorSeries<VwapData>;
+
+// ─── Helper methods ─────────────────────────────────────────────────────────
+
+/// Access helpers for [`IndicatorSeries`].
+impl<T> IndicatorSeries<T> {
+  /// Returns the most recent data point (the last key in timestamp order).
+  pub fn latest(&self) -> Option<(&String, &T)> {
+    self.values.iter().next_back()
+  }
+
+  pub fn len(&self) -> usize {
+    self.values.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample_sma_json() -> &'static str {
+    r#"{
+      "Meta Data": {
+        "1: Symbol": "IBM",
+        "2: Indicator": "Simple Moving Average (SMA)",
+        "3: Last Refreshed": "2024-01-16",
+        "4: Interval": "daily",
+        "5: Time Period": 20,
+        "6: Series Type": "close",
+        "7: Time Zone": "US/Eastern"
+      },
+      "Technical Analysis: SMA": {
+        "2024-01-16": { "SMA": "163.4120" },
+        "2024-01-12": { "SMA": "162.9805" }
+      }
+    }"#
+  }
+
+  fn sample_macd_json() -> &'static str {
+    r#"{
+      "Meta Data": {
+        "1: Symbol": "IBM",
+        "2: Indicator": "Moving Average Convergence/Divergence (MACD)",
+        "3: Last Refreshed": "2024-01-16",
+        "4: Interval": "daily",
+        "5.1: Fast Period": "12",
+        "5.2: Slow Period": "26",
+        "5.3: Signal Period": "9",
+        "6: Series Type": "close",
+        "7: Time Zone": "US/Eastern Time"
+      },
+      "Technical Analysis: MACD": {
+        "2024-01-16": { "MACD": "2.1731", "MACD_Hist": "0.1120", "MACD_Signal": "2.0611" }
+      }
+    }"#
+  }
+
+  #[test]
+  fn test_sma_deserialization() {
+    let series: SmaSeries = serde_json::from_str(sample_sma_json()).unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series.meta_data.symbol(), Some("IBM"));
+    assert_eq!(series.meta_data.interval(), Some("daily"));
+    assert_eq!(series.meta_data.get("Time Period"), Some("20"));
+
+    let (date, point) = series.latest().unwrap();
+    assert_eq!(date, "2024-01-16");
+    assert_eq!(point.sma, "163.4120");
+  }
+
+  #[test]
+  fn test_macd_metadata_lookup() {
+    let series: MacdSeries = serde_json::from_str(sample_macd_json()).unwrap();
+
+    assert_eq!(series.meta_data.get("fast period"), Some("12"));
+    assert_eq!(series.meta_data.get("Signal Period"), Some("9"));
+    assert_eq!(series.meta_data.time_zone(), Some("US/Eastern Time"));
+    assert_eq!(series.values["2024-01-16"].macd_signal, "2.0611");
+  }
+
+  #[test]
+  fn test_missing_analysis_key() {
+    let json = r#"{ "Meta Data": { "1: Symbol": "IBM" } }"#;
+    assert!(serde_json::from_str::<RsiSeries>(json).is_err());
+  }
+}
@@ -53,9 +53,14 @@
 //! | Module            | Endpoint family              | Key types                                              |
 //! |-------------------|------------------------------|--------------------------------------------------------|
 //! | [`common`]        | Shared across endpoints      | `Metadata`, `OhlcvData`, `SymbolMatch`, `ApiResponse`  |
-//! | [`time_series`]   | `TIME_SERIES_*`, `SYMBOL_SEARCH`, `MARKET_STATUS`, `GLOBAL_QUOTE` | `IntradayTimeSeries`, `DailyTimeSeries`, `DailyAdjustedTimeSeries`, `SymbolSearch`, `GlobalQuote`, technical indicators |
+//! | [`time_series`]   | `TIME_SERIES_*`, `SYMBOL_SEARCH`, `MARKET_STATUS`, `GLOBAL_QUOTE`, `REALTIME_BULK_QUOTES` | `IntradayTimeSeries`, `DailyTimeSeries`, `DailyAdjustedTimeSeries`, `SymbolSearch`, `GlobalQuote`, `BulkQuotes`, technical indicators |
+//! | [`indicators`]    | `SMA`, `EMA`, `RSI`, `MACD`, `BBANDS`, `ADX`, `STOCH`, `ATR`, `OBV`, `VWAP` | `IndicatorSeries<T>`, `SmaSeries`, `MacdSeries`, `StochSeries` |
 //! | [`fundamentals`]  | `OVERVIEW`, `INCOME_STATEMENT`, `BALANCE_SHEET`, `CASH_FLOW`, `EARNINGS`, `TOP_GAINERS_LOSERS`, `LISTING_STATUS`, calendars | `CompanyOverview`, `IncomeStatement`, `BalanceSheet`, `CashFlow`, `Earnings`, `TopGainersLosers`, `ListingStatus` |
+//! | [`commodities`]   | `WTI`, `BRENT`, `NATURAL_GAS`, `COPPER`, `ALUMINUM`, `WHEAT`, `CORN`, `COTTON`, `SUGAR`, `COFFEE`, `ALL_COMMODITIES` | `CommoditySeries`, `CommodityDataPoint` |
+//! | [`economics`]     | `REAL_GDP`, `CPI`, `INFLATION`, `TREASURY_YIELD`, `FEDERAL_FUNDS_RATE`, `UNEMPLOYMENT`, `NONFARM_PAYROLL` | `EconomicSeries`, `EconomicDataPoint` |
 //! | [`news`]          | `NEWS_SENTIMENT`             | `NewsSentiment`, `NewsArticle`, `TickerSentiment`, `SentimentTrend` |
+//! | [`options`]       | `REALTIME_OPTIONS`, `HISTORICAL_OPTIONS` | `OptionChain`, `OptionContract`, `OptionGreeks` |
+//! | [`analytics`]     | `ANALYTICS_FIXED_WINDOW`, `ANALYTICS_SLIDING_WINDOW` | `ReturnsAnalytics`, `CalculationResult`, `ReturnsMatrix` |
 //! | [`forex`]         | `CURRENCY_EXCHANGE_RATE`, `FX_*` | `ExchangeRate`, `FxIntraday`, `FxDaily`, `CurrencyPair` |
 //! | [`crypto`]        | `CRYPTO_*`, `DIGITAL_CURRENCY_*` | `CryptoExchangeRate`, `CryptoIntraday`, `CryptoDaily`  |
 //! | [`crypto_social`] | CoinGecko social/developer API | `CoinGeckoSocialResponse`, `ProcessedSocialData`       |
@@ -90,6 +95,20 @@
 
 #![warn(clippy::all)]
 
+/// Server-side returns analytics models.
+///
+/// Covers `ANALYTICS_FIXED_WINDOW` and `ANALYTICS_SLIDING_WINDOW`. Key
+/// types: [`ReturnsAnalytics`], [`CalculationResult`], [`ReturnsMatrix`],
+/// [`MaxDrawdown`], [`ReturnsHistogram`].
+pub mod analytics;
+
+/// Commodity price models.
+///
+/// Covers the energy, metals, and agricultural commodity endpoints plus
+/// `ALL_COMMODITIES`. [`CommoditySeries`] aliases [`EconomicSeries`], which
+/// has the same response shape.
+pub mod commodities;
+
 /// Common types shared across all API response families.
 ///
 /// Includes [`Metadata`], OHLCV data structs ([`OhlcvData`],
@@ -114,6 +133,14 @@ pub mod crypto;
 /// that normalizes this data for database storage.
 pub mod crypto_social;
 
+/// US economic indicator models.
+///
+/// Covers `REAL_GDP`, `CPI`, `INFLATION`, `TREASURY_YIELD`,
+/// `FEDERAL_FUNDS_RATE`, `UNEMPLOYMENT`, and `NONFARM_PAYROLL`, which all
+/// share the [`EconomicSeries`] response shape of dated
+/// [`EconomicDataPoint`] observations.
+pub mod economics;
+
 /// Foreign exchange (forex) data models.
 ///
 /// Covers `CURRENCY_EXCHANGE_RATE` and `FX_INTRADAY/DAILY/WEEKLY/MONTHLY`.
@@ -129,6 +156,13 @@ pub mod forex;
 /// [`BalanceSheet`], [`CashFlow`], [`Earnings`], [`TopGainersLosers`].
 pub mod fundamentals;
 
+/// Typed technical indicator models.
+///
+/// Covers `SMA`, `EMA`, `RSI`, `MACD`, `BBANDS`, `ADX`, `STOCH`, `ATR`,
+/// `OBV`, and `VWAP`. Key types: [`IndicatorSeries`], [`IndicatorMetadata`],
+/// and one response alias per indicator (e.g., [`SmaSeries`]).
+pub mod indicators;
+
 /// News sentiment analysis models.
 ///
 /// Covers the `NEWS_SENTIMENT` endpoint. Key types: [`NewsSentiment`],
@@ -136,13 +170,20 @@ pub mod fundamentals;
 /// [`SentimentDistribution`].
 pub mod news;
 
+/// Option chain models.
+///
+/// Covers `REALTIME_OPTIONS` and `HISTORICAL_OPTIONS`. Key types:
+/// [`OptionChain`], [`OptionContract`], [`OptionGreeks`].
+pub mod options;
+
 /// Stock time-series, search, market status, and technical indicator models.
 ///
 /// Covers `TIME_SERIES_INTRADAY/DAILY/WEEKLY/MONTHLY` (with adjusted
-/// variants), `SYMBOL_SEARCH`, `MARKET_STATUS`, `GLOBAL_QUOTE`, and
-/// technical indicators (SMA, RSI, MACD, Bollinger Bands). Key types:
-/// [`IntradayTimeSeries`], [`DailyTimeSeries`], [`DailyAdjustedTimeSeries`],
-/// [`SymbolSearch`], [`GlobalQuote`], [`TechnicalIndicator`].
+/// variants), `SYMBOL_SEARCH`, `MARKET_STATUS`, `GLOBAL_QUOTE`,
+/// `REALTIME_BULK_QUOTES`, and technical indicators (SMA, RSI, MACD,
+/// Bollinger Bands). Key types: [`IntradayTimeSeries`], [`DailyTimeSeries`],
+/// [`DailyAdjustedTimeSeries`], [`SymbolSearch`], [`GlobalQuote`],
+/// [`BulkQuotes`], [`TechnicalIndicator`].
 pub mod time_series;
 
 // ─── Glob re-exports ────────────────────────────────────────────────────────
@@ -151,10 +192,15 @@ pub mod time_series;
 // so consumers can write `use av_models::DailyTimeSeries` without module
 // qualification. The sub-modules remain available for explicit imports.
 
+pub use analytics::*;
+pub use commodities::*;
 pub use common::*;
 pub use crypto::*;
 pub use crypto_social::*;
+pub use economics::*;
 pub use forex::*;
 pub use fundamentals::*;
+pub use indicators::*;
 pub use news::*;
+pub use options::*;
 pub use time_series::*;
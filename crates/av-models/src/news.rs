@@ -470,7 +470,7 @@ impl NewsSentiment {
       })
       .collect();
 
-    ticker_mentions.sort_by(|a, b| b.mention_count.cmp(&a.mention_count));
+    ticker_mentions.sort_by_key(|m| std::cmp::Reverse(m.mention_count));
     ticker_mentions.truncate(limit);
     ticker_mentions
   }
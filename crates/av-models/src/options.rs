@@ -0,0 +1,338 @@
+/*
+ *
+ *
+ *
+ *
+ * MIT License
+ * Copyright (c) 2025. Dwight J. Browne
+ * dwight[-at-]dwightjbrowne[-dot-]com
+ *
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Option chain models.
+//!
+//! Both option endpoints return an envelope with an `endpoint` label, a
+//! `message` status, and a flat `data` array with one entry per contract.
+//! Contracts are identified by their OCC symbol (`contractID`, e.g.
+//! `IBM270115C00390000`); `av_core::types::market::OccSymbol` parses it.
+//!
+//! # Endpoint mapping
+//!
+//! | Endpoint             | Parameters                                   | Greeks / IV            |
+//! |----------------------|----------------------------------------------|------------------------|
+//! | `REALTIME_OPTIONS`   | `symbol`, `require_greeks`, `contract`       | only when requested    |
+//! | `HISTORICAL_OPTIONS` | `symbol`, `date` (defaults to last session)  | always                 |
+//!
+//! # Numeric fields
+//!
+//! Every numeric field arrives as a JSON string. The `*_f64` / `*_i64`
+//! helpers parse them, returning `None` for empty or malformed values.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+// ─── Response container ─────────────────────────────────────────────────────
+
+/// Response from `REALTIME_OPTIONS` or `HISTORICAL_OPTIONS`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionChain {
+  /// Endpoint label (e.g., `"Historical Options"`)
+  #[serde(default)]
+  pub endpoint: String,
+
+  /// Status message (`"success"` when contracts were returned)
+  #[serde(default)]
+  pub message: String,
+
+  /// One entry per contract; empty for non-trading days
+  #[serde(default)]
+  pub data: Vec<OptionContract>,
+}
+
+/// A single option contract quote within an [`OptionChain`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionContract {
+  /// OCC contract symbol
+  #[serde(rename = "contractID")]
+  pub contract_id: String,
+
+  /// Underlying symbol
+  pub symbol: String,
+
+  /// Expiration date in `YYYY-MM-DD` format
+  pub expiration: String,
+
+  /// Strike price
+  pub strike: String,
+
+  /// `"call"` or `"put"`
+  #[serde(rename = "type")]
+  pub option_type: String,
+
+  pub last: String,
+  pub mark: String,
+  pub bid: String,
+  pub bid_size: String,
+  pub ask: String,
+  pub ask_size: String,
+  pub volume: String,
+  pub open_interest: String,
+
+  /// Quote date in `YYYY-MM-DD` format
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub date: Option<String>,
+
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub implied_volatility: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub delta: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub gamma: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub theta: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub vega: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub rho: Option<String>,
+}
+
+/// Parsed Greeks for an [`OptionContract`]; each is `None` when absent.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OptionGreeks {
+  pub delta: Option<f64>,
+  pub gamma: Option<f64>,
+  pub theta: Option<f64>,
+  pub vega: Option<f64>,
+  pub rho: Option<f64>,
+}
+
+// ─── Helper methods ─────────────────────────────────────────────────────────
+
+fn parse_f64(value: &str) -> Option<f64> {
+  value.trim().parse::<f64>().ok()
+}
+
+fn parse_i64(value: &str) -> Option<i64> {
+  let value = value.trim();
+  value.parse::<i64>().ok().or_else(|| value.parse::<f64>().ok().map(|v| v as i64))
+}
+
+impl OptionContract {
+  pub fn is_call(&self) -> bool {
+    self.option_type.eq_ignore_ascii_case("call")
+  }
+
+  pub fn is_put(&self) -> bool {
+    self.option_type.eq_ignore_ascii_case("put")
+  }
+
+  /// Parses [`expiration`](Self::expiration) as a calendar date.
+  pub fn expiration_date(&self) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&self.expiration, "%Y-%m-%d").ok()
+  }
+
+  /// Parses [`date`](Self::date) as a calendar date.
+  pub fn quote_date(&self) -> Option<NaiveDate> {
+    self.date.as_deref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+  }
+
+  pub fn strike_f64(&self) -> Option<f64> {
+    parse_f64(&self.strike)
+  }
+
+  pub fn last_f64(&self) -> Option<f64> {
+    parse_f64(&self.last)
+  }
+
+  pub fn mark_f64(&self) -> Option<f64> {
+    parse_f64(&self.mark)
+  }
+
+  pub fn bid_f64(&self) -> Option<f64> {
+    parse_f64(&self.bid)
+  }
+
+  pub fn ask_f64(&self) -> Option<f64> {
+    parse_f64(&self.ask)
+  }
+
+  pub fn bid_size_i64(&self) -> Option<i64> {
+    parse_i64(&self.bid_size)
+  }
+
+  pub fn ask_size_i64(&self) -> Option<i64> {
+    parse_i64(&self.ask_size)
+  }
+
+  pub fn volume_i64(&self) -> Option<i64> {
+    parse_i64(&self.volume)
+  }
+
+  pub fn open_interest_i64(&self) -> Option<i64> {
+    parse_i64(&self.open_interest)
+  }
+
+  pub fn implied_volatility_f64(&self) -> Option<f64> {
+    self.implied_volatility.as_deref().and_then(parse_f64)
+  }
+
+  /// Parses the Greeks. All are `None` for realtime quotes fetched without
+  /// `require_greeks`.
+  pub fn greeks(&self) -> OptionGreeks {
+    let parse = |value: &Option<String>| value.as_deref().and_then(parse_f64);
+    OptionGreeks {
+      delta: parse(&self.delta),
+      gamma: parse(&self.gamma),
+      theta: parse(&self.theta),
+      vega: parse(&self.vega),
+      rho: parse(&self.rho),
+    }
+  }
+}
+
+/// Access helpers for [`OptionChain`].
+impl OptionChain {
+  pub fn calls(&self) -> impl Iterator<Item = &OptionContract> {
+    self.data.iter().filter(|c| c.is_call())
+  }
+
+  pub fn puts(&self) -> impl Iterator<Item = &OptionContract> {
+    self.data.iter().filter(|c| c.is_put())
+  }
+
+  /// Distinct expiration dates in the chain, ascending.
+  pub fn expirations(&self) -> Vec<NaiveDate> {
+    let mut dates: Vec<NaiveDate> =
+      self.data.iter().filter_map(OptionContract::expiration_date).collect();
+    dates.sort_unstable();
+    dates.dedup();
+    dates
+  }
+
+  pub fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample_historical_json() -> &'static str {
+    r#"{
+      "endpoint": "Historical Options",
+      "message": "success",
+      "data": [
+        {
+          "contractID": "IBM240119C00100000",
+          "symbol": "IBM",
+          "expiration": "2024-01-19",
+          "strike": "100.00",
+          "type": "call",
+          "last": "62.45",
+          "mark": "62.73",
+          "bid": "61.90",
+          "bid_size": "17",
+          "ask": "63.55",
+          "ask_size": "17",
+          "volume": "0",
+          "open_interest": "12",
+          "date": "2023-11-17",
+          "implied_volatility": "1.27402",
+          "delta": "0.99723",
+          "gamma": "0.00048",
+          "theta": "-0.05093",
+          "vega": "0.00297",
+          "rho": "0.06232"
+        },
+        {
+          "contractID": "IBM240119P00100000",
+          "symbol": "IBM",
+          "expiration": "2024-01-19",
+          "strike": "100.00",
+          "type": "put",
+          "last": "0.01",
+          "mark": "0.02",
+          "bid": "0.00",
+          "bid_size": "0",
+          "ask": "0.04",
+          "ask_size": "40",
+          "volume": "0",
+          "open_interest": "2136",
+          "date": "2023-11-17",
+          "implied_volatility": "0.68432",
+          "delta": "-0.00128",
+          "gamma": "0.00013",
+          "theta": "-0.00306",
+          "vega": "0.00061",
+          "rho": "-0.00015"
+        }
+      ]
+    }"#
+  }
+
+  #[test]
+  fn test_historical_chain_deserialization() {
+    let chain: OptionChain = serde_json::from_str(sample_historical_json()).unwrap();
+
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.calls().count(), 1);
+    assert_eq!(chain.puts().count(), 1);
+    assert_eq!(chain.expirations(), vec![NaiveDate::from_ymd_opt(2024, 1, 19).unwrap()]);
+
+    let call = &chain.data[0];
+    assert_eq!(call.contract_id, "IBM240119C00100000");
+    assert_eq!(call.strike_f64(), Some(100.0));
+    assert_eq!(call.open_interest_i64(), Some(12));
+    assert_eq!(call.quote_date(), NaiveDate::from_ymd_opt(2023, 11, 17));
+    assert_eq!(call.implied_volatility_f64(), Some(1.27402));
+    assert_eq!(call.greeks().delta, Some(0.99723));
+  }
+
+  #[test]
+  fn test_realtime_contract_without_greeks() {
+    let json = r#"{
+      "contractID": "IBM270115C00390000",
+      "symbol": "IBM",
+      "expiration": "2027-01-15",
+      "strike": "390.00",
+      "type": "call",
+      "last": "0.00",
+      "mark": "0.00",
+      "bid": "0.00",
+      "bid_size": "0",
+      "ask": "0.00",
+      "ask_size": "0",
+      "volume": "0",
+      "open_interest": "0"
+    }"#;
+
+    let contract: OptionContract = serde_json::from_str(json).unwrap();
+    assert!(contract.is_call());
+    assert_eq!(contract.quote_date(), None);
+    assert_eq!(contract.implied_volatility_f64(), None);
+    assert_eq!(contract.greeks(), OptionGreeks::default());
+  }
+}
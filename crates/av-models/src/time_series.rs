@@ -54,6 +54,7 @@
 //! | `SYMBOL_SEARCH`  | [`SymbolSearch`]     | Keyword search for tickers               |
 //! | `MARKET_STATUS`  | [`MarketStatus`]     | Global exchange open/closed status       |
 //! | `GLOBAL_QUOTE`   | [`GlobalQuote`]      | Real-time quote with change data         |
+//! | `REALTIME_BULK_QUOTES` | [`BulkQuotes`] | Quotes for up to 100 symbols per call    |
 //! | Technical APIs   | [`TechnicalIndicator`] | Generic container for SMA, RSI, MACD, etc. |
 //!
 //! # Technical indicator data points
@@ -295,6 +296,84 @@ pub struct QuoteData {
   pub change_percent: String,
 }
 
+/// Response from the `REALTIME_BULK_QUOTES` endpoint.
+///
+/// Unlike `GLOBAL_QUOTE`, entries use plain snake_case keys. Symbols the API
+/// does not recognize are silently omitted from `data`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkQuotes {
+  /// Endpoint label (e.g., `"Realtime Bulk Quotes"`)
+  #[serde(default)]
+  pub endpoint: String,
+
+  /// Status or informational message
+  #[serde(default)]
+  pub message: String,
+
+  /// One entry per recognized symbol
+  #[serde(default)]
+  pub data: Vec<BulkQuoteData>,
+}
+
+/// A single entry of a [`BulkQuotes`] response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkQuoteData {
+  pub symbol: String,
+
+  /// Quote time, `YYYY-MM-DD HH:MM:SS.fff` (US/Eastern)
+  pub timestamp: String,
+
+  pub open: String,
+  pub high: String,
+  pub low: String,
+
+  /// Latest price
+  pub close: String,
+
+  pub volume: String,
+  pub previous_close: String,
+  pub change: String,
+
+  /// Change percentage, without a `%` suffix
+  pub change_percent: String,
+
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub extended_hours_quote: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub extended_hours_change: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub extended_hours_change_percent: Option<String>,
+}
+
+/// Converts a bulk entry to the `GLOBAL_QUOTE` shape so both endpoints can
+/// feed the same code paths. The extended-hours fields are dropped.
+impl From<BulkQuoteData> for GlobalQuote {
+  fn from(bulk: BulkQuoteData) -> Self {
+    let latest_trading_day =
+      bulk.timestamp.split_whitespace().next().unwrap_or_default().to_string();
+    let change_percent = if bulk.change_percent.ends_with('%') {
+      bulk.change_percent
+    } else {
+      format!("{}%", bulk.change_percent)
+    };
+
+    GlobalQuote {
+      global_quote: QuoteData {
+        symbol: bulk.symbol,
+        open: bulk.open,
+        high: bulk.high,
+        low: bulk.low,
+        price: bulk.close,
+        volume: bulk.volume,
+        latest_trading_day,
+        previous_close: bulk.previous_close,
+        change: bulk.change,
+        change_percent,
+      },
+    }
+  }
+}
+
 // ─── Technical indicators ───────────────────────────────────────────────────
 
 /// Generic response for Alpha Vantage technical indicator endpoints
@@ -488,4 +567,39 @@ mod tests {
     assert_eq!(quote.change_as_f64().unwrap(), 1.5);
     assert_eq!(quote.change_percent_as_f64().unwrap(), 0.99);
   }
+
+  #[test]
+  fn test_bulk_quote_to_global_quote() {
+    let json = r#"{
+      "endpoint": "Realtime Bulk Quotes",
+      "message": "",
+      "data": [
+        {
+          "symbol": "MSFT",
+          "timestamp": "2024-01-16 16:15:50.000",
+          "open": "393.6600",
+          "high": "394.0300",
+          "low": "387.6200",
+          "close": "390.2700",
+          "volume": "27202256",
+          "previous_close": "388.4700",
+          "change": "1.8000",
+          "change_percent": "0.4634",
+          "extended_hours_quote": "390.5000",
+          "extended_hours_change": "0.2300",
+          "extended_hours_change_percent": "0.0589"
+        }
+      ]
+    }"#;
+
+    let bulk: BulkQuotes = serde_json::from_str(json).unwrap();
+    assert_eq!(bulk.data.len(), 1);
+
+    let quote: GlobalQuote = bulk.data.into_iter().next().unwrap().into();
+    let data = &quote.global_quote;
+    assert_eq!(data.symbol, "MSFT");
+    assert_eq!(data.latest_trading_day, "2024-01-16");
+    assert_eq!(data.price_as_f64().unwrap(), 390.27);
+    assert_eq!(data.change_percent_as_f64().unwrap(), 0.4634);
+  }
 }
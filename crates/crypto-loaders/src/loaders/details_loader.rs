@@ -446,7 +446,7 @@ impl CoinGeckoDetailsLoader {
     let http_client = Arc::new(http_client);
 
     // Process coins concurrently
-    let results = stream::iter(coins.into_iter())
+    let results = stream::iter(coins)
       .map(|coin_info| {
         let client = http_client.clone();
         let semaphore = self.semaphore.clone();